@@ -61,6 +61,33 @@ impl TwoAdicData for BabyBearParameters {
     ]);
 }
 
+impl BinomialExtensionData<2> for BabyBearParameters {
+    const W: BabyBear = BabyBear::new(11);
+    const DTH_ROOT: BabyBear = BabyBear::new(2013265920);
+    const EXT_GENERATOR: [BabyBear; 2] = BabyBear::new_array([13, 1]);
+    const EXT_TWO_ADICITY: usize = 28;
+
+    type ArrayLike = [[BabyBear; 2]; 1];
+    const TWO_ADIC_EXTENSION_GENERATORS: Self::ArrayLike =
+        BabyBear::new_2d_array([[0, 1996171314]]);
+}
+
+impl BinomialExtensionData<3> for BabyBearParameters {
+    const W: BabyBear = BabyBear::new(2);
+
+    #[inline(always)]
+    fn mul_w<A: Algebra<MontyField31<Self>>>(a: A) -> A {
+        a.double()
+    }
+
+    const DTH_ROOT: BabyBear = BabyBear::new(1314723123);
+    const EXT_GENERATOR: [BabyBear; 3] = BabyBear::new_array([9, 1, 0]);
+    const EXT_TWO_ADICITY: usize = 27;
+
+    type ArrayLike = [[BabyBear; 3]; 0];
+    const TWO_ADIC_EXTENSION_GENERATORS: Self::ArrayLike = [];
+}
+
 impl BinomialExtensionData<4> for BabyBearParameters {
     const W: BabyBear = BabyBear::new(11);
     const DTH_ROOT: BabyBear = BabyBear::new(1728404513);
@@ -88,6 +115,17 @@ impl BinomialExtensionData<5> for BabyBearParameters {
     const TWO_ADIC_EXTENSION_GENERATORS: Self::ArrayLike = [];
 }
 
+impl BinomialExtensionData<6> for BabyBearParameters {
+    const W: BabyBear = BabyBear::new(22);
+    const DTH_ROOT: BabyBear = BabyBear::new(1314723124);
+    const EXT_GENERATOR: [BabyBear; 6] = BabyBear::new_array([6, 1, 0, 0, 0, 0]);
+    const EXT_TWO_ADICITY: usize = 28;
+
+    type ArrayLike = [[BabyBear; 6]; 1];
+    const TWO_ADIC_EXTENSION_GENERATORS: Self::ArrayLike =
+        BabyBear::new_2d_array([[0, 0, 0, 615027702, 0, 0]]);
+}
+
 impl BinomialExtensionData<8> for BabyBearParameters {
     const W: BabyBear = BabyBear::new(11);
     const DTH_ROOT: BabyBear = BabyBear::new(420899707);
@@ -110,8 +148,8 @@ mod tests {
     use p3_field::extension::BinomialExtensionField;
     use p3_field::{InjectiveMonomial, PermutationMonomial, PrimeField64, TwoAdicField};
     use p3_field_testing::{
-        test_field, test_field_dft, test_field_dft_large, test_prime_field, test_prime_field_32,
-        test_prime_field_64, test_two_adic_field,
+        test_constant_time_field, test_field, test_field_dft, test_field_dft_large,
+        test_prime_field, test_prime_field_32, test_prime_field_64, test_two_adic_field,
     };
 
     use super::*;
@@ -219,6 +257,12 @@ mod tests {
         p3_dft::Radix2DFTSmallBatch<_>
     );
     test_field_dft!(bowers, crate::BabyBear, super::EF, p3_dft::Radix2Bowers);
+    test_field_dft!(
+        four_step,
+        crate::BabyBear,
+        super::EF,
+        p3_dft::FourStepDft<p3_dft::Radix2DFTSmallBatch<_>>
+    );
     test_field_dft!(
         parallel,
         crate::BabyBear,
@@ -240,5 +284,6 @@ mod tests {
     );
     test_prime_field!(crate::BabyBear);
     test_prime_field_64!(crate::BabyBear, &super::ZEROS, &super::ONES);
+    test_constant_time_field!(crate::BabyBear, &super::ZEROS, &super::ONES);
     test_prime_field_32!(crate::BabyBear, &super::ZEROS, &super::ONES);
 }
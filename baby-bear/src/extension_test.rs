@@ -1,3 +1,95 @@
+#[cfg(test)]
+mod test_quadratic_extension {
+    use num_bigint::BigUint;
+    use p3_field::extension::BinomialExtensionField;
+    use p3_field::{ExtensionField, PrimeCharacteristicRing};
+    use p3_field_testing::{
+        test_extension_field, test_field, test_packed_extension_field,
+        test_two_adic_extension_field,
+    };
+
+    use crate::BabyBear;
+
+    type F = BabyBear;
+    type EF = BinomialExtensionField<F, 2>;
+
+    // MontyField31's have no redundant representations.
+    const ZEROS: [EF; 1] = [EF::ZERO];
+    const ONES: [EF; 1] = [EF::ONE];
+
+    // Get the prime factorization of the order of the multiplicative group.
+    // i.e. the prime factorization of P^2 - 1.
+    fn multiplicative_group_prime_factorization() -> [(BigUint, u32); 5] {
+        [
+            (BigUint::from(2u8), 28),
+            (BigUint::from(3u8), 1),
+            (BigUint::from(5u8), 1),
+            (BigUint::from(31u8), 1),
+            (BigUint::from(32472031u32), 1),
+        ]
+    }
+
+    test_field!(
+        super::EF,
+        &super::ZEROS,
+        &super::ONES,
+        &super::multiplicative_group_prime_factorization()
+    );
+    test_extension_field!(super::F, super::EF);
+    test_two_adic_extension_field!(super::F, super::EF);
+
+    type Pef = <EF as ExtensionField<F>>::ExtensionPacking;
+    const PACKED_ZEROS: [Pef; 1] = [Pef::ZERO];
+    const PACKED_ONES: [Pef; 1] = [Pef::ONE];
+    test_packed_extension_field!(super::Pef, &super::PACKED_ZEROS, &super::PACKED_ONES);
+}
+
+#[cfg(test)]
+mod test_cubic_extension {
+    use num_bigint::BigUint;
+    use p3_field::extension::BinomialExtensionField;
+    use p3_field::{ExtensionField, PrimeCharacteristicRing};
+    use p3_field_testing::{
+        test_extension_field, test_field, test_packed_extension_field,
+        test_two_adic_extension_field,
+    };
+
+    use crate::BabyBear;
+
+    type F = BabyBear;
+    type EF = BinomialExtensionField<F, 3>;
+
+    // MontyField31's have no redundant representations.
+    const ZEROS: [EF; 1] = [EF::ZERO];
+    const ONES: [EF; 1] = [EF::ONE];
+
+    // Get the prime factorization of the order of the multiplicative group.
+    // i.e. the prime factorization of P^3 - 1.
+    fn multiplicative_group_prime_factorization() -> [(BigUint, u32); 5] {
+        [
+            (BigUint::from(2u8), 27),
+            (BigUint::from(3u8), 2),
+            (BigUint::from(5u8), 1),
+            (BigUint::from(7u8), 3),
+            (BigUint::from(3939008426310247u64), 1),
+        ]
+    }
+
+    test_field!(
+        super::EF,
+        &super::ZEROS,
+        &super::ONES,
+        &super::multiplicative_group_prime_factorization()
+    );
+    test_extension_field!(super::F, super::EF);
+    test_two_adic_extension_field!(super::F, super::EF);
+
+    type Pef = <EF as ExtensionField<F>>::ExtensionPacking;
+    const PACKED_ZEROS: [Pef; 1] = [Pef::ZERO];
+    const PACKED_ONES: [Pef; 1] = [Pef::ONE];
+    test_packed_extension_field!(super::Pef, &super::PACKED_ZEROS, &super::PACKED_ONES);
+}
+
 #[cfg(test)]
 mod test_quartic_extension {
     use alloc::format;
@@ -111,6 +203,59 @@ mod test_quintic_extension {
     test_packed_extension_field!(super::Pef, &super::PACKED_ZEROS, &super::PACKED_ONES);
 }
 
+#[cfg(test)]
+mod test_sextic_extension {
+    use num_bigint::BigUint;
+    use p3_field::extension::BinomialExtensionField;
+    use p3_field::{ExtensionField, PrimeCharacteristicRing};
+    use p3_field_testing::{
+        test_extension_field, test_field, test_packed_extension_field,
+        test_two_adic_extension_field,
+    };
+
+    use crate::BabyBear;
+
+    type F = BabyBear;
+    type EF = BinomialExtensionField<F, 6>;
+
+    // MontyField31's have no redundant representations.
+    const ZEROS: [EF; 1] = [EF::ZERO];
+    const ONES: [EF; 1] = [EF::ONE];
+
+    // Get the prime factorization of the order of the multiplicative group.
+    // i.e. the prime factorization of P^6 - 1.
+    fn multiplicative_group_prime_factorization() -> [(BigUint, u32); 12] {
+        [
+            (BigUint::from(2u8), 28),
+            (BigUint::from(3u8), 2),
+            (BigUint::from(5u8), 1),
+            (BigUint::from(7u8), 3),
+            (BigUint::from(13u8), 1),
+            (BigUint::from(31u8), 1),
+            (BigUint::from(1171u16), 1),
+            (BigUint::from(7459u16), 1),
+            (BigUint::from(103237u32), 1),
+            (BigUint::from(345769u32), 1),
+            (BigUint::from(32472031u32), 1),
+            (BigUint::from(3939008426310247u64), 1),
+        ]
+    }
+
+    test_field!(
+        super::EF,
+        &super::ZEROS,
+        &super::ONES,
+        &super::multiplicative_group_prime_factorization()
+    );
+    test_extension_field!(super::F, super::EF);
+    test_two_adic_extension_field!(super::F, super::EF);
+
+    type Pef = <EF as ExtensionField<F>>::ExtensionPacking;
+    const PACKED_ZEROS: [Pef; 1] = [Pef::ZERO];
+    const PACKED_ONES: [Pef; 1] = [Pef::ONE];
+    test_packed_extension_field!(super::Pef, &super::PACKED_ZEROS, &super::PACKED_ONES);
+}
+
 #[cfg(test)]
 mod test_octic_extension {
     use alloc::format;
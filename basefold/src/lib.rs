@@ -0,0 +1,18 @@
+//! The Basefold multilinear polynomial commitment scheme.
+//!
+//! Basefold commits to a multilinear polynomial by encoding its coefficients with a Reed-Solomon
+//! code, and opens it by interleaving a sumcheck with FRI folding of the codeword, using the same
+//! challenge for each sumcheck round and the corresponding fold. See
+//! [Basefold](https://eprint.iacr.org/2023/1705).
+
+#![no_std]
+
+extern crate alloc;
+
+mod pcs;
+mod proof;
+pub mod prover;
+pub mod verifier;
+
+pub use pcs::*;
+pub use proof::*;
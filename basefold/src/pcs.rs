@@ -0,0 +1,212 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use p3_challenger::{CanObserve, FieldChallenger, GrindingChallenger};
+use p3_commit::{Mmcs, MultilinearPcs, OpenedValues};
+use p3_dft::TwoAdicSubgroupDft;
+use p3_field::{ExtensionField, Field, TwoAdicField};
+use p3_fri::FriParameters;
+use p3_matrix::Matrix;
+use p3_matrix::bitrev::BitReversibleMatrix;
+use p3_matrix::dense::{RowMajorMatrix, RowMajorMatrixView};
+use p3_matrix::util::reverse_matrix_index_bits;
+use p3_maybe_rayon::prelude::*;
+use p3_util::log2_strict_usize;
+use tracing::instrument;
+
+use crate::verifier::verify;
+use crate::{BasefoldError, BasefoldProof, prover};
+
+/// The Basefold polynomial commitment scheme for multilinear polynomials.
+///
+/// A multilinear polynomial `P(x_0, ..., x_{n-1})` is identified with the univariate polynomial
+/// `f(X) = P(X, X^2, X^4, ..., X^{2^{n-1}})` of degree less than `2^n`, i.e. the coefficient of
+/// `X^i` in `f` is the coefficient of the monomial `\prod_{j : i_j = 1} x_j` in `P`. We commit to
+/// `P` via the evaluations of `f` over the subgroup of order `2^{n + log_blowup}`, in bit-reversed
+/// order.
+///
+/// A FRI fold of the codeword of `f` with challenge `r` gives the codeword of
+/// `f_e(Y) + r f_o(Y) = P(r, Y, Y^2, ...)`, so folding with `r_0, ..., r_{n-1}` leaves the constant
+/// `P(r_0, ..., r_{n-1})`. An opening runs a sumcheck reducing the claimed evaluations to this
+/// value, sampling each round's challenge after the codeword it folds has been committed.
+///
+/// All polynomials opened together must have the same number of variables. As in `CirclePcs`,
+/// FRI early stopping is not implemented, so `fri.log_final_poly_len` must be zero.
+#[derive(Debug)]
+pub struct BasefoldPcs<Val, Dft, InputMmcs, FriMmcs> {
+    pub(crate) dft: Dft,
+    pub(crate) mmcs: InputMmcs,
+    pub(crate) fri: FriParameters<FriMmcs>,
+    _phantom: PhantomData<Val>,
+}
+
+impl<Val, Dft, InputMmcs, FriMmcs> BasefoldPcs<Val, Dft, InputMmcs, FriMmcs> {
+    pub const fn new(dft: Dft, mmcs: InputMmcs, fri: FriParameters<FriMmcs>) -> Self {
+        assert!(
+            fri.log_final_poly_len == 0,
+            "Basefold folds down to a constant"
+        );
+        Self {
+            dft,
+            mmcs,
+            fri,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// The data the prover keeps for a Basefold commitment.
+pub struct BasefoldProverData<Val: Field, InputMmcs: Mmcs<Val>> {
+    /// The committed matrices of evaluations over the hypercube.
+    pub(crate) evals: Vec<RowMajorMatrix<Val>>,
+    /// The Merkle tree over the bit-reversed codewords of each matrix.
+    pub(crate) codewords: InputMmcs::ProverData<RowMajorMatrix<Val>>,
+}
+
+/// Convert the evaluations over the hypercube of each column of `evals` into the coefficients of
+/// the univariate polynomial it is identified with.
+fn evals_to_coeffs<F: Field>(mut evals: RowMajorMatrix<F>) -> RowMajorMatrix<F> {
+    let width = evals.width();
+    let log_height = log2_strict_usize(evals.height());
+
+    // The Möbius transform turns evaluations into coefficients in the monomial basis. The
+    // coefficient of `\prod_{j in S} x_j` ends up at the row whose big-endian bits are `S`.
+    for bit in 0..log_height {
+        let half = width << bit;
+        evals
+            .values
+            .par_chunks_exact_mut(2 * half)
+            .for_each(|chunk| {
+                let (lo, hi) = chunk.split_at_mut(half);
+                hi.iter_mut().zip(lo.iter()).for_each(|(h, &l)| *h -= l);
+            });
+    }
+
+    // The coefficient of `X^i` is that of the monomial with the little-endian bits of `i`.
+    reverse_matrix_index_bits(&mut evals);
+    evals
+}
+
+impl<Val, Dft, InputMmcs, FriMmcs, Challenge, Challenger> MultilinearPcs<Val, Challenge, Challenger>
+    for BasefoldPcs<Val, Dft, InputMmcs, FriMmcs>
+where
+    Val: TwoAdicField,
+    Dft: TwoAdicSubgroupDft<Val>,
+    InputMmcs: Mmcs<Val>,
+    FriMmcs: Mmcs<Challenge>,
+    Challenge: ExtensionField<Val>,
+    Challenger:
+        FieldChallenger<Val> + CanObserve<FriMmcs::Commitment> + GrindingChallenger<Witness = Val>,
+{
+    type Commitment = InputMmcs::Commitment;
+    type ProverData = BasefoldProverData<Val, InputMmcs>;
+    type Proof = BasefoldProof<Val, Challenge, InputMmcs, FriMmcs, Val>;
+    type Error = BasefoldError<FriMmcs::Error, InputMmcs::Error>;
+
+    /// Commit to a collection of evaluation matrices.
+    ///
+    /// Each column of a matrix of height `2^n` is turned into the coefficients of a univariate
+    /// polynomial of degree less than `2^n`, which is then evaluated over the subgroup of order
+    /// `2^{n + fri.log_blowup}`. This outputs a Merkle commitment to the bit-reversed codewords.
+    #[instrument(name = "commit to multilinears", skip_all)]
+    fn commit(
+        &self,
+        evaluations: Vec<RowMajorMatrix<Val>>,
+    ) -> (Self::Commitment, Self::ProverData) {
+        let codewords = evaluations
+            .iter()
+            .map(|evals| {
+                let mut coeffs = evals_to_coeffs(evals.clone());
+                coeffs
+                    .values
+                    .resize(coeffs.values.len() << self.fri.log_blowup, Val::ZERO);
+                self.dft
+                    .dft_batch(coeffs)
+                    .bit_reverse_rows()
+                    .to_row_major_matrix()
+            })
+            .collect();
+        let (commitment, codewords) = self.mmcs.commit(codewords);
+        (
+            commitment,
+            BasefoldProverData {
+                evals: evaluations,
+                codewords,
+            },
+        )
+    }
+
+    fn get_evaluations<'a>(
+        &self,
+        prover_data: &'a Self::ProverData,
+        idx: usize,
+    ) -> RowMajorMatrixView<'a, Val> {
+        prover_data.evals[idx].as_view()
+    }
+
+    fn open(
+        &self,
+        commitment_data_with_opening_points: Vec<(&Self::ProverData, Vec<Vec<Vec<Challenge>>>)>,
+        challenger: &mut Challenger,
+    ) -> (OpenedValues<Challenge>, Self::Proof) {
+        prover::open(
+            &self.fri,
+            &self.mmcs,
+            &commitment_data_with_opening_points,
+            challenger,
+        )
+    }
+
+    fn verify(
+        &self,
+        commitments_with_opening_points: Vec<(
+            Self::Commitment,
+            Vec<(usize, Vec<(Vec<Challenge>, Vec<Challenge>)>)>,
+        )>,
+        proof: &Self::Proof,
+        challenger: &mut Challenger,
+    ) -> Result<(), Self::Error> {
+        verify(
+            &self.fri,
+            &self.mmcs,
+            &commitments_with_opening_points,
+            proof,
+            challenger,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use p3_baby_bear::BabyBear;
+    use p3_field::PrimeCharacteristicRing;
+    use p3_matrix::dense::RowMajorMatrix;
+    use p3_multilinear_util::poly::MultilinearPoly;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    use super::evals_to_coeffs;
+
+    type F = BabyBear;
+
+    #[test]
+    fn coefficients_match_evaluations() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let num_vars = 5;
+        let evals = RowMajorMatrix::<F>::rand(&mut rng, 1 << num_vars, 3);
+        let coeffs = evals_to_coeffs(evals.clone());
+
+        // `f(X) = P(X, X^2, X^4, ...)`, so both sides agree at any `X`.
+        let x = F::from_u32(12345);
+        let point: Vec<F> = (0..num_vars).map(|j| x.exp_power_of_2(j)).collect();
+        for col in 0..3 {
+            let poly = MultilinearPoly::from_matrix_column(&evals, col);
+            let f: F = (0..1 << num_vars)
+                .rev()
+                .fold(F::ZERO, |acc, i| acc * x + coeffs.values[i * 3 + col]);
+            assert_eq!(poly.evaluate(&point), f);
+        }
+    }
+}
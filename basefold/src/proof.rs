@@ -0,0 +1,76 @@
+use alloc::vec::Vec;
+
+use p3_commit::{BatchOpening, Mmcs};
+use p3_field::Field;
+use p3_sumcheck::SumcheckProof;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(bound(
+    serialize = "Witness: Serialize",
+    deserialize = "Witness: Deserialize<'de>"
+))]
+pub struct BasefoldProof<Val, Challenge, InputMmcs, FriMmcs, Witness>
+where
+    Val: Field,
+    Challenge: Field,
+    InputMmcs: Mmcs<Val>,
+    FriMmcs: Mmcs<Challenge>,
+{
+    /// The sumcheck round polynomials, each given by its evaluations at `0, 1, 2`.
+    pub sumcheck_proof: SumcheckProof<Challenge>,
+    /// For each fold but the last, a commitment to the folded codewords of all opened matrices.
+    pub commit_phase_commits: Vec<FriMmcs::Commitment>,
+    /// For each opened matrix, the constant its codeword folds down to.
+    pub final_values: Vec<Challenge>,
+    pub pow_witness: Witness,
+    pub query_proofs: Vec<QueryProof<Val, Challenge, InputMmcs, FriMmcs>>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(bound = "")]
+pub struct QueryProof<Val, Challenge, InputMmcs, FriMmcs>
+where
+    Val: Field,
+    Challenge: Field,
+    InputMmcs: Mmcs<Val>,
+    FriMmcs: Mmcs<Challenge>,
+{
+    /// For each input commitment, the opening of all its matrices at the queried index.
+    pub input_openings: Vec<BatchOpening<Val, InputMmcs>>,
+    /// For each input commitment, the opening of all its matrices at the sibling of the queried
+    /// index. These are empty if the polynomials have no variables, as there is nothing to fold.
+    pub input_sibling_openings: Vec<BatchOpening<Val, InputMmcs>>,
+    /// For each commit phase commitment, the siblings of the folded values at the queried
+    /// location, along with an opening proof.
+    pub commit_phase_openings: Vec<CommitPhaseProofStep<Challenge, FriMmcs>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(bound = "")]
+pub struct CommitPhaseProofStep<F: Field, M: Mmcs<F>> {
+    /// For each opened matrix, the value of its folded codeword at the sibling location.
+    pub sibling_values: Vec<F>,
+
+    pub opening_proof: M::Proof,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum BasefoldError<CommitMmcsErr, InputMmcsErr> {
+    InvalidProofShape,
+    /// The claimed openings are malformed: there are no opened matrices, the matrices do not all
+    /// have the same number of variables, or an opening point does not have one coordinate per
+    /// variable.
+    InvalidOpeningShape,
+    /// A sumcheck round polynomial does not sum to the claim of the previous round.
+    SumMismatch {
+        round: usize,
+    },
+    CommitPhaseMmcsError(CommitMmcsErr),
+    InputError(InputMmcsErr),
+    /// A query does not fold down to the final value of its codeword.
+    FinalValueMismatch,
+    /// The final values are inconsistent with the claim left by the sumcheck.
+    FinalEvalMismatch,
+    InvalidPowWitness,
+}
@@ -0,0 +1,345 @@
+//! The Basefold opening prover.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter;
+use core::marker::PhantomData;
+
+use itertools::Itertools;
+use p3_challenger::{CanObserve, FieldChallenger, GrindingChallenger};
+use p3_commit::{Mmcs, OpenedValues};
+use p3_field::{ExtensionField, Field, PackedFieldExtension, TwoAdicField};
+use p3_fri::{FriFoldingStrategy, FriParameters, TwoAdicFriFolding};
+use p3_matrix::Matrix;
+use p3_matrix::dense::{RowMajorMatrix, RowMajorMatrixView};
+use p3_maybe_rayon::prelude::*;
+use p3_multilinear_util::eq_batch::eval_eq_batch;
+use p3_multilinear_util::poly::MultilinearPoly;
+use p3_sumcheck::SumcheckProof;
+use p3_util::log2_strict_usize;
+use tracing::{info_span, instrument};
+
+use crate::{BasefoldProof, BasefoldProverData, CommitPhaseProofStep, QueryProof};
+
+/// The prover data of a commitment, and for each of its matrices, the points to open it at.
+pub type ProverDataWithOpeningPoints<'a, Val, Challenge, InputMmcs> = (
+    &'a BasefoldProverData<Val, InputMmcs>,
+    Vec<Vec<Vec<Challenge>>>,
+);
+
+/// Open a collection of Basefold commitments at a collection of points.
+///
+/// After observing the opened values, the verifier samples `alpha` and `beta`. For each matrix
+/// `M_i` with columns `P_{i, c}` and opening points `z_{i, k}`, define
+/// ```text
+///     R_i(x) = \sum_c alpha^c P_{i, c}(x),
+///     E_i(x) = \sum_k beta^{t(i, k)} eq(x, z_{i, k}),
+/// ```
+/// where `t(i, k)` counts the opening points of all matrices. The claims are then reduced by a
+/// degree two sumcheck of `\sum_x \sum_i R_i(x) E_i(x)` to the values `R_i(r)` at a random point
+/// `r`. Folding the codeword of each `R_i` with the sumcheck challenges proves these values.
+///
+/// # Panics
+///
+/// Panics if no matrices are opened, if the number of point lists of a commitment differs from
+/// its number of matrices, or if the matrices and points do not all have the same number of
+/// variables. The verifier rejects claims of these shapes with
+/// [`BasefoldError::InvalidOpeningShape`](crate::BasefoldError::InvalidOpeningShape).
+#[instrument(name = "Basefold prover", skip_all)]
+#[allow(clippy::type_complexity)]
+pub fn open<Val, Challenge, InputMmcs, FriMmcs, Challenger>(
+    params: &FriParameters<FriMmcs>,
+    input_mmcs: &InputMmcs,
+    commitment_data_with_opening_points: &[ProverDataWithOpeningPoints<
+        '_,
+        Val,
+        Challenge,
+        InputMmcs,
+    >],
+    challenger: &mut Challenger,
+) -> (
+    OpenedValues<Challenge>,
+    BasefoldProof<Val, Challenge, InputMmcs, FriMmcs, Challenger::Witness>,
+)
+where
+    Val: TwoAdicField,
+    Challenge: ExtensionField<Val>,
+    InputMmcs: Mmcs<Val>,
+    FriMmcs: Mmcs<Challenge>,
+    Challenger: FieldChallenger<Val> + GrindingChallenger + CanObserve<FriMmcs::Commitment>,
+{
+    let folding = TwoAdicFriFolding::<(), ()>(PhantomData);
+
+    // Pair every committed matrix with its codeword and its opening points.
+    let mats_and_points = commitment_data_with_opening_points
+        .iter()
+        .flat_map(|(data, points)| {
+            assert_eq!(
+                data.evals.len(),
+                points.len(),
+                "each matrix should have a corresponding set of evaluation points"
+            );
+            mats_with_codewords(data, input_mmcs).zip(points)
+        })
+        .map(|((evals, codeword), points)| (evals, codeword, points))
+        .collect_vec();
+
+    let (first_evals, _, _) = mats_and_points
+        .first()
+        .expect("at least one matrix must be opened");
+    let num_vars = log2_strict_usize(first_evals.height());
+    for (evals, _, points) in &mats_and_points {
+        assert_eq!(
+            evals.height(),
+            1 << num_vars,
+            "all opened polynomials must have the same number of variables"
+        );
+        assert!(
+            points.iter().all(|point| point.len() == num_vars),
+            "every opening point must have one coordinate per variable"
+        );
+    }
+
+    // Evaluate each column of each matrix at its points, and send the values to the verifier.
+    let all_opened_values = info_span!("evaluate matrices").in_scope(|| {
+        commitment_data_with_opening_points
+            .iter()
+            .map(|(data, points)| {
+                data.evals
+                    .iter()
+                    .zip(points)
+                    .map(|(evals, points)| {
+                        points
+                            .iter()
+                            .map(|point| {
+                                let eq = MultilinearPoly::eq(point);
+                                let ys = evals.columnwise_dot_product(eq.evals());
+                                ys.iter()
+                                    .for_each(|&y| challenger.observe_algebra_element(y));
+                                ys
+                            })
+                            .collect_vec()
+                    })
+                    .collect_vec()
+            })
+            .collect_vec()
+    });
+
+    // Combination challenges for the columns of a matrix and for the opening points.
+    let alpha: Challenge = challenger.sample_algebra_element();
+    let beta: Challenge = challenger.sample_algebra_element();
+
+    // Combine the columns of each matrix, both over the hypercube and in the codeword.
+    let (mut tables, mut codewords): (Vec<_>, Vec<_>) =
+        info_span!("combine columns").in_scope(|| {
+            mats_and_points
+                .iter()
+                .map(|(evals, codeword, _)| {
+                    let alpha_powers =
+                        Challenge::ExtensionPacking::packed_ext_powers_capped(alpha, evals.width())
+                            .collect_vec();
+                    let table = evals
+                        .rowwise_packed_dot_product::<Challenge>(&alpha_powers)
+                        .collect();
+                    let codeword: Vec<Challenge> = codeword
+                        .rowwise_packed_dot_product::<Challenge>(&alpha_powers)
+                        .collect();
+                    (MultilinearPoly::new(table), codeword)
+                })
+                .unzip()
+        });
+
+    // The weights `E_i` of each matrix, combining its eq tables.
+    let mut beta_powers = beta.powers();
+    let mut weights = mats_and_points
+        .iter()
+        .map(|(_, _, points)| {
+            let mut weights = Challenge::zero_vec(1 << num_vars);
+            if !points.is_empty() {
+                let scalars = beta_powers.by_ref().take(points.len()).collect_vec();
+                let points = RowMajorMatrix::new(
+                    (0..num_vars)
+                        .flat_map(|j| points.iter().map(move |point| point[j]))
+                        .collect(),
+                    points.len(),
+                );
+                eval_eq_batch::<Val, Challenge, false>(points.as_view(), &mut weights, &scalars);
+            }
+            MultilinearPoly::new(weights)
+        })
+        .collect_vec();
+
+    let mut round_polys = Vec::with_capacity(num_vars);
+    let mut commits = vec![];
+    let mut data: Vec<FriMmcs::ProverData<RowMajorMatrix<Challenge>>> = vec![];
+    info_span!("sumcheck and commit phase").in_scope(|| {
+        for round in 0..num_vars {
+            let round_poly = round_evals(&tables, &weights);
+            round_poly
+                .iter()
+                .for_each(|&x| challenger.observe_algebra_element(x));
+            round_polys.push(round_poly);
+            let r: Challenge = challenger.sample_algebra_element();
+
+            // Fix the first variable of the sumcheck tables.
+            tables = tables.iter().map(|t| t.fix_high_variables(&[r])).collect();
+            weights = weights.iter().map(|w| w.fix_high_variables(&[r])).collect();
+
+            // Fold the codewords with the same challenge. In the first round, they come from the
+            // combined input codewords, and afterwards from the previous commitment.
+            let leaves = match data.last() {
+                Some(prover_data) => params
+                    .mmcs
+                    .get_matrices(prover_data)
+                    .into_iter()
+                    .map(|m| m.as_view())
+                    .collect_vec(),
+                None => codewords
+                    .iter()
+                    .map(|c| RowMajorMatrixView::new(c, 2))
+                    .collect_vec(),
+            };
+            let folded = leaves
+                .into_iter()
+                .map(|m| folding.fold_matrix(r, m))
+                .collect_vec();
+
+            // Commit to the folded codewords, unless they are the final constants.
+            if round + 1 < num_vars {
+                let (commit, prover_data) = params.mmcs.commit(
+                    folded
+                        .into_iter()
+                        .map(|c| RowMajorMatrix::new(c, 2))
+                        .collect(),
+                );
+                challenger.observe(commit.clone());
+                commits.push(commit);
+                data.push(prover_data);
+            } else {
+                codewords = folded;
+            }
+        }
+    });
+
+    // After folding all variables away, each codeword is constant.
+    let final_values = codewords.iter().map(|c| c[0]).collect_vec();
+    final_values
+        .iter()
+        .for_each(|&x| challenger.observe_algebra_element(x));
+
+    let pow_witness = challenger.grind(params.proof_of_work_bits);
+
+    let log_height = num_vars + params.log_blowup;
+    let query_proofs = info_span!("query phase").in_scope(|| {
+        iter::repeat_with(|| {
+            let index = challenger.sample_bits(log_height);
+            let open_inputs = |index| {
+                commitment_data_with_opening_points
+                    .iter()
+                    .map(|(data, _)| input_mmcs.open_batch(index, &data.codewords))
+                    .collect_vec()
+            };
+            QueryProof {
+                input_openings: open_inputs(index),
+                input_sibling_openings: if num_vars > 0 {
+                    open_inputs(index ^ 1)
+                } else {
+                    vec![]
+                },
+                commit_phase_openings: answer_query(params, &data, index),
+            }
+        })
+        .take(params.num_queries)
+        .collect()
+    });
+
+    (
+        all_opened_values,
+        BasefoldProof {
+            sumcheck_proof: SumcheckProof { round_polys },
+            commit_phase_commits: commits,
+            final_values,
+            pow_witness,
+            query_proofs,
+        },
+    )
+}
+
+/// The committed evaluation matrices of a commitment, each with its codeword.
+fn mats_with_codewords<'a, Val, InputMmcs>(
+    data: &'a BasefoldProverData<Val, InputMmcs>,
+    input_mmcs: &'a InputMmcs,
+) -> impl Iterator<Item = (&'a RowMajorMatrix<Val>, &'a RowMajorMatrix<Val>)>
+where
+    Val: TwoAdicField,
+    InputMmcs: Mmcs<Val>,
+{
+    data.evals
+        .iter()
+        .zip(input_mmcs.get_matrices(&data.codewords))
+}
+
+/// Compute the evaluations at `0, 1, 2` of the round polynomial
+/// `g(t) = \sum_{x'} \sum_i R_i(t, x') E_i(t, x')`.
+fn round_evals<EF: Field>(
+    tables: &[MultilinearPoly<EF>],
+    weights: &[MultilinearPoly<EF>],
+) -> Vec<EF> {
+    tables
+        .iter()
+        .zip(weights)
+        .map(|(table, weight)| {
+            let half = table.evals().len() / 2;
+            let (t_lo, t_hi) = table.evals().split_at(half);
+            let (w_lo, w_hi) = weight.evals().split_at(half);
+            t_lo.par_iter()
+                .zip(t_hi)
+                .zip(w_lo.par_iter().zip(w_hi))
+                .par_fold_reduce(
+                    || [EF::ZERO; 3],
+                    |[s0, s1, s2], ((&t0, &t1), (&w0, &w1))| {
+                        // Evaluations at 2 are `2 * hi - lo`.
+                        let t2 = t1.double() - t0;
+                        let w2 = w1.double() - w0;
+                        [s0 + t0 * w0, s1 + t1 * w1, s2 + t2 * w2]
+                    },
+                    |[a0, a1, a2], [b0, b1, b2]| [a0 + b0, a1 + b1, a2 + b2],
+                )
+        })
+        .fold([EF::ZERO; 3], |[a0, a1, a2], [b0, b1, b2]| {
+            [a0 + b0, a1 + b1, a2 + b2]
+        })
+        .to_vec()
+}
+
+/// Open each commit phase commitment at the parent of the queried location, returning the
+/// siblings of the folded values along with an opening proof.
+///
+/// The codewords committed in round `k` are the result of `k + 1` folds, so the queried location
+/// in them is `index >> (k + 1)`.
+fn answer_query<F, M>(
+    params: &FriParameters<M>,
+    data: &[M::ProverData<RowMajorMatrix<F>>],
+    index: usize,
+) -> Vec<CommitPhaseProofStep<F, M>>
+where
+    F: Field,
+    M: Mmcs<F>,
+{
+    data.iter()
+        .enumerate()
+        .map(|(k, prover_data)| {
+            let index_k = index >> (k + 1);
+            let (opened_rows, opening_proof) =
+                params.mmcs.open_batch(index_k >> 1, prover_data).unpack();
+            let sibling_values = opened_rows
+                .iter()
+                .map(|row| row[(index_k ^ 1) % 2])
+                .collect();
+            CommitPhaseProofStep {
+                sibling_values,
+                opening_proof,
+            }
+        })
+        .collect()
+}
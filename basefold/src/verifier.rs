@@ -0,0 +1,311 @@
+//! The Basefold opening verifier.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use itertools::Itertools;
+use p3_challenger::{CanObserve, FieldChallenger, GrindingChallenger};
+use p3_commit::{BatchOpening, BatchOpeningRef, Mmcs};
+use p3_field::{ExtensionField, Field, TwoAdicField};
+use p3_fri::{FriFoldingStrategy, FriParameters, TwoAdicFriFolding};
+use p3_interpolation::{barycentric_weights, interpolate_lagrange_with_weights};
+use p3_matrix::Dimensions;
+use p3_matrix::dense::RowMajorMatrixView;
+use p3_multilinear_util::poly::eval_eq;
+use p3_util::zip_eq::zip_eq;
+
+use crate::{BasefoldError, BasefoldProof, CommitPhaseProofStep};
+
+/// A commitment to a collection of matrices, each with its number of variables and a list of
+/// opening points and claimed evaluations.
+pub type CommitmentWithOpeningPoints<Challenge, Commitment> = (
+    Commitment,
+    // For each matrix in the commitment:
+    Vec<(
+        // its number of variables,
+        usize,
+        // A vector of (point, claimed_evaluation) pairs
+        Vec<(Vec<Challenge>, Vec<Challenge>)>,
+    )>,
+);
+
+/// Verify a Basefold opening proof. This is the verifier complement to [`open`](crate::prover::open).
+///
+/// Returns [`BasefoldError::InvalidOpeningShape`] if no matrices are opened, the matrices do not
+/// all have the same number of variables, or an opening point does not have one coordinate per
+/// variable.
+pub fn verify<Val, Challenge, InputMmcs, FriMmcs, Challenger>(
+    params: &FriParameters<FriMmcs>,
+    input_mmcs: &InputMmcs,
+    commitments_with_opening_points: &[CommitmentWithOpeningPoints<
+        Challenge,
+        InputMmcs::Commitment,
+    >],
+    proof: &BasefoldProof<Val, Challenge, InputMmcs, FriMmcs, Challenger::Witness>,
+    challenger: &mut Challenger,
+) -> Result<(), BasefoldError<FriMmcs::Error, InputMmcs::Error>>
+where
+    Val: TwoAdicField,
+    Challenge: ExtensionField<Val>,
+    InputMmcs: Mmcs<Val>,
+    FriMmcs: Mmcs<Challenge>,
+    Challenger: FieldChallenger<Val> + GrindingChallenger + CanObserve<FriMmcs::Commitment>,
+{
+    let folding = TwoAdicFriFolding::<(), ()>(PhantomData);
+
+    let mats = commitments_with_opening_points
+        .iter()
+        .flat_map(|(_, mats)| mats)
+        .collect_vec();
+    let num_vars = mats.first().ok_or(BasefoldError::InvalidOpeningShape)?.0;
+    if mats.iter().any(|(mat_num_vars, points_and_values)| {
+        *mat_num_vars != num_vars
+            || points_and_values
+                .iter()
+                .any(|(point, _)| point.len() != num_vars)
+    }) {
+        return Err(BasefoldError::InvalidOpeningShape);
+    }
+
+    for (_, points_and_values) in &mats {
+        for (_, values) in points_and_values {
+            values
+                .iter()
+                .for_each(|&y| challenger.observe_algebra_element(y));
+        }
+    }
+
+    let alpha: Challenge = challenger.sample_algebra_element();
+    let beta: Challenge = challenger.sample_algebra_element();
+
+    // The claimed sum is `\sum_i \sum_k beta^{t(i, k)} R_i(z_{i, k})`, and we keep the weighted
+    // points of each matrix to evaluate `E_i` at the end.
+    let mut beta_pow = Challenge::ONE;
+    let mut claimed_sum = Challenge::ZERO;
+    let weighted_points = mats
+        .iter()
+        .map(|(_, points_and_values)| {
+            points_and_values
+                .iter()
+                .map(|(point, values)| {
+                    let combined: Challenge = alpha
+                        .powers()
+                        .zip(values)
+                        .map(|(alpha_pow, &y)| alpha_pow * y)
+                        .sum();
+                    claimed_sum += beta_pow * combined;
+                    let weighted = (point, beta_pow);
+                    beta_pow *= beta;
+                    weighted
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+
+    if proof.sumcheck_proof.round_polys.len() != num_vars
+        || proof
+            .sumcheck_proof
+            .round_polys
+            .iter()
+            .any(|p| p.len() != 3)
+        || proof.commit_phase_commits.len() != num_vars.saturating_sub(1)
+        || proof.final_values.len() != mats.len()
+        || proof.query_proofs.len() != params.num_queries
+    {
+        return Err(BasefoldError::InvalidProofShape);
+    }
+
+    // Replay the sumcheck, observing each commitment to the folded codewords before sampling the
+    // challenge for the next fold.
+    let nodes = [Challenge::ZERO, Challenge::ONE, Challenge::TWO];
+    let interpolation_weights = barycentric_weights(&nodes);
+    let mut claim = claimed_sum;
+    let mut rs = Vec::with_capacity(num_vars);
+    for (round, round_poly) in proof.sumcheck_proof.round_polys.iter().enumerate() {
+        if round_poly[0] + round_poly[1] != claim {
+            return Err(BasefoldError::SumMismatch { round });
+        }
+        round_poly
+            .iter()
+            .for_each(|&x| challenger.observe_algebra_element(x));
+        let r: Challenge = challenger.sample_algebra_element();
+        claim = interpolate_lagrange_with_weights(
+            &nodes,
+            &interpolation_weights,
+            &RowMajorMatrixView::new_col(round_poly),
+            r,
+        )[0];
+        rs.push(r);
+        if let Some(commit) = proof.commit_phase_commits.get(round) {
+            challenger.observe(commit.clone());
+        }
+    }
+
+    proof
+        .final_values
+        .iter()
+        .for_each(|&x| challenger.observe_algebra_element(x));
+
+    // The sumcheck leaves the claim `\sum_i R_i(r) E_i(r)`, where `R_i(r)` are the final values.
+    let final_eval: Challenge = proof
+        .final_values
+        .iter()
+        .zip(&weighted_points)
+        .map(|(&final_value, points)| {
+            final_value
+                * points
+                    .iter()
+                    .map(|&(point, beta_pow)| beta_pow * eval_eq(point, &rs))
+                    .sum::<Challenge>()
+        })
+        .sum();
+    if final_eval != claim {
+        return Err(BasefoldError::FinalEvalMismatch);
+    }
+
+    if !challenger.check_witness(params.proof_of_work_bits, proof.pow_witness) {
+        return Err(BasefoldError::InvalidPowWitness);
+    }
+
+    let log_height = num_vars + params.log_blowup;
+    for query_proof in &proof.query_proofs {
+        let index = challenger.sample_bits(log_height);
+
+        let mut folded = open_input(
+            input_mmcs,
+            commitments_with_opening_points,
+            &query_proof.input_openings,
+            index,
+            log_height,
+            alpha,
+        )?;
+
+        if num_vars > 0 {
+            // The first fold pairs the input codewords with their siblings.
+            let siblings = open_input(
+                input_mmcs,
+                commitments_with_opening_points,
+                &query_proof.input_sibling_openings,
+                index ^ 1,
+                log_height,
+                alpha,
+            )?;
+            for (value, sibling) in folded.iter_mut().zip(siblings) {
+                let mut evals = [*value; 2];
+                evals[(index ^ 1) % 2] = sibling;
+                *value = folding.fold_row(index >> 1, log_height - 1, rs[0], evals.into_iter());
+            }
+        } else if !query_proof.input_sibling_openings.is_empty() {
+            return Err(BasefoldError::InvalidProofShape);
+        }
+
+        for (k, (commit, step)) in zip_eq(
+            &proof.commit_phase_commits,
+            &query_proof.commit_phase_openings,
+            BasefoldError::InvalidProofShape,
+        )?
+        .enumerate()
+        {
+            let CommitPhaseProofStep {
+                sibling_values,
+                opening_proof,
+            } = step;
+            // The codewords committed in round `k` have been folded `k + 1` times.
+            let index_k = index >> (k + 1);
+            let log_folded_height = log_height - k - 2;
+            let rows = zip_eq(&folded, sibling_values, BasefoldError::InvalidProofShape)?
+                .map(|(&value, &sibling)| {
+                    let mut evals = vec![value; 2];
+                    evals[(index_k ^ 1) % 2] = sibling;
+                    evals
+                })
+                .collect_vec();
+            let dims = vec![
+                Dimensions {
+                    width: 2,
+                    height: 1 << log_folded_height,
+                };
+                rows.len()
+            ];
+            params
+                .mmcs
+                .verify_batch(
+                    commit,
+                    &dims,
+                    index_k >> 1,
+                    BatchOpeningRef::new(&rows, opening_proof),
+                )
+                .map_err(BasefoldError::CommitPhaseMmcsError)?;
+            for (value, row) in folded.iter_mut().zip(rows) {
+                *value =
+                    folding.fold_row(index_k >> 1, log_folded_height, rs[k + 1], row.into_iter());
+            }
+        }
+
+        if folded != proof.final_values {
+            return Err(BasefoldError::FinalValueMismatch);
+        }
+    }
+
+    Ok(())
+}
+
+/// Check the openings of all input commitments at `index`, and combine the columns of each
+/// matrix with powers of `alpha`, returning one value per matrix.
+fn open_input<Val, Challenge, InputMmcs, FriMmcsErr>(
+    input_mmcs: &InputMmcs,
+    commitments_with_opening_points: &[CommitmentWithOpeningPoints<
+        Challenge,
+        InputMmcs::Commitment,
+    >],
+    input_openings: &[BatchOpening<Val, InputMmcs>],
+    index: usize,
+    log_height: usize,
+    alpha: Challenge,
+) -> Result<Vec<Challenge>, BasefoldError<FriMmcsErr, InputMmcs::Error>>
+where
+    Val: Field,
+    Challenge: ExtensionField<Val>,
+    InputMmcs: Mmcs<Val>,
+{
+    let mut combined = vec![];
+    for (batch_opening, (commit, mats)) in zip_eq(
+        input_openings,
+        commitments_with_opening_points,
+        BasefoldError::InvalidProofShape,
+    )? {
+        // MMCS doesn't really need width; we put 0 like the FRI verifier does.
+        let dims = vec![
+            Dimensions {
+                width: 0,
+                height: 1 << log_height,
+            };
+            mats.len()
+        ];
+        input_mmcs
+            .verify_batch(commit, &dims, index, batch_opening.into())
+            .map_err(BasefoldError::InputError)?;
+
+        for (row, (_, points_and_values)) in zip_eq(
+            &batch_opening.opened_values,
+            mats,
+            BasefoldError::InvalidProofShape,
+        )? {
+            if points_and_values
+                .iter()
+                .any(|(_, values)| values.len() != row.len())
+            {
+                return Err(BasefoldError::InvalidProofShape);
+            }
+            combined.push(
+                alpha
+                    .powers()
+                    .zip(row)
+                    .map(|(alpha_pow, &x)| alpha_pow * x)
+                    .sum(),
+            );
+        }
+    }
+    Ok(combined)
+}
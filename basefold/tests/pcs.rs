@@ -0,0 +1,243 @@
+use itertools::Itertools;
+use p3_baby_bear::{BabyBear, Poseidon2BabyBear};
+use p3_basefold::{BasefoldError, BasefoldPcs};
+use p3_challenger::{CanObserve, DuplexChallenger};
+use p3_commit::{ExtensionMmcs, MultilinearPcs};
+use p3_dft::Radix2DitParallel;
+use p3_field::extension::BinomialExtensionField;
+use p3_field::{Field, PrimeCharacteristicRing};
+use p3_fri::FriParameters;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_merkle_tree::MerkleTreeMmcs;
+use p3_multilinear_util::poly::MultilinearPoly;
+use p3_symmetric::{PaddingFreeSponge, TruncatedPermutation};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+type Val = BabyBear;
+type Challenge = BinomialExtensionField<Val, 4>;
+
+type Perm = Poseidon2BabyBear<16>;
+type MyHash = PaddingFreeSponge<Perm, 16, 8, 8>;
+type MyCompress = TruncatedPermutation<Perm, 2, 8, 16>;
+
+type ValMmcs =
+    MerkleTreeMmcs<<Val as Field>::Packing, <Val as Field>::Packing, MyHash, MyCompress, 8>;
+type ChallengeMmcs = ExtensionMmcs<Val, Challenge, ValMmcs>;
+
+type Dft = Radix2DitParallel<Val>;
+type Challenger = DuplexChallenger<Val, Perm, 16, 8>;
+type MyPcs = BasefoldPcs<Val, Dft, ValMmcs, ChallengeMmcs>;
+
+fn seeded_rng() -> SmallRng {
+    SmallRng::seed_from_u64(0)
+}
+
+fn get_pcs(log_blowup: usize) -> (MyPcs, Challenger) {
+    let perm = Perm::new_from_rng_128(&mut seeded_rng());
+    let hash = MyHash::new(perm.clone());
+    let compress = MyCompress::new(perm.clone());
+
+    let val_mmcs = ValMmcs::new(hash, compress);
+    let challenge_mmcs = ChallengeMmcs::new(val_mmcs.clone());
+
+    let fri_params = FriParameters {
+        log_blowup,
+        log_final_poly_len: 0,
+        num_queries: 10,
+        proof_of_work_bits: 8,
+        mmcs: challenge_mmcs,
+    };
+
+    let pcs = MyPcs::new(Dft::default(), val_mmcs, fri_params);
+    (pcs, Challenger::new(perm))
+}
+
+type Claims = Vec<(
+    <MyPcs as MultilinearPcs<Val, Challenge, Challenger>>::Commitment,
+    Vec<(usize, Vec<(Vec<Challenge>, Vec<Challenge>)>)>,
+)>;
+
+/// Commit to one round of matrices per entry of `num_points_by_round`, open each matrix at the
+/// given number of random points, and return the proof along with the claims to verify.
+fn prove(
+    (pcs, challenger): &(MyPcs, Challenger),
+    num_vars: usize,
+    num_points_by_round: &[&[usize]],
+) -> (
+    Claims,
+    <MyPcs as MultilinearPcs<Val, Challenge, Challenger>>::Proof,
+) {
+    let mut rng = seeded_rng();
+
+    let mats_by_round = num_points_by_round
+        .iter()
+        .map(|num_points| {
+            num_points
+                .iter()
+                .map(|_| {
+                    let width = 1 + rng.random_range(0..8);
+                    RowMajorMatrix::<Val>::rand(&mut rng, 1 << num_vars, width)
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+
+    let (commits, data): (Vec<_>, Vec<_>) = mats_by_round
+        .iter()
+        .map(|mats| MultilinearPcs::<Val, Challenge, Challenger>::commit(pcs, mats.clone()))
+        .unzip();
+
+    let points_by_round = num_points_by_round
+        .iter()
+        .map(|num_points| {
+            num_points
+                .iter()
+                .map(|&n| {
+                    (0..n)
+                        .map(|_| (0..num_vars).map(|_| rng.random()).collect_vec())
+                        .collect_vec()
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+
+    let mut p_challenger = challenger.clone();
+    p_challenger.observe_slice(&commits);
+    let data_and_points = data.iter().zip(points_by_round.clone()).collect();
+    let (opened_values, proof) = pcs.open(data_and_points, &mut p_challenger);
+
+    // The opened values are the evaluations of the columns.
+    for ((mats, points), values) in mats_by_round
+        .iter()
+        .zip(&points_by_round)
+        .zip(&opened_values)
+    {
+        for ((mat, points), values) in mats.iter().zip(points).zip(values) {
+            for (point, values) in points.iter().zip(values) {
+                for (col, &value) in values.iter().enumerate() {
+                    let poly = MultilinearPoly::from_matrix_column(mat, col);
+                    assert_eq!(poly.evaluate(point), value);
+                }
+            }
+        }
+    }
+
+    let claims = commits
+        .into_iter()
+        .zip(points_by_round)
+        .zip(opened_values)
+        .map(|((commit, points), values)| {
+            let mats = points
+                .into_iter()
+                .zip(values)
+                .map(|(points, values)| (num_vars, points.into_iter().zip(values).collect()))
+                .collect();
+            (commit, mats)
+        })
+        .collect();
+    (claims, proof)
+}
+
+fn verify(
+    (pcs, challenger): &(MyPcs, Challenger),
+    claims: Claims,
+    proof: &<MyPcs as MultilinearPcs<Val, Challenge, Challenger>>::Proof,
+) -> Result<(), <MyPcs as MultilinearPcs<Val, Challenge, Challenger>>::Error> {
+    let mut v_challenger = challenger.clone();
+    v_challenger.observe_slice(&claims.iter().map(|(c, _)| *c).collect_vec());
+    pcs.verify(claims, proof, &mut v_challenger)
+}
+
+fn prove_and_verify(log_blowup: usize, num_vars: usize, num_points_by_round: &[&[usize]]) {
+    let pcs = get_pcs(log_blowup);
+    let (claims, proof) = prove(&pcs, num_vars, num_points_by_round);
+    verify(&pcs, claims, &proof).unwrap();
+}
+
+#[test]
+fn single() {
+    for log_blowup in [1, 2] {
+        for num_vars in 0..6 {
+            prove_and_verify(log_blowup, num_vars, &[&[1]]);
+        }
+    }
+}
+
+#[test]
+fn many_points() {
+    prove_and_verify(1, 5, &[&[3, 1, 2]]);
+    prove_and_verify(2, 4, &[&[0, 2]]);
+}
+
+#[test]
+fn multiple_rounds() {
+    prove_and_verify(1, 4, &[&[1], &[1]]);
+    prove_and_verify(1, 6, &[&[2, 1], &[1, 3], &[1]]);
+}
+
+#[test]
+fn wrong_claims_are_rejected() {
+    let pcs = get_pcs(1);
+    let (claims, proof) = prove(&pcs, 5, &[&[2, 1]]);
+
+    let mut wrong_claims = claims.clone();
+    wrong_claims[0].1[1].1[0].1[0] += Challenge::ONE;
+    assert!(matches!(
+        verify(&pcs, wrong_claims, &proof),
+        Err(BasefoldError::SumMismatch { round: 0 })
+    ));
+
+    // A sumcheck consistent with a wrong final value is caught by the final check.
+    let mut wrong_proof = proof.clone();
+    wrong_proof.final_values[0] += Challenge::ONE;
+    assert!(matches!(
+        verify(&pcs, claims.clone(), &wrong_proof),
+        Err(BasefoldError::FinalEvalMismatch)
+    ));
+
+    let mut wrong_proof = proof.clone();
+    wrong_proof.query_proofs[0].commit_phase_openings[1].sibling_values[1] += Challenge::ONE;
+    assert!(matches!(
+        verify(&pcs, claims.clone(), &wrong_proof),
+        Err(BasefoldError::CommitPhaseMmcsError(_))
+    ));
+
+    let mut wrong_proof = proof;
+    wrong_proof.query_proofs.pop();
+    assert!(matches!(
+        verify(&pcs, claims, &wrong_proof),
+        Err(BasefoldError::InvalidProofShape)
+    ));
+}
+
+#[test]
+fn malformed_claims_are_rejected() {
+    let pcs = get_pcs(1);
+    let (claims, proof) = prove(&pcs, 4, &[&[1], &[1]]);
+
+    assert!(matches!(
+        verify(&pcs, Vec::new(), &proof),
+        Err(BasefoldError::InvalidOpeningShape)
+    ));
+
+    let mut wrong_claims = claims.clone();
+    wrong_claims[1].1[0].0 += 1;
+    assert!(matches!(
+        verify(&pcs, wrong_claims, &proof),
+        Err(BasefoldError::InvalidOpeningShape)
+    ));
+
+    let mut wrong_claims = claims;
+    wrong_claims[0].1[0].1[0].0.pop();
+    assert!(matches!(
+        verify(&pcs, wrong_claims, &proof),
+        Err(BasefoldError::InvalidOpeningShape)
+    ));
+}
+
+#[test]
+#[should_panic(expected = "at least one matrix must be opened")]
+fn opening_nothing_panics() {
+    prove(&get_pcs(1), 4, &[]);
+}
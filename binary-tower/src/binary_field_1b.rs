@@ -0,0 +1,275 @@
+use core::fmt::{self, Debug, Display, Formatter};
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num_bigint::BigUint;
+use p3_field::integers::QuotientMap;
+use p3_field::op_assign_macros::{
+    impl_add_assign, impl_div_methods, impl_mul_methods, impl_sub_assign, ring_sum,
+};
+use p3_field::{Field, Packable, PrimeCharacteristicRing, PrimeField, RawDataSerializable};
+use rand::Rng;
+use rand::distr::{Distribution, StandardUniform};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// The field `GF(2)`, the prime subfield of every binary tower field.
+#[derive(Copy, Clone, Default, Eq, Hash, PartialEq, Ord, PartialOrd)]
+#[repr(transparent)] // Important for reasoning about memory layout.
+#[must_use]
+pub struct BinaryField1b {
+    /// Always either `0` or `1`.
+    pub(crate) value: u8,
+}
+
+impl BinaryField1b {
+    /// Create a new field element from a bit.
+    #[inline]
+    pub const fn new(value: bool) -> Self {
+        Self { value: value as u8 }
+    }
+
+    /// Return the underlying bit.
+    #[inline]
+    pub const fn to_bit(self) -> bool {
+        self.value == 1
+    }
+}
+
+impl Display for BinaryField1b {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.value, f)
+    }
+}
+
+impl Debug for BinaryField1b {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.value, f)
+    }
+}
+
+impl Distribution<BinaryField1b> for StandardUniform {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BinaryField1b {
+        BinaryField1b::new(rng.random())
+    }
+}
+
+impl Serialize for BinaryField1b {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bool(self.to_bit())
+    }
+}
+
+impl<'de> Deserialize<'de> for BinaryField1b {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let val = bool::deserialize(d)?;
+        Ok(Self::new(val))
+    }
+}
+
+impl Packable for BinaryField1b {}
+
+impl PrimeCharacteristicRing for BinaryField1b {
+    type PrimeSubfield = Self;
+
+    const ZERO: Self = Self::new(false);
+    const ONE: Self = Self::new(true);
+    const TWO: Self = Self::new(false);
+    const NEG_ONE: Self = Self::new(true);
+
+    #[inline]
+    fn from_prime_subfield(f: Self::PrimeSubfield) -> Self {
+        f
+    }
+
+    #[inline]
+    fn from_bool(b: bool) -> Self {
+        Self::new(b)
+    }
+
+    /// # Panics
+    /// As `2 = 0` in characteristic two, halving is not defined and this always panics.
+    #[inline]
+    fn halve(&self) -> Self {
+        panic!("Cannot halve an element of a field of characteristic 2")
+    }
+
+    #[inline]
+    fn square(&self) -> Self {
+        *self
+    }
+}
+
+impl RawDataSerializable for BinaryField1b {
+    const NUM_BYTES: usize = 1;
+
+    #[allow(refining_impl_trait)]
+    #[inline]
+    fn into_bytes(self) -> [u8; 1] {
+        [self.value]
+    }
+}
+
+impl Field for BinaryField1b {
+    type Packing = Self;
+
+    const GENERATOR: Self = Self::ONE;
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+
+    #[inline]
+    fn try_inverse(&self) -> Option<Self> {
+        (!self.is_zero()).then_some(*self)
+    }
+
+    #[inline]
+    fn sqrt(&self) -> Option<Self> {
+        Some(*self)
+    }
+
+    #[inline]
+    fn order() -> BigUint {
+        BigUint::from(2u8)
+    }
+}
+
+/// Implement `QuotientMap` for `BinaryField1b` by reducing integers mod `2`.
+///
+/// Only `0` and `1` are considered canonical.
+macro_rules! quotient_map_gf2 {
+    ($($int:ty),*) => {
+        $(
+            impl QuotientMap<$int> for BinaryField1b {
+                /// Reduce the integer mod `2`, which is just its lowest bit.
+                #[inline]
+                fn from_int(int: $int) -> Self {
+                    Self::new(int & 1 == 1)
+                }
+
+                /// Returns `None` unless the input is `0` or `1`.
+                #[inline]
+                fn from_canonical_checked(int: $int) -> Option<Self> {
+                    (int == 0 || int == 1).then(|| Self::new(int == 1))
+                }
+
+                /// Convert a given integer into an element of the field `GF(2)`.
+                ///
+                /// # Safety
+                /// The input must be `0` or `1`, otherwise only the lowest bit is used.
+                #[inline]
+                unsafe fn from_canonical_unchecked(int: $int) -> Self {
+                    Self::from_int(int)
+                }
+            }
+        )*
+    };
+}
+
+quotient_map_gf2!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl PrimeField for BinaryField1b {
+    #[inline]
+    fn as_canonical_biguint(&self) -> BigUint {
+        BigUint::from(self.value)
+    }
+}
+
+impl Add for BinaryField1b {
+    type Output = Self;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            value: self.value ^ rhs.value,
+        }
+    }
+}
+
+impl Sub for BinaryField1b {
+    type Output = Self;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            value: self.value ^ rhs.value,
+        }
+    }
+}
+
+impl Neg for BinaryField1b {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self
+    }
+}
+
+impl Mul for BinaryField1b {
+    type Output = Self;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            value: self.value & rhs.value,
+        }
+    }
+}
+
+impl_add_assign!(BinaryField1b);
+impl_sub_assign!(BinaryField1b);
+impl_mul_methods!(BinaryField1b);
+ring_sum!(BinaryField1b);
+impl_div_methods!(BinaryField1b, BinaryField1b);
+
+#[cfg(test)]
+mod tests {
+    use p3_field::integers::QuotientMap;
+
+    use super::*;
+
+    type F = BinaryField1b;
+
+    #[test]
+    fn test_gf2_arithmetic() {
+        for (a, b) in [(false, false), (false, true), (true, false), (true, true)] {
+            let (x, y) = (F::new(a), F::new(b));
+            assert_eq!((x + y).to_bit(), a ^ b);
+            assert_eq!((x - y).to_bit(), a ^ b);
+            assert_eq!((x * y).to_bit(), a & b);
+        }
+        assert_eq!(F::TWO, F::ZERO);
+        assert_eq!(F::NEG_ONE, F::ONE);
+        assert_eq!(F::ONE.inverse(), F::ONE);
+        assert_eq!(F::ZERO.try_inverse(), None);
+    }
+
+    #[test]
+    fn test_quotient_map() {
+        assert_eq!(F::from_int(6u32), F::ZERO);
+        assert_eq!(F::from_int(-3i64), F::ONE);
+        assert_eq!(F::from_canonical_checked(1u8), Some(F::ONE));
+        assert_eq!(F::from_canonical_checked(2u8), None);
+        assert_eq!(F::from_canonical_checked(-1i8), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_halve_panics() {
+        let _ = F::ONE.halve();
+    }
+
+    #[test]
+    fn test_serde() {
+        let serialized = serde_json::to_string(&F::ONE).unwrap();
+        assert_eq!(serialized, "true");
+        let deserialized: F = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, F::ONE);
+    }
+}
@@ -0,0 +1,413 @@
+use core::fmt::{self, Debug, Display, Formatter};
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num_bigint::BigUint;
+use p3_field::op_assign_macros::{
+    impl_add_assign, impl_add_base_field, impl_div_methods, impl_mul_base_field, impl_mul_methods,
+    impl_sub_assign, impl_sub_base_field, impl_sum_prod_base_field, ring_sum,
+};
+use p3_field::{
+    Algebra, BasedVectorSpace, ExtensionField, Field, Packable, PackedFieldExtension, Powers,
+    PrimeCharacteristicRing, RawDataSerializable,
+};
+use rand::Rng;
+use rand::distr::{Distribution, StandardUniform};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::{BinaryField1b, PackedBinaryField16x8b};
+
+/// Multiply two elements of the tower field `T_level` of size `2^{2^level}`, stored in the low bits of a `u8`.
+///
+/// Here `T_0 = GF(2)` and `T_{i + 1} = T_i[X_i]/(X_i^2 + X_{i - 1} X_i + 1)` with `X_{-1} = 1`. Writing elements
+/// of `T_{i + 1}` as `a_0 + a_1 X_i` with `a_0, a_1` in `T_i`, the low half of the bits stores `a_0` and the
+/// high half stores `a_1`.
+///
+/// This is slow and only used to build the multiplication tables at compile time.
+const fn tower_mul_u8(lhs: u8, rhs: u8, level: usize) -> u8 {
+    if level == 0 {
+        return lhs & rhs;
+    }
+    let half = 1 << (level - 1);
+    let mask = (1 << half) - 1;
+    let (l0, l1) = (lhs & mask, lhs >> half);
+    let (r0, r1) = (rhs & mask, rhs >> half);
+
+    // Karatsuba: (l0 + l1 X)(r0 + r1 X) = l0 r0 + l1 r1 + ((l0 + l1)(r0 + r1) + l0 r0 + l1 r1 + l1 r1 X') X
+    // using X^2 = X' X + 1 where X' is the generator of the subfield.
+    let z0 = tower_mul_u8(l0, r0, level - 1);
+    let z2 = tower_mul_u8(l1, r1, level - 1);
+    let z1 = tower_mul_u8(l0 ^ l1, r0 ^ r1, level - 1);
+    let alpha = if level == 1 { 1 } else { 1 << (half >> 1) };
+    let lo = z0 ^ z2;
+    let hi = z1 ^ z0 ^ z2 ^ tower_mul_u8(z2, alpha, level - 1);
+    lo | (hi << half)
+}
+
+/// A generator of the multiplicative group of `GF(2^8)` in the tower basis.
+const GENERATOR_8B: u8 = 0x13;
+
+/// `EXP_TABLE[i] = g^i` where `g` is `GENERATOR_8B`.
+///
+/// The table is stored twice so that the sum of two logarithms never needs to be reduced.
+const EXP_TABLE: [u8; 510] = {
+    let mut table = [0; 510];
+    let mut acc = 1;
+    let mut i = 0;
+    while i < 510 {
+        table[i] = acc;
+        acc = tower_mul_u8(acc, GENERATOR_8B, 3);
+        i += 1;
+    }
+    table
+};
+
+/// `LOG_TABLE[x] = i` where `g^i = x`. The entry for `0` is unused.
+const LOG_TABLE: [u8; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 255 {
+        table[EXP_TABLE[i] as usize] = i as u8;
+        i += 1;
+    }
+    table
+};
+
+/// The field `GF(2^8)` constructed as the third level of the binary tower
+/// `GF(2) ⊂ GF(2^2) ⊂ GF(2^4) ⊂ GF(2^8)`.
+#[derive(Copy, Clone, Default, Eq, Hash, PartialEq, Ord, PartialOrd)]
+#[repr(transparent)] // Important for reasoning about memory layout.
+#[must_use]
+pub struct BinaryField8b {
+    /// The coordinates of the element in the tower basis.
+    pub(crate) value: u8,
+}
+
+impl BinaryField8b {
+    /// Create a new field element from its coordinates in the tower basis.
+    #[inline]
+    pub const fn new(value: u8) -> Self {
+        Self { value }
+    }
+
+    /// Return the coordinates of the element in the tower basis.
+    #[inline]
+    pub const fn to_bits(self) -> u8 {
+        self.value
+    }
+
+    /// Multiply by the generator `X_2` of `GF(2^8)` over `GF(2^4)`.
+    #[inline]
+    pub(crate) fn mul_alpha(self) -> Self {
+        self * Self::new(0x10)
+    }
+}
+
+impl Display for BinaryField8b {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#04x}", self.value)
+    }
+}
+
+impl Debug for BinaryField8b {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Distribution<BinaryField8b> for StandardUniform {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BinaryField8b {
+        BinaryField8b::new(rng.random())
+    }
+}
+
+impl Serialize for BinaryField8b {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.value)
+    }
+}
+
+impl<'de> Deserialize<'de> for BinaryField8b {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let val = u8::deserialize(d)?;
+        Ok(Self::new(val))
+    }
+}
+
+impl Packable for BinaryField8b {}
+
+impl PrimeCharacteristicRing for BinaryField8b {
+    type PrimeSubfield = BinaryField1b;
+
+    const ZERO: Self = Self::new(0);
+    const ONE: Self = Self::new(1);
+    const TWO: Self = Self::new(0);
+    const NEG_ONE: Self = Self::new(1);
+
+    #[inline]
+    fn from_prime_subfield(f: Self::PrimeSubfield) -> Self {
+        Self::new(f.value)
+    }
+
+    #[inline]
+    fn from_bool(b: bool) -> Self {
+        Self::new(b as u8)
+    }
+
+    #[inline]
+    fn double(&self) -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<BinaryField1b> for BinaryField8b {
+    #[inline]
+    fn from(f: BinaryField1b) -> Self {
+        Self::from_prime_subfield(f)
+    }
+}
+
+impl RawDataSerializable for BinaryField8b {
+    const NUM_BYTES: usize = 1;
+
+    #[allow(refining_impl_trait)]
+    #[inline]
+    fn into_bytes(self) -> [u8; 1] {
+        [self.value]
+    }
+}
+
+impl Field for BinaryField8b {
+    type Packing = PackedBinaryField16x8b;
+
+    /// The element `1 + X_0 + X_2 = 0x13` in the tower basis.
+    const GENERATOR: Self = Self::new(GENERATOR_8B);
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+
+    #[inline]
+    fn try_inverse(&self) -> Option<Self> {
+        (!self.is_zero())
+            .then(|| Self::new(EXP_TABLE[255 - LOG_TABLE[self.value as usize] as usize]))
+    }
+
+    /// As squaring is a bijection in characteristic two, `sqrt(x) = x^{2^7}`.
+    #[inline]
+    fn sqrt(&self) -> Option<Self> {
+        Some(self.exp_power_of_2(7))
+    }
+
+    #[inline]
+    fn order() -> BigUint {
+        BigUint::from(1u32) << 8
+    }
+}
+
+impl Add for BinaryField8b {
+    type Output = Self;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.value ^ rhs.value)
+    }
+}
+
+impl Sub for BinaryField8b {
+    type Output = Self;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.value ^ rhs.value)
+    }
+}
+
+impl Neg for BinaryField8b {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self
+    }
+}
+
+impl Mul for BinaryField8b {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        if self.value == 0 || rhs.value == 0 {
+            return Self::ZERO;
+        }
+        let log_sum =
+            LOG_TABLE[self.value as usize] as usize + LOG_TABLE[rhs.value as usize] as usize;
+        Self::new(EXP_TABLE[log_sum])
+    }
+}
+
+impl_add_assign!(BinaryField8b);
+impl_sub_assign!(BinaryField8b);
+impl_mul_methods!(BinaryField8b);
+ring_sum!(BinaryField8b);
+impl_div_methods!(BinaryField8b, BinaryField8b);
+
+impl_add_base_field!(BinaryField8b, BinaryField1b);
+impl_sub_base_field!(BinaryField8b, BinaryField1b);
+impl_mul_base_field!(BinaryField8b, BinaryField1b);
+impl_div_methods!(BinaryField8b, BinaryField1b);
+impl_sum_prod_base_field!(BinaryField8b, BinaryField1b);
+
+impl Algebra<BinaryField1b> for BinaryField8b {}
+
+/// `BITS[x]` holds the bits of `x`, least significant first, as elements of `GF(2)`.
+///
+/// These are the coordinates of `x` over `GF(2)` in the tower basis. The table lets
+/// [`BasedVectorSpace::as_basis_coefficients_slice`] return them by reference.
+static BITS: [[BinaryField1b; 8]; 256] = {
+    let mut table = [[BinaryField1b::ZERO; 8]; 256];
+    let mut x = 0;
+    while x < 256 {
+        let mut i = 0;
+        while i < 8 {
+            table[x][i] = BinaryField1b::new((x >> i) & 1 == 1);
+            i += 1;
+        }
+        x += 1;
+    }
+    table
+};
+
+impl BasedVectorSpace<BinaryField1b> for BinaryField8b {
+    const DIMENSION: usize = 8;
+
+    #[inline]
+    fn as_basis_coefficients_slice(&self) -> &[BinaryField1b] {
+        &BITS[self.value as usize]
+    }
+
+    #[inline]
+    fn from_basis_coefficients_fn<Fn: FnMut(usize) -> BinaryField1b>(mut f: Fn) -> Self {
+        Self::new((0..8).fold(0, |acc, i| acc | ((f(i).value) << i)))
+    }
+
+    #[inline]
+    fn from_basis_coefficients_iter<I: ExactSizeIterator<Item = BinaryField1b>>(
+        iter: I,
+    ) -> Option<Self> {
+        (iter.len() == 8).then(|| {
+            Self::new(
+                iter.enumerate()
+                    .fold(0, |acc, (i, bit)| acc | (bit.value << i)),
+            )
+        })
+    }
+}
+
+impl ExtensionField<BinaryField1b> for BinaryField8b {
+    /// `GF(2)` is not packed, so neither is its extension `GF(2^8)`.
+    type ExtensionPacking = Self;
+
+    #[inline]
+    fn is_in_basefield(&self) -> bool {
+        self.value <= 1
+    }
+
+    #[inline]
+    fn as_base(&self) -> Option<BinaryField1b> {
+        (self.value <= 1).then(|| BinaryField1b::new(self.value == 1))
+    }
+}
+
+impl PackedFieldExtension<BinaryField1b, Self> for BinaryField8b {
+    #[inline]
+    fn from_ext_slice(ext_slice: &[Self]) -> Self {
+        assert_eq!(ext_slice.len(), 1);
+        ext_slice[0]
+    }
+
+    #[inline]
+    fn packed_ext_powers(base: Self) -> Powers<Self> {
+        base.powers()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use p3_field_testing::{
+        test_galois_extension, test_generator, test_into_stream, test_inverse,
+        test_packed_extension, test_powers_collect, test_to_from_extension_field,
+    };
+
+    use super::*;
+
+    type F = BinaryField8b;
+
+    #[test]
+    fn test_tables_match_tower_mul() {
+        for lhs in 0..=255 {
+            for rhs in 0..=255 {
+                assert_eq!(
+                    (F::new(lhs) * F::new(rhs)).to_bits(),
+                    tower_mul_u8(lhs, rhs, 3)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_defining_relations() {
+        // X_0^2 = X_0 + 1, X_1^2 = X_0 X_1 + 1 and X_2^2 = X_1 X_2 + 1.
+        assert_eq!(F::new(0x02).square(), F::new(0x03));
+        assert_eq!(F::new(0x04).square(), F::new(0x09));
+        assert_eq!(F::new(0x10).square(), F::new(0x41));
+        assert_eq!(F::new(0x10).mul_alpha(), F::new(0x41));
+    }
+
+    #[test]
+    fn test_sqrt() {
+        for x in 0..=255 {
+            let x = F::new(x);
+            assert_eq!(x.sqrt().unwrap().square(), x);
+            assert!(x.is_square());
+        }
+    }
+
+    #[test]
+    fn test_serde() {
+        let x = F::new(0xa7);
+        let serialized = serde_json::to_string(&x).unwrap();
+        assert_eq!(serialized, "167");
+        let deserialized: F = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, x);
+    }
+
+    #[test]
+    fn test_field_properties() {
+        test_inverse::<F>();
+        test_into_stream::<F>();
+        test_powers_collect::<F>();
+        test_generator::<F>(&[
+            (BigUint::from(3u8), 1),
+            (BigUint::from(5u8), 1),
+            (BigUint::from(17u8), 1),
+        ]);
+    }
+
+    #[test]
+    fn test_extension_of_gf2() {
+        for x in 0..=255 {
+            let x = F::new(x);
+            let bits = BasedVectorSpace::<BinaryField1b>::as_basis_coefficients_slice(&x);
+            assert_eq!(F::from_basis_coefficients_slice(bits), Some(x));
+        }
+        test_to_from_extension_field::<BinaryField1b, F>();
+        test_galois_extension::<BinaryField1b, F>();
+        test_packed_extension::<BinaryField1b, F>();
+    }
+}
@@ -0,0 +1,419 @@
+use alloc::vec::Vec;
+use core::array;
+use core::iter::{Product, Sum};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use p3_field::{
+    Algebra, BasedVectorSpace, ExtensionField, Field, PackedFieldExtension, Powers,
+    PrimeCharacteristicRing,
+};
+use rand::distr::{Distribution, StandardUniform};
+
+use crate::{
+    BinaryField1b, BinaryField8b, BinaryField16b, BinaryField32b, BinaryField64b,
+    PackedBinaryField2x64b, PackedBinaryField4x32b, PackedBinaryField8x16b, PackedBinaryField16x8b,
+};
+
+/// A vector of elements of a tower field `EF` of degree `D` over the scalars of the packing `P`.
+///
+/// Elements are stored coefficient by coefficient, so the `i`'th entry is the packed vector of the
+/// `i`'th coordinates of each element over the scalars of `P`. This makes addition and
+/// multiplication by packed base field elements cheap. Full multiplications and squarings are not
+/// vectorized: they transpose back to the extension field and loop over the lanes with the scalar
+/// arithmetic.
+///
+/// `P` defaults to [`PackedBinaryField16x8b`], which packs the extensions of `GF(2^8)`. The other
+/// portable packings are used for the quadratic extensions of each level of the tower.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(transparent)] // Needed to make various casts safe.
+#[must_use]
+pub struct PackedBinaryTowerExtension<EF, const D: usize, P = PackedBinaryField16x8b> {
+    pub(crate) value: [P; D],
+    _phantom: PhantomData<EF>,
+}
+
+/// Implement the `*Assign` operators and iterator traits in terms of the binary operators.
+macro_rules! impl_assign_ops {
+    ($packed:ty, $base:ty; $($rhs:ty),*) => {
+        $(
+            impl<EF: Field + BasedVectorSpace<$base>, const D: usize> AddAssign<$rhs>
+                for PackedBinaryTowerExtension<EF, D, $packed>
+            {
+                #[inline]
+                fn add_assign(&mut self, rhs: $rhs) {
+                    *self = *self + rhs;
+                }
+            }
+
+            impl<EF: Field + BasedVectorSpace<$base>, const D: usize> SubAssign<$rhs>
+                for PackedBinaryTowerExtension<EF, D, $packed>
+            {
+                #[inline]
+                fn sub_assign(&mut self, rhs: $rhs) {
+                    *self = *self - rhs;
+                }
+            }
+
+            impl<EF: Field + BasedVectorSpace<$base>, const D: usize> MulAssign<$rhs>
+                for PackedBinaryTowerExtension<EF, D, $packed>
+            {
+                #[inline]
+                fn mul_assign(&mut self, rhs: $rhs) {
+                    *self = *self * rhs;
+                }
+            }
+        )*
+    };
+}
+
+/// Implement the packed extensions of the tower fields over `$base`, packed as `$packed` with
+/// `$width` lanes.
+macro_rules! packed_binary_tower_extension {
+    ($packed:ident, $base:ident, $width:literal) => {
+        impl<EF, const D: usize> PackedBinaryTowerExtension<EF, D, $packed> {
+            const fn new(value: [$packed; D]) -> Self {
+                Self {
+                    value,
+                    _phantom: PhantomData,
+                }
+            }
+
+            /// Embed a packed base field vector as the first coordinate.
+            const fn from_base(x: $packed) -> Self {
+                let mut value = [$packed::ZERO; D];
+                value[0] = x;
+                Self::new(value)
+            }
+        }
+
+        impl<EF: Field + BasedVectorSpace<$base>, const D: usize>
+            PackedBinaryTowerExtension<EF, D, $packed>
+        {
+            /// Transpose into the individual extension field elements.
+            #[inline]
+            fn unpack(&self) -> [EF; $width] {
+                array::from_fn(|i| EF::from_basis_coefficients_fn(|j| self.value[j].0[i]))
+            }
+
+            /// Transpose a collection of extension field elements into a packed vector.
+            #[inline]
+            fn pack(elems: &[EF]) -> Self {
+                assert_eq!(elems.len(), $width);
+                Self::new(array::from_fn(|j| {
+                    $packed(array::from_fn(|i| {
+                        elems[i].as_basis_coefficients_slice()[j]
+                    }))
+                }))
+            }
+        }
+
+        impl<EF, const D: usize> Default for PackedBinaryTowerExtension<EF, D, $packed> {
+            #[inline]
+            fn default() -> Self {
+                Self::new([$packed::ZERO; D])
+            }
+        }
+
+        impl<EF: Field + BasedVectorSpace<$base>, const D: usize> From<EF>
+            for PackedBinaryTowerExtension<EF, D, $packed>
+        {
+            #[inline]
+            fn from(x: EF) -> Self {
+                Self::new(array::from_fn(|j| {
+                    x.as_basis_coefficients_slice()[j].into()
+                }))
+            }
+        }
+
+        impl<EF, const D: usize> From<$packed> for PackedBinaryTowerExtension<EF, D, $packed> {
+            #[inline]
+            fn from(x: $packed) -> Self {
+                Self::from_base(x)
+            }
+        }
+
+        impl<EF, const D: usize> Distribution<PackedBinaryTowerExtension<EF, D, $packed>> for StandardUniform {
+            #[inline]
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> PackedBinaryTowerExtension<EF, D, $packed> {
+                PackedBinaryTowerExtension::<EF, D, $packed>::new(array::from_fn(|_| self.sample(rng)))
+            }
+        }
+
+        impl<EF: Field + BasedVectorSpace<$base>, const D: usize> PrimeCharacteristicRing
+            for PackedBinaryTowerExtension<EF, D, $packed>
+        {
+            type PrimeSubfield = BinaryField1b;
+
+            const ZERO: Self = Self::new([$packed::ZERO; D]);
+            const ONE: Self = Self::from_base($packed::ONE);
+            const TWO: Self = Self::new([$packed::ZERO; D]);
+            const NEG_ONE: Self = Self::from_base($packed::ONE);
+
+            #[inline]
+            fn from_prime_subfield(f: Self::PrimeSubfield) -> Self {
+                $packed::from_prime_subfield(f).into()
+            }
+
+            #[inline]
+            fn double(&self) -> Self {
+                Self::ZERO
+            }
+
+            #[inline]
+            fn square(&self) -> Self {
+                Self::pack(&self.unpack().map(|x| x.square()))
+            }
+        }
+
+        impl<EF: Field + BasedVectorSpace<$base>, const D: usize> Algebra<EF>
+            for PackedBinaryTowerExtension<EF, D, $packed>
+        {
+        }
+
+        impl<EF: Field + BasedVectorSpace<$base>, const D: usize> Algebra<$packed>
+            for PackedBinaryTowerExtension<EF, D, $packed>
+        {
+        }
+
+        impl<EF: Field + BasedVectorSpace<$base>, const D: usize>
+            BasedVectorSpace<$packed> for PackedBinaryTowerExtension<EF, D, $packed>
+        {
+            const DIMENSION: usize = D;
+
+            #[inline]
+            fn as_basis_coefficients_slice(&self) -> &[$packed] {
+                &self.value
+            }
+
+            #[inline]
+            fn from_basis_coefficients_fn<Fn: FnMut(usize) -> $packed>(f: Fn) -> Self {
+                Self::new(array::from_fn(f))
+            }
+
+            #[inline]
+            fn from_basis_coefficients_iter<I: ExactSizeIterator<Item = $packed>>(
+                mut iter: I,
+            ) -> Option<Self> {
+                // The unwrap is safe as we just checked the length of iter.
+                (iter.len() == D).then(|| Self::new(array::from_fn(|_| iter.next().unwrap())))
+            }
+        }
+
+        impl<EF, const D: usize> PackedFieldExtension<$base, EF>
+            for PackedBinaryTowerExtension<EF, D, $packed>
+        where
+            EF: ExtensionField<$base, ExtensionPacking = Self>,
+        {
+            #[inline]
+            fn from_ext_slice(ext_slice: &[EF]) -> Self {
+                Self::pack(ext_slice)
+            }
+
+            #[inline]
+            fn to_ext_iter(iter: impl IntoIterator<Item = Self>) -> impl Iterator<Item = EF> {
+                iter.into_iter().flat_map(|x| x.unpack())
+            }
+
+            #[inline]
+            fn packed_ext_powers(base: EF) -> Powers<Self> {
+                let powers: Vec<EF> = base.powers().take($width + 1).collect();
+                Powers {
+                    // Broadcast the power of `base` by the width.
+                    base: powers[$width].into(),
+                    current: Self::pack(&powers[..$width]),
+                }
+            }
+        }
+
+        impl<EF, const D: usize> Add for PackedBinaryTowerExtension<EF, D, $packed> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self::new(array::from_fn(|i| self.value[i] + rhs.value[i]))
+            }
+        }
+
+        impl<EF, const D: usize> Sub for PackedBinaryTowerExtension<EF, D, $packed> {
+            type Output = Self;
+
+            #[inline]
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn sub(self, rhs: Self) -> Self {
+                self + rhs
+            }
+        }
+
+        impl<EF, const D: usize> Neg for PackedBinaryTowerExtension<EF, D, $packed> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                self
+            }
+        }
+
+        impl<EF: Field + BasedVectorSpace<$base>, const D: usize> Mul
+            for PackedBinaryTowerExtension<EF, D, $packed>
+        {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                let lhs = self.unpack();
+                let rhs = rhs.unpack();
+                Self::pack(&array::from_fn::<_, $width, _>(|i| lhs[i] * rhs[i]))
+            }
+        }
+
+        impl<EF: Field + BasedVectorSpace<$base>, const D: usize> Add<EF>
+            for PackedBinaryTowerExtension<EF, D, $packed>
+        {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: EF) -> Self {
+                self + Self::from(rhs)
+            }
+        }
+
+        impl<EF: Field + BasedVectorSpace<$base>, const D: usize> Sub<EF>
+            for PackedBinaryTowerExtension<EF, D, $packed>
+        {
+            type Output = Self;
+
+            #[inline]
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn sub(self, rhs: EF) -> Self {
+                self + Self::from(rhs)
+            }
+        }
+
+        impl<EF: Field + BasedVectorSpace<$base>, const D: usize> Mul<EF>
+            for PackedBinaryTowerExtension<EF, D, $packed>
+        {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: EF) -> Self {
+                Self::pack(&self.unpack().map(|x| x * rhs))
+            }
+        }
+
+        impl<EF, const D: usize> Add<$packed> for PackedBinaryTowerExtension<EF, D, $packed> {
+            type Output = Self;
+
+            #[inline]
+            fn add(mut self, rhs: $packed) -> Self {
+                self.value[0] += rhs;
+                self
+            }
+        }
+
+        impl<EF, const D: usize> Sub<$packed> for PackedBinaryTowerExtension<EF, D, $packed> {
+            type Output = Self;
+
+            #[inline]
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn sub(self, rhs: $packed) -> Self {
+                self + rhs
+            }
+        }
+
+        impl<EF, const D: usize> Mul<$packed> for PackedBinaryTowerExtension<EF, D, $packed> {
+            type Output = Self;
+
+            /// Multiplication by a base field element acts independently on each coordinate.
+            #[inline]
+            fn mul(self, rhs: $packed) -> Self {
+                Self::new(self.value.map(|x| x * rhs))
+            }
+        }
+
+        impl_assign_ops!($packed, $base; PackedBinaryTowerExtension<EF, D, $packed>, EF, $packed);
+
+        impl<EF: Field + BasedVectorSpace<$base>, const D: usize> Sum
+            for PackedBinaryTowerExtension<EF, D, $packed>
+        {
+            #[inline]
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.reduce(|x, y| x + y).unwrap_or(Self::ZERO)
+            }
+        }
+
+        impl<EF: Field + BasedVectorSpace<$base>, const D: usize> Product
+            for PackedBinaryTowerExtension<EF, D, $packed>
+        {
+            #[inline]
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.reduce(|x, y| x * y).unwrap_or(Self::ONE)
+            }
+        }
+    };
+}
+
+packed_binary_tower_extension!(PackedBinaryField16x8b, BinaryField8b, 16);
+packed_binary_tower_extension!(PackedBinaryField8x16b, BinaryField16b, 8);
+packed_binary_tower_extension!(PackedBinaryField4x32b, BinaryField32b, 4);
+packed_binary_tower_extension!(PackedBinaryField2x64b, BinaryField64b, 2);
+
+#[cfg(test)]
+mod tests {
+    use p3_field::{PackedValue, PrimeCharacteristicRing};
+    use rand::distr::{Distribution, StandardUniform};
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+    use crate::BinaryField128b;
+
+    /// Check the packed extension arithmetic lane by lane against the scalar arithmetic.
+    fn test_vs_scalar<F, EF>()
+    where
+        F: Field,
+        EF: ExtensionField<F>,
+        StandardUniform: Distribution<F> + Distribution<EF>,
+    {
+        type Packing<F, EF> = <EF as ExtensionField<F>>::ExtensionPacking;
+
+        let width = F::Packing::WIDTH;
+        let mut rng = SmallRng::seed_from_u64(1);
+        let xs: Vec<EF> = (0..width).map(|_| rng.random()).collect();
+        let ys: Vec<EF> = (0..width).map(|_| rng.random()).collect();
+        let scalar: EF = rng.random();
+        let base = F::Packing::from_fn(|_| rng.random::<F>());
+
+        let x = Packing::<F, EF>::from_ext_slice(&xs);
+        let y = Packing::<F, EF>::from_ext_slice(&ys);
+        let unpack = |p| Packing::<F, EF>::to_ext_iter([p]).collect::<Vec<_>>();
+
+        assert_eq!(unpack(x), xs);
+        for i in 0..width {
+            let b = EF::from(base.as_slice()[i]);
+            assert_eq!(unpack(x + y)[i], xs[i] + ys[i]);
+            assert_eq!(unpack(x - y)[i], xs[i] - ys[i]);
+            assert_eq!(unpack(x * y)[i], xs[i] * ys[i]);
+            assert_eq!(unpack(x.square())[i], xs[i].square());
+            assert_eq!(unpack(x * scalar)[i], xs[i] * scalar);
+            assert_eq!(unpack(x + base)[i], xs[i] + b);
+            assert_eq!(unpack(x * base)[i], xs[i] * b);
+        }
+    }
+
+    #[test]
+    fn test_packed_binary_tower_extensions() {
+        test_vs_scalar::<BinaryField8b, BinaryField16b>();
+        test_vs_scalar::<BinaryField8b, BinaryField32b>();
+        test_vs_scalar::<BinaryField8b, BinaryField64b>();
+        test_vs_scalar::<BinaryField8b, BinaryField128b>();
+    }
+
+    #[test]
+    fn test_packed_subfield_extensions() {
+        test_vs_scalar::<BinaryField1b, BinaryField8b>();
+        test_vs_scalar::<BinaryField16b, BinaryField32b>();
+        test_vs_scalar::<BinaryField32b, BinaryField64b>();
+        test_vs_scalar::<BinaryField64b, BinaryField128b>();
+    }
+}
@@ -0,0 +1,25 @@
+//! The binary tower fields `GF(2) ⊂ GF(2^8) ⊂ GF(2^16) ⊂ GF(2^32) ⊂ GF(2^64) ⊂ GF(2^128)`.
+//!
+//! Every level of the tower is a quadratic extension of the previous one, following the construction of
+//! Wiedemann as used in [Diamond and Posen](https://eprint.iacr.org/2023/1784). Elements are stored in the
+//! resulting tower basis, in which the embedding of a subfield just pads the coordinates with zeros and
+//! the coordinates of an element over `GF(2^8)` are simply its bytes.
+//!
+//! As the characteristic is two, `TWO = 0` so [`PrimeCharacteristicRing::halve`](p3_field::PrimeCharacteristicRing::halve)
+//! and `div_2exp_u64` are not defined and will panic.
+
+#![no_std]
+
+extern crate alloc;
+
+mod binary_field_1b;
+mod binary_field_8b;
+mod extension_packing;
+mod packing;
+mod tower;
+
+pub use binary_field_1b::*;
+pub use binary_field_8b::*;
+pub use extension_packing::*;
+pub use packing::*;
+pub use tower::*;
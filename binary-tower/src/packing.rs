@@ -0,0 +1,194 @@
+//! Portable packings of the binary tower fields into 128-bit vectors.
+//!
+//! Addition is a lane-wise XOR which the compiler readily vectorizes. Multiplication falls back to
+//! the scalar tower arithmetic in each lane, so no carry-less multiplication (PCLMULQDQ) or Galois
+//! field (GFNI) instructions are required.
+
+use core::array;
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use p3_field::op_assign_macros::{
+    impl_add_assign, impl_add_base_field, impl_div_methods, impl_mul_base_field, impl_mul_methods,
+    impl_packed_div_methods, impl_packed_value, impl_rng, impl_sub_assign, impl_sub_base_field,
+    impl_sum_prod_base_field, ring_sum,
+};
+use p3_field::{Algebra, Field, PackedField, PackedValue, PrimeCharacteristicRing};
+use rand::Rng;
+use rand::distr::{Distribution, StandardUniform};
+
+use crate::{BinaryField1b, BinaryField8b, BinaryField16b, BinaryField32b, BinaryField64b};
+
+/// Define a packed vector of `$width` elements of `$field`.
+macro_rules! packed_binary_field {
+    ($(#[$doc:meta])* $name:ident, $field:ty, $width:literal) => {
+        $(#[$doc])*
+        ///
+        /// The operations loop over the lanes with the scalar arithmetic. Additions are XORs which
+        /// the compiler readily vectorizes, but multiplications and inversions are not vectorized.
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        #[repr(transparent)] // Needed to make `transmute`s safe.
+        #[must_use]
+        pub struct $name(pub [$field; $width]);
+
+        impl $name {
+            #[inline]
+            const fn broadcast(value: $field) -> Self {
+                Self([value; $width])
+            }
+        }
+
+        impl Default for $name {
+            #[inline]
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl From<$field> for $name {
+            #[inline]
+            fn from(value: $field) -> Self {
+                Self::broadcast(value)
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self(array::from_fn(|i| self.0[i] + rhs.0[i]))
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            #[inline]
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn sub(self, rhs: Self) -> Self {
+                self + rhs
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                self
+            }
+        }
+
+        impl Mul for $name {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                Self(array::from_fn(|i| self.0[i] * rhs.0[i]))
+            }
+        }
+
+        impl_add_assign!($name);
+        impl_sub_assign!($name);
+        impl_mul_methods!($name);
+        ring_sum!($name);
+        impl_rng!($name);
+
+        impl PrimeCharacteristicRing for $name {
+            type PrimeSubfield = BinaryField1b;
+
+            const ZERO: Self = Self::broadcast(<$field>::ZERO);
+            const ONE: Self = Self::broadcast(<$field>::ONE);
+            const TWO: Self = Self::broadcast(<$field>::TWO);
+            const NEG_ONE: Self = Self::broadcast(<$field>::NEG_ONE);
+
+            #[inline]
+            fn from_prime_subfield(f: Self::PrimeSubfield) -> Self {
+                <$field>::from_prime_subfield(f).into()
+            }
+
+            #[inline]
+            fn double(&self) -> Self {
+                Self::ZERO
+            }
+
+            #[inline]
+            fn square(&self) -> Self {
+                Self(self.0.map(|x| x.square()))
+            }
+        }
+
+        impl_add_base_field!($name, $field);
+        impl_sub_base_field!($name, $field);
+        impl_mul_base_field!($name, $field);
+        impl_div_methods!($name, $field);
+        impl_packed_div_methods!($name);
+        impl_sum_prod_base_field!($name, $field);
+
+        impl Algebra<$field> for $name {}
+
+        impl_packed_value!($name, $field, $width);
+
+        unsafe impl PackedField for $name {
+            type Scalar = $field;
+        }
+    };
+}
+
+packed_binary_field!(
+    /// A vector of 16 elements of `GF(2^8)`.
+    PackedBinaryField16x8b, BinaryField8b, 16
+);
+packed_binary_field!(
+    /// A vector of 8 elements of `GF(2^16)`.
+    PackedBinaryField8x16b, BinaryField16b, 8
+);
+packed_binary_field!(
+    /// A vector of 4 elements of `GF(2^32)`.
+    PackedBinaryField4x32b, BinaryField32b, 4
+);
+packed_binary_field!(
+    /// A vector of 2 elements of `GF(2^64)`.
+    PackedBinaryField2x64b, BinaryField64b, 2
+);
+
+#[cfg(test)]
+mod tests {
+    use p3_field_testing::{
+        test_multiplicative_inverse, test_packed_linear_combination, test_vs_scalar,
+    };
+
+    use super::*;
+
+    macro_rules! test_packed_binary_field {
+        ($mod_name:ident, $packed:ty, $field:ty) => {
+            mod $mod_name {
+                use super::*;
+
+                #[test]
+                fn test_vs_scalar_ops() {
+                    let specials = <$packed>::from_fn(|i| {
+                        [<$field>::ZERO, <$field>::ONE, <$field>::GENERATOR][i % 3]
+                    });
+                    test_vs_scalar(specials);
+                }
+
+                #[test]
+                fn test_inverse() {
+                    test_multiplicative_inverse::<$packed>();
+                }
+
+                #[test]
+                fn test_linear_combination() {
+                    test_packed_linear_combination::<$packed>();
+                }
+            }
+        };
+    }
+
+    test_packed_binary_field!(packed_16x8b, PackedBinaryField16x8b, BinaryField8b);
+    test_packed_binary_field!(packed_8x16b, PackedBinaryField8x16b, BinaryField16b);
+    test_packed_binary_field!(packed_4x32b, PackedBinaryField4x32b, BinaryField32b);
+    test_packed_binary_field!(packed_2x64b, PackedBinaryField2x64b, BinaryField64b);
+}
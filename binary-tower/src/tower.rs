@@ -0,0 +1,609 @@
+//! The binary tower fields `GF(2^16) ⊂ GF(2^32) ⊂ GF(2^64) ⊂ GF(2^128)`.
+//!
+//! Each level is a quadratic extension of the previous one: `T_{i + 1} = T_i[X_i]/(X_i^2 + X_{i - 1} X_i + 1)`.
+//! An element `a_0 + a_1 X_i` is stored with the coordinates of `a_0` in the low half and those of `a_1`
+//! in the high half. Unrolling this, the bytes of an element are its coordinates over `GF(2^8)`.
+
+use core::fmt::{self, Debug, Display, Formatter};
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::{array, slice};
+
+use num_bigint::BigUint;
+use p3_field::op_assign_macros::{
+    impl_add_assign, impl_add_base_field, impl_div_methods, impl_mul_base_field, impl_mul_methods,
+    impl_sub_assign, impl_sub_base_field, impl_sum_prod_base_field, ring_sum,
+};
+use p3_field::{
+    Algebra, BasedVectorSpace, ExtensionField, Field, Packable, PrimeCharacteristicRing,
+    RawDataSerializable,
+};
+use rand::Rng;
+use rand::distr::{Distribution, StandardUniform};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::{
+    BinaryField1b, BinaryField8b, PackedBinaryField2x64b, PackedBinaryField4x32b,
+    PackedBinaryField8x16b, PackedBinaryTowerExtension,
+};
+
+/// Define a binary tower field which is a quadratic extension of `$half`.
+macro_rules! binary_tower_field {
+    (
+        $(#[$doc:meta])*
+        $name:ident, $half:ty, $int:ty, $half_int:ty, $bytes:literal, $packing:ty, $generator:literal
+    ) => {
+        $(#[$doc])*
+        #[derive(Copy, Clone, Default, Eq, Hash, PartialEq)]
+        #[repr(transparent)] // Important for reasoning about memory layout.
+        #[must_use]
+        pub struct $name {
+            /// The coordinates of the element over `GF(2^8)` in the tower basis.
+            pub(crate) value: [BinaryField8b; $bytes],
+        }
+
+        impl $name {
+            /// Create a new field element from its coordinates in the tower basis.
+            #[inline]
+            pub const fn new(value: $int) -> Self {
+                let bytes = value.to_le_bytes();
+                let mut out = [BinaryField8b::ZERO; $bytes];
+                let mut i = 0;
+                while i < $bytes {
+                    out[i] = BinaryField8b::new(bytes[i]);
+                    i += 1;
+                }
+                Self { value: out }
+            }
+
+            /// Return the coordinates of the element in the tower basis.
+            #[inline]
+            pub fn to_bits(self) -> $int {
+                <$int>::from_le_bytes(self.value.map(BinaryField8b::to_bits))
+            }
+
+            /// Split `a_0 + a_1 X` into `(a_0, a_1)`.
+            #[inline]
+            fn split(self) -> ($half, $half) {
+                let bits = self.to_bits();
+                (
+                    <$half>::new(bits as $half_int),
+                    <$half>::new((bits >> (4 * $bytes)) as $half_int),
+                )
+            }
+
+            /// Construct `lo + hi X`.
+            #[inline]
+            fn join(lo: $half, hi: $half) -> Self {
+                Self::new((lo.to_bits() as $int) | ((hi.to_bits() as $int) << (4 * $bytes)))
+            }
+
+            /// Multiply by the generator `X` of this field over the subfield of half the size.
+            #[inline]
+            #[allow(dead_code)] // Unused at the top of the tower.
+            pub(crate) fn mul_alpha(self) -> Self {
+                // (a_0 + a_1 X) X = a_1 + (a_0 + a_1 X') X where X' is the generator of the subfield.
+                let (lo, hi) = self.split();
+                Self::join(hi, lo + hi.mul_alpha())
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write!(f, "{:#0width$x}", self.to_bits(), width = 2 * $bytes + 2)
+            }
+        }
+
+        impl Debug for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                Display::fmt(self, f)
+            }
+        }
+
+        impl Distribution<$name> for StandardUniform {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $name {
+                $name {
+                    value: rng.random(),
+                }
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.to_bits().serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+                let val = <$int>::deserialize(d)?;
+                Ok(Self::new(val))
+            }
+        }
+
+        impl Packable for $name {}
+
+        impl PrimeCharacteristicRing for $name {
+            type PrimeSubfield = BinaryField1b;
+
+            const ZERO: Self = Self::new(0);
+            const ONE: Self = Self::new(1);
+            const TWO: Self = Self::new(0);
+            const NEG_ONE: Self = Self::new(1);
+
+            #[inline]
+            fn from_prime_subfield(f: Self::PrimeSubfield) -> Self {
+                Self::from(BinaryField8b::from(f))
+            }
+
+            #[inline]
+            fn from_bool(b: bool) -> Self {
+                Self::new(b as $int)
+            }
+
+            #[inline]
+            fn double(&self) -> Self {
+                Self::ZERO
+            }
+
+            #[inline]
+            fn square(&self) -> Self {
+                // (a_0 + a_1 X)^2 = a_0^2 + a_1^2 X^2 = a_0^2 + a_1^2 + a_1^2 X' X.
+                let (lo, hi) = self.split();
+                let hi_sq = hi.square();
+                Self::join(lo.square() + hi_sq, hi_sq.mul_alpha())
+            }
+        }
+
+        impl RawDataSerializable for $name {
+            const NUM_BYTES: usize = $bytes;
+
+            #[allow(refining_impl_trait)]
+            #[inline]
+            fn into_bytes(self) -> [u8; $bytes] {
+                self.value.map(BinaryField8b::to_bits)
+            }
+        }
+
+        impl Field for $name {
+            type Packing = $packing;
+
+            const GENERATOR: Self = Self::new($generator);
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.value.iter().all(Field::is_zero)
+            }
+
+            #[inline]
+            fn try_inverse(&self) -> Option<Self> {
+                // The conjugate of X is X + X' so the norm of a_0 + a_1 X is
+                // (a_0 + a_1 X)(a_0 + a_1 X' + a_1 X) = a_0 (a_0 + a_1 X') + a_1^2.
+                let (lo, hi) = self.split();
+                let lo_plus_hi_alpha = lo + hi.mul_alpha();
+                let norm = lo * lo_plus_hi_alpha + hi.square();
+                let norm_inv = norm.try_inverse()?;
+                Some(Self::join(lo_plus_hi_alpha * norm_inv, hi * norm_inv))
+            }
+
+            /// As squaring is a bijection in characteristic two, `sqrt(x) = x^{2^{n - 1}}`.
+            #[inline]
+            fn sqrt(&self) -> Option<Self> {
+                Some(self.exp_power_of_2(8 * $bytes - 1))
+            }
+
+            #[inline]
+            fn order() -> BigUint {
+                BigUint::from(1u32) << (8 * $bytes)
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self {
+                    value: array::from_fn(|i| self.value[i] + rhs.value[i]),
+                }
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            #[inline]
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn sub(self, rhs: Self) -> Self {
+                self + rhs
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                self
+            }
+        }
+
+        impl Mul for $name {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                // Karatsuba multiplication using X^2 = X' X + 1:
+                // (a_0 + a_1 X)(b_0 + b_1 X) = a_0 b_0 + a_1 b_1 + (a_0 b_1 + a_1 b_0 + a_1 b_1 X') X
+                // where a_0 b_1 + a_1 b_0 = (a_0 + a_1)(b_0 + b_1) + a_0 b_0 + a_1 b_1.
+                let (l0, l1) = self.split();
+                let (r0, r1) = rhs.split();
+                let z0 = l0 * r0;
+                let z2 = l1 * r1;
+                let z1 = (l0 + l1) * (r0 + r1);
+                Self::join(z0 + z2, z1 + z0 + z2 + z2.mul_alpha())
+            }
+        }
+
+        impl_add_assign!($name);
+        impl_sub_assign!($name);
+        impl_mul_methods!($name);
+        ring_sum!($name);
+        impl_div_methods!($name, $name);
+
+        impl From<BinaryField8b> for $name {
+            #[inline]
+            fn from(f: BinaryField8b) -> Self {
+                let mut value = [BinaryField8b::ZERO; $bytes];
+                value[0] = f;
+                Self { value }
+            }
+        }
+
+        impl From<BinaryField1b> for $name {
+            #[inline]
+            fn from(f: BinaryField1b) -> Self {
+                Self::from_prime_subfield(f)
+            }
+        }
+
+        impl_add_base_field!($name, BinaryField1b);
+        impl_sub_base_field!($name, BinaryField1b);
+        impl_mul_base_field!($name, BinaryField1b);
+        impl_div_methods!($name, BinaryField1b);
+        impl_sum_prod_base_field!($name, BinaryField1b);
+
+        impl Algebra<BinaryField1b> for $name {}
+
+        impl_add_base_field!($name, BinaryField8b);
+        impl_sub_base_field!($name, BinaryField8b);
+        impl_div_methods!($name, BinaryField8b);
+        impl_sum_prod_base_field!($name, BinaryField8b);
+
+        // Multiplication by an element of `GF(2^8)` acts independently on each coordinate.
+        impl Mul<BinaryField8b> for $name {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: BinaryField8b) -> Self {
+                Self {
+                    value: self.value.map(|x| x * rhs),
+                }
+            }
+        }
+
+        impl Mul<$name> for BinaryField8b {
+            type Output = $name;
+
+            #[inline]
+            fn mul(self, rhs: $name) -> $name {
+                rhs * self
+            }
+        }
+
+        impl Algebra<BinaryField8b> for $name {}
+
+        impl BasedVectorSpace<BinaryField8b> for $name {
+            const DIMENSION: usize = $bytes;
+
+            #[inline]
+            fn as_basis_coefficients_slice(&self) -> &[BinaryField8b] {
+                &self.value
+            }
+
+            #[inline]
+            fn from_basis_coefficients_fn<Fn: FnMut(usize) -> BinaryField8b>(f: Fn) -> Self {
+                Self {
+                    value: array::from_fn(f),
+                }
+            }
+
+            #[inline]
+            fn from_basis_coefficients_iter<I: ExactSizeIterator<Item = BinaryField8b>>(
+                mut iter: I,
+            ) -> Option<Self> {
+                // The unwrap is safe as we just checked the length of iter.
+                (iter.len() == $bytes).then(|| Self {
+                    value: array::from_fn(|_| iter.next().unwrap()),
+                })
+            }
+        }
+
+        impl ExtensionField<BinaryField8b> for $name {
+            type ExtensionPacking = PackedBinaryTowerExtension<$name, $bytes>;
+
+            #[inline]
+            fn is_in_basefield(&self) -> bool {
+                self.value[1..].iter().all(Field::is_zero)
+            }
+
+            #[inline]
+            fn as_base(&self) -> Option<BinaryField8b> {
+                ExtensionField::<BinaryField8b>::is_in_basefield(self).then_some(self.value[0])
+            }
+        }
+    };
+}
+
+/// Implement `$name` as a quadratic extension of `$half`, whose packing is `$half_packing`.
+///
+/// Every level is already an extension of `GF(2^8)`. This adds the extension over the previous
+/// level, in the basis `1, X` where `X` is the generator of `$name` over `$half`.
+macro_rules! tower_quadratic_extension {
+    ($name:ty, $half:ty, $half_packing:ty) => {
+        impl_add_base_field!($name, $half);
+        impl_sub_base_field!($name, $half);
+        impl_div_methods!($name, $half);
+        impl_sum_prod_base_field!($name, $half);
+
+        // Multiplication by an element of the previous level acts independently on both halves.
+        impl Mul<$half> for $name {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: $half) -> Self {
+                let (lo, hi) = self.split();
+                Self::join(lo * rhs, hi * rhs)
+            }
+        }
+
+        impl Mul<$name> for $half {
+            type Output = $name;
+
+            #[inline]
+            fn mul(self, rhs: $name) -> $name {
+                rhs * self
+            }
+        }
+
+        impl Algebra<$half> for $name {}
+
+        impl BasedVectorSpace<$half> for $name {
+            const DIMENSION: usize = 2;
+
+            #[inline]
+            fn as_basis_coefficients_slice(&self) -> &[$half] {
+                // SAFETY: `$half` is a `repr(transparent)` wrapper around half as many `GF(2^8)`
+                // coordinates, so the coordinates of `a_0 + a_1 X` have the layout of `[a_0, a_1]`.
+                unsafe { slice::from_raw_parts(self.value.as_ptr().cast::<$half>(), 2) }
+            }
+
+            #[inline]
+            fn from_basis_coefficients_fn<Fn: FnMut(usize) -> $half>(mut f: Fn) -> Self {
+                let lo = f(0);
+                Self::join(lo, f(1))
+            }
+
+            #[inline]
+            fn from_basis_coefficients_iter<I: ExactSizeIterator<Item = $half>>(
+                mut iter: I,
+            ) -> Option<Self> {
+                // The unwraps are safe as we just checked the length of iter.
+                (iter.len() == 2).then(|| {
+                    let lo = iter.next().unwrap();
+                    Self::join(lo, iter.next().unwrap())
+                })
+            }
+        }
+
+        impl ExtensionField<$half> for $name {
+            type ExtensionPacking = PackedBinaryTowerExtension<$name, 2, $half_packing>;
+
+            #[inline]
+            fn is_in_basefield(&self) -> bool {
+                self.split().1.is_zero()
+            }
+
+            #[inline]
+            fn as_base(&self) -> Option<$half> {
+                let (lo, hi) = self.split();
+                hi.is_zero().then_some(lo)
+            }
+        }
+    };
+}
+
+/// Implement the embedding of a smaller tower field into a larger one.
+///
+/// In the tower basis this just pads the coordinates with zeros.
+macro_rules! tower_subfield {
+    ($field:ty, $subfield:ty, $int:ty) => {
+        impl From<$subfield> for $field {
+            #[inline]
+            fn from(f: $subfield) -> Self {
+                Self::new(f.to_bits() as $int)
+            }
+        }
+    };
+}
+
+binary_tower_field!(
+    /// The field `GF(2^16)`, a quadratic extension of `GF(2^8)` in the binary tower.
+    BinaryField16b, BinaryField8b, u16, u8, 2, PackedBinaryField8x16b, 0x0102
+);
+binary_tower_field!(
+    /// The field `GF(2^32)`, a quadratic extension of `GF(2^16)` in the binary tower.
+    BinaryField32b, BinaryField16b, u32, u16, 4, PackedBinaryField4x32b, 0x0001_0005
+);
+binary_tower_field!(
+    /// The field `GF(2^64)`, a quadratic extension of `GF(2^32)` in the binary tower.
+    BinaryField64b, BinaryField32b, u64, u32, 8, PackedBinaryField2x64b, 0x0000_0001_0000_0004
+);
+binary_tower_field!(
+    /// The field `GF(2^128)`, a quadratic extension of `GF(2^64)` in the binary tower.
+    BinaryField128b, BinaryField64b, u128, u64, 16, BinaryField128b, 0x0000_0000_0000_0001_0000_0000_0000_0005
+);
+
+tower_subfield!(BinaryField32b, BinaryField16b, u32);
+tower_subfield!(BinaryField64b, BinaryField16b, u64);
+tower_subfield!(BinaryField64b, BinaryField32b, u64);
+tower_subfield!(BinaryField128b, BinaryField16b, u128);
+tower_subfield!(BinaryField128b, BinaryField32b, u128);
+tower_subfield!(BinaryField128b, BinaryField64b, u128);
+
+tower_quadratic_extension!(BinaryField32b, BinaryField16b, PackedBinaryField8x16b);
+tower_quadratic_extension!(BinaryField64b, BinaryField32b, PackedBinaryField4x32b);
+tower_quadratic_extension!(BinaryField128b, BinaryField64b, PackedBinaryField2x64b);
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use num_bigint::BigUint;
+    use p3_field_testing::{
+        test_dot_product, test_galois_extension, test_generator, test_into_stream, test_inverse,
+        test_packed_extension, test_powers_collect, test_sums, test_to_from_extension_field,
+    };
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+
+    /// The prime factorization of `2^n - 1` for `n = 16, 32, 64, 128`.
+    fn multiplicative_group_prime_factorization(n: usize) -> Vec<(BigUint, u32)> {
+        let primes: &[u64] = match n {
+            16 => &[3, 5, 17, 257],
+            32 => &[3, 5, 17, 257, 65537],
+            64 | 128 => &[3, 5, 17, 257, 641, 65537, 6700417],
+            _ => unreachable!(),
+        };
+        let mut factors: Vec<_> = primes.iter().map(|&p| (BigUint::from(p), 1)).collect();
+        if n == 128 {
+            // 2^128 - 1 = (2^64 - 1)(2^64 + 1) and 2^64 + 1 = 274177 * 67280421310721.
+            factors.push((BigUint::from(274177u64), 1));
+            factors.push((BigUint::from(67280421310721u64), 1));
+        }
+        factors
+    }
+
+    macro_rules! test_tower_field {
+        ($mod_name:ident, $field:ty, $half:ty, $int:ty) => {
+            mod $mod_name {
+                use super::*;
+
+                type F = $field;
+
+                #[test]
+                fn test_ring_axioms() {
+                    let mut rng = SmallRng::seed_from_u64(1);
+                    for _ in 0..100 {
+                        let (x, y, z): (F, F, F) = (rng.random(), rng.random(), rng.random());
+                        assert_eq!((x * y) * z, x * (y * z));
+                        assert_eq!(x * y, y * x);
+                        assert_eq!(x * (y + z), x * y + x * z);
+                        assert_eq!(x.square(), x * x);
+                        assert_eq!(x + x, F::ZERO);
+                        assert_eq!(-x, x);
+                    }
+                }
+
+                #[test]
+                fn test_defining_relation() {
+                    // The generator X of this field over the previous level satisfies X^2 = X' X + 1.
+                    let x = F::new(1 << (4 * F::NUM_BYTES));
+                    let x_prev: F = <$half>::ONE.mul_alpha().into();
+                    assert_eq!(x.square(), x_prev * x + F::ONE);
+                    assert_eq!(F::ONE.mul_alpha(), x);
+                }
+
+                #[test]
+                fn test_subfield_embedding() {
+                    let mut rng = SmallRng::seed_from_u64(1);
+                    for _ in 0..100 {
+                        let (a, b): ($half, $half) = (rng.random(), rng.random());
+                        assert_eq!(F::from(a * b), F::from(a) * F::from(b));
+                        assert_eq!(F::from(a + b), F::from(a) + F::from(b));
+                        assert_eq!(F::from(a).split(), (a, <$half>::ZERO));
+                    }
+                }
+
+                #[test]
+                fn test_mul_by_gf256() {
+                    let mut rng = SmallRng::seed_from_u64(1);
+                    for _ in 0..100 {
+                        let x: F = rng.random();
+                        let y: BinaryField8b = rng.random();
+                        assert_eq!(x * y, x * F::from(y));
+                        assert_eq!(y * x, x * F::from(y));
+                    }
+                }
+
+                #[test]
+                fn test_sqrt() {
+                    let mut rng = SmallRng::seed_from_u64(1);
+                    for _ in 0..100 {
+                        let x: F = rng.random();
+                        assert_eq!(x.sqrt().unwrap().square(), x);
+                    }
+                }
+
+                #[test]
+                fn test_serde() {
+                    let x = F::new(<$int>::MAX - 5);
+                    let serialized = serde_json::to_string(&x).unwrap();
+                    let deserialized: F = serde_json::from_str(&serialized).unwrap();
+                    assert_eq!(deserialized, x);
+                }
+
+                #[test]
+                fn test_field_properties() {
+                    let mut rng = SmallRng::seed_from_u64(1);
+                    test_inverse::<F>();
+                    test_into_stream::<F>();
+                    test_powers_collect::<F>();
+                    test_dot_product::<F>(&rng.random(), &rng.random());
+                    test_sums::<F>(&rng.random());
+                    test_generator::<F>(&multiplicative_group_prime_factorization(
+                        8 * F::NUM_BYTES,
+                    ));
+                }
+
+                #[test]
+                fn test_extension_of_gf256() {
+                    test_to_from_extension_field::<BinaryField8b, F>();
+                    test_galois_extension::<BinaryField8b, F>();
+                    test_packed_extension::<BinaryField8b, F>();
+                }
+
+                #[test]
+                fn test_extension_of_previous_level() {
+                    let mut rng = SmallRng::seed_from_u64(1);
+                    for _ in 0..100 {
+                        let (x, a): (F, $half) = (rng.random(), rng.random());
+                        assert_eq!(x * a, x * F::from(a));
+                        assert_eq!(a * x, x * F::from(a));
+                    }
+                    test_to_from_extension_field::<$half, F>();
+                    test_galois_extension::<$half, F>();
+                    test_packed_extension::<$half, F>();
+                }
+            }
+        };
+    }
+
+    test_tower_field!(binary_field_16b, BinaryField16b, BinaryField8b, u16);
+    test_tower_field!(binary_field_32b, BinaryField32b, BinaryField16b, u32);
+    test_tower_field!(binary_field_64b, BinaryField64b, BinaryField32b, u64);
+    test_tower_field!(binary_field_128b, BinaryField128b, BinaryField64b, u128);
+}
@@ -0,0 +1,92 @@
+//! Commit to matrices of binary tower field elements using the generic `Mmcs`, `Matrix` and
+//! challenger abstractions.
+
+use p3_binary_tower::{BinaryField8b, BinaryField32b, BinaryField128b};
+use p3_challenger::{CanObserve, CanSample, HashChallenger};
+use p3_commit::{ExtensionMmcs, Mmcs};
+use p3_field::PrimeCharacteristicRing;
+use p3_keccak::Keccak256Hash;
+use p3_matrix::Matrix;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_merkle_tree::MerkleTreeMmcs;
+use p3_symmetric::{CompressionFunctionFromHasher, SerializingHasher};
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+
+type ByteHash = Keccak256Hash;
+type FieldHash = SerializingHasher<ByteHash>;
+type MyCompress = CompressionFunctionFromHasher<ByteHash, 2, 32>;
+type ValMmcs<F> = MerkleTreeMmcs<F, u8, FieldHash, MyCompress, 32>;
+type Challenger = HashChallenger<u8, ByteHash, 32>;
+
+fn val_mmcs<F>() -> ValMmcs<F> {
+    ValMmcs::new(FieldHash::new(ByteHash {}), MyCompress::new(ByteHash {}))
+}
+
+/// Sample a challenge in `GF(2^128)` from 16 uniformly random bytes.
+fn sample_challenge(challenger: &mut Challenger) -> BinaryField128b {
+    let bytes: [u8; 16] = core::array::from_fn(|_| challenger.sample());
+    BinaryField128b::new(u128::from_le_bytes(bytes))
+}
+
+#[test]
+fn test_commit_open_verify() {
+    let mut rng = SmallRng::seed_from_u64(1);
+    let mmcs = val_mmcs::<BinaryField32b>();
+
+    let mat = RowMajorMatrix::<BinaryField32b>::rand(&mut rng, 1 << 6, 5);
+    let dims = [mat.dimensions()];
+    let (commit, prover_data) = mmcs.commit_matrix(mat.clone());
+
+    let index = 37;
+    let opening = mmcs.open_batch(index, &prover_data);
+    assert_eq!(
+        opening.opened_values[0],
+        mat.row(index).unwrap().into_iter().collect::<Vec<_>>()
+    );
+    mmcs.verify_batch(&commit, &dims, index, (&opening).into())
+        .expect("expected verification to succeed");
+
+    let mut tampered = opening;
+    tampered.opened_values[0][0] += BinaryField32b::ONE;
+    assert!(
+        mmcs.verify_batch(&commit, &dims, index, (&tampered).into())
+            .is_err()
+    );
+}
+
+#[test]
+fn test_extension_mmcs_over_gf256() {
+    let mut rng = SmallRng::seed_from_u64(1);
+    let mmcs = ExtensionMmcs::<BinaryField8b, BinaryField32b, _>::new(val_mmcs::<BinaryField8b>());
+
+    let mat = RowMajorMatrix::<BinaryField32b>::rand(&mut rng, 1 << 5, 3);
+    let dims = [mat.dimensions()];
+    let (commit, prover_data) = mmcs.commit_matrix(mat);
+
+    let opening = mmcs.open_batch(11, &prover_data);
+    mmcs.verify_batch(&commit, &dims, 11, (&opening).into())
+        .expect("expected verification to succeed");
+}
+
+#[test]
+fn test_challenger_transcript() {
+    let mut rng = SmallRng::seed_from_u64(1);
+    let mmcs = val_mmcs::<BinaryField32b>();
+    let mat = RowMajorMatrix::<BinaryField32b>::rand(&mut rng, 1 << 4, 2);
+    let (commit, _) = mmcs.commit_matrix(mat);
+
+    let transcript = || {
+        let mut challenger = Challenger::new(vec![], ByteHash {});
+        challenger.observe(<[u8; 32]>::from(commit));
+        let alpha = sample_challenge(&mut challenger);
+        let beta = sample_challenge(&mut challenger);
+        (alpha, beta)
+    };
+
+    // The prover and verifier derive the same challenges from the same transcript.
+    let (alpha, beta) = transcript();
+    assert_eq!((alpha, beta), transcript());
+    assert_ne!(alpha, beta);
+    assert_ne!(alpha, BinaryField128b::ZERO);
+}
@@ -0,0 +1,112 @@
+//! Traits for multilinear polynomial commitment schemes.
+
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use p3_field::{ExtensionField, Field};
+use p3_matrix::dense::{RowMajorMatrix, RowMajorMatrixView};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::OpenedValues;
+
+/// A polynomial commitment scheme for multilinear polynomials, as used by sumcheck-based provers.
+///
+/// This mirrors [`Pcs`](crate::Pcs), but for multilinear polynomials given by their evaluations
+/// over the Boolean hypercube. Each column of a committed matrix of height `2^n` holds the
+/// evaluations of an `n`-variate multilinear polynomial, in big-endian order: the evaluation at
+/// `(x_0, ..., x_{n-1})` is in row `\sum_i x_i 2^{n-1-i}`. Opening points are given in the same
+/// variable order, with one coordinate per variable.
+pub trait MultilinearPcs<Val, Challenge, Challenger>
+where
+    Val: Field,
+    Challenge: ExtensionField<Val>,
+{
+    /// The commitment that's sent to the verifier.
+    type Commitment: Clone + Serialize + DeserializeOwned;
+
+    /// Data that the prover stores for committed polynomials, to help the prover with opening.
+    type ProverData;
+
+    /// The opening argument.
+    type Proof: Clone + Serialize + DeserializeOwned;
+
+    /// The type of a proof verification error.
+    type Error: Debug;
+
+    /// Given a collection of evaluation matrices, produce a binding commitment to the
+    /// multilinear polynomials defined by their columns.
+    ///
+    /// Returns both the commitment which should be sent to the verifier
+    /// and the prover data which can be used to produce opening proofs.
+    fn commit(&self, evaluations: Vec<RowMajorMatrix<Val>>)
+    -> (Self::Commitment, Self::ProverData);
+
+    /// Return the evaluations over the hypercube of the `idx`'th committed matrix.
+    fn get_evaluations<'a>(
+        &self,
+        prover_data: &'a Self::ProverData,
+        idx: usize,
+    ) -> RowMajorMatrixView<'a, Val>;
+
+    /// Open a collection of polynomial commitments at a set of points. Produce the values at
+    /// those points along with a proof of correctness.
+    ///
+    /// Arguments:
+    /// - `commitment_data_with_opening_points`: A vector whose elements are a pair:
+    ///     - `data`: The prover data corresponding to a multi-matrix commitment.
+    ///     - `opening_points`: A vector containing, for each matrix committed to, a vector of
+    ///       opening points, each with one coordinate per variable.
+    /// - `fiat_shamir_challenger`: The challenger that will be used to generate the proof.
+    ///
+    /// Each column of the `i`'th matrix is evaluated at each point in `opening_points[i]`.
+    #[allow(clippy::type_complexity)]
+    fn open(
+        &self,
+        // For each multi-matrix commitment,
+        commitment_data_with_opening_points: Vec<(
+            // The matrices and auxiliary prover data
+            &Self::ProverData,
+            // for each matrix,
+            Vec<
+                // the points to open
+                Vec<Vec<Challenge>>,
+            >,
+        )>,
+        fiat_shamir_challenger: &mut Challenger,
+    ) -> (OpenedValues<Challenge>, Self::Proof);
+
+    /// Verify that a collection of opened values is correct.
+    ///
+    /// Arguments:
+    /// - `commitments_with_opening_points`: A vector whose elements are a pair:
+    ///     - `commitment`: A multi matrix commitment.
+    ///     - `opening_points`: A vector containing, for each matrix committed to, its number of
+    ///       variables and a vector of opening points and claimed evaluations.
+    /// - `proof`: A claimed proof of correctness for the opened values.
+    /// - `fiat_shamir_challenger`: The challenger that will be used to generate the proof.
+    #[allow(clippy::type_complexity)]
+    fn verify(
+        &self,
+        // For each commitment:
+        commitments_with_opening_points: Vec<(
+            // The commitment
+            Self::Commitment,
+            // for each matrix in the commitment:
+            Vec<(
+                // its number of variables,
+                usize,
+                // A vector of (point, claimed_evaluation) pairs
+                Vec<(
+                    // the point the matrix was opened at,
+                    Vec<Challenge>,
+                    // the claimed evaluations at that point
+                    Vec<Challenge>,
+                )>,
+            )>,
+        )>,
+        // The opening proof for all claimed evaluations.
+        proof: &Self::Proof,
+        fiat_shamir_challenger: &mut Challenger,
+    ) -> Result<(), Self::Error>;
+}
@@ -1,7 +1,7 @@
 use core::mem::MaybeUninit;
 
 use itertools::izip;
-use p3_field::{Field, PackedField, PackedValue};
+use p3_field::{Algebra, Field, PackedField, PackedValue};
 
 /// A butterfly operation used in NTT to combine two values into a new pair.
 ///
@@ -103,6 +103,33 @@ pub trait Butterfly<F: Field>: Copy + Send + Sync {
     }
 }
 
+/// A butterfly which can also be applied to elements of an algebra over `F`.
+///
+/// This is kept separate from [`Butterfly`] so that implementors of the latter are not forced to
+/// support algebra elements. It is used by [`ExtensionDft`](crate::ExtensionDft) to run
+/// transforms directly on (packed) extension field elements.
+pub trait AlgebraButterfly<F: Field>: Butterfly<F> {
+    /// Applies the butterfly transformation to two elements of an algebra over `T`.
+    ///
+    /// Here `T` is either `F` or a packing of `F`, so `A` can be an extension field or a packed
+    /// extension field. The twiddle is lifted into `T`, so applying it costs one multiplication
+    /// by `T` per basis coefficient of `A` rather than a full multiplication in `A`.
+    fn apply_algebra<T: Algebra<F> + Copy, A: Algebra<T> + Copy>(&self, x_1: A, x_2: A) -> (A, A);
+
+    /// Applies [`AlgebraButterfly::apply_algebra`] in-place to two rows of algebra elements.
+    #[inline]
+    fn apply_to_algebra_rows<T: Algebra<F> + Copy, A: Algebra<T> + Copy>(
+        &self,
+        row_1: &mut [A],
+        row_2: &mut [A],
+    ) {
+        debug_assert_eq!(row_1.len(), row_2.len());
+        for (x_1, x_2) in row_1.iter_mut().zip(row_2) {
+            (*x_1, *x_2) = self.apply_algebra::<T, A>(*x_1, *x_2);
+        }
+    }
+}
+
 /// DIF (Decimation-In-Frequency) butterfly operation.
 ///
 /// Used in the *output-ordering* variant of NTT.
@@ -124,6 +151,13 @@ impl<F: Field> Butterfly<F> for DifButterfly<F> {
     }
 }
 
+impl<F: Field> AlgebraButterfly<F> for DifButterfly<F> {
+    #[inline]
+    fn apply_algebra<T: Algebra<F> + Copy, A: Algebra<T> + Copy>(&self, x_1: A, x_2: A) -> (A, A) {
+        (x_1 + x_2, (x_1 - x_2) * T::from(self.0))
+    }
+}
+
 /// DIF (Decimation-In-Frequency) butterfly operation where `x_2` is guaranteed to be zero.
 ///
 /// Useful in scenarios where the input has just been padded with zeros.
@@ -162,6 +196,13 @@ impl<F: Field> Butterfly<F> for DifButterflyZeros<F> {
     }
 }
 
+impl<F: Field> AlgebraButterfly<F> for DifButterflyZeros<F> {
+    #[inline]
+    fn apply_algebra<T: Algebra<F> + Copy, A: Algebra<T> + Copy>(&self, x_1: A, _x_2: A) -> (A, A) {
+        (x_1, x_1 * T::from(self.0))
+    }
+}
+
 /// DIT (Decimation-In-Time) butterfly operation.
 ///
 /// Used in the *input-ordering* variant of NTT/FFT.
@@ -184,6 +225,14 @@ impl<F: Field> Butterfly<F> for DitButterfly<F> {
     }
 }
 
+impl<F: Field> AlgebraButterfly<F> for DitButterfly<F> {
+    #[inline]
+    fn apply_algebra<T: Algebra<F> + Copy, A: Algebra<T> + Copy>(&self, x_1: A, x_2: A) -> (A, A) {
+        let x_2_twiddle = x_2 * T::from(self.0);
+        (x_1 + x_2_twiddle, x_1 - x_2_twiddle)
+    }
+}
+
 /// Butterfly with no twiddle factor (`twiddle = 1`).
 ///
 /// This is used when no root-of-unity scaling is needed.
@@ -204,3 +253,10 @@ impl<F: Field> Butterfly<F> for TwiddleFreeButterfly {
         (x_1 + x_2, x_1 - x_2)
     }
 }
+
+impl<F: Field> AlgebraButterfly<F> for TwiddleFreeButterfly {
+    #[inline]
+    fn apply_algebra<T: Algebra<F> + Copy, A: Algebra<T> + Copy>(&self, x_1: A, x_2: A) -> (A, A) {
+        (x_1 + x_2, x_1 - x_2)
+    }
+}
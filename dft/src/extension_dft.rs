@@ -0,0 +1,333 @@
+use p3_field::{Algebra, ExtensionField, PrimeCharacteristicRing, TwoAdicField};
+use p3_matrix::Matrix;
+use p3_matrix::dense::{RowMajorMatrix, RowMajorMatrixViewMut};
+use p3_matrix::util::reverse_matrix_index_bits;
+use p3_maybe_rayon::prelude::*;
+use p3_util::log2_strict_usize;
+use tracing::instrument;
+
+use crate::util::{interleave_coset, lde_by_cosets};
+use crate::{AlgebraButterfly, DitButterfly, TwiddleCache};
+
+/// A radix-2 DIT FFT which works directly on extension field elements, or on packed extension
+/// field elements, using twiddles from the base field `F`.
+///
+/// The `*_algebra_batch` methods of [`TwoAdicSubgroupDft`](crate::TwoAdicSubgroupDft) flatten
+/// extension values into `DIMENSION` base field columns, run a base field DFT and reconstitute
+/// the result, copying the matrix twice. The kernels here avoid those copies: each butterfly
+/// multiplies the extension (or packed extension) value by a base field twiddle, costing
+/// `DIMENSION` base field multiplications. This suits extension-valued polynomials, such as the
+/// auxiliary columns of lookup arguments, or the folded polynomials in FRI.
+///
+/// The packed variants take matrices of `EF::ExtensionPacking`, where each entry holds
+/// `F::Packing::WIDTH` adjacent columns, as produced by `from_ext_slice`. All outputs are in
+/// natural order.
+///
+/// This is experimental: nothing in this workspace routes its extension field DFTs through it
+/// yet, and its API may change once it does.
+#[derive(Clone, Debug)]
+pub struct ExtensionDft<F> {
+    twiddles: TwiddleCache<F>,
+}
+
+impl<F> Default for ExtensionDft<F> {
+    fn default() -> Self {
+        Self {
+            twiddles: TwiddleCache::new(1, false),
+        }
+    }
+}
+
+impl<F: TwoAdicField> ExtensionDft<F> {
+    /// Create a DFT with twiddles pre-computed for transforms of size up to `n`.
+    pub fn new(n: usize) -> Self {
+        let dft = Self::default();
+        dft.twiddles.get(log2_strict_usize(n));
+        dft
+    }
+
+    /// Compute the DFT of each column of `mat`.
+    pub fn dft_ext_batch<EF: ExtensionField<F>>(
+        &self,
+        mut mat: RowMajorMatrix<EF>,
+    ) -> RowMajorMatrix<EF> {
+        self.dft_in_place::<F, _>(&mut mat.as_view_mut(), false);
+        mat
+    }
+
+    /// Compute the inverse DFT of each column of `mat`.
+    pub fn idft_ext_batch<EF: ExtensionField<F>>(
+        &self,
+        mut mat: RowMajorMatrix<EF>,
+    ) -> RowMajorMatrix<EF> {
+        self.dft_in_place::<F, _>(&mut mat.as_view_mut(), true);
+        mat
+    }
+
+    /// Compute the DFT of each column of `mat` over the coset `shift * H`.
+    pub fn coset_dft_ext_batch<EF: ExtensionField<F>>(
+        &self,
+        mut mat: RowMajorMatrix<EF>,
+        shift: F,
+    ) -> RowMajorMatrix<EF> {
+        scale_rows_by_powers::<F, F, _>(&mut mat.as_view_mut(), shift);
+        self.dft_in_place::<F, _>(&mut mat.as_view_mut(), false);
+        mat
+    }
+
+    /// Compute the low-degree extension of each column of `mat`, evaluating over the coset
+    /// `shift * K` where `K` is the subgroup of order `mat.height() << added_bits`.
+    pub fn coset_lde_ext_batch<EF: ExtensionField<F>>(
+        &self,
+        mat: RowMajorMatrix<EF>,
+        added_bits: usize,
+        shift: F,
+    ) -> RowMajorMatrix<EF> {
+        self.coset_lde::<F, _>(mat, added_bits, shift)
+    }
+
+    /// Compute the DFT of each column of a matrix of packed extension field elements.
+    pub fn dft_packed_ext_batch<EF: ExtensionField<F>>(
+        &self,
+        mut mat: RowMajorMatrix<EF::ExtensionPacking>,
+    ) -> RowMajorMatrix<EF::ExtensionPacking> {
+        self.dft_in_place::<F::Packing, _>(&mut mat.as_view_mut(), false);
+        mat
+    }
+
+    /// Compute the inverse DFT of each column of a matrix of packed extension field elements.
+    pub fn idft_packed_ext_batch<EF: ExtensionField<F>>(
+        &self,
+        mut mat: RowMajorMatrix<EF::ExtensionPacking>,
+    ) -> RowMajorMatrix<EF::ExtensionPacking> {
+        self.dft_in_place::<F::Packing, _>(&mut mat.as_view_mut(), true);
+        mat
+    }
+
+    /// Compute the DFT of each column of a matrix of packed extension field elements over the
+    /// coset `shift * H`.
+    pub fn coset_dft_packed_ext_batch<EF: ExtensionField<F>>(
+        &self,
+        mut mat: RowMajorMatrix<EF::ExtensionPacking>,
+        shift: F,
+    ) -> RowMajorMatrix<EF::ExtensionPacking> {
+        scale_rows_by_powers::<F, F::Packing, _>(&mut mat.as_view_mut(), shift);
+        self.dft_in_place::<F::Packing, _>(&mut mat.as_view_mut(), false);
+        mat
+    }
+
+    /// Compute the low-degree extension of each column of a matrix of packed extension field
+    /// elements, evaluating over the coset `shift * K` where `K` is the subgroup of order
+    /// `mat.height() << added_bits`.
+    pub fn coset_lde_packed_ext_batch<EF: ExtensionField<F>>(
+        &self,
+        mat: RowMajorMatrix<EF::ExtensionPacking>,
+        added_bits: usize,
+        shift: F,
+    ) -> RowMajorMatrix<EF::ExtensionPacking> {
+        self.coset_lde::<F::Packing, _>(mat, added_bits, shift)
+    }
+
+    /// Run a DIT FFT over each column of `mat`, whose entries lie in an algebra over `T`, which
+    /// is `F` or a packing of `F`. The inverse transform also divides by the height.
+    #[instrument(skip_all, fields(dims = %mat.dimensions(), inverse))]
+    fn dft_in_place<T, A>(&self, mat: &mut RowMajorMatrixViewMut<'_, A>, inverse: bool)
+    where
+        T: Algebra<F> + Copy + Send + Sync,
+        A: Algebra<T> + Copy + Send + Sync,
+    {
+        let h = mat.height();
+        let w = mat.width();
+        let log_h = log2_strict_usize(h);
+        if log_h == 0 {
+            return;
+        }
+        let table = self.twiddles.get(log_h);
+        let layers = if inverse {
+            table.inv_twiddles()
+        } else {
+            table.twiddles()
+        };
+        // The last `log_h` layers are those for block lengths `2^log_h, ..., 2`.
+        let layers = &layers[layers.len() - log_h..];
+
+        reverse_matrix_index_bits(mat);
+        let num_threads = current_num_threads();
+        for twiddles in layers.iter().rev() {
+            let half_block_len = twiddles.len();
+            let num_blocks = h / (2 * half_block_len);
+            let butterfly = |((row_1, row_2), &twiddle): ((&mut [A], &mut [A]), &F)| {
+                DitButterfly(twiddle).apply_to_algebra_rows::<T, A>(row_1, row_2);
+            };
+            mat.par_row_chunks_exact_mut(2 * half_block_len)
+                .for_each(|block| {
+                    let (lo, hi) = block.values.split_at_mut(half_block_len * w);
+                    // The last layers have fewer blocks than threads, so the butterflies within
+                    // each block are split between threads instead.
+                    if num_blocks >= num_threads {
+                        lo.chunks_exact_mut(w)
+                            .zip(hi.chunks_exact_mut(w))
+                            .zip(twiddles)
+                            .for_each(butterfly);
+                    } else {
+                        lo.par_chunks_exact_mut(w)
+                            .zip(hi.par_chunks_exact_mut(w))
+                            .zip(twiddles.par_iter())
+                            .for_each(butterfly);
+                    }
+                });
+        }
+
+        if inverse {
+            let h_inv = T::from(F::from_prime_subfield(
+                F::PrimeSubfield::ONE.div_2exp_u64(log_h as u64),
+            ));
+            mat.values.par_iter_mut().for_each(|x| *x *= h_inv);
+        }
+    }
+
+    /// Evaluate the low-degree extension one coset of `H` at a time, as in
+    /// [`coset_lde_by_cosets`](crate::coset_lde_by_cosets).
+    fn coset_lde<T, A>(
+        &self,
+        mut mat: RowMajorMatrix<A>,
+        added_bits: usize,
+        shift: F,
+    ) -> RowMajorMatrix<A>
+    where
+        T: Algebra<F> + Copy + Send + Sync,
+        A: Algebra<T> + Copy + Send + Sync,
+    {
+        let w = mat.width();
+        let num_cosets = 1 << added_bits;
+
+        self.dft_in_place::<T, A>(&mut mat.as_view_mut(), true);
+        let mut lde = A::zero_vec(mat.values.len() << added_bits);
+        let mut scratch = A::zero_vec(mat.values.len());
+        lde_by_cosets::<F, T, A, _>(
+            &mat.values,
+            &mut scratch,
+            w,
+            added_bits,
+            shift,
+            false,
+            |j, twisted| {
+                self.dft_in_place::<T, A>(&mut RowMajorMatrixViewMut::new(twisted, w), false);
+                interleave_coset(&mut lde, twisted, w, j, num_cosets);
+            },
+        );
+        RowMajorMatrix::new(lde, w)
+    }
+}
+
+/// Multiply row `i` of `mat` by `shift^i`.
+fn scale_rows_by_powers<F, T, A>(mat: &mut RowMajorMatrixViewMut<'_, A>, shift: F)
+where
+    F: TwoAdicField,
+    T: Algebra<F> + Copy + Send + Sync,
+    A: Algebra<T> + Copy + Send + Sync,
+{
+    let w = mat.width();
+    mat.values
+        .chunks_exact_mut(w)
+        .zip(shift.powers())
+        .for_each(|(row, weight)| {
+            let weight = T::from(weight);
+            row.iter_mut().for_each(|x| *x *= weight);
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use p3_baby_bear::BabyBear;
+    use p3_field::extension::BinomialExtensionField;
+    use p3_field::{ExtensionField, Field, PackedFieldExtension, PackedValue};
+    use p3_matrix::dense::RowMajorMatrix;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    use super::ExtensionDft;
+    use crate::{NaiveDft, TwoAdicSubgroupDft};
+
+    type F = BabyBear;
+    type EF = BinomialExtensionField<F, 4>;
+    type PackedEF = <EF as ExtensionField<F>>::ExtensionPacking;
+
+    fn naive() -> impl TwoAdicSubgroupDft<F> {
+        NaiveDft
+    }
+
+    fn pack(mat: &RowMajorMatrix<EF>) -> RowMajorMatrix<PackedEF> {
+        let width = <F as Field>::Packing::WIDTH;
+        let values: Vec<PackedEF> = mat
+            .values
+            .chunks_exact(width)
+            .map(PackedEF::from_ext_slice)
+            .collect();
+        RowMajorMatrix::new(values, mat.width / width)
+    }
+
+    fn unpack(mat: RowMajorMatrix<PackedEF>) -> RowMajorMatrix<EF> {
+        let width = mat.width * <F as Field>::Packing::WIDTH;
+        RowMajorMatrix::new(
+            <PackedEF as PackedFieldExtension<F, EF>>::to_ext_iter(mat.values).collect(),
+            width,
+        )
+    }
+
+    #[test]
+    fn ext_matches_flattened() {
+        let dft = ExtensionDft::<F>::default();
+        let mut rng = SmallRng::seed_from_u64(1);
+        let shift = F::GENERATOR;
+        for log_h in 0..8 {
+            let mat = RowMajorMatrix::<EF>::rand(&mut rng, 1 << log_h, 3);
+            assert_eq!(
+                dft.dft_ext_batch(mat.clone()),
+                naive().dft_algebra_batch(mat.clone())
+            );
+            assert_eq!(
+                dft.idft_ext_batch(mat.clone()),
+                naive().idft_algebra_batch(mat.clone())
+            );
+            assert_eq!(
+                dft.coset_dft_ext_batch(mat.clone(), shift),
+                naive().coset_dft_algebra_batch(mat.clone(), shift)
+            );
+            assert_eq!(
+                dft.coset_lde_ext_batch(mat.clone(), 2, shift),
+                naive().coset_lde_algebra_batch(mat, 2, shift)
+            );
+        }
+    }
+
+    #[test]
+    fn packed_ext_matches_unpacked() {
+        let dft = ExtensionDft::<F>::new(1 << 6);
+        let mut rng = SmallRng::seed_from_u64(2);
+        let shift = F::GENERATOR;
+        let width = 2 * <F as Field>::Packing::WIDTH;
+        for log_h in 0..7 {
+            let mat = RowMajorMatrix::<EF>::rand(&mut rng, 1 << log_h, width);
+            let packed = pack(&mat);
+            assert_eq!(
+                unpack(dft.dft_packed_ext_batch::<EF>(packed.clone())),
+                dft.dft_ext_batch(mat.clone())
+            );
+            assert_eq!(
+                unpack(dft.idft_packed_ext_batch::<EF>(packed.clone())),
+                dft.idft_ext_batch(mat.clone())
+            );
+            assert_eq!(
+                unpack(dft.coset_dft_packed_ext_batch::<EF>(packed.clone(), shift)),
+                dft.coset_dft_ext_batch(mat.clone(), shift)
+            );
+            assert_eq!(
+                unpack(dft.coset_lde_packed_ext_batch::<EF>(packed, 1, shift)),
+                dft.coset_lde_ext_batch(mat, 1, shift)
+            );
+        }
+    }
+}
@@ -0,0 +1,370 @@
+use alloc::vec::Vec;
+use core::mem;
+
+use p3_field::TwoAdicField;
+use p3_matrix::Matrix;
+use p3_matrix::dense::{RowMajorMatrix, RowMajorMatrixViewMut};
+use p3_maybe_rayon::prelude::*;
+use p3_util::log2_strict_usize;
+use tracing::instrument;
+
+use crate::TwoAdicSubgroupDft;
+use crate::util::lde_by_cosets;
+
+/// The default bound on the number of field elements in a tile.
+const DEFAULT_MAX_TILE_ELEMS: usize = 1 << 22;
+
+/// A four-step (Bailey) DFT, which works on large matrices one tile of rows at a time.
+///
+/// A DFT of length `n = n_1 * n_2` is split into `n_2` DFTs of length `n_1`, a multiplication by
+/// twiddle factors, and `n_1` DFTs of length `n_2`. Each step gathers a tile of rows into memory,
+/// runs the inner DFT on it, and writes it back, reading and writing the matrix in contiguous
+/// runs of rows. This means the matrices themselves need not fit in memory: the out-of-core
+/// methods such as [`FourStepDft::coset_lde_out_of_core`] take mutable views, which may be backed
+/// by memory-mapped files. The working memory is bounded by `max_tile_elems` field elements,
+/// plus whatever the inner DFT needs for a tile.
+///
+/// It also implements [`TwoAdicSubgroupDft`] on in-memory matrices, so it can be used anywhere
+/// another DFT can, e.g. in `TwoAdicFriPcs`.
+#[derive(Clone, Debug)]
+pub struct FourStepDft<Dft> {
+    /// The DFT used for the tiles.
+    inner: Dft,
+
+    /// The maximum number of field elements in a tile, unless a single row of the tile is larger.
+    max_tile_elems: usize,
+}
+
+impl<Dft: Default> Default for FourStepDft<Dft> {
+    fn default() -> Self {
+        Self::new(Dft::default(), DEFAULT_MAX_TILE_ELEMS)
+    }
+}
+
+impl<Dft> FourStepDft<Dft> {
+    /// Create a four-step DFT running `inner` on tiles of at most `max_tile_elems` field
+    /// elements.
+    ///
+    /// Tiles have a power-of-two number of columns of the `n_1 x n_2` split, so the actual bound
+    /// is the largest such tile below `max_tile_elems`, or a single column if that is already
+    /// larger.
+    pub const fn new(inner: Dft, max_tile_elems: usize) -> Self {
+        Self {
+            inner,
+            max_tile_elems,
+        }
+    }
+
+    /// The largest power of two `t <= max` with `t * elems_per_unit <= max_tile_elems`, or one.
+    fn tile_len(&self, elems_per_unit: usize, max: usize) -> usize {
+        let t = (self.max_tile_elems / elems_per_unit).max(1);
+        (1 << log2_floor(t)).min(max)
+    }
+
+    /// Compute the DFT of each column of `input`, writing the result to `output` in natural
+    /// order. `input` is overwritten in the process.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the height of `input` is not a power of two, or if `output` does not have the
+    /// same dimensions as `input`.
+    pub fn dft_out_of_core<F>(
+        &self,
+        input: &mut RowMajorMatrixViewMut<'_, F>,
+        output: &mut RowMajorMatrixViewMut<'_, F>,
+    ) where
+        F: TwoAdicField,
+        Dft: TwoAdicSubgroupDft<F>,
+    {
+        assert_eq!(input.dimensions(), output.dimensions());
+        self.four_step(input, output, false, 0, 1);
+    }
+
+    /// Compute the inverse DFT of each column of `input`, writing the result to `output` in
+    /// natural order. `input` is overwritten in the process.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the height of `input` is not a power of two, or if `output` does not have the
+    /// same dimensions as `input`.
+    pub fn idft_out_of_core<F>(
+        &self,
+        input: &mut RowMajorMatrixViewMut<'_, F>,
+        output: &mut RowMajorMatrixViewMut<'_, F>,
+    ) where
+        F: TwoAdicField,
+        Dft: TwoAdicSubgroupDft<F>,
+    {
+        assert_eq!(input.dimensions(), output.dimensions());
+        self.four_step(input, output, true, 0, 1);
+    }
+
+    /// Compute the low-degree extension of each column of `evals`, evaluating over the coset
+    /// `shift * K` where `K` is the subgroup of order `evals.height() << added_bits`. The result
+    /// is written to `output` in natural order.
+    ///
+    /// `coeffs` is scratch space of the same dimensions as `evals`, and ends up holding the
+    /// coefficients of the columns. `evals` is overwritten in the process.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the height of `evals` is not a power of two, or if the dimensions of `coeffs`
+    /// or `output` do not match.
+    #[instrument(skip_all, fields(dims = %evals.dimensions(), added_bits))]
+    pub fn coset_lde_out_of_core<F>(
+        &self,
+        evals: &mut RowMajorMatrixViewMut<'_, F>,
+        coeffs: &mut RowMajorMatrixViewMut<'_, F>,
+        added_bits: usize,
+        shift: F,
+        output: &mut RowMajorMatrixViewMut<'_, F>,
+    ) where
+        F: TwoAdicField,
+        Dft: TwoAdicSubgroupDft<F>,
+    {
+        let h = evals.height();
+        let w = evals.width();
+        assert_eq!(coeffs.dimensions(), evals.dimensions());
+        assert_eq!(output.width(), w);
+        assert_eq!(output.height(), h << added_bits);
+
+        self.four_step(evals, coeffs, true, 0, 1);
+
+        // Evaluate over each coset `shift * g_K^j * H` separately, using `evals` as the work space
+        // for the twisted coefficients and writing the results straight to their rows of
+        // `output`.
+        let num_cosets = 1 << added_bits;
+        let mut scratch: &mut [F] = evals.values;
+        lde_by_cosets::<F, F, F, _>(
+            coeffs.values,
+            &mut scratch,
+            w,
+            added_bits,
+            shift,
+            false,
+            |j, twisted| {
+                let mut twisted = RowMajorMatrixViewMut::new(twisted, w);
+                self.four_step(&mut twisted, output, false, j, num_cosets);
+            },
+        );
+    }
+
+    /// Compute the (inverse) DFT of each column of `src`, writing entry `k` of the result to row
+    /// `dst_offset + dst_stride * k` of `dst`. `src` is overwritten in the process.
+    ///
+    /// Write `n = n_1 * n_2`, input indices as `n_2 * i_1 + i_2` and output indices as
+    /// `k_1 + n_1 * k_2`. Then
+    /// `X[k_1 + n_1 k_2] = sum_{i_2} w_{n_2}^{i_2 k_2} w_n^{i_2 k_1} sum_{i_1} w_{n_1}^{i_1 k_1} x[n_2 i_1 + i_2]`,
+    /// which we compute in three passes over the matrix:
+    /// 1. the inner sums, which are DFTs of length `n_1` over rows strided by `n_2`;
+    /// 2. the multiplication by the twiddles `w_n^{i_2 k_1}`, done together with the first step,
+    ///    with the result stored in row `n_2 k_1 + i_2` of `src`;
+    /// 3. the outer sums, which are DFTs of length `n_2` over contiguous runs of rows of `src`.
+    fn four_step<F>(
+        &self,
+        src: &mut RowMajorMatrixViewMut<'_, F>,
+        dst: &mut RowMajorMatrixViewMut<'_, F>,
+        inverse: bool,
+        dst_offset: usize,
+        dst_stride: usize,
+    ) where
+        F: TwoAdicField,
+        Dft: TwoAdicSubgroupDft<F>,
+    {
+        let n = src.height();
+        let w = src.width();
+        let log_n = log2_strict_usize(n);
+        let log_n1 = log_n / 2;
+        let n1 = 1 << log_n1;
+        let n2 = n >> log_n1;
+
+        let root = F::two_adic_generator(log_n);
+        let root = if inverse { root.inverse() } else { root };
+        let run_inner = |tile: RowMajorMatrix<F>| {
+            if inverse {
+                self.inner.idft_batch(tile)
+            } else {
+                self.inner.dft_batch(tile).to_row_major_matrix()
+            }
+        };
+
+        // The buffer holding the current tile, which is allocated once and handed back and forth
+        // with the inner DFT.
+        let mut buffer = Vec::new();
+
+        // Steps 1 and 2: each tile holds `t` consecutive values of `i_2` for every `i_1`.
+        let t = self.tile_len(n1 * w, n2);
+        let run = t * w;
+        for i2_start in (0..n2).step_by(t) {
+            buffer.clear();
+            for i1 in 0..n1 {
+                let row = n2 * i1 + i2_start;
+                buffer.extend_from_slice(&src.values[row * w..(row + t) * w]);
+            }
+
+            let mut tile = run_inner(RowMajorMatrix::new(mem::take(&mut buffer), run));
+            tile.par_rows_mut().enumerate().for_each(|(k1, row)| {
+                let step = root.exp_u64(k1 as u64);
+                let start = step.exp_u64(i2_start as u64);
+                for (chunk, twiddle) in row.chunks_exact_mut(w).zip(step.shifted_powers(start)) {
+                    chunk.iter_mut().for_each(|x| *x *= twiddle);
+                }
+            });
+
+            for (k1, row) in tile.row_slices().enumerate() {
+                let start = n2 * k1 + i2_start;
+                src.values[start * w..(start + t) * w].copy_from_slice(row);
+            }
+            buffer = tile.values;
+        }
+
+        // Step 3: each tile holds all `n_2` rows for `t` consecutive values of `k_1`. Every entry
+        // of the tile is overwritten, so the buffer only needs to have the right length.
+        let t = self.tile_len(n2 * w, n1);
+        let run = t * w;
+        buffer.resize(n2 * run, F::ZERO);
+        for k1_start in (0..n1).step_by(t) {
+            let mut tile = RowMajorMatrix::new(mem::take(&mut buffer), run);
+            for dk1 in 0..t {
+                let block = &src.values[n2 * (k1_start + dk1) * w..n2 * (k1_start + dk1 + 1) * w];
+                for (tile_row, src_row) in tile.rows_mut().zip(block.chunks_exact(w)) {
+                    tile_row[dk1 * w..(dk1 + 1) * w].copy_from_slice(src_row);
+                }
+            }
+
+            let tile = run_inner(tile);
+
+            // Row `k_2` of the tile holds the outputs `k_1 + n_1 k_2` for `t` consecutive `k_1`.
+            // They are contiguous in `dst` unless the outputs are interleaved with other cosets,
+            // in which case consecutive outputs are `dst_stride` rows apart.
+            for (k2, row) in tile.row_slices().enumerate() {
+                let first = dst_offset + dst_stride * (k1_start + n1 * k2);
+                if dst_stride == 1 {
+                    dst.values[first * w..(first + t) * w].copy_from_slice(row);
+                } else {
+                    dst.values[first * w..]
+                        .chunks_mut(dst_stride * w)
+                        .zip(row.chunks_exact(w))
+                        .for_each(|(dst_rows, chunk)| dst_rows[..w].copy_from_slice(chunk));
+                }
+            }
+            buffer = tile.values;
+        }
+    }
+}
+
+const fn log2_floor(n: usize) -> usize {
+    (usize::BITS - 1 - n.leading_zeros()) as usize
+}
+
+impl<F, Dft> TwoAdicSubgroupDft<F> for FourStepDft<Dft>
+where
+    F: TwoAdicField,
+    Dft: TwoAdicSubgroupDft<F>,
+{
+    type Evaluations = RowMajorMatrix<F>;
+
+    fn dft_batch(&self, mut mat: RowMajorMatrix<F>) -> RowMajorMatrix<F> {
+        let mut out = RowMajorMatrix::new(F::zero_vec(mat.values.len()), mat.width());
+        self.dft_out_of_core(&mut mat.as_view_mut(), &mut out.as_view_mut());
+        out
+    }
+
+    fn idft_batch(&self, mut mat: RowMajorMatrix<F>) -> RowMajorMatrix<F> {
+        let mut out = RowMajorMatrix::new(F::zero_vec(mat.values.len()), mat.width());
+        self.idft_out_of_core(&mut mat.as_view_mut(), &mut out.as_view_mut());
+        out
+    }
+
+    fn coset_lde_batch(
+        &self,
+        mut mat: RowMajorMatrix<F>,
+        added_bits: usize,
+        shift: F,
+    ) -> RowMajorMatrix<F> {
+        let w = mat.width();
+        let mut coeffs = RowMajorMatrix::new(F::zero_vec(mat.values.len()), w);
+        let mut out = RowMajorMatrix::new(F::zero_vec(mat.values.len() << added_bits), w);
+        self.coset_lde_out_of_core(
+            &mut mat.as_view_mut(),
+            &mut coeffs.as_view_mut(),
+            added_bits,
+            shift,
+            &mut out.as_view_mut(),
+        );
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_baby_bear::BabyBear;
+    use p3_field::{Field, PrimeCharacteristicRing};
+    use p3_goldilocks::Goldilocks;
+    use p3_matrix::dense::{RowMajorMatrix, RowMajorMatrixViewMut};
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    use super::FourStepDft;
+    use crate::{NaiveDft, Radix2DFTSmallBatch, TwoAdicSubgroupDft};
+
+    type F = BabyBear;
+
+    #[test]
+    fn matches_naive_for_all_tile_sizes() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        for log_h in 0..7 {
+            let mat = RowMajorMatrix::<F>::rand(&mut rng, 1 << log_h, 3);
+            let expected = NaiveDft.dft_batch(mat.clone());
+            let expected_inv = NaiveDft.idft_batch(mat.clone());
+            for max_tile_elems in [1, 5, 24, 1 << 10] {
+                let dft = FourStepDft::new(Radix2DFTSmallBatch::<F>::default(), max_tile_elems);
+                assert_eq!(dft.dft_batch(mat.clone()), expected);
+                assert_eq!(dft.idft_batch(mat.clone()), expected_inv);
+            }
+        }
+    }
+
+    #[test]
+    fn coset_lde_matches_naive() {
+        let mut rng = SmallRng::seed_from_u64(2);
+        let shift = Goldilocks::GENERATOR;
+        for (log_h, added_bits) in [(0, 2), (3, 1), (5, 3), (8, 1)] {
+            let mat = RowMajorMatrix::<Goldilocks>::rand(&mut rng, 1 << log_h, 4);
+            let expected = NaiveDft.coset_lde_batch(mat.clone(), added_bits, shift);
+            for max_tile_elems in [1, 64, 1 << 12] {
+                let dft = FourStepDft::new(NaiveDft, max_tile_elems);
+                assert_eq!(
+                    dft.coset_lde_batch(mat.clone(), added_bits, shift),
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn out_of_core_views() {
+        // The out-of-core API only needs views, so large matrices can live in mapped files. Here
+        // we check it on slices carved out of a single buffer.
+        let mut rng = SmallRng::seed_from_u64(3);
+        let (h, w, added_bits) = (64, 2, 2);
+        let mat = RowMajorMatrix::<F>::rand(&mut rng, h, w);
+        let shift = F::GENERATOR.square();
+        let expected = NaiveDft.coset_lde_batch(mat.clone(), added_bits, shift);
+
+        let mut buffer = F::zero_vec(2 * h * w + ((h * w) << added_bits));
+        buffer[..h * w].copy_from_slice(&mat.values);
+        let (evals, rest) = buffer.split_at_mut(h * w);
+        let (coeffs, lde) = rest.split_at_mut(h * w);
+        let dft = FourStepDft::new(Radix2DFTSmallBatch::<F>::default(), 32);
+        dft.coset_lde_out_of_core(
+            &mut RowMajorMatrixViewMut::new(evals, w),
+            &mut RowMajorMatrixViewMut::new(coeffs, w),
+            added_bits,
+            shift,
+            &mut RowMajorMatrixViewMut::new(lde, w),
+        );
+        assert_eq!(lde, expected.values.as_slice());
+        assert_eq!(coeffs, NaiveDft.idft_batch(mat).values.as_slice());
+    }
+}
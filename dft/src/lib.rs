@@ -5,19 +5,25 @@
 extern crate alloc;
 
 mod butterflies;
+mod extension_dft;
+mod four_step;
 mod naive;
 mod radix_2_bowers;
 mod radix_2_dit;
 mod radix_2_dit_parallel;
 mod radix_2_small_batch;
 mod traits;
+mod twiddle_cache;
 mod util;
 
 pub use butterflies::*;
+pub use extension_dft::*;
+pub use four_step::*;
 pub use naive::*;
 pub use radix_2_bowers::*;
 pub use radix_2_dit::*;
 pub use radix_2_dit_parallel::*;
 pub use radix_2_small_batch::*;
 pub use traits::*;
+pub use twiddle_cache::*;
 pub use util::*;
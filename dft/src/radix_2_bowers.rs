@@ -1,6 +1,6 @@
 use alloc::vec::Vec;
 
-use p3_field::{Field, PrimeCharacteristicRing, TwoAdicField};
+use p3_field::{Field, TwoAdicField};
 use p3_matrix::Matrix;
 use p3_matrix::dense::{RowMajorMatrix, RowMajorMatrixViewMut};
 use p3_matrix::util::reverse_matrix_index_bits;
@@ -10,7 +10,7 @@ use tracing::instrument;
 
 use crate::TwoAdicSubgroupDft;
 use crate::butterflies::{Butterfly, DifButterfly, DitButterfly, TwiddleFreeButterfly};
-use crate::util::divide_by_height;
+use crate::util::{divide_by_height, interleave_coset, lde_by_cosets};
 
 /// The Bowers G FFT algorithm.
 /// See: "Improved Twiddle Access for Fast Fourier Transforms"
@@ -49,31 +49,31 @@ impl<F: TwoAdicField> TwoAdicSubgroupDft<F> for Radix2Bowers {
         added_bits: usize,
         shift: F,
     ) -> RowMajorMatrix<F> {
-        let h = mat.height();
-        let log_h = log2_strict_usize(h);
-        // It's cheaper to use div_2exp_u64 as this usually avoids an inversion.
-        // It's also cheaper to work in the PrimeSubfield whenever possible.
-        let h_inv_subfield = F::PrimeSubfield::ONE.div_2exp_u64(log_h as u64);
-        let h_inv = F::from_prime_subfield(h_inv_subfield);
-
+        // The coefficients, in bit-reversed order.
         bowers_g_t(&mut mat.as_view_mut());
+        divide_by_height(&mut mat);
 
-        // Rescale coefficients in two ways:
-        // - divide by height (since we're doing an inverse DFT)
-        // - multiply by powers of the coset shift (see default coset LDE impl for an explanation)
-        let mut weights = shift.shifted_powers(h_inv).collect_n(h);
-        // reverse_bits because mat is encoded in bit-reversed order
-        reverse_slice_index_bits(&mut weights);
-
-        mat.par_rows_mut()
-            .zip(weights.into_par_iter())
-            .for_each(|(row, weight)| row.iter_mut().for_each(|elem| *elem *= weight));
-
-        mat = mat.bit_reversed_zero_pad(added_bits);
-
-        bowers_g(&mut mat.as_view_mut());
-
-        mat
+        // Instead of zero padding the coefficients, we evaluate them over each coset
+        // `shift * g_big^j * H` of the subgroup `H` of order `h` separately, and interleave the
+        // results. As the twisted coefficients stay in bit-reversed order, `bowers_g` gives the
+        // evaluations in natural order.
+        let w = mat.width();
+        let num_cosets = 1 << added_bits;
+        let mut lde = F::zero_vec(mat.values.len() << added_bits);
+        let mut scratch = F::zero_vec(mat.values.len());
+        lde_by_cosets::<F, F, F, _>(
+            &mat.values,
+            &mut scratch,
+            w,
+            added_bits,
+            shift,
+            true,
+            |j, twisted| {
+                bowers_g(&mut RowMajorMatrixViewMut::new(twisted, w));
+                interleave_coset(&mut lde, twisted, w, j, num_cosets);
+            },
+        );
+        RowMajorMatrix::new(lde, w)
     }
 }
 
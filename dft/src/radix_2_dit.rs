@@ -9,8 +9,8 @@ use p3_matrix::util::reverse_matrix_index_bits;
 use p3_maybe_rayon::prelude::*;
 use p3_util::log2_strict_usize;
 
-use crate::TwoAdicSubgroupDft;
 use crate::butterflies::{Butterfly, DitButterfly, TwiddleFreeButterfly};
+use crate::{TwoAdicSubgroupDft, coset_lde_by_cosets};
 
 /// Radix-2 Decimation-in-Time FFT over a two-adic subgroup.
 ///
@@ -53,6 +53,16 @@ impl<F: TwoAdicField> TwoAdicSubgroupDft<F> for Radix2Dit<F> {
         }
         mat
     }
+
+    fn coset_lde_batch(
+        &self,
+        mat: RowMajorMatrix<F>,
+        added_bits: usize,
+        shift: F,
+    ) -> RowMajorMatrix<F> {
+        let coeffs = self.idft_batch(mat);
+        coset_lde_by_cosets(self, coeffs, added_bits, shift)
+    }
 }
 
 /// Applies one layer of the Radix-2 DIT FFT butterfly network.
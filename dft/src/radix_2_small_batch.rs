@@ -1,8 +1,6 @@
 //! An FFT implementation optimized for small batch sizes.
 
 use alloc::vec::Vec;
-use core::cell::RefCell;
-use core::iter;
 
 use itertools::Itertools;
 use p3_field::{Field, TwoAdicField, scale_slice_in_place_single_core};
@@ -13,7 +11,7 @@ use p3_maybe_rayon::prelude::*;
 use p3_util::{as_base_slice, log2_strict_usize, reverse_slice_index_bits};
 
 use crate::{
-    Butterfly, DifButterfly, DifButterflyZeros, DitButterfly, TwiddleFreeButterfly,
+    Butterfly, DifButterfly, DifButterflyZeros, DitButterfly, TwiddleCache, TwiddleFreeButterfly,
     TwoAdicSubgroupDft,
 };
 
@@ -23,29 +21,32 @@ const LAYERS_PER_GROUP: usize = 3;
 /// An FFT algorithm which divides a butterfly network's layers into two halves.
 ///
 /// Unlike other FFT algorithms, this algorithm is optimized for small batch sizes.
-/// It also stores its twiddle factors and only re-computes if it is asked to do a
-/// larger FFT.
+/// It also stores its twiddle factors and only computes new ones if it is asked to do a
+/// larger FFT. The twiddles are shared by all clones of an instance, which may be used from
+/// several threads at once.
 ///
 /// Instead of parallelizing across rows, this algorithm parallelizes across groups of rows
 /// with the same twiddle factors. This allows it to make use of field packings far more than
 /// the standard methods even for low width matrices. Once the chunk size is small enough, it
 /// computes a large set of layers fully on a single thread, which avoids the overhead of
 /// passing data between threads.
-#[derive(Default, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct Radix2DFTSmallBatch<F> {
     /// Memoized twiddle factors for each length log_n.
     ///
     /// For each `i`, `twiddles[i]` contains a list of twiddles stored in
     /// bit reversed order. The final set of twiddles `twiddles[-1]` is the
     /// one element vectors `[1]` and more general `twiddles[-i]` has length `2^i`.
-    ///
-    /// TODO: The use of RefCell means this can't be shared across
-    /// threads; consider using RwLock or finding a better design
-    /// instead.
-    twiddles: RefCell<Vec<Vec<F>>>,
+    /// The inverses used for the inverse fft are stored alongside.
+    twiddles: TwiddleCache<F>,
+}
 
-    /// Similar to `twiddles`, but stored the inverses used for the inverse fft.
-    inv_twiddles: RefCell<Vec<Vec<F>>>,
+impl<F> Default for Radix2DFTSmallBatch<F> {
+    fn default() -> Self {
+        Self {
+            twiddles: TwiddleCache::new(1, true),
+        }
+    }
 }
 
 impl<F: TwoAdicField> Radix2DFTSmallBatch<F> {
@@ -53,64 +54,10 @@ impl<F: TwoAdicField> Radix2DFTSmallBatch<F> {
     ///
     /// The input `n` should be a power of two, representing the maximal FFT size you expect to handle.
     pub fn new(n: usize) -> Self {
-        let res = Self {
-            twiddles: RefCell::default(),
-            inv_twiddles: RefCell::default(),
-        };
-        res.update_twiddles(n);
+        let res = Self::default();
+        res.twiddles.get(log2_strict_usize(n));
         res
     }
-
-    /// Given a field element `gen` of order n where `n = 2^lg_n`,
-    /// return a vector of vectors `table` where table[i] is the
-    /// vector of twiddle factors for an fft of length n/2^i. The
-    /// values g_i^k for k >= i/2 are skipped as these are just the
-    /// negatives of the other roots (using g_i^{i/2} = -1). The
-    /// value gen^0 = 1 is included to aid consistency between the
-    /// packed and non-packed variants.
-    fn roots_of_unity_table(&self, n: usize) -> Vec<Vec<F>> {
-        let lg_n = log2_strict_usize(n);
-        let generator = F::two_adic_generator(lg_n);
-        let half_n = 1 << (lg_n - 1);
-        // nth_roots = [1, g, g^2, g^3, ..., g^{n/2 - 1}]
-        let nth_roots = generator.powers().collect_n(half_n);
-
-        (0..lg_n)
-            .map(|i| nth_roots.iter().step_by(1 << i).copied().collect())
-            .collect()
-    }
-
-    /// Compute twiddle and inv_twiddle factors, or take memoized ones if already available.
-    fn update_twiddles(&self, fft_len: usize) {
-        // TODO: This recomputes the entire table from scratch if we
-        // need it to be larger, which is wasteful.
-
-        // roots_of_unity_table(fft_len) returns a vector of twiddles of length log_2(fft_len).
-        let curr_max_fft_len = 1 << self.twiddles.borrow().len();
-        if fft_len > curr_max_fft_len {
-            let mut new_twiddles = self.roots_of_unity_table(fft_len);
-            let mut new_inv_twiddles: Vec<Vec<F>> = new_twiddles
-                .iter()
-                .map(|ts| {
-                    // The first twiddle is still one, instead of inverting, we can
-                    // just reverse and negate.
-                    iter::once(F::ONE)
-                        .chain(ts[1..].iter().rev().map(|&f| -f))
-                        .collect()
-                })
-                .collect();
-
-            new_twiddles.iter_mut().for_each(|ts| {
-                reverse_slice_index_bits(ts);
-            });
-            new_inv_twiddles.iter_mut().for_each(|ts| {
-                reverse_slice_index_bits(ts);
-            });
-
-            self.twiddles.replace(new_twiddles);
-            self.inv_twiddles.replace(new_inv_twiddles);
-        }
-    }
 }
 
 impl<F> TwoAdicSubgroupDft<F> for Radix2DFTSmallBatch<F>
@@ -124,8 +71,8 @@ where
         let w = mat.width();
         let log_h = log2_strict_usize(h);
 
-        self.update_twiddles(h);
-        let root_table = self.twiddles.borrow();
+        let twiddles = self.twiddles.get(log_h);
+        let root_table = twiddles.twiddles();
         let len = root_table.len();
         let root_table = &root_table[len - log_h..];
 
@@ -177,8 +124,8 @@ where
         let w = mat.width();
         let log_h = log2_strict_usize(h);
 
-        self.update_twiddles(h);
-        let root_table = self.inv_twiddles.borrow();
+        let twiddles = self.twiddles.get(log_h);
+        let root_table = twiddles.inv_twiddles();
         let len = root_table.len();
         let root_table = &root_table[len - log_h..];
 
@@ -243,9 +190,9 @@ where
         let w = mat.width();
         let log_h = log2_strict_usize(h);
 
-        self.update_twiddles(h << added_bits);
-        let root_table = self.twiddles.borrow();
-        let inv_root_table = self.inv_twiddles.borrow();
+        let twiddles = self.twiddles.get(log_h + added_bits);
+        let root_table = twiddles.twiddles();
+        let inv_root_table = twiddles.inv_twiddles();
         let len = root_table.len();
 
         let root_table = &root_table[len - (log_h + added_bits)..];
@@ -0,0 +1,221 @@
+//! A cache of twiddle factors which grows on demand and can be shared between threads.
+
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::iter;
+
+use p3_field::TwoAdicField;
+use p3_util::reverse_slice_index_bits;
+use spin::RwLock;
+
+/// Twiddle factors for the layers of a radix-2 FFT, along with their inverses.
+///
+/// There is one layer for each block length `2^k`, starting with the largest block length. The
+/// layer for block length `2^k` holds the `2^(k - 1)` powers `1, g, ..., g^(2^(k - 1) - 1)` of
+/// `g = F::two_adic_generator(k)`, and the matching inverse layer holds the same powers of `g^{-1}`.
+/// Depending on the [`TwiddleCache`] which produced it, the layers may be stored in
+/// bit-reversed order.
+///
+/// As `g_k^2 = g_{k - 1}`, a layer only depends on its block length. Thus the table for FFTs of
+/// length `2^n` is a suffix of the table for FFTs of length `2^(n + 1)`.
+#[derive(Debug, Default)]
+pub struct TwiddleTable<F> {
+    twiddles: Vec<Vec<F>>,
+    inv_twiddles: Vec<Vec<F>>,
+}
+
+impl<F> TwiddleTable<F> {
+    /// The twiddle factors of each layer, from the largest block length down to the smallest.
+    #[inline]
+    pub fn twiddles(&self) -> &[Vec<F>] {
+        &self.twiddles
+    }
+
+    /// The inverse twiddle factors of each layer, from the largest block length down to the
+    /// smallest.
+    #[inline]
+    pub fn inv_twiddles(&self) -> &[Vec<F>] {
+        &self.inv_twiddles
+    }
+}
+
+/// A [`TwiddleTable`] which is extended on demand and can be shared by concurrent users.
+///
+/// Clones of a cache share the same table. Users take a cheap snapshot of the current table, so
+/// no lock is held while an FFT runs, and extending the table never waits for FFTs using a
+/// smaller one. When a longer FFT is requested, only the layers for the new block lengths are
+/// computed, while the existing ones are copied over.
+#[derive(Clone, Debug)]
+pub struct TwiddleCache<F> {
+    /// The current table, replaced by a longer one whenever it needs to be extended.
+    table: Arc<RwLock<Arc<TwiddleTable<F>>>>,
+
+    /// The log2 of the smallest block length which has a layer in the table.
+    log_min_block_len: usize,
+
+    /// Whether each layer is stored in bit-reversed order.
+    bit_reversed: bool,
+}
+
+impl<F> TwiddleCache<F> {
+    /// Create an empty cache.
+    ///
+    /// The tables it produces have layers for the block lengths down to `2^log_min_block_len`,
+    /// stored in bit-reversed order if `bit_reversed` is set. `log_min_block_len` must be
+    /// positive.
+    pub fn new(log_min_block_len: usize, bit_reversed: bool) -> Self {
+        assert!(
+            log_min_block_len >= 1,
+            "the smallest block length must be at least 2"
+        );
+        Self {
+            table: Arc::new(RwLock::new(Arc::new(TwiddleTable {
+                twiddles: Vec::new(),
+                inv_twiddles: Vec::new(),
+            }))),
+            log_min_block_len,
+            bit_reversed,
+        }
+    }
+
+    /// The log2 of the largest FFT length covered by the current table.
+    pub fn log_max_len(&self) -> usize {
+        self.log_max_len_of(&self.table.read())
+    }
+
+    #[inline]
+    const fn log_max_len_of(&self, table: &TwiddleTable<F>) -> usize {
+        table.twiddles.len() + self.log_min_block_len - 1
+    }
+}
+
+impl<F: TwoAdicField> TwiddleCache<F> {
+    /// Return a table covering FFTs of length at least `2^log_len`, extending the cache if needed.
+    ///
+    /// This can also be used to pre-compute the twiddles for the largest expected FFT up front.
+    pub fn get(&self, log_len: usize) -> Arc<TwiddleTable<F>> {
+        let current = self.table.read().clone();
+        let log_current_len = self.log_max_len_of(&current);
+        if log_len <= log_current_len {
+            return current;
+        }
+
+        // Compute the new layers outside of the lock, so that other users are not blocked.
+        let roots = F::two_adic_generator(log_len)
+            .powers()
+            .collect_n(1 << (log_len - 1));
+        let (mut twiddles, mut inv_twiddles): (Vec<Vec<F>>, Vec<Vec<F>>) = (log_current_len + 1
+            ..=log_len)
+            .rev()
+            .map(|log_block_len| {
+                // Every `2^(log_len - log_block_len)`'th power of g_{log_len} is a power of
+                // g_{log_block_len}.
+                let mut layer: Vec<F> = roots
+                    .iter()
+                    .step_by(1 << (log_len - log_block_len))
+                    .copied()
+                    .collect();
+                // The first twiddle is still one, instead of inverting, we can
+                // just reverse and negate.
+                let mut inv_layer: Vec<F> = iter::once(F::ONE)
+                    .chain(layer[1..].iter().rev().map(|&t| -t))
+                    .collect();
+                if self.bit_reversed {
+                    reverse_slice_index_bits(&mut layer);
+                    reverse_slice_index_bits(&mut inv_layer);
+                }
+                (layer, inv_layer)
+            })
+            .unzip();
+        twiddles.extend(current.twiddles.iter().cloned());
+        inv_twiddles.extend(current.inv_twiddles.iter().cloned());
+        let extended = Arc::new(TwiddleTable {
+            twiddles,
+            inv_twiddles,
+        });
+
+        let mut table = self.table.write();
+        // Someone else may have extended the table even further in the meantime.
+        if self.log_max_len_of(&table) < log_len {
+            *table = extended;
+        }
+        table.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use alloc::vec::Vec;
+
+    use p3_baby_bear::BabyBear;
+    use p3_field::{Field, PrimeCharacteristicRing, TwoAdicField};
+    use p3_util::reverse_slice_index_bits;
+
+    use super::TwiddleCache;
+
+    type F = BabyBear;
+
+    #[test]
+    fn layers_are_powers_of_roots_of_unity() {
+        let cache = TwiddleCache::<F>::new(1, false);
+        let table = cache.get(6);
+        assert_eq!(cache.log_max_len(), 6);
+        assert_eq!(table.twiddles().len(), 6);
+
+        for (layer, inv_layer, log_block_len) in
+            itertools::izip!(table.twiddles(), table.inv_twiddles(), (1..=6).rev())
+        {
+            let g = F::two_adic_generator(log_block_len);
+            let half_block_len = 1 << (log_block_len - 1);
+            assert_eq!(layer, &g.powers().collect_n(half_block_len));
+            assert_eq!(inv_layer, &g.inverse().powers().collect_n(half_block_len));
+        }
+    }
+
+    #[test]
+    fn extending_matches_fresh_table() {
+        for (log_min_block_len, bit_reversed) in [(1, true), (2, false)] {
+            let cache = TwiddleCache::<F>::new(log_min_block_len, bit_reversed);
+            for log_len in [0, 3, 2, 7, 10] {
+                let table = cache.get(log_len);
+                let fresh = TwiddleCache::<F>::new(log_min_block_len, bit_reversed).get(10);
+                let skipped = fresh.twiddles().len() - table.twiddles().len();
+                assert_eq!(table.twiddles(), &fresh.twiddles()[skipped..]);
+                assert_eq!(table.inv_twiddles(), &fresh.inv_twiddles()[skipped..]);
+            }
+            assert_eq!(cache.log_max_len(), 10);
+        }
+
+        // Bit reversal is applied to every layer.
+        let table = TwiddleCache::<F>::new(1, true).get(5);
+        let mut layer = F::two_adic_generator(5).powers().collect_n(16);
+        reverse_slice_index_bits(&mut layer);
+        assert_eq!(table.twiddles()[0], layer);
+    }
+
+    #[test]
+    fn shared_between_threads() {
+        let cache = TwiddleCache::<F>::new(1, true);
+        let tables: Vec<_> = std::thread::scope(|s| {
+            let handles: Vec<_> = (4..12)
+                .map(|log_len| {
+                    let cache = cache.clone();
+                    s.spawn(move || (log_len, cache.get(log_len)))
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        // All clones share the extended table.
+        assert_eq!(cache.log_max_len(), 11);
+        let largest = cache.get(11);
+        for (log_len, table) in tables {
+            let num_layers = table.twiddles().len();
+            assert!(num_layers >= log_len);
+            assert_eq!(table.twiddles(), &largest.twiddles()[11 - num_layers..]);
+            assert_eq!(table.inv_twiddles()[0][0], F::ONE);
+        }
+    }
+}
@@ -1,11 +1,16 @@
+use alloc::vec::Vec;
 use core::borrow::BorrowMut;
+use core::mem;
 
-use p3_field::{Field, PrimeCharacteristicRing};
+use p3_field::{Algebra, Field, PrimeCharacteristicRing, TwoAdicField};
 use p3_matrix::Matrix;
 use p3_matrix::dense::{DenseMatrix, DenseStorage, RowMajorMatrix};
-use p3_util::log2_strict_usize;
+use p3_maybe_rayon::prelude::*;
+use p3_util::{log2_strict_usize, reverse_slice_index_bits};
 use tracing::instrument;
 
+use crate::TwoAdicSubgroupDft;
+
 /// Divide each coefficient of the given matrix by its height.
 ///
 /// # Panics
@@ -35,6 +40,134 @@ pub(crate) fn coset_shift_cols<F: Field>(mat: &mut RowMajorMatrix<F>, shift: F)
         });
 }
 
+/// Compute the low-degree extension of the polynomials whose coefficients are the columns of
+/// `coeffs`, evaluating them over the coset `shift * K` where `K` is the two-adic subgroup of
+/// order `coeffs.height() << added_bits`. The result is in natural order.
+///
+/// Rather than padding the coefficients with zeros and running a DFT over `K`, this writes
+/// `shift * K` as the union of the cosets `shift * g_K^j * H`, where `H` is the subgroup of order
+/// `coeffs.height()`, and evaluates over each of them using a coset DFT of size `|H|`. The `j`'th
+/// coset holds rows `j, j + 2^added_bits, j + 2 * 2^added_bits, ...` of the result. This avoids
+/// any work on the padding zeros, saving `added_bits / (log2(|H|) + added_bits)` of the
+/// butterflies.
+#[instrument(skip_all, fields(dims = %coeffs.dimensions(), added_bits))]
+pub fn coset_lde_by_cosets<F, Dft>(
+    dft: &Dft,
+    coeffs: RowMajorMatrix<F>,
+    added_bits: usize,
+    shift: F,
+) -> RowMajorMatrix<F>
+where
+    F: TwoAdicField,
+    Dft: TwoAdicSubgroupDft<F>,
+{
+    let w = coeffs.width();
+    let num_cosets = 1 << added_bits;
+    let mut lde = F::zero_vec(coeffs.values.len() << added_bits);
+    let mut scratch = F::zero_vec(coeffs.values.len());
+    lde_by_cosets::<F, F, F, _>(
+        &coeffs.values,
+        &mut scratch,
+        w,
+        added_bits,
+        shift,
+        false,
+        |j, twisted| {
+            // The DFTs in this crate work in place, so this reuses the scratch buffer.
+            let evals = dft
+                .dft_batch(RowMajorMatrix::new(mem::take(twisted), w))
+                .to_row_major_matrix();
+            interleave_coset(&mut lde, &evals.values, w, j, num_cosets);
+            *twisted = evals.values;
+        },
+    );
+    RowMajorMatrix::new(lde, w)
+}
+
+/// The number of rows twisted by a single task of [`lde_by_cosets`], which computes their
+/// weights from a single exponentiation.
+const TWIST_CHUNK_ROWS: usize = 1 << 8;
+
+/// Evaluate the polynomials whose coefficients are the columns of `coeffs` over each coset
+/// `shift * g_K^j * H` in turn, as in [`coset_lde_by_cosets`].
+///
+/// `coeffs` is a matrix of width `width` and power-of-two height `|H|`, whose entries lie in an
+/// algebra `A` over `T`, which is `F` or a packing of `F`. Its rows are the coefficients in
+/// natural order, or in bit-reversed order if `bit_reversed` is set. For each `j`, the buffer of
+/// `scratch` (which must have the same length as `coeffs`) is overwritten with the coefficient of
+/// `x^i` multiplied by `(shift * g_K^j)^i`, and `evaluate(j, scratch)` is called. It should run a
+/// DFT on the twisted coefficients, which gives the evaluations over the `j`'th coset, and store
+/// them. As the twisting overwrites the whole buffer, `evaluate` may replace or leave behind
+/// anything in it.
+pub(crate) fn lde_by_cosets<F, T, A, S>(
+    coeffs: &[A],
+    scratch: &mut S,
+    width: usize,
+    added_bits: usize,
+    shift: F,
+    bit_reversed: bool,
+    mut evaluate: impl FnMut(usize, &mut S),
+) where
+    F: TwoAdicField,
+    T: Algebra<F> + Copy + Send + Sync,
+    A: Algebra<T> + Copy + Send + Sync,
+    S: BorrowMut<[A]>,
+{
+    let h = coeffs.len() / width;
+    let log_h = log2_strict_usize(h);
+    let g_big = F::two_adic_generator(log_h + added_bits);
+    let twist_row = |dst: &mut [A], src: &[A], weight: F| {
+        let weight = T::from(weight);
+        dst.iter_mut().zip(src).for_each(|(d, &s)| *d = s * weight);
+    };
+
+    let mut weights = Vec::new();
+    for (j, coset_shift) in g_big
+        .shifted_powers(shift)
+        .take(1 << added_bits)
+        .enumerate()
+    {
+        let dst: &mut [A] = scratch.borrow_mut();
+        if bit_reversed {
+            weights.clear();
+            weights.extend(coset_shift.powers().take(h));
+            reverse_slice_index_bits(&mut weights);
+            dst.par_chunks_exact_mut(width)
+                .zip(coeffs.par_chunks_exact(width))
+                .zip(weights.par_iter())
+                .for_each(|((dst_row, src_row), &weight)| twist_row(dst_row, src_row, weight));
+        } else {
+            dst.par_chunks_mut(TWIST_CHUNK_ROWS * width)
+                .zip(coeffs.par_chunks(TWIST_CHUNK_ROWS * width))
+                .enumerate()
+                .for_each(|(chunk, (dst, src))| {
+                    let start = coset_shift.exp_u64((chunk * TWIST_CHUNK_ROWS) as u64);
+                    dst.chunks_exact_mut(width)
+                        .zip(src.chunks_exact(width))
+                        .zip(coset_shift.shifted_powers(start))
+                        .for_each(|((dst_row, src_row), weight)| {
+                            twist_row(dst_row, src_row, weight)
+                        });
+                });
+        }
+        evaluate(j, scratch);
+    }
+}
+
+/// Copy `evals`, the evaluations over the `j`'th of `num_cosets` cosets, to rows
+/// `j, j + num_cosets, j + 2 * num_cosets, ...` of `lde`.
+pub(crate) fn interleave_coset<A: Copy + Send + Sync>(
+    lde: &mut [A],
+    evals: &[A],
+    width: usize,
+    j: usize,
+    num_cosets: usize,
+) {
+    lde.par_chunks_exact_mut(num_cosets * width)
+        .zip(evals.par_chunks_exact(width))
+        .for_each(|(rows, eval)| rows[j * width..(j + 1) * width].copy_from_slice(eval));
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::vec;
@@ -137,6 +270,31 @@ mod tests {
         assert_eq!(mat.values, expected);
     }
 
+    #[test]
+    fn test_coset_lde_by_cosets_matches_padded() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        use crate::NaiveDft;
+
+        let mut rng = SmallRng::seed_from_u64(1);
+        let shift = F::GENERATOR;
+        for log_h in 0..4 {
+            let coeffs = RowMajorMatrix::<F>::rand(&mut rng, 1 << log_h, 2);
+            for added_bits in 0..3 {
+                // Zero pad the coefficients and evaluate over the whole coset directly.
+                let mut padded = coeffs.clone();
+                padded
+                    .values
+                    .resize(coeffs.values.len() << added_bits, F::ZERO);
+                let expected = NaiveDft.coset_dft_batch(padded, shift);
+
+                let lde = coset_lde_by_cosets(&NaiveDft, coeffs.clone(), added_bits, shift);
+                assert_eq!(lde, expected);
+            }
+        }
+    }
+
     #[test]
     fn test_coset_shift_cols_identity_shift() {
         // shift = 1 → all weights = 1 → matrix should remain unchanged
@@ -0,0 +1,142 @@
+#!/usr/bin/env python3
+"""Known answer values for the EcGFp5 tests in `src/curve.rs`.
+
+This is a direct, unoptimised implementation of the curve `y^2 = x (x^2 + a x + b)` over
+`GF(p^5) = GF(p)[z] / (z^5 - 3)`, with `p = 2^64 - 2^32 + 1`, `a = 2` and `b = 263 z`, following
+the EcGFp5 paper (https://eprint.iacr.org/2022/274). It only depends on the Python standard
+library. Field elements are printed as their five coefficients in the basis `1, z, ..., z^4`.
+"""
+
+P = 2**64 - 2**32 + 1
+Q = P**5
+W = 3
+
+
+def add(x, y):
+    return tuple((a + b) % P for a, b in zip(x, y))
+
+
+def sub(x, y):
+    return tuple((a - b) % P for a, b in zip(x, y))
+
+
+def mul(x, y):
+    c = [0] * 5
+    for i in range(5):
+        for j in range(5):
+            if i + j < 5:
+                c[i + j] += x[i] * y[j]
+            else:
+                c[i + j - 5] += W * x[i] * y[j]
+    return tuple(v % P for v in c)
+
+
+def const(v):
+    return (v % P, 0, 0, 0, 0)
+
+
+ZERO, ONE = const(0), const(1)
+A = const(2)
+B = (0, 263, 0, 0, 0)
+
+
+def power(x, e):
+    result = ONE
+    while e:
+        if e & 1:
+            result = mul(result, x)
+        x = mul(x, x)
+        e >>= 1
+    return result
+
+
+def inv(x):
+    return power(x, Q - 2)
+
+
+def is_square(x):
+    return x == ZERO or power(x, (Q - 1) // 2) == ONE
+
+
+def sqrt(x):
+    """Tonelli-Shanks in GF(p^5), returning None for non-squares."""
+    if x == ZERO:
+        return ZERO
+    if not is_square(x):
+        return None
+    s, t = 0, Q - 1
+    while t % 2 == 0:
+        s, t = s + 1, t // 2
+    z = next(c for c in (const(k) for k in range(2, 100)) if not is_square(c))
+    m, c, r, u = s, power(z, t), power(x, (t + 1) // 2), power(x, t)
+    while u != ONE:
+        i, u2 = 0, u
+        while u2 != ONE:
+            u2, i = mul(u2, u2), i + 1
+        b = power(c, 2 ** (m - i - 1))
+        m, c = i, mul(b, b)
+        r, u = mul(r, b), mul(u, c)
+    return r
+
+
+def decode(w):
+    """Decode a point from `w = y / x`, as in `Point::decode`. The neutral point is None."""
+    if w == ZERO:
+        return None
+    e = sub(mul(w, w), A)
+    delta = sub(mul(e, e), mul(const(4), B))
+    root = sqrt(delta)
+    assert root is not None, "invalid encoding"
+    half = inv(const(2))
+    x1, x2 = mul(add(e, root), half), mul(sub(e, root), half)
+    x = x1 if is_square(x1) else x2
+    return (x, mul(w, x))
+
+
+def encode(point):
+    if point is None:
+        return ZERO
+    x, y = point
+    return mul(y, inv(x))
+
+
+def point_add(p1, p2):
+    if p1 is None:
+        return p2
+    if p2 is None:
+        return p1
+    (x1, y1), (x2, y2) = p1, p2
+    if x1 == x2:
+        if y1 != y2:
+            return None
+        num = add(add(mul(const(3), mul(x1, x1)), mul(mul(const(2), A), x1)), B)
+        lam = mul(num, inv(mul(const(2), y1)))
+    else:
+        lam = mul(sub(y2, y1), inv(sub(x2, x1)))
+    x3 = sub(sub(sub(mul(lam, lam), A), x1), x2)
+    y3 = sub(mul(lam, sub(x1, x3)), y1)
+    return (x3, y3)
+
+
+def scalar_mul(point, k):
+    result = None
+    for bit in bin(k)[2:]:
+        result = point_add(result, result)
+        if bit == "1":
+            result = point_add(result, point)
+    return result
+
+
+def main():
+    g = decode(const(4))
+    print("G.x       =", list(g[0]))
+    print("G.y       =", list(g[1]))
+    g2 = point_add(g, g)
+    print("enc(2 G)  =", list(encode(g2)))
+    print("enc(3 G)  =", list(encode(point_add(g2, g))))
+    k = 12345678901234567890123456789
+    print("enc(k G)  =", list(encode(scalar_mul(g, k))))
+
+
+if __name__ == "__main__":
+    main()
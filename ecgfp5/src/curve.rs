@@ -0,0 +1,353 @@
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use p3_field::extension::BinomialExtensionField;
+use p3_field::{BasedVectorSpace, Field, PrimeCharacteristicRing};
+use p3_goldilocks::Goldilocks;
+
+use crate::Scalar;
+use crate::projective::ct_mul_scalar;
+
+/// The field `GF(p^5) = GF(p)[z]/(z^5 - 3)`, where `p` is the Goldilocks prime.
+pub type GFp5 = BinomialExtensionField<Goldilocks, 5>;
+
+/// The coefficient `a = 2` of the curve equation.
+pub(crate) fn curve_a() -> GFp5 {
+    GFp5::TWO
+}
+
+/// The coefficient `b = 263 z` of the curve equation.
+pub(crate) fn curve_b() -> GFp5 {
+    GFp5::from_basis_coefficients_fn(|i| {
+        if i == 1 {
+            Goldilocks::from_u16(263)
+        } else {
+            Goldilocks::ZERO
+        }
+    })
+}
+
+/// A point in the prime order group of the EcGFp5 curve `y^2 = x (x^2 + a x + b)`.
+///
+/// The curve has order `2n` for the prime `n` given by [`Scalar::order`]. Its only point of order
+/// two is `N = (0, 0)`, and since `b` is not a square, the points of order dividing `n` are exactly
+/// the neutral point and the points whose `x` coordinate is a nonzero square. This is the group we
+/// work in, so every `Point` has order `1` or `n`.
+///
+/// Points are kept in affine coordinates, with a flag for the neutral point.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Point {
+    x: GFp5,
+    y: GFp5,
+    is_neutral: bool,
+}
+
+impl Point {
+    /// The neutral element of the group, i.e. the point at infinity.
+    pub const NEUTRAL: Self = Self {
+        x: GFp5::ZERO,
+        y: GFp5::ZERO,
+        is_neutral: true,
+    };
+
+    /// The conventional generator, which is the point with encoding `4`, the smallest integer
+    /// which is a valid encoding.
+    pub fn generator() -> Self {
+        Self::decode(GFp5::from_u8(4)).unwrap()
+    }
+
+    /// Create a point from its affine coordinates.
+    ///
+    /// Returns `None` if `(x, y)` is not on the curve or not in the prime order group.
+    pub fn from_affine(x: GFp5, y: GFp5) -> Option<Self> {
+        let on_curve = y.square() == x * (x.square() + curve_a() * x + curve_b());
+        (on_curve && !x.is_zero() && x.is_square()).then_some(Self {
+            x,
+            y,
+            is_neutral: false,
+        })
+    }
+
+    /// Create a point from affine coordinates which are known to be those of a point of the group.
+    pub(crate) const fn from_affine_unchecked(x: GFp5, y: GFp5) -> Self {
+        Self {
+            x,
+            y,
+            is_neutral: false,
+        }
+    }
+
+    /// The affine coordinates of this point, or `None` for the neutral point.
+    pub const fn to_affine(&self) -> Option<(GFp5, GFp5)> {
+        if self.is_neutral {
+            None
+        } else {
+            Some((self.x, self.y))
+        }
+    }
+
+    /// Whether this is the neutral point of the group.
+    pub const fn is_neutral(&self) -> bool {
+        self.is_neutral
+    }
+
+    /// Encode this point as the field element `w = y / x`, with the neutral point encoded as `0`.
+    ///
+    /// The encoding is injective on the prime order group: the two points with a given `w` are `P`
+    /// and `N - P`, and only one of them is in the group.
+    pub fn encode(&self) -> GFp5 {
+        if self.is_neutral {
+            GFp5::ZERO
+        } else {
+            self.y * self.x.inverse()
+        }
+    }
+
+    /// Decode a point from its encoding, returning `None` if `w` is not a valid encoding.
+    pub fn decode(w: GFp5) -> Option<Self> {
+        if w.is_zero() {
+            return Some(Self::NEUTRAL);
+        }
+
+        // Substituting `y = w x` in the curve equation gives `x^2 - (w^2 - a) x + b = 0`, whose
+        // roots have product `b`. As `b` is not a square, exactly one root is a square, and it is
+        // the `x` coordinate of the point in the prime order group.
+        let e = w.square() - curve_a();
+        let delta = e.square() - curve_b().double().double();
+        let root = delta.sqrt()?;
+        let x1 = (e + root).halve();
+        let x2 = (e - root).halve();
+        let x = if x1.is_square() { x1 } else { x2 };
+        Some(Self {
+            x,
+            y: w * x,
+            is_neutral: false,
+        })
+    }
+
+    /// Double this point.
+    #[must_use]
+    pub fn double(&self) -> Self {
+        // No point of the group has `y = 0`, as that would be a point of order two.
+        if self.is_neutral {
+            return *self;
+        }
+        let lambda = (self.x.square() * GFp5::from_u8(3) + curve_a().double() * self.x + curve_b())
+            * self.y.double().inverse();
+        self.with_slope(lambda, self.x)
+    }
+
+    /// The sum of `self` and the point with `x` coordinate `other_x` on the line of slope `lambda`
+    /// through `self`.
+    fn with_slope(&self, lambda: GFp5, other_x: GFp5) -> Self {
+        let x = lambda.square() - curve_a() - self.x - other_x;
+        let y = lambda * (self.x - x) - self.y;
+        Self {
+            x,
+            y,
+            is_neutral: false,
+        }
+    }
+
+    /// Multiply this point by a scalar.
+    ///
+    /// This uses a Montgomery ladder over complete projective formulas and runs in time
+    /// independent of the scalar, so it may be used with secret scalars.
+    #[must_use]
+    pub fn mul_scalar(&self, scalar: &Scalar) -> Self {
+        ct_mul_scalar(self, scalar)
+    }
+}
+
+impl Default for Point {
+    fn default() -> Self {
+        Self::NEUTRAL
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        if self.is_neutral {
+            return rhs;
+        }
+        if rhs.is_neutral {
+            return self;
+        }
+        if self.x == rhs.x {
+            return if self.y == rhs.y {
+                self.double()
+            } else {
+                Self::NEUTRAL
+            };
+        }
+        let lambda = (rhs.y - self.y) * (rhs.x - self.x).inverse();
+        self.with_slope(lambda, rhs.x)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { y: -self.y, ..self }
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + (-rhs)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<&Scalar> for Point {
+    type Output = Self;
+
+    fn mul(self, rhs: &Scalar) -> Self {
+        self.mul_scalar(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use rand::Rng;
+
+    use super::*;
+    use crate::test_rng::TestRng;
+
+    fn gfp5(coeffs: [u64; 5]) -> GFp5 {
+        GFp5::from_basis_coefficients_fn(|i| Goldilocks::from_u64(coeffs[i]))
+    }
+
+    #[test]
+    fn generator_has_order_n() {
+        let g = Point::generator();
+        assert!(!g.is_neutral());
+        assert_eq!(g * &Scalar::new(Scalar::order()), Point::NEUTRAL);
+        assert_eq!(g * &Scalar::new(Scalar::order() - 1u32), -g);
+        assert_eq!(-g, Point::decode(-GFp5::from_u8(4)).unwrap());
+    }
+
+    #[test]
+    fn known_multiples() {
+        // Generated by `scripts/known_answers.py`, an independent Python implementation.
+        let g = Point::generator();
+        let (x, y) = g.to_affine().unwrap();
+        assert_eq!(
+            x,
+            gfp5([
+                5563608483237702766,
+                14090224426659529053,
+                13197813503519687414,
+                16280770174934269299,
+                15998333998318935536
+            ])
+        );
+        assert_eq!(
+            y,
+            gfp5([
+                3807689863536226743,
+                1020665498394363249,
+                15897765875249581014,
+                9782848491493324233,
+                8653103785031989181
+            ])
+        );
+        assert_eq!(
+            g.double().encode(),
+            gfp5([
+                9158372289535233080,
+                10327954189174774606,
+                15619016834217869504,
+                16517814385077291378,
+                10141215455047792195
+            ])
+        );
+        assert_eq!(
+            (g.double() + g).encode(),
+            gfp5([
+                6052337009455581569,
+                14364284273112518944,
+                6784982068192735943,
+                3108585027458804187,
+                12998922769182173772
+            ])
+        );
+        let k = Scalar::new(BigUint::parse_bytes(b"12345678901234567890123456789", 10).unwrap());
+        assert_eq!(
+            (g * &k).encode(),
+            gfp5([
+                11491856540425307975,
+                9737392986518262619,
+                1115499624320084349,
+                7699785212350182651,
+                10056618310839149535
+            ])
+        );
+    }
+
+    #[test]
+    fn group_law() {
+        let mut rng = TestRng::seed_from_u64(1);
+        let g = Point::generator();
+        let a = Scalar::random(&mut rng);
+        let b = Scalar::random(&mut rng);
+        let p = g * &a;
+        let q = g * &b;
+
+        assert_eq!(p + q, g * &(a.clone() + b.clone()));
+        assert_eq!(p - q, g * &(a.clone() - b));
+        assert_eq!(p + p, p.double());
+        assert_eq!(p - p, Point::NEUTRAL);
+        assert_eq!(p + Point::NEUTRAL, p);
+        assert_eq!(Point::NEUTRAL.double(), Point::NEUTRAL);
+        assert_eq!(g * &Scalar::ZERO, Point::NEUTRAL);
+        assert_eq!(p * &Scalar::new(BigUint::from(3u8)), p.double() + p);
+
+        let (x, y) = p.to_affine().unwrap();
+        assert_eq!(Point::from_affine(x, y), Some(p));
+        assert_eq!(Point::from_affine(x, y + GFp5::ONE), None);
+
+        // The point `N - p`, which has the same encoding as `p`, is on the curve but not in the
+        // group.
+        let b = curve_b();
+        let x_n = b * x.inverse();
+        let y_n = b * y * x.square().inverse();
+        assert_eq!(y_n.square(), x_n * (x_n.square() + curve_a() * x_n + b));
+        assert_eq!(y_n * x_n.inverse(), p.encode());
+        assert_eq!(Point::from_affine(x_n, y_n), None);
+    }
+
+    #[test]
+    fn encoding_roundtrip() {
+        let mut rng = TestRng::seed_from_u64(2);
+        let g = Point::generator();
+        assert_eq!(Point::decode(Point::NEUTRAL.encode()), Some(Point::NEUTRAL));
+        for _ in 0..10 {
+            let p = g * &Scalar::random(&mut rng);
+            assert_eq!(Point::decode(p.encode()), Some(p));
+            assert_eq!((-p).encode(), -p.encode());
+        }
+
+        // About half of the field elements are not encodings.
+        let invalid = (0..)
+            .map(|_| rng.random::<GFp5>())
+            .find(|&w| Point::decode(w).is_none());
+        assert!(invalid.is_some());
+    }
+}
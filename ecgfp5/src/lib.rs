@@ -0,0 +1,50 @@
+//! The EcGFp5 elliptic curve, defined over the degree five extension of the Goldilocks field,
+//! along with Schnorr signatures over its prime order group.
+//!
+//! As the base field is an extension of a native STARK field, curve operations and signature
+//! verification can be arithmetized cheaply in AIRs over Goldilocks.
+
+#![no_std]
+
+extern crate alloc;
+
+mod curve;
+mod projective;
+mod scalar;
+mod schnorr;
+
+pub use curve::*;
+pub use scalar::*;
+pub use schnorr::*;
+
+#[cfg(test)]
+mod test_rng {
+    use rand::rngs::SmallRng;
+    use rand::{CryptoRng, RngCore, SeedableRng};
+
+    /// A seeded generator which claims to be cryptographically secure, so that the tests can
+    /// sample keys and nonces deterministically. It must never be used outside of tests.
+    pub(crate) struct TestRng(SmallRng);
+
+    impl TestRng {
+        pub(crate) fn seed_from_u64(seed: u64) -> Self {
+            Self(SmallRng::seed_from_u64(seed))
+        }
+    }
+
+    impl RngCore for TestRng {
+        fn next_u32(&mut self) -> u32 {
+            self.0.next_u32()
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0.next_u64()
+        }
+
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            self.0.fill_bytes(dst);
+        }
+    }
+
+    impl CryptoRng for TestRng {}
+}
@@ -0,0 +1,257 @@
+//! Constant-time scalar multiplication.
+//!
+//! The affine group law of [`Point`] branches on its inputs and inverts a field element for every
+//! operation, which is fine for public points but leaks the scalar when multiplying by a secret.
+//! Here the curve `y^2 = x (x^2 + a x + b)` is mapped to the isomorphic short Weierstrass curve
+//! `Y^2 = X^3 + A X + B` by `X = x + a / 3`, and points are kept in projective coordinates. The
+//! complete addition formulas of [Renes, Costello and Batina](https://eprint.iacr.org/2015/1060)
+//! then handle doublings and the neutral point without special cases: their only exceptions are
+//! pairs of points whose difference has order two, which never occurs in the prime order group.
+//!
+//! The scalar multiplication is a Montgomery ladder over a fixed number of bits, with the
+//! conditional swaps and all field arithmetic done with the constant-time operations of
+//! [`ConstantTimeField`].
+
+use p3_field::{BasedVectorSpace, ConstantTimeField, Field, PrimeCharacteristicRing};
+use p3_goldilocks::Goldilocks;
+
+use crate::{GFp5, Point, Scalar, curve_a, curve_b};
+
+/// The little-endian limbs of `p^5 - 2`, where `p` is the Goldilocks prime.
+const INVERSE_EXPONENT: [u64; 5] = [
+    0xfffffffaffffffff,
+    0xffffffe20000000e,
+    0xffffffcd0000002c,
+    0xffffffe20000002c,
+    0xfffffffb0000000e,
+];
+
+fn coeffs(x: &GFp5) -> &[Goldilocks] {
+    x.as_basis_coefficients_slice()
+}
+
+fn ct_add(x: &GFp5, y: &GFp5) -> GFp5 {
+    let (x, y) = (coeffs(x), coeffs(y));
+    GFp5::from_basis_coefficients_fn(|i| x[i].ct_add(&y[i]))
+}
+
+fn ct_sub(x: &GFp5, y: &GFp5) -> GFp5 {
+    let (x, y) = (coeffs(x), coeffs(y));
+    GFp5::from_basis_coefficients_fn(|i| x[i].ct_sub(&y[i]))
+}
+
+fn ct_mul(x: &GFp5, y: &GFp5) -> GFp5 {
+    let (x, y) = (coeffs(x), coeffs(y));
+    let w = Goldilocks::from_u8(3);
+    // Products `x_i y_j` with `i + j = k + 5` wrap around with a factor of `z^5 = 3`.
+    GFp5::from_basis_coefficients_fn(|k| {
+        let low = (0..=k).fold(Goldilocks::ZERO, |acc, i| {
+            acc.ct_add(&x[i].ct_mul(&y[k - i]))
+        });
+        let high = (k + 1..5).fold(Goldilocks::ZERO, |acc, i| {
+            acc.ct_add(&x[i].ct_mul(&y[k + 5 - i]))
+        });
+        low.ct_add(&high.ct_mul(&w))
+    })
+}
+
+fn ct_select(x: &GFp5, y: &GFp5, choice: bool) -> GFp5 {
+    let (x, y) = (coeffs(x), coeffs(y));
+    GFp5::from_basis_coefficients_fn(|i| x[i].ct_select(&y[i], choice))
+}
+
+/// Compute `x^{p^5 - 2}`, which is the inverse of `x` if it is nonzero and `0` otherwise.
+///
+/// The exponent is public, so the running time does not depend on `x`.
+fn ct_inverse(x: &GFp5) -> GFp5 {
+    INVERSE_EXPONENT.iter().rev().fold(GFp5::ONE, |acc, &limb| {
+        (0..u64::BITS).rev().fold(acc, |acc, j| {
+            let acc = ct_mul(&acc, &acc);
+            if (limb >> j) & 1 == 1 {
+                ct_mul(&acc, x)
+            } else {
+                acc
+            }
+        })
+    })
+}
+
+/// The coefficients of the short Weierstrass curve isomorphic to EcGFp5.
+struct ShortWeierstrass {
+    /// `A = b - a^2 / 3`.
+    a: GFp5,
+    /// `3 B = 2 a^3 / 9 - a b`.
+    b3: GFp5,
+    /// The shift `a / 3` between the `x` coordinates of the two curves.
+    shift: GFp5,
+}
+
+impl ShortWeierstrass {
+    fn new() -> Self {
+        let (a, b) = (curve_a(), curve_b());
+        let shift = a * GFp5::from_u8(3).inverse();
+        Self {
+            a: b - a * shift,
+            b3: a.square() * shift.double() * GFp5::from_u8(3).inverse() - a * b,
+            shift,
+        }
+    }
+}
+
+/// A point `(X : Y : Z)` of the short Weierstrass curve, in projective coordinates.
+#[derive(Copy, Clone, Debug)]
+struct ProjectivePoint {
+    x: GFp5,
+    y: GFp5,
+    z: GFp5,
+}
+
+impl ProjectivePoint {
+    const NEUTRAL: Self = Self {
+        x: GFp5::ZERO,
+        y: GFp5::ONE,
+        z: GFp5::ZERO,
+    };
+
+    fn from_point(point: &Point, curve: &ShortWeierstrass) -> Self {
+        match point.to_affine() {
+            Some((x, y)) => Self {
+                x: x + curve.shift,
+                y,
+                z: GFp5::ONE,
+            },
+            None => Self::NEUTRAL,
+        }
+    }
+
+    fn to_point(self, curve: &ShortWeierstrass) -> Point {
+        // The result is the neutral point only when the scalar is a multiple of `n`.
+        if self.z.is_zero() {
+            return Point::NEUTRAL;
+        }
+        let z_inv = ct_inverse(&self.z);
+        Point::from_affine_unchecked(
+            ct_sub(&ct_mul(&self.x, &z_inv), &curve.shift),
+            ct_mul(&self.y, &z_inv),
+        )
+    }
+
+    /// Complete addition, following Algorithm 1 of Renes, Costello and Batina.
+    fn add(&self, rhs: &Self, curve: &ShortWeierstrass) -> Self {
+        let (x1, y1, z1) = (&self.x, &self.y, &self.z);
+        let (x2, y2, z2) = (&rhs.x, &rhs.y, &rhs.z);
+
+        let mut t0 = ct_mul(x1, x2);
+        let mut t1 = ct_mul(y1, y2);
+        let mut t2 = ct_mul(z1, z2);
+        let mut t3 = ct_mul(&ct_add(x1, y1), &ct_add(x2, y2));
+        let mut t4 = ct_add(&t0, &t1);
+        t3 = ct_sub(&t3, &t4);
+        t4 = ct_mul(&ct_add(x1, z1), &ct_add(x2, z2));
+        let mut t5 = ct_add(&t0, &t2);
+        t4 = ct_sub(&t4, &t5);
+        t5 = ct_mul(&ct_add(y1, z1), &ct_add(y2, z2));
+        let mut x3 = ct_add(&t1, &t2);
+        t5 = ct_sub(&t5, &x3);
+        let mut z3 = ct_mul(&curve.a, &t4);
+        x3 = ct_mul(&curve.b3, &t2);
+        z3 = ct_add(&x3, &z3);
+        x3 = ct_sub(&t1, &z3);
+        z3 = ct_add(&t1, &z3);
+        let mut y3 = ct_mul(&x3, &z3);
+        t1 = ct_add(&ct_add(&t0, &t0), &t0);
+        t2 = ct_mul(&curve.a, &t2);
+        t4 = ct_mul(&curve.b3, &t4);
+        t1 = ct_add(&t1, &t2);
+        t2 = ct_mul(&curve.a, &ct_sub(&t0, &t2));
+        t4 = ct_add(&t4, &t2);
+        t0 = ct_mul(&t1, &t4);
+        y3 = ct_add(&y3, &t0);
+        t0 = ct_mul(&t5, &t4);
+        x3 = ct_sub(&ct_mul(&t3, &x3), &t0);
+        t0 = ct_mul(&t3, &t1);
+        z3 = ct_add(&ct_mul(&t5, &z3), &t0);
+
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Swap `a` and `b` if `choice` is `true`, without branching on `choice`.
+    fn ct_swap(a: &mut Self, b: &mut Self, choice: bool) {
+        let new_a = Self {
+            x: ct_select(&a.x, &b.x, choice),
+            y: ct_select(&a.y, &b.y, choice),
+            z: ct_select(&a.z, &b.z, choice),
+        };
+        let new_b = Self {
+            x: ct_select(&b.x, &a.x, choice),
+            y: ct_select(&b.y, &a.y, choice),
+            z: ct_select(&b.z, &a.z, choice),
+        };
+        *a = new_a;
+        *b = new_b;
+    }
+}
+
+/// Compute `scalar * point` in time independent of `scalar`.
+///
+/// The ladder always runs over [`Scalar::BITS`] bits and performs one addition and one doubling per
+/// bit, whatever their values.
+pub(crate) fn ct_mul_scalar(point: &Point, scalar: &Scalar) -> Point {
+    let curve = ShortWeierstrass::new();
+    let mut r0 = ProjectivePoint::NEUTRAL;
+    let mut r1 = ProjectivePoint::from_point(point, &curve);
+    // Invariant: `r1 - r0 = point`, up to the pending swap.
+    let mut swapped = false;
+    for i in (0..Scalar::BITS).rev() {
+        let bit = scalar.bit(i);
+        ProjectivePoint::ct_swap(&mut r0, &mut r1, bit ^ swapped);
+        swapped = bit;
+        r1 = r0.add(&r1, &curve);
+        r0 = r0.add(&r0, &curve);
+    }
+    ProjectivePoint::ct_swap(&mut r0, &mut r1, swapped);
+    r0.to_point(&curve)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn constant_time_field_arithmetic() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        for _ in 0..20 {
+            let x: GFp5 = rng.random();
+            let y: GFp5 = rng.random();
+            assert_eq!(ct_add(&x, &y), x + y);
+            assert_eq!(ct_sub(&x, &y), x - y);
+            assert_eq!(ct_mul(&x, &y), x * y);
+            assert_eq!(ct_select(&x, &y, false), x);
+            assert_eq!(ct_select(&x, &y, true), y);
+            assert_eq!(ct_inverse(&x), x.inverse());
+        }
+        assert_eq!(ct_inverse(&GFp5::ZERO), GFp5::ZERO);
+    }
+
+    #[test]
+    fn complete_addition_matches_affine() {
+        let curve = ShortWeierstrass::new();
+        let g = Point::generator();
+        let points = [Point::NEUTRAL, g, -g, g.double(), g.double() + g];
+        for p in points {
+            for q in points {
+                let sum = ProjectivePoint::from_point(&p, &curve)
+                    .add(&ProjectivePoint::from_point(&q, &curve), &curve)
+                    .to_point(&curve);
+                assert_eq!(sum, p + q);
+            }
+        }
+    }
+}
@@ -0,0 +1,296 @@
+use alloc::vec::Vec;
+use core::fmt::{Debug, Formatter};
+use core::ops::{Add, Mul, Neg, Sub};
+
+use num_bigint::BigUint;
+use p3_field::{PrimeField64, ct_mask_u64};
+use p3_goldilocks::Goldilocks;
+use rand::{CryptoRng, Rng};
+
+/// The number of 64-bit limbs of a scalar.
+const NUM_LIMBS: usize = 5;
+
+/// The little-endian limbs of the prime order `n` of the EcGFp5 group.
+const ORDER: [u64; NUM_LIMBS] = [
+    0xe80fd996948bffe1,
+    0xe8885c39d724a09c,
+    0x7fffffe6cfb80639,
+    0x7ffffff100000016,
+    0x7ffffffd80000007,
+];
+
+/// `-n^{-1} mod 2^64`, used by Montgomery multiplication.
+const ORDER_NEG_INV: u64 = 0xd78bef72057b7bdf;
+
+/// `R^2 mod n` for the Montgomery radix `R = 2^320`.
+const R_SQUARED: [u64; NUM_LIMBS] = [
+    0xa01001dce33dc739,
+    0x6c3228d33f62accf,
+    0xd1d796cc91cf8525,
+    0xaadfff5d1574c1d8,
+    0x4aca13b28ca251f5,
+];
+
+/// An integer modulo the prime order `n` of the EcGFp5 group.
+///
+/// Scalars are stored as their canonical representative in `[0, n)`, as little-endian 64-bit
+/// limbs. Addition, subtraction, negation and multiplication run in constant time: they perform
+/// a fixed sequence of limb operations and use masks rather than branches for the conditional
+/// corrections, so they are safe to use on secret scalars. Conversions from and to [`BigUint`] are
+/// not constant time.
+///
+/// As scalars are usually secret, their [`Debug`] output is redacted. Use
+/// [`to_biguint`](Self::to_biguint) to print the value of a public scalar.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct Scalar([u64; NUM_LIMBS]);
+
+impl Debug for Scalar {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Scalar").field(&"<redacted>").finish()
+    }
+}
+
+impl Scalar {
+    /// The scalar `0`.
+    pub const ZERO: Self = Self([0; NUM_LIMBS]);
+
+    /// The number of bits of the order `n`, which bounds the bit length of every scalar.
+    pub const BITS: usize = 319;
+
+    /// The order `n` of the EcGFp5 group, a 319-bit prime.
+    pub fn order() -> BigUint {
+        limbs_to_biguint(&ORDER)
+    }
+
+    /// Create a scalar from an integer, reducing it modulo `n`.
+    pub fn new(value: BigUint) -> Self {
+        let reduced = value % Self::order();
+        let mut limbs = [0; NUM_LIMBS];
+        limbs
+            .iter_mut()
+            .zip(reduced.iter_u64_digits())
+            .for_each(|(limb, digit)| *limb = digit);
+        Self(limbs)
+    }
+
+    /// The canonical representative of this scalar, in `[0, n)`.
+    pub fn to_biguint(&self) -> BigUint {
+        limbs_to_biguint(&self.0)
+    }
+
+    /// Whether this scalar is zero.
+    pub fn is_zero(&self) -> bool {
+        self.0.iter().fold(0, |acc, &limb| acc | limb) == 0
+    }
+
+    /// Bit `i` of the canonical representative of this scalar, for `i < 320`.
+    pub(crate) const fn bit(&self, i: usize) -> bool {
+        (self.0[i / 64] >> (i % 64)) & 1 == 1
+    }
+
+    /// Sample a scalar uniformly at random.
+    ///
+    /// We reduce 512 random bits modulo `n`, so the bias is negligible. Scalars sampled here are
+    /// used as secret keys and nonces, so `rng` must be cryptographically secure.
+    pub fn random<R: Rng + CryptoRng + ?Sized>(rng: &mut R) -> Self {
+        let digits: [u64; 8] = rng.random();
+        Self::from_digits(&digits)
+    }
+
+    /// Interpret a sequence of Goldilocks elements as the little-endian digits, in base `2^64`, of
+    /// an integer, and reduce it modulo `n`.
+    pub fn from_goldilocks(digits: &[Goldilocks]) -> Self {
+        let digits: Vec<u64> = digits.iter().map(|d| d.as_canonical_u64()).collect();
+        Self::from_digits(&digits)
+    }
+
+    /// Reduce the integer with little-endian digits `digits`, in base `2^64`, modulo `n`, using
+    /// Horner's rule.
+    fn from_digits(digits: &[u64]) -> Self {
+        // `2^64 mod n`, which is simply `2^64` as `n > 2^64`.
+        let radix = Self([0, 1, 0, 0, 0]);
+        digits.iter().rev().fold(Self::ZERO, |acc, &digit| {
+            acc * radix.clone() + Self([digit, 0, 0, 0, 0])
+        })
+    }
+}
+
+fn limbs_to_biguint(limbs: &[u64; NUM_LIMBS]) -> BigUint {
+    BigUint::from_slice(
+        &limbs
+            .iter()
+            .flat_map(|&limb| [limb as u32, (limb >> 32) as u32])
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Compute `a + b` and return the sum along with the final carry.
+#[inline]
+fn add_with_carry(a: &[u64; NUM_LIMBS], b: &[u64; NUM_LIMBS]) -> ([u64; NUM_LIMBS], u64) {
+    let mut carry = 0;
+    let sum = core::array::from_fn(|i| {
+        let (s, c1) = a[i].overflowing_add(b[i]);
+        let (s, c2) = s.overflowing_add(carry);
+        carry = u64::from(c1 | c2);
+        s
+    });
+    (sum, carry)
+}
+
+/// Compute `a - b` and return the difference along with the final borrow.
+#[inline]
+fn sub_with_borrow(a: &[u64; NUM_LIMBS], b: &[u64; NUM_LIMBS]) -> ([u64; NUM_LIMBS], u64) {
+    let mut borrow = 0;
+    let diff = core::array::from_fn(|i| {
+        let (d, b1) = a[i].overflowing_sub(b[i]);
+        let (d, b2) = d.overflowing_sub(borrow);
+        borrow = u64::from(b1 | b2);
+        d
+    });
+    (diff, borrow)
+}
+
+/// Return `a` if `mask` is zero and `b` if `mask` is all ones.
+#[inline]
+fn select(a: &[u64; NUM_LIMBS], b: &[u64; NUM_LIMBS], mask: u64) -> [u64; NUM_LIMBS] {
+    core::array::from_fn(|i| a[i] ^ ((a[i] ^ b[i]) & mask))
+}
+
+/// Montgomery multiplication: compute `a b R^{-1} mod n` for `a, b < n`, with the CIOS method.
+#[inline]
+fn mont_mul(a: &[u64; NUM_LIMBS], b: &[u64; NUM_LIMBS]) -> [u64; NUM_LIMBS] {
+    // `t` has one limb more than `n` plus a top carry. As `n < R / 2`, the result before the final
+    // subtraction is less than `2n < R`.
+    let mut t = [0u64; NUM_LIMBS + 2];
+    for &a_i in a {
+        let mut carry = 0u64;
+        for j in 0..NUM_LIMBS {
+            let s = u128::from(t[j]) + u128::from(a_i) * u128::from(b[j]) + u128::from(carry);
+            t[j] = s as u64;
+            carry = (s >> 64) as u64;
+        }
+        let s = u128::from(t[NUM_LIMBS]) + u128::from(carry);
+        t[NUM_LIMBS] = s as u64;
+        t[NUM_LIMBS + 1] = (s >> 64) as u64;
+
+        let m = t[0].wrapping_mul(ORDER_NEG_INV);
+        let s = u128::from(t[0]) + u128::from(m) * u128::from(ORDER[0]);
+        let mut carry = (s >> 64) as u64;
+        for j in 1..NUM_LIMBS {
+            let s = u128::from(t[j]) + u128::from(m) * u128::from(ORDER[j]) + u128::from(carry);
+            t[j - 1] = s as u64;
+            carry = (s >> 64) as u64;
+        }
+        let s = u128::from(t[NUM_LIMBS]) + u128::from(carry);
+        t[NUM_LIMBS - 1] = s as u64;
+        t[NUM_LIMBS] = t[NUM_LIMBS + 1] + (s >> 64) as u64;
+    }
+
+    let result: [u64; NUM_LIMBS] = core::array::from_fn(|i| t[i]);
+    let (reduced, borrow) = sub_with_borrow(&result, &ORDER);
+    // Keep `result` only if it is less than `n`, i.e. the subtraction borrowed.
+    select(&reduced, &result, ct_mask_u64(borrow == 1))
+}
+
+impl Add for Scalar {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        // Both inputs are less than `n < 2^319`, so the sum fits in five limbs.
+        let (sum, _) = add_with_carry(&self.0, &rhs.0);
+        let (reduced, borrow) = sub_with_borrow(&sum, &ORDER);
+        Self(select(&reduced, &sum, ct_mask_u64(borrow == 1)))
+    }
+}
+
+impl Sub for Scalar {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let (diff, borrow) = sub_with_borrow(&self.0, &rhs.0);
+        // Add `n` back if the subtraction borrowed.
+        let mask = ct_mask_u64(borrow == 1);
+        let (result, _) = add_with_carry(&diff, &select(&[0; NUM_LIMBS], &ORDER, mask));
+        Self(result)
+    }
+}
+
+impl Mul for Scalar {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        // `mont_mul(a, b) = a b / R`, and multiplying by `R^2` in the Montgomery domain restores
+        // the factor of `R`.
+        Self(mont_mul(&mont_mul(&self.0, &rhs.0), &R_SQUARED))
+    }
+}
+
+impl Neg for Scalar {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::ZERO - self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_field::PrimeCharacteristicRing;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    use super::*;
+
+    #[test]
+    fn constants() {
+        let n = Scalar::order();
+        assert_eq!(n.bits(), Scalar::BITS as u64);
+        assert_eq!(ORDER[0].wrapping_mul(ORDER_NEG_INV), u64::MAX);
+        assert_eq!(
+            limbs_to_biguint(&R_SQUARED),
+            (BigUint::from(1u8) << 640) % &n
+        );
+    }
+
+    #[test]
+    fn arithmetic_mod_n() {
+        let n = Scalar::order();
+        let minus_one = Scalar::new(&n - 1u32);
+        let one = Scalar::new(BigUint::from(1u8));
+        assert_eq!(Scalar::new(n), Scalar::ZERO);
+        assert_eq!(-one.clone(), minus_one);
+        assert_eq!(minus_one.clone() + one.clone(), Scalar::ZERO);
+        assert_eq!(Scalar::ZERO - one.clone(), minus_one);
+        assert_eq!(minus_one.clone() * minus_one, one);
+        assert_eq!(-Scalar::ZERO, Scalar::ZERO);
+    }
+
+    #[test]
+    fn matches_biguint_arithmetic() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let n = Scalar::order();
+        for _ in 0..100 {
+            let a = Scalar::from_digits(&rng.random::<[u64; 8]>());
+            let b = Scalar::from_digits(&rng.random::<[u64; 8]>());
+            let (x, y) = (a.to_biguint(), b.to_biguint());
+            assert!(x < n && y < n);
+            assert_eq!((a.clone() + b.clone()).to_biguint(), (&x + &y) % &n);
+            assert_eq!((a.clone() - b.clone()).to_biguint(), (&x + &n - &y) % &n);
+            assert_eq!((a.clone() * b).to_biguint(), (&x * &y) % &n);
+            assert_eq!(Scalar::new(x), a);
+        }
+    }
+
+    #[test]
+    fn from_goldilocks_digits() {
+        let digits = [Goldilocks::from_u64(5), Goldilocks::from_u64(7)];
+        let expected = (BigUint::from(7u8) << 64) + 5u32;
+        assert_eq!(Scalar::from_goldilocks(&digits), Scalar::new(expected));
+
+        let digits = [Goldilocks::NEG_ONE; 8];
+        let expected: BigUint = (0..8)
+            .map(|i| BigUint::from(Goldilocks::ORDER_U64 - 1) << (64 * i))
+            .sum();
+        assert_eq!(Scalar::from_goldilocks(&digits), Scalar::new(expected));
+    }
+}
@@ -0,0 +1,246 @@
+use core::fmt::{Debug, Display, Formatter};
+
+use p3_field::BasedVectorSpace;
+use p3_goldilocks::Goldilocks;
+use p3_symmetric::CryptographicHasher;
+use rand::{CryptoRng, Rng};
+
+use crate::{GFp5, Point, Scalar};
+
+/// A Schnorr signing key: a secret scalar together with the corresponding public point.
+#[derive(Clone)]
+pub struct SigningKey {
+    secret: Scalar,
+    public: Point,
+}
+
+impl Debug for SigningKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SigningKey")
+            .field("secret", &"<redacted>")
+            .field("public", &self.public)
+            .finish()
+    }
+}
+
+/// A Schnorr verifying key, i.e. a public point of the EcGFp5 group.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VerifyingKey {
+    point: Point,
+}
+
+/// A Schnorr signature `(r, s)`, where `r` is the encoding of the commitment point.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Signature {
+    pub r: GFp5,
+    pub s: Scalar,
+}
+
+impl Debug for Signature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        // Unlike most scalars, `s` is public.
+        f.debug_struct("Signature")
+            .field("r", &self.r)
+            .field("s", &self.s.to_biguint())
+            .finish()
+    }
+}
+
+/// The error returned when a signature or a key fails to verify.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SignatureError;
+
+impl Display for SignatureError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("invalid signature")
+    }
+}
+
+impl core::error::Error for SignatureError {}
+
+/// Compute the Fiat-Shamir challenge `e = H(r || pk || msg)` as a scalar.
+fn challenge<H, const OUT: usize>(hasher: &H, r: GFp5, pk: GFp5, msg: &[Goldilocks]) -> Scalar
+where
+    H: CryptographicHasher<Goldilocks, [Goldilocks; OUT]>,
+{
+    let digest = hasher.hash_iter_slices([
+        r.as_basis_coefficients_slice(),
+        pk.as_basis_coefficients_slice(),
+        msg,
+    ]);
+    Scalar::from_goldilocks(&digest)
+}
+
+impl SigningKey {
+    /// Create a signing key from a secret scalar.
+    ///
+    /// Returns `None` if the secret is zero, as the public key would then be the neutral point.
+    pub fn new(secret: Scalar) -> Option<Self> {
+        if secret.is_zero() {
+            return None;
+        }
+        let public = Point::generator() * &secret;
+        Some(Self { secret, public })
+    }
+
+    /// Sample a uniformly random signing key from a cryptographically secure generator.
+    pub fn random<R: Rng + CryptoRng + ?Sized>(rng: &mut R) -> Self {
+        loop {
+            if let Some(key) = Self::new(Scalar::random(rng)) {
+                return key;
+            }
+        }
+    }
+
+    /// The verifying key matching this signing key.
+    pub const fn verifying_key(&self) -> VerifyingKey {
+        VerifyingKey { point: self.public }
+    }
+
+    /// Sign a message, given as a sequence of Goldilocks elements.
+    ///
+    /// The nonce `k` is sampled from `rng`, which must therefore be a cryptographically secure
+    /// source of randomness: reusing or leaking a nonce reveals the secret key.
+    pub fn sign<H, R, const OUT: usize>(
+        &self,
+        hasher: &H,
+        msg: &[Goldilocks],
+        rng: &mut R,
+    ) -> Signature
+    where
+        H: CryptographicHasher<Goldilocks, [Goldilocks; OUT]>,
+        R: Rng + CryptoRng + ?Sized,
+    {
+        let k = Scalar::random(rng);
+        let r = (Point::generator() * &k).encode();
+        let e = challenge(hasher, r, self.public.encode(), msg);
+        Signature {
+            r,
+            s: k + e * self.secret.clone(),
+        }
+    }
+}
+
+impl VerifyingKey {
+    /// The encoding of the public point, see [`Point::encode`].
+    pub fn encode(&self) -> GFp5 {
+        self.point.encode()
+    }
+
+    /// Decode a verifying key, rejecting invalid encodings and the neutral point.
+    pub fn from_encoding(w: GFp5) -> Result<Self, SignatureError> {
+        match Point::decode(w) {
+            Some(point) if !point.is_neutral() => Ok(Self { point }),
+            _ => Err(SignatureError),
+        }
+    }
+
+    /// The public point of this key, which is never the neutral point.
+    pub const fn point(&self) -> Point {
+        self.point
+    }
+
+    /// Verify a signature on a message, checking that `encode(s G - e PK) = r`.
+    pub fn verify<H, const OUT: usize>(
+        &self,
+        hasher: &H,
+        msg: &[Goldilocks],
+        signature: &Signature,
+    ) -> Result<(), SignatureError>
+    where
+        H: CryptographicHasher<Goldilocks, [Goldilocks; OUT]>,
+    {
+        let e = challenge(hasher, signature.r, self.encode(), msg);
+        let commitment = Point::generator() * &signature.s - self.point * &e;
+        if commitment.encode() == signature.r {
+            Ok(())
+        } else {
+            Err(SignatureError)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::string::ToString;
+
+    use p3_field::PrimeCharacteristicRing;
+    use p3_goldilocks::Poseidon2Goldilocks;
+    use p3_symmetric::PaddingFreeSponge;
+
+    use super::*;
+    use crate::test_rng::TestRng;
+
+    type Hash = PaddingFreeSponge<Poseidon2Goldilocks<8>, 8, 4, 4>;
+
+    fn setup() -> (Hash, TestRng) {
+        let mut rng = TestRng::seed_from_u64(1);
+        let hasher = Hash::new(Poseidon2Goldilocks::<8>::new_from_rng_128(&mut rng));
+        (hasher, rng)
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let (hasher, mut rng) = setup();
+        let key = SigningKey::random(&mut rng);
+        let vk = key.verifying_key();
+        let msg = [1, 2, 3, 4, 5, 6, 7].map(Goldilocks::from_u64);
+
+        let signature = key.sign(&hasher, &msg, &mut rng);
+        assert_eq!(vk.verify(&hasher, &msg, &signature), Ok(()));
+
+        let decoded = VerifyingKey::from_encoding(vk.encode()).unwrap();
+        assert_eq!(decoded, vk);
+        assert_eq!(decoded.verify(&hasher, &msg, &signature), Ok(()));
+    }
+
+    #[test]
+    fn reject_invalid_signatures() {
+        let (hasher, mut rng) = setup();
+        let key = SigningKey::random(&mut rng);
+        let vk = key.verifying_key();
+        let msg = [1, 2, 3].map(Goldilocks::from_u64);
+        let signature = key.sign(&hasher, &msg, &mut rng);
+
+        let other_msg = [1, 2, 4].map(Goldilocks::from_u64);
+        assert_eq!(
+            vk.verify(&hasher, &other_msg, &signature),
+            Err(SignatureError)
+        );
+
+        let other_vk = SigningKey::random(&mut rng).verifying_key();
+        assert_eq!(
+            other_vk.verify(&hasher, &msg, &signature),
+            Err(SignatureError)
+        );
+
+        let bad_s = Signature {
+            s: signature.s.clone() + Scalar::new(1u8.into()),
+            ..signature.clone()
+        };
+        assert_eq!(vk.verify(&hasher, &msg, &bad_s), Err(SignatureError));
+
+        let bad_r = Signature {
+            r: signature.r + GFp5::ONE,
+            ..signature
+        };
+        assert_eq!(vk.verify(&hasher, &msg, &bad_r), Err(SignatureError));
+    }
+
+    #[test]
+    fn debug_redacts_secret() {
+        let (_, mut rng) = setup();
+        let key = SigningKey::random(&mut rng);
+        let debug = format!("{key:?}");
+        assert!(debug.contains("<redacted>"));
+        assert!(!debug.contains(&key.secret.to_biguint().to_string()));
+        assert_eq!(format!("{:?}", key.secret), "Scalar(\"<redacted>\")");
+    }
+
+    #[test]
+    fn reject_invalid_keys() {
+        assert!(SigningKey::new(Scalar::ZERO).is_none());
+        assert_eq!(VerifyingKey::from_encoding(GFp5::ZERO), Err(SignatureError));
+    }
+}
@@ -0,0 +1,241 @@
+//! Correctness and dudect-style timing tests for [`ConstantTimeField`] implementations.
+//!
+//! The timing tests follow the approach of [dudect](https://eprint.iacr.org/2016/1123.pdf):
+//! inputs are split into two classes, a fixed class (typically a special value such as `0`)
+//! and a uniformly random class. Measurements from both classes are interleaved in a random
+//! order and Welch's t-test is applied to the resulting timing distributions. If the operation
+//! runs in constant time, the two distributions are indistinguishable and `|t|` stays small.
+//!
+//! Timing results are only meaningful for optimised builds on an otherwise idle machine, so the
+//! timing tests generated by [`test_constant_time_field!`] are ignored by default. Run them with
+//! `cargo test --release -- --ignored constant_time_timing`.
+
+extern crate std;
+
+use alloc::vec::Vec;
+use core::hint::black_box;
+use std::time::Instant;
+
+use p3_field::ConstantTimeField;
+use rand::distr::{Distribution, StandardUniform};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+/// Number of timing measurements taken per dudect test.
+pub const DUDECT_NUM_MEASUREMENTS: usize = 20_000;
+
+/// Number of times the operation is repeated within a single timing measurement.
+///
+/// A single field addition takes around a nanosecond in release builds, while the system clock
+/// resolves a few tens of nanoseconds at best. Each measurement therefore times a batch of calls on
+/// the same input, long enough that even the cheapest operation takes well over a microsecond and
+/// clock quantisation is negligible next to the timing differences being measured.
+pub const DUDECT_BATCH_SIZE: usize = 1024;
+
+/// Threshold on `|t|` above which a timing leak is reported.
+///
+/// dudect flags `|t| > 4.5` as a probable leak and `|t| > 10` as a definite one. As these tests
+/// may run on shared machines we use the conservative threshold to avoid spurious failures.
+/// Genuinely data-dependent code (such as an early exit on zero) produces values orders of
+/// magnitude larger.
+pub const DUDECT_T_THRESHOLD: f64 = 10.0;
+
+/// Compute Welch's t-statistic for two samples.
+///
+/// Returns `0` if either sample has fewer than two elements or both samples have zero variance.
+pub fn welch_t_statistic(a: &[f64], b: &[f64]) -> f64 {
+    if a.len() < 2 || b.len() < 2 {
+        return 0.0;
+    }
+
+    let mean_var = |xs: &[f64]| {
+        let n = xs.len() as f64;
+        let mean = xs.iter().sum::<f64>() / n;
+        let var = xs.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / (n - 1.0);
+        (mean, var / n)
+    };
+    let (mean_a, scaled_var_a) = mean_var(a);
+    let (mean_b, scaled_var_b) = mean_var(b);
+
+    let denominator = (scaled_var_a + scaled_var_b).sqrt();
+    if denominator == 0.0 {
+        0.0
+    } else {
+        (mean_a - mean_b) / denominator
+    }
+}
+
+/// Run a dudect-style timing test on `op` and return the largest `|t|` observed.
+///
+/// Each measurement uses either `fixed` or a fresh output of `random` (chosen uniformly at random)
+/// as the input to `op`. All inputs are generated before any timing takes place. Following dudect,
+/// the t-statistic is computed both on the raw measurements and after discarding measurements
+/// above a range of percentiles, which removes outliers caused by interrupts and context switches.
+pub fn dudect_max_t_statistic<I: Copy, O>(
+    fixed: I,
+    mut random: impl FnMut(&mut SmallRng) -> I,
+    op: impl Fn(I) -> O,
+) -> f64 {
+    let mut rng = SmallRng::seed_from_u64(1);
+    let inputs: Vec<(bool, I)> = (0..DUDECT_NUM_MEASUREMENTS)
+        .map(|_| {
+            if rng.random() {
+                (true, fixed)
+            } else {
+                (false, random(&mut rng))
+            }
+        })
+        .collect();
+
+    // Warm up caches and branch predictors so the first measurements are not outliers.
+    for &(_, input) in inputs.iter().take(DUDECT_NUM_MEASUREMENTS / 10) {
+        black_box(op(black_box(input)));
+    }
+
+    let timings: Vec<(bool, f64)> = inputs
+        .iter()
+        .map(|&(is_fixed, input)| {
+            let start = Instant::now();
+            for _ in 0..DUDECT_BATCH_SIZE {
+                black_box(op(black_box(input)));
+            }
+            (is_fixed, start.elapsed().as_nanos() as f64)
+        })
+        .collect();
+
+    let mut sorted: Vec<f64> = timings.iter().map(|&(_, t)| t).collect();
+    sorted.sort_by(f64::total_cmp);
+
+    [100, 99, 95, 90, 80, 50]
+        .into_iter()
+        .map(|percentile| {
+            let cutoff = sorted[(sorted.len() - 1) * percentile / 100];
+            let (fixed_class, random_class): (Vec<_>, Vec<_>) = timings
+                .iter()
+                .filter(|&&(_, t)| t <= cutoff)
+                .partition(|&&(f, _)| f);
+            let fixed_class: Vec<f64> = fixed_class.into_iter().map(|(_, t)| t).collect();
+            let random_class: Vec<f64> = random_class.into_iter().map(|(_, t)| t).collect();
+            welch_t_statistic(&fixed_class, &random_class).abs()
+        })
+        .fold(0.0, f64::max)
+}
+
+/// Assert that `op` passes a dudect-style timing test. See [`dudect_max_t_statistic`].
+pub fn assert_constant_time<I: Copy, O>(
+    name: &str,
+    fixed: I,
+    random: impl FnMut(&mut SmallRng) -> I,
+    op: impl Fn(I) -> O,
+) {
+    let t = dudect_max_t_statistic(fixed, random, op);
+    assert!(
+        t < DUDECT_T_THRESHOLD,
+        "Timing leak detected in {name}: |t| = {t:.2} exceeds {DUDECT_T_THRESHOLD}"
+    );
+}
+
+/// Check that the constant-time operations agree with the standard field operations.
+///
+/// `zeros` and `ones` should contain the different representatives of `F::ZERO` and `F::ONE`.
+pub fn test_constant_time_arithmetic<F: ConstantTimeField>(zeros: &[F], ones: &[F])
+where
+    StandardUniform: Distribution<F>,
+{
+    let mut rng = SmallRng::seed_from_u64(1);
+    let special = [F::ZERO, F::ONE, F::TWO, F::NEG_ONE, F::NEG_ONE.halve()];
+    let elements: Vec<F> = special
+        .into_iter()
+        .chain(zeros.iter().copied())
+        .chain(ones.iter().copied())
+        .chain((0..32).map(|_| rng.random()))
+        .collect();
+
+    for &x in &elements {
+        assert_eq!(x.ct_neg(), -x, "Error in ct_neg for {x}");
+        assert_eq!(x.ct_double(), x.double(), "Error in ct_double for {x}");
+        assert_eq!(x.ct_square(), x.square(), "Error in ct_square for {x}");
+        assert_eq!(
+            x.ct_inverse(),
+            x.try_inverse().unwrap_or(F::ZERO),
+            "Error in ct_inverse for {x}"
+        );
+
+        for &y in &elements {
+            assert_eq!(x.ct_add(&y), x + y, "Error in ct_add for {x}, {y}");
+            assert_eq!(x.ct_sub(&y), x - y, "Error in ct_sub for {x}, {y}");
+            assert_eq!(x.ct_mul(&y), x * y, "Error in ct_mul for {x}, {y}");
+            assert_eq!(x.ct_select(&y, false), x, "Error in ct_select for {x}, {y}");
+            assert_eq!(x.ct_select(&y, true), y, "Error in ct_select for {x}, {y}");
+        }
+    }
+
+    for power in [0, 1, 2, 3, 7, F::ORDER_U64 - 1, F::ORDER_U64, u64::MAX] {
+        for &x in &elements {
+            assert_eq!(
+                x.ct_exp_u64(power),
+                x.exp_u64(power),
+                "Error in ct_exp_u64 for {x}^{power}"
+            );
+        }
+    }
+    for _ in 0..16 {
+        let x: F = rng.random();
+        let power = rng.random::<u64>();
+        assert_eq!(x.ct_exp_u64(power), x.exp_u64(power));
+    }
+}
+
+/// Run dudect-style timing tests on every constant-time operation.
+///
+/// For each operation the fixed class uses `0` (or `-1` where `0` is not the interesting edge
+/// case) and the random class uses uniformly random field elements.
+pub fn test_constant_time_timing<F: ConstantTimeField>()
+where
+    StandardUniform: Distribution<F>,
+{
+    let pair = |rng: &mut SmallRng| (rng.random::<F>(), rng.random::<F>());
+
+    assert_constant_time("ct_add", (F::NEG_ONE, F::NEG_ONE), pair, |(x, y)| {
+        x.ct_add(&y)
+    });
+    assert_constant_time("ct_sub", (F::ZERO, F::NEG_ONE), pair, |(x, y)| x.ct_sub(&y));
+    assert_constant_time("ct_mul", (F::ZERO, F::ZERO), pair, |(x, y)| x.ct_mul(&y));
+    assert_constant_time("ct_neg", F::ZERO, |rng| rng.random::<F>(), |x| x.ct_neg());
+    assert_constant_time(
+        "ct_select",
+        (F::ZERO, F::ONE, false),
+        |rng| (rng.random::<F>(), rng.random::<F>(), rng.random::<bool>()),
+        |(x, y, choice)| x.ct_select(&y, choice),
+    );
+    assert_constant_time(
+        "ct_exp_u64",
+        (F::TWO, 0),
+        |rng| (rng.random::<F>(), rng.random::<u64>()),
+        |(x, power)| x.ct_exp_u64(power),
+    );
+    assert_constant_time(
+        "ct_inverse",
+        F::ZERO,
+        |rng| rng.random::<F>(),
+        |x| x.ct_inverse(),
+    );
+}
+
+#[macro_export]
+macro_rules! test_constant_time_field {
+    ($field:ty, $zeros: expr, $ones: expr) => {
+        mod constant_time_tests {
+            #[test]
+            fn test_constant_time_arithmetic() {
+                $crate::test_constant_time_arithmetic::<$field>($zeros, $ones);
+            }
+
+            #[test]
+            #[ignore = "timing measurements need a release build on an idle machine"]
+            fn test_constant_time_timing() {
+                $crate::test_constant_time_timing::<$field>();
+            }
+        }
+    };
+}
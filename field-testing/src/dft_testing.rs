@@ -105,9 +105,11 @@ where
         let h = 1 << log_h;
         let mat = RowMajorMatrix::<F>::rand(&mut rng, h, 3);
         let shift = F::GENERATOR;
-        let coset_lde_naive = NaiveDft.coset_lde_batch(mat.clone(), 1, shift);
-        let coset_lde_result = dft.coset_lde_batch(mat, 1, shift);
-        assert_eq!(coset_lde_naive, coset_lde_result.to_row_major_matrix());
+        for added_bits in 0..4 {
+            let coset_lde_naive = NaiveDft.coset_lde_batch(mat.clone(), added_bits, shift);
+            let coset_lde_result = dft.coset_lde_batch(mat.clone(), added_bits, shift);
+            assert_eq!(coset_lde_naive, coset_lde_result.to_row_major_matrix());
+        }
     }
 }
 
@@ -1,5 +1,7 @@
 use alloc::vec::Vec;
+use core::array;
 
+use p3_field::extension::BinomiallyExtendable;
 use p3_field::{ExtensionField, Field, PackedFieldExtension, PackedValue};
 use rand::distr::{Distribution, StandardUniform};
 use rand::rngs::SmallRng;
@@ -122,3 +124,36 @@ where
         EF::ExtensionPacking::to_ext_iter(packed_powers_capped).collect();
     assert_eq!(base_powers, unpacked_powers);
 }
+
+/// Check that the `BinomiallyExtendableAlgebra` arithmetic of `F` agrees with the schoolbook
+/// arithmetic in `F[X]/(X^D - W)`.
+///
+/// This is meant for fields which override the default arithmetic, e.g. with packed helpers.
+pub fn test_binomial_arithmetic<F, const D: usize>()
+where
+    F: BinomiallyExtendable<D>,
+    StandardUniform: Distribution<F>,
+{
+    let mut rng = SmallRng::seed_from_u64(1);
+    for _ in 0..100 {
+        let a: [F; D] = array::from_fn(|_| rng.random());
+        let b: [F; D] = array::from_fn(|_| rng.random());
+
+        let mut expected = [F::ZERO; D];
+        for (i, &a_i) in a.iter().enumerate() {
+            for (j, &b_j) in b.iter().enumerate() {
+                if i + j < D {
+                    expected[i + j] += a_i * b_j;
+                } else {
+                    expected[i + j - D] += F::W * a_i * b_j;
+                }
+            }
+        }
+        let mut res = [F::ZERO; D];
+        F::binomial_mul(&a, &b, &mut res, F::W);
+        assert_eq!(res, expected);
+
+        assert_eq!(F::binomial_add(&a, &b), array::from_fn(|i| a[i] + b[i]));
+        assert_eq!(F::binomial_sub(&a, &b), array::from_fn(|i| a[i] - b[i]));
+    }
+}
@@ -5,6 +5,7 @@
 extern crate alloc;
 
 pub mod bench_func;
+pub mod constant_time_testing;
 pub mod dft_testing;
 pub mod extension_testing;
 pub mod from_integer_tests;
@@ -15,13 +16,15 @@ use core::array;
 use core::iter::successors;
 
 pub use bench_func::*;
+pub use constant_time_testing::*;
 pub use dft_testing::*;
 pub use extension_testing::*;
 use num_bigint::BigUint;
 use p3_field::coset::TwoAdicMultiplicativeCoset;
+use p3_field::exponentiation::exp_biguint;
 use p3_field::{
-    ExtensionField, Field, PackedValue, PrimeCharacteristicRing, PrimeField32, PrimeField64,
-    TwoAdicField,
+    ExtensionField, Field, LegendreSymbol, PackedValue, PrimeCharacteristicRing, PrimeField32,
+    PrimeField64, TwoAdicField,
 };
 use p3_util::iter_array_chunks_padded;
 pub use packedfield_testing::*;
@@ -324,6 +327,41 @@ where
     }
 }
 
+pub fn test_sqrt<F: Field>()
+where
+    StandardUniform: Distribution<F>,
+{
+    assert_eq!(F::ZERO.sqrt(), Some(F::ZERO));
+    assert_eq!(F::ZERO.legendre_symbol(), LegendreSymbol::Zero);
+    assert_eq!(F::ONE.legendre_symbol(), LegendreSymbol::QuadraticResidue);
+    assert_eq!(F::ONE.sqrt().unwrap().square(), F::ONE);
+
+    // A generator of the multiplicative group is never a square in a field of odd order.
+    assert_eq!(
+        F::GENERATOR.legendre_symbol(),
+        LegendreSymbol::QuadraticNonResidue
+    );
+    assert_eq!(F::GENERATOR.sqrt(), None);
+
+    let mut rng = SmallRng::seed_from_u64(1);
+    for _ in 0..100 {
+        let x = rng.random::<F>();
+        let square = x.square();
+        assert!(square.is_square());
+        let root = square.sqrt().unwrap();
+        assert!(root == x || root == -x);
+
+        // Roughly half of the elements are squares, and exactly those have a square root.
+        match x.sqrt() {
+            Some(root) => {
+                assert!(x.is_square());
+                assert_eq!(root.square(), x);
+            }
+            None => assert!(!x.is_square()),
+        }
+    }
+}
+
 pub fn test_dot_product<R: PrimeCharacteristicRing + Eq + Copy>(u: &[R; 64], v: &[R; 64]) {
     let mut dot = R::ZERO;
     assert_eq!(
@@ -587,25 +625,6 @@ pub fn test_powers_collect<F: Field>() {
     }
 }
 
-/// A function which extends the `exp_u64` code to handle `BigUints`.
-///
-/// This solution is slow (particularly when dealing with extension fields
-/// which should really be making use of the frobenius map) but should be
-/// fast enough for testing purposes.
-pub(crate) fn exp_biguint<F: Field>(x: F, exponent: &BigUint) -> F {
-    let digits = exponent.to_u64_digits();
-    let size = digits.len();
-
-    let mut power = F::ONE;
-
-    let bases = (0..size).map(|i| x.exp_power_of_2(64 * i));
-    digits
-        .iter()
-        .zip(bases)
-        .for_each(|(digit, base)| power *= base.exp_u64(*digit));
-    power
-}
-
 /// Given a list of the factors of the multiplicative group of a field, check
 /// that the defined generator is actually a generator of that group.
 pub fn test_generator<F: Field>(multiplicative_group_factors: &[(BigUint, u32)]) {
@@ -824,6 +843,10 @@ macro_rules! test_field {
                 $crate::test_generator::<$field>($factors);
             }
             #[test]
+            fn test_sqrt() {
+                $crate::test_sqrt::<$field>();
+            }
+            #[test]
             fn test_streaming() {
                 $crate::test_into_stream::<$field>();
             }
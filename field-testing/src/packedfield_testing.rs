@@ -1,7 +1,10 @@
 use alloc::vec;
 use alloc::vec::Vec;
 
-use p3_field::{Field, PackedField, PackedFieldPow2, PackedValue, PrimeCharacteristicRing};
+use p3_field::{
+    Field, PackedField, PackedFieldPow2, PackedValue, PrimeCharacteristicRing,
+    batch_multiplicative_inverse_packed,
+};
 use rand::distr::{Distribution, StandardUniform};
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
@@ -294,6 +297,44 @@ where
         PF::ONE,
         "Error when testing multiplication by inverse."
     );
+    assert_eq!(
+        vec.packed_inverse(),
+        vec_inv,
+        "Error when testing packed inverse consistency with scalar inverse."
+    );
+
+    let vec1: PF = packed_from_random(0x6f8c3bbf3e9e1a4d);
+    let arr1 = vec1.as_slice();
+    let vec_div = vec1 / vec;
+    let mut vec_div_assign = vec1;
+    vec_div_assign /= vec;
+    for i in 0..PF::WIDTH {
+        assert_eq!(
+            vec_div.as_slice()[i],
+            arr1[i] / arr[i],
+            "Error when testing div consistency of packed and scalar at location {i}.",
+        );
+    }
+    assert_eq!(
+        vec_div, vec_div_assign,
+        "Error when testing div_assign consistency with div."
+    );
+}
+
+pub fn test_batch_multiplicative_inverse_packed<PF>()
+where
+    PF: PackedField + Eq,
+    StandardUniform: Distribution<PF::Scalar>,
+{
+    let vecs: Vec<PF> = (0..37).map(|i| packed_from_random(0x4a1f + i)).collect();
+    let invs = batch_multiplicative_inverse_packed(&vecs);
+    for (vec, inv) in vecs.iter().zip(invs) {
+        assert_eq!(
+            inv,
+            vec.packed_inverse(),
+            "Error when testing packed batch inverse consistency with packed inverse."
+        );
+    }
 }
 
 #[macro_export]
@@ -320,6 +361,10 @@ macro_rules! test_packed_field {
             fn test_multiplicative_inverse() {
                 $crate::test_multiplicative_inverse::<$packedfield>();
             }
+            #[test]
+            fn test_batch_multiplicative_inverse_packed() {
+                $crate::test_batch_multiplicative_inverse_packed::<$packedfield>();
+            }
         }
     };
 }
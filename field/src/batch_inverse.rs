@@ -4,7 +4,10 @@ use p3_maybe_rayon::prelude::*;
 use tracing::instrument;
 
 use crate::field::Field;
-use crate::{FieldArray, PackedValue, PrimeCharacteristicRing};
+use crate::{
+    ExtensionField, FieldArray, PackedField, PackedFieldExtension, PackedValue,
+    PrimeCharacteristicRing,
+};
 
 /// Batch multiplicative inverses with Montgomery's trick
 /// This is Montgomery's trick. At a high level, we invert the product of the given field
@@ -34,6 +37,59 @@ pub fn batch_multiplicative_inverse<F: Field>(x: &[F]) -> Vec<F> {
     result
 }
 
+/// Batch multiplicative inverses of packed field elements with Montgomery's trick.
+///
+/// This inverts every element of every packed vector. The cumulative products are computed
+/// on packed vectors and the single inversion of the final product uses `PackedField::packed_inverse`
+/// so the inputs never need to be unpacked.
+///
+/// # Panics
+/// This will panic if any of the inputs is zero.
+#[instrument(level = "debug", skip_all)]
+#[must_use]
+pub fn batch_multiplicative_inverse_packed<P: PackedField>(x: &[P]) -> Vec<P> {
+    // How many packed elements to invert in one thread.
+    const CHUNK_SIZE: usize = 256;
+
+    let n = x.len();
+    let mut result = P::zero_vec(n);
+
+    x.par_chunks(CHUNK_SIZE)
+        .zip(result.par_chunks_mut(CHUNK_SIZE))
+        .for_each(|(x, result)| {
+            batch_multiplicative_inverse_general(x, result, |x| x.packed_inverse());
+        });
+
+    result
+}
+
+/// Batch multiplicative inverses of packed extension field elements with Montgomery's trick.
+///
+/// The extension field analogue of `batch_multiplicative_inverse_packed`, using
+/// `PackedFieldExtension::packed_ext_inverse` to invert the final product.
+///
+/// # Panics
+/// This will panic if any of the inputs is zero.
+#[instrument(level = "debug", skip_all)]
+#[must_use]
+pub fn batch_multiplicative_inverse_packed_ext<F: Field, EF: ExtensionField<F>>(
+    x: &[EF::ExtensionPacking],
+) -> Vec<EF::ExtensionPacking> {
+    // How many packed elements to invert in one thread.
+    const CHUNK_SIZE: usize = 256;
+
+    let n = x.len();
+    let mut result = EF::ExtensionPacking::zero_vec(n);
+
+    x.par_chunks(CHUNK_SIZE)
+        .zip(result.par_chunks_mut(CHUNK_SIZE))
+        .for_each(|(x, result)| {
+            batch_multiplicative_inverse_general(x, result, |x| x.packed_ext_inverse());
+        });
+
+    result
+}
+
 /// Like `batch_multiplicative_inverse`, but writes the result to the given output buffer.
 fn batch_multiplicative_inverse_helper<F: Field>(x: &[F], result: &mut [F]) {
     // Higher WIDTH increases instruction-level parallelism, but too high a value will cause us
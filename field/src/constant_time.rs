@@ -0,0 +1,106 @@
+//! A constant-time arithmetic API for prime fields.
+//!
+//! The standard arithmetic implementations (`Add`, `Sub`, `Mul`, `Field::try_inverse`, ...) are
+//! optimised for throughput and are free to branch on the values of their inputs. For example
+//! conditional subtractions in `add`/`sub` are often written with an `if`, `try_inverse` returns
+//! early on zero and runs a binary GCD whose control flow depends on the input, and `exp_u64` only
+//! multiplies for the set bits of the exponent.
+//!
+//! When field elements are secret and the prover runs on shared hardware, these data-dependent
+//! timings can leak information. The [`ConstantTimeField`] trait provides a parallel API whose
+//! running time is independent of the values of the field elements (and, for
+//! [`ConstantTimeField::ct_exp_u64`], of the exponent).
+//!
+//! Implementations only use fixed sequences of arithmetic and bitwise operations, with conditional
+//! corrections computed via masks instead of branches. The masks produced from a `bool` are passed
+//! through [`core::hint::black_box`] to discourage the compiler from turning them back into
+//! branches. As with any constant-time code in Rust this is a best effort: it should be paired with
+//! the dudect-style timing tests in `p3-field-testing`.
+
+use core::hint::black_box;
+
+use crate::PrimeField64;
+
+/// Convert a `bool` into a `u32` mask which is `0xFFFFFFFF` if `choice` is `true` and `0`
+/// otherwise.
+#[inline(always)]
+#[must_use]
+pub fn ct_mask_u32(choice: bool) -> u32 {
+    black_box(choice as u32).wrapping_neg()
+}
+
+/// Convert a `bool` into a `u64` mask which is `0xFFFFFFFFFFFFFFFF` if `choice` is `true` and
+/// `0` otherwise.
+#[inline(always)]
+#[must_use]
+pub fn ct_mask_u64(choice: bool) -> u64 {
+    black_box(choice as u64).wrapping_neg()
+}
+
+/// A prime field which supports constant-time scalar arithmetic.
+///
+/// Every method in this trait must run in time independent of the values of its inputs. The
+/// results must agree with the corresponding standard operations.
+pub trait ConstantTimeField: PrimeField64 {
+    /// Constant-time version of `self + rhs`.
+    #[must_use]
+    fn ct_add(&self, rhs: &Self) -> Self;
+
+    /// Constant-time version of `self - rhs`.
+    #[must_use]
+    fn ct_sub(&self, rhs: &Self) -> Self;
+
+    /// Constant-time version of `-self`.
+    #[must_use]
+    fn ct_neg(&self) -> Self;
+
+    /// Constant-time version of `self * rhs`.
+    #[must_use]
+    fn ct_mul(&self, rhs: &Self) -> Self;
+
+    /// Return `rhs` if `choice` is `true` and `self` otherwise, without branching on `choice`.
+    #[must_use]
+    fn ct_select(&self, rhs: &Self, choice: bool) -> Self;
+
+    /// Constant-time version of `self.double()`.
+    #[must_use]
+    #[inline]
+    fn ct_double(&self) -> Self {
+        self.ct_add(self)
+    }
+
+    /// Constant-time version of `self.square()`.
+    #[must_use]
+    #[inline]
+    fn ct_square(&self) -> Self {
+        self.ct_mul(self)
+    }
+
+    /// Constant-time exponentiation by a `u64` power.
+    ///
+    /// Unlike `exp_u64`, this always performs `64` squarings and `64` multiplications and selects
+    /// the result of each multiplication using [`ConstantTimeField::ct_select`], so neither the
+    /// base nor the exponent affects the running time.
+    #[must_use]
+    #[inline]
+    fn ct_exp_u64(&self, power: u64) -> Self {
+        let mut product = Self::ONE;
+        for j in (0..u64::BITS).rev() {
+            product = product.ct_square();
+            let multiplied = product.ct_mul(self);
+            product = product.ct_select(&multiplied, (power >> j) & 1 != 0);
+        }
+        product
+    }
+
+    /// Constant-time multiplicative inverse.
+    ///
+    /// This computes `self^{p - 2}` using [`ConstantTimeField::ct_exp_u64`]. In particular, there
+    /// is no early exit on zero: the inverse of `0` is defined to be `0`. Callers which need to
+    /// reject zero should do so outside of any secret-dependent code.
+    #[must_use]
+    #[inline]
+    fn ct_inverse(&self) -> Self {
+        self.ct_exp_u64(Self::ORDER_U64 - 2)
+    }
+}
@@ -1,9 +1,26 @@
+use num_bigint::BigUint;
+
 use crate::PrimeCharacteristicRing;
 
 pub(crate) const fn bits_u64(n: u64) -> usize {
     (64 - n.leading_zeros()) as usize
 }
 
+/// Compute `val^exponent` for an arbitrarily large exponent.
+///
+/// This works through the exponent one 64-bit limb at a time. It is meant for exponents which do
+/// not fit in a `u64`, such as those derived from the order of a large field or extension field.
+#[must_use]
+pub fn exp_biguint<R: PrimeCharacteristicRing>(val: R, exponent: &BigUint) -> R {
+    let mut base = val;
+    let mut power = R::ONE;
+    for digit in exponent.to_u64_digits() {
+        power *= base.exp_u64(digit);
+        base = base.exp_power_of_2(64);
+    }
+    power
+}
+
 /// Compute the exponential `x -> x^1717986917` using a custom addition chain.
 ///
 /// This map computes the fifth root of `x` if `x` is a member of the field `Mersenne31`.
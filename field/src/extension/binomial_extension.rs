@@ -18,8 +18,8 @@ use super::{HasFrobenius, HasTwoAdicBinomialExtension, PackedBinomialExtensionFi
 use crate::extension::{BinomiallyExtendable, BinomiallyExtendableAlgebra};
 use crate::field::Field;
 use crate::{
-    Algebra, BasedVectorSpace, ExtensionField, Packable, PrimeCharacteristicRing,
-    RawDataSerializable, TwoAdicField, field_to_array,
+    Algebra, BasedVectorSpace, ExtensionField, LegendreSymbol, Packable, PackedValue,
+    PrimeCharacteristicRing, RawDataSerializable, TwoAdicField, field_to_array,
 };
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize, PartialOrd, Ord)]
@@ -333,6 +333,15 @@ impl<F: BinomiallyExtendable<D>, const D: usize> Field for BinomialExtensionFiel
         Some(res)
     }
 
+    fn legendre_symbol(&self) -> LegendreSymbol {
+        // Writing `q` for the order of the base field, `(q^D - 1)/2 = (q - 1)/2 * (1 + q + ... + q^{D - 1})`
+        // and `Norm(x) = x^(1 + q + ... + q^{D - 1})`, so `x` is a square exactly when its norm is.
+        let norm = (1..D)
+            .map(|i| self.repeated_frobenius(i))
+            .fold(*self, |acc, conj| acc * conj);
+        norm.value[0].legendre_symbol()
+    }
+
     #[inline]
     fn add_slices(slice_1: &mut [Self], slice_2: &[Self]) {
         // By construction, Self is repr(transparent) over [F; D].
@@ -640,6 +649,81 @@ pub(crate) fn vector_sub<
     array::from_fn(|i| a[i].clone() - b[i].clone())
 }
 
+/// Apply `op` to the packings of `a` and `b`, `F::Packing::WIDTH` coefficients at a time.
+///
+/// The unused lanes of the last packing are set to zero.
+#[inline(always)]
+fn packed_vector_op<F: Field, const D: usize>(
+    a: &[F; D],
+    b: &[F; D],
+    op: impl Fn(F::Packing, F::Packing) -> F::Packing,
+) -> [F; D] {
+    let mut res = [F::ZERO; D];
+    for ((a, b), res) in a
+        .chunks(F::Packing::WIDTH)
+        .zip(b.chunks(F::Packing::WIDTH))
+        .zip(res.chunks_mut(F::Packing::WIDTH))
+    {
+        let pad = |x: &[F]| F::Packing::from_fn(|lane| x.get(lane).copied().unwrap_or(F::ZERO));
+        let out = op(pad(a), pad(b));
+        res.copy_from_slice(&out.as_slice()[..res.len()]);
+    }
+    res
+}
+
+/// Add two vectors element wise, using the packing of `F`.
+///
+/// This is meant for fields whose packing is vectorized, to implement
+/// [`BinomiallyExtendableAlgebra::binomial_add`].
+#[inline]
+pub fn packed_vector_add<F: Field, const D: usize>(a: &[F; D], b: &[F; D]) -> [F; D] {
+    packed_vector_op(a, b, |a, b| a + b)
+}
+
+/// Subtract two vectors element wise, using the packing of `F`.
+///
+/// This is meant for fields whose packing is vectorized, to implement
+/// [`BinomiallyExtendableAlgebra::binomial_sub`].
+#[inline]
+pub fn packed_vector_sub<F: Field, const D: usize>(a: &[F; D], b: &[F; D]) -> [F; D] {
+    packed_vector_op(a, b, |a, b| a - b)
+}
+
+/// Multiply two elements of the binomial extension `F[X]/(X^D - w)`, using the packing of `F`.
+///
+/// The `k`'th coefficient of the product is
+/// `a0*bk + ... + ak*b0 + w(a(k+1)*b(D-1) + ... + a(D-1)*b(k+1))`.
+/// Hence, broadcasting `ai` and placing either `b(k-i)` or `w*b(D+k-i)` in lane `k` of the `i`'th
+/// right hand side, each group of `F::Packing::WIDTH` coefficients is a single `D`-term dot
+/// product. The unused lanes are set to zero.
+///
+/// This is meant for fields whose packing is vectorized, to implement
+/// [`BinomiallyExtendableAlgebra::binomial_mul`].
+#[inline]
+pub fn packed_binomial_mul<F: Field, const D: usize>(
+    a: &[F; D],
+    b: &[F; D],
+    res: &mut [F; D],
+    w: F,
+) {
+    let w_b = b.map(|b_i| b_i * w);
+    let lhs: [F::Packing; D] = a.map(Into::into);
+    for (chunk, res) in res.chunks_mut(F::Packing::WIDTH).enumerate() {
+        let rhs: [F::Packing; D] = array::from_fn(|i| {
+            F::Packing::from_fn(|lane| {
+                let k = chunk * F::Packing::WIDTH + lane;
+                match k {
+                    _ if k >= D => F::ZERO,
+                    _ if k >= i => b[k - i],
+                    _ => w_b[D + k - i],
+                }
+            })
+        });
+        let dot = F::Packing::dot_product(&lhs, &rhs);
+        res.copy_from_slice(&dot.as_slice()[..res.len()]);
+    }
+}
+
 /// Multiply two vectors representing elements in a binomial extension.
 #[inline]
 pub(super) fn binomial_mul<
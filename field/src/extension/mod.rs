@@ -6,12 +6,16 @@ use crate::{Algebra, ExtensionField};
 mod binomial_extension;
 mod complex;
 mod packed_binomial_extension;
+mod packed_polynomial_extension;
+mod polynomial_extension;
 
 use alloc::vec::Vec;
 
 pub use binomial_extension::*;
 pub use complex::*;
 pub use packed_binomial_extension::*;
+pub use packed_polynomial_extension::*;
+pub use polynomial_extension::*;
 
 /// Trait for fields that support binomial extension of the form `F[X]/(X^D - W)`.
 ///
@@ -130,3 +134,36 @@ pub trait HasTwoAdicBinomialExtension<const D: usize>: BinomiallyExtendable<D> {
     #[must_use]
     fn ext_two_adic_generator(bits: usize) -> [Self; D];
 }
+
+/// Trait for fields that support an extension of the form `E[X]/(P(X))` for an arbitrary
+/// irreducible polynomial `P` of degree `D` with coefficients in `E`.
+///
+/// By default `E = Self` and this defines a degree-`D` extension of `Self`. This is useful
+/// when no irreducible binomial `X^D - W` exists, e.g. a degree `4` or `5` extension of
+/// `Mersenne31`. Taking `E` to be an extension field of `Self` instead gives a tower
+/// `Self < E < E[X]/(P(X))` which is still an extension field of `Self`.
+///
+/// In a tower, [`PolynomialExtensionField`] only implements `ExtensionField<Self>`, not
+/// `ExtensionField<E>`: for example `PolynomialExtensionField<Mersenne31, D, Complex<Mersenne31>>`
+/// is an extension of `Mersenne31` but not of `Complex<Mersenne31>`. Its coordinates over `Self`
+/// are the coordinates over `Self` of each coefficient in `E`, so `E` must store its elements as
+/// exactly their basis coefficients over `Self`, in order, as the extension fields of this crate
+/// do. The size of `E` is checked at compile time when the coordinates are accessed, and the
+/// layout is additionally checked in debug builds.
+///
+/// # Safety
+/// - The basis coefficients over `Self` of an element of `E`, as returned by
+///   `as_basis_coefficients_slice`, must be stored inline and in order, so that `[E; N]` can be
+///   cast to `[Self; N * E::DIMENSION]` without UB.
+/// - The same must hold for `E::ExtensionPacking` over `Self::Packing`.
+pub unsafe trait PolynomiallyExtendable<const D: usize, E: ExtensionField<Self> = Self>:
+    Field
+{
+    /// The coefficients `[m_0, ..., m_{D - 1}]` of the monic irreducible polynomial
+    /// `P(X) = X^D + m_{D - 1} X^{D - 1} + ... + m_1 X + m_0`.
+    const MODULUS: [E; D];
+
+    /// A generator for the multiplicative group of the extension field, expressed as a
+    /// polynomial of degree less than `D` with coefficients in `E`.
+    const EXT_GENERATOR: [E; D];
+}
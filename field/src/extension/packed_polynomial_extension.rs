@@ -0,0 +1,568 @@
+use core::fmt::Debug;
+use core::iter::{Product, Sum};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::{array, slice};
+
+use itertools::Itertools;
+use rand::distr::{Distribution, StandardUniform};
+
+use super::polynomial_extension::{coefficients_are_inline, polynomial_mul};
+use super::{PolynomialExtensionField, PolynomiallyExtendable};
+use crate::{
+    Algebra, BasedVectorSpace, ExtensionField, Field, PackedField, PackedFieldExtension,
+    PackedValue, Powers, PrimeCharacteristicRing, field_to_array,
+};
+
+/// A packed version of [`PolynomialExtensionField`].
+///
+/// The coefficients of the polynomial are stored as packed elements of `E`, so this holds
+/// `F::Packing::WIDTH` elements of the extension field.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[repr(transparent)] // Needed to make various casts safe.
+#[must_use]
+pub struct PackedPolynomialExtensionField<
+    F: Field,
+    PF: PackedField<Scalar = F>,
+    const D: usize,
+    E: ExtensionField<F> = F,
+> {
+    pub(crate) value: [E::ExtensionPacking; D],
+    _phantom: PhantomData<PF>,
+}
+
+impl<F: Field, PF: PackedField<Scalar = F>, E: ExtensionField<F>, const D: usize>
+    PackedPolynomialExtensionField<F, PF, D, E>
+{
+    const fn new(value: [E::ExtensionPacking; D]) -> Self {
+        Self {
+            value,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<F, PF, E, const D: usize> Default for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<F, PF, E, const D: usize> From<PolynomialExtensionField<F, D, E>>
+    for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    #[inline]
+    fn from(x: PolynomialExtensionField<F, D, E>) -> Self {
+        Self::new(x.value.map(Into::into))
+    }
+}
+
+impl<F, PF, E, const D: usize> From<PF> for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    #[inline]
+    fn from(x: PF) -> Self {
+        Self::new(field_to_array(x.into()))
+    }
+}
+
+impl<F, PF, E, const D: usize> Distribution<PackedPolynomialExtensionField<F, PF, D, E>>
+    for StandardUniform
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+    Self: Distribution<E::ExtensionPacking>,
+{
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> PackedPolynomialExtensionField<F, PF, D, E> {
+        PackedPolynomialExtensionField::new(array::from_fn(|_| self.sample(rng)))
+    }
+}
+
+impl<F, PF, E, const D: usize> Algebra<PolynomialExtensionField<F, D, E>>
+    for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+}
+
+impl<F, PF, E, const D: usize> Algebra<PF> for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+}
+
+impl<F, PF, E, const D: usize> PrimeCharacteristicRing
+    for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    type PrimeSubfield = <E::ExtensionPacking as PrimeCharacteristicRing>::PrimeSubfield;
+
+    const ZERO: Self = Self::new([E::ExtensionPacking::ZERO; D]);
+
+    const ONE: Self = Self::new(field_to_array(E::ExtensionPacking::ONE));
+
+    const TWO: Self = Self::new(field_to_array(E::ExtensionPacking::TWO));
+
+    const NEG_ONE: Self = Self::new(field_to_array(E::ExtensionPacking::NEG_ONE));
+
+    #[inline]
+    fn from_prime_subfield(val: Self::PrimeSubfield) -> Self {
+        Self::new(field_to_array(E::ExtensionPacking::from_prime_subfield(
+            val,
+        )))
+    }
+
+    #[inline]
+    fn halve(&self) -> Self {
+        Self::new(self.value.map(|x| x.halve()))
+    }
+
+    #[inline]
+    fn mul_2exp_u64(&self, exp: u64) -> Self {
+        Self::new(self.value.map(|x| x.mul_2exp_u64(exp)))
+    }
+
+    #[inline]
+    fn div_2exp_u64(&self, exp: u64) -> Self {
+        Self::new(self.value.map(|x| x.div_2exp_u64(exp)))
+    }
+}
+
+impl<F, PF, E, const D: usize> BasedVectorSpace<PF> for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    const DIMENSION: usize = D * <E::ExtensionPacking as BasedVectorSpace<PF>>::DIMENSION;
+
+    #[inline]
+    fn as_basis_coefficients_slice(&self) -> &[PF] {
+        const {
+            assert!(
+                size_of::<E::ExtensionPacking>()
+                    == <E::ExtensionPacking as BasedVectorSpace<PF>>::DIMENSION * size_of::<PF>(),
+                "The basis coefficients of the packed base field must be stored inline"
+            );
+        }
+        debug_assert!(coefficients_are_inline::<PF, E::ExtensionPacking>(
+            &self.value
+        ));
+        unsafe {
+            // Safety:
+            // The safety contract of `PolynomiallyExtendable` requires the packing of `E` to store
+            // each element as exactly its basis coefficients, in order.
+            slice::from_raw_parts(
+                self.value.as_ptr().cast::<PF>(),
+                <Self as BasedVectorSpace<PF>>::DIMENSION,
+            )
+        }
+    }
+
+    #[inline]
+    fn from_basis_coefficients_fn<Fn: FnMut(usize) -> PF>(mut f: Fn) -> Self {
+        let inner_dim = <E::ExtensionPacking as BasedVectorSpace<PF>>::DIMENSION;
+        Self::new(array::from_fn(|i| {
+            E::ExtensionPacking::from_basis_coefficients_fn(|j| f(i * inner_dim + j))
+        }))
+    }
+
+    #[inline]
+    fn from_basis_coefficients_iter<I: ExactSizeIterator<Item = PF>>(mut iter: I) -> Option<Self> {
+        (iter.len() == <Self as BasedVectorSpace<PF>>::DIMENSION)
+            .then(|| Self::from_basis_coefficients_fn(|_| iter.next().unwrap())) // The unwrap is safe as we just checked the length of iter.
+    }
+}
+
+impl<F, E, const D: usize> PackedFieldExtension<F, PolynomialExtensionField<F, D, E>>
+    for PackedPolynomialExtensionField<F, F::Packing, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    #[inline]
+    fn from_ext_slice(ext_slice: &[PolynomialExtensionField<F, D, E>]) -> Self {
+        assert_eq!(ext_slice.len(), F::Packing::WIDTH);
+
+        // Coefficient `i` of lane `j` is the `i`-th coefficient of `ext_slice[j]`, and each of them
+        // is packed by transposing its own basis coefficients.
+        Self::new(array::from_fn(|i| {
+            E::ExtensionPacking::from_basis_coefficients_fn(|k| {
+                F::Packing::from_fn(|j| ext_slice[j].value[i].as_basis_coefficients_slice()[k])
+            })
+        }))
+    }
+
+    #[inline]
+    fn to_ext_iter(
+        iter: impl IntoIterator<Item = Self>,
+    ) -> impl Iterator<Item = PolynomialExtensionField<F, D, E>> {
+        iter.into_iter().flat_map(|x| {
+            (0..F::Packing::WIDTH).map(move |j| {
+                PolynomialExtensionField::new(array::from_fn(|i| {
+                    E::from_basis_coefficients_fn(|k| {
+                        x.value[i].as_basis_coefficients_slice()[k].as_slice()[j]
+                    })
+                }))
+            })
+        })
+    }
+
+    #[inline]
+    fn packed_ext_powers(base: PolynomialExtensionField<F, D, E>) -> Powers<Self> {
+        let width = F::Packing::WIDTH;
+        let powers = base.powers().take(width + 1).collect_vec();
+        // Transpose first WIDTH powers
+        let current = Self::from_ext_slice(&powers[..width]);
+
+        // Broadcast self^WIDTH
+        let multiplier = powers[width].into();
+
+        Powers {
+            base: multiplier,
+            current,
+        }
+    }
+}
+
+impl<F, PF, E, const D: usize> Neg for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(self.value.map(|x| -x))
+    }
+}
+
+impl<F, PF, E, const D: usize> Add for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(array::from_fn(|i| self.value[i] + rhs.value[i]))
+    }
+}
+
+impl<F, PF, E, const D: usize> Add<PolynomialExtensionField<F, D, E>>
+    for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: PolynomialExtensionField<F, D, E>) -> Self {
+        Self::new(array::from_fn(|i| self.value[i] + rhs.value[i]))
+    }
+}
+
+impl<F, PF, E, const D: usize> Add<PF> for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: PF) -> Self {
+        self.value[0] += rhs;
+        self
+    }
+}
+
+impl<F, PF, E, const D: usize> AddAssign for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<F, PF, E, const D: usize> AddAssign<PolynomialExtensionField<F, D, E>>
+    for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: PolynomialExtensionField<F, D, E>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<F, PF, E, const D: usize> AddAssign<PF> for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: PF) {
+        self.value[0] += rhs;
+    }
+}
+
+impl<F, PF, E, const D: usize> Sum for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(|acc, x| acc + x).unwrap_or(Self::ZERO)
+    }
+}
+
+impl<F, PF, E, const D: usize> Sub for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(array::from_fn(|i| self.value[i] - rhs.value[i]))
+    }
+}
+
+impl<F, PF, E, const D: usize> Sub<PolynomialExtensionField<F, D, E>>
+    for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: PolynomialExtensionField<F, D, E>) -> Self {
+        Self::new(array::from_fn(|i| self.value[i] - rhs.value[i]))
+    }
+}
+
+impl<F, PF, E, const D: usize> Sub<PF> for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: PF) -> Self {
+        self.value[0] -= rhs;
+        self
+    }
+}
+
+impl<F, PF, E, const D: usize> SubAssign for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<F, PF, E, const D: usize> SubAssign<PolynomialExtensionField<F, D, E>>
+    for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: PolynomialExtensionField<F, D, E>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<F, PF, E, const D: usize> SubAssign<PF> for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: PF) {
+        self.value[0] -= rhs;
+    }
+}
+
+impl<F, PF, E, const D: usize> Mul for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(polynomial_mul::<E, _, E::ExtensionPacking, D>(
+            &self.value,
+            &rhs.value,
+            &F::MODULUS,
+        ))
+    }
+}
+
+impl<F, PF, E, const D: usize> Mul<PolynomialExtensionField<F, D, E>>
+    for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: PolynomialExtensionField<F, D, E>) -> Self {
+        Self::new(polynomial_mul::<E, _, E, D>(
+            &self.value,
+            &rhs.value,
+            &F::MODULUS,
+        ))
+    }
+}
+
+impl<F, PF, E, const D: usize> Mul<PF> for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: PF) -> Self {
+        Self::new(self.value.map(|x| x * rhs))
+    }
+}
+
+impl<F, PF, E, const D: usize> Product for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    #[inline]
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(|acc, x| acc * x).unwrap_or(Self::ONE)
+    }
+}
+
+impl<F, PF, E, const D: usize> MulAssign for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<F, PF, E, const D: usize> MulAssign<PolynomialExtensionField<F, D, E>>
+    for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: PolynomialExtensionField<F, D, E>) {
+        *self = *self * rhs;
+    }
+}
+
+impl<F, PF, E, const D: usize> MulAssign<PF> for PackedPolynomialExtensionField<F, PF, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    PF: PackedField<Scalar = F>,
+    E: ExtensionField<F>,
+    E::ExtensionPacking: Algebra<PF> + BasedVectorSpace<PF>,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: PF) {
+        *self = *self * rhs;
+    }
+}
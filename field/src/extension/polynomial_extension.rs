@@ -0,0 +1,685 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::fmt::{self, Debug, Display, Formatter};
+use core::iter::{Product, Sum};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::{array, ptr, slice};
+
+use itertools::Itertools;
+use num_bigint::BigUint;
+use rand::distr::StandardUniform;
+use rand::prelude::Distribution;
+use serde::{Deserialize, Serialize};
+
+use super::{HasFrobenius, PackedPolynomialExtensionField, PolynomiallyExtendable};
+use crate::exponentiation::exp_biguint;
+use crate::field::Field;
+use crate::{
+    Algebra, BasedVectorSpace, ExtensionField, Packable, PrimeCharacteristicRing,
+    RawDataSerializable, field_to_array,
+};
+
+/// The extension field `E[X]/(P(X))` where `P` is the irreducible polynomial of degree `D`
+/// given by [`PolynomiallyExtendable::MODULUS`].
+///
+/// An element is stored as the coefficients `[a_0, ..., a_{D - 1}]` of `a_0 + a_1 X + ... + a_{D - 1} X^{D - 1}`.
+/// If `E` is an extension of `F`, this is a tower of extensions and the basis over `F` is made up of the
+/// products `e_j X^i` of the basis of `E` with the powers of `X`, ordered first by `i` and then by `j`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize, PartialOrd, Ord)]
+#[repr(transparent)] // Needed to make various casts safe.
+#[must_use]
+pub struct PolynomialExtensionField<F, const D: usize, E = F> {
+    #[serde(
+        with = "p3_util::array_serialization",
+        bound(serialize = "E: Serialize", deserialize = "E: Deserialize<'de>")
+    )]
+    pub(crate) value: [E; D],
+    _phantom: PhantomData<F>,
+}
+
+impl<F, E, const D: usize> PolynomialExtensionField<F, D, E> {
+    pub(crate) const fn new(value: [E; D]) -> Self {
+        Self {
+            value,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<F, E, const D: usize> Default for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<F, E, const D: usize> From<F> for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    fn from(x: F) -> Self {
+        Self::new(field_to_array(E::from(x)))
+    }
+}
+
+impl<F, E, const D: usize> Packable for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+}
+
+/// Check that the basis coefficients of every element of `values` are stored inline.
+///
+/// If this holds, `[A; N]` is laid out in memory identically to the `N * A::DIMENSION`
+/// basis coefficients of its elements and so can be cast to `[B; N * A::DIMENSION]`. This is
+/// required of the coefficients of a [`PolynomialExtensionField`] by the safety contract of
+/// [`PolynomiallyExtendable`], and additionally checked in debug builds.
+#[inline]
+pub(super) fn coefficients_are_inline<B: PrimeCharacteristicRing, A: BasedVectorSpace<B>>(
+    values: &[A],
+) -> bool {
+    size_of::<A>() == A::DIMENSION * size_of::<B>()
+        && values.iter().all(|x| {
+            ptr::eq(
+                x.as_basis_coefficients_slice().as_ptr(),
+                ptr::from_ref(x).cast::<B>(),
+            )
+        })
+}
+
+impl<F, E, const D: usize> BasedVectorSpace<F> for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    const DIMENSION: usize = D * <E as BasedVectorSpace<F>>::DIMENSION;
+
+    #[inline]
+    fn as_basis_coefficients_slice(&self) -> &[F] {
+        const {
+            assert!(
+                size_of::<E>() == <E as BasedVectorSpace<F>>::DIMENSION * size_of::<F>(),
+                "The basis coefficients of the base field must be stored inline"
+            );
+        }
+        debug_assert!(coefficients_are_inline::<F, E>(&self.value));
+        unsafe {
+            // Safety:
+            // The safety contract of `PolynomiallyExtendable` requires each element of `self.value`
+            // to consist of exactly its basis coefficients, in order.
+            slice::from_raw_parts(
+                self.value.as_ptr().cast::<F>(),
+                <Self as BasedVectorSpace<F>>::DIMENSION,
+            )
+        }
+    }
+
+    #[inline]
+    fn from_basis_coefficients_fn<Fn: FnMut(usize) -> F>(mut f: Fn) -> Self {
+        let inner_dim = <E as BasedVectorSpace<F>>::DIMENSION;
+        Self::new(array::from_fn(|i| {
+            E::from_basis_coefficients_fn(|j| f(i * inner_dim + j))
+        }))
+    }
+
+    #[inline]
+    fn from_basis_coefficients_iter<I: ExactSizeIterator<Item = F>>(mut iter: I) -> Option<Self> {
+        (iter.len() == <Self as BasedVectorSpace<F>>::DIMENSION)
+            .then(|| Self::from_basis_coefficients_fn(|_| iter.next().unwrap())) // The unwrap is safe as we just checked the length of iter.
+    }
+}
+
+impl<F, E, const D: usize> ExtensionField<F> for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    type ExtensionPacking = PackedPolynomialExtensionField<F, F::Packing, D, E>;
+
+    #[inline]
+    fn is_in_basefield(&self) -> bool {
+        self.value[1..].iter().all(E::is_zero) && self.value[0].is_in_basefield()
+    }
+
+    #[inline]
+    fn as_base(&self) -> Option<F> {
+        if self.value[1..].iter().all(E::is_zero) {
+            self.value[0].as_base()
+        } else {
+            None
+        }
+    }
+}
+
+impl<F, E, const D: usize> HasFrobenius<F> for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    /// FrobeniusField automorphisms: x -> x^n, where n is the order of F.
+    ///
+    /// Unlike the binomial case, there is no simple formula for the image of `X` so
+    /// this is computed directly using square and multiply.
+    #[inline]
+    fn frobenius(&self) -> Self {
+        exp_biguint(*self, &F::order())
+    }
+
+    /// Repeated Frobenius automorphisms: x -> x^(n^count).
+    #[inline]
+    fn repeated_frobenius(&self, count: usize) -> Self {
+        // x |-> x^(n^DIMENSION) is the identity so we can reduce count first.
+        let count = count % <Self as BasedVectorSpace<F>>::DIMENSION;
+        exp_biguint(*self, &F::order().pow(count as u32))
+    }
+
+    /// Returns `0` if `self == 0`, and `1/self` otherwise.
+    ///
+    /// The extended Euclidean algorithm used by `try_inverse` is much cheaper than
+    /// computing the norm using Frobenius automorphisms so we use it directly.
+    #[inline]
+    fn pseudo_inv(&self) -> Self {
+        self.try_inverse().unwrap_or(Self::ZERO)
+    }
+}
+
+impl<F, E, const D: usize> PrimeCharacteristicRing for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    type PrimeSubfield = E::PrimeSubfield;
+
+    const ZERO: Self = Self::new([E::ZERO; D]);
+
+    const ONE: Self = Self::new(field_to_array(E::ONE));
+
+    const TWO: Self = Self::new(field_to_array(E::TWO));
+
+    const NEG_ONE: Self = Self::new(field_to_array(E::NEG_ONE));
+
+    #[inline]
+    fn from_prime_subfield(f: Self::PrimeSubfield) -> Self {
+        Self::new(field_to_array(E::from_prime_subfield(f)))
+    }
+
+    #[inline]
+    fn halve(&self) -> Self {
+        Self::new(self.value.map(|x| x.halve()))
+    }
+
+    #[inline]
+    fn mul_2exp_u64(&self, exp: u64) -> Self {
+        Self::new(self.value.map(|x| x.mul_2exp_u64(exp)))
+    }
+
+    #[inline]
+    fn div_2exp_u64(&self, exp: u64) -> Self {
+        Self::new(self.value.map(|x| x.div_2exp_u64(exp)))
+    }
+}
+
+impl<F, E, const D: usize> Algebra<F> for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+}
+
+impl<F, E, const D: usize> RawDataSerializable for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    const NUM_BYTES: usize = E::NUM_BYTES * D;
+
+    #[inline]
+    fn into_bytes(self) -> impl IntoIterator<Item = u8> {
+        self.value.into_iter().flat_map(|x| x.into_bytes())
+    }
+
+    #[inline]
+    fn into_byte_stream(input: impl IntoIterator<Item = Self>) -> impl IntoIterator<Item = u8> {
+        E::into_byte_stream(input.into_iter().flat_map(|x| x.value))
+    }
+
+    #[inline]
+    fn into_u32_stream(input: impl IntoIterator<Item = Self>) -> impl IntoIterator<Item = u32> {
+        E::into_u32_stream(input.into_iter().flat_map(|x| x.value))
+    }
+
+    #[inline]
+    fn into_u64_stream(input: impl IntoIterator<Item = Self>) -> impl IntoIterator<Item = u64> {
+        E::into_u64_stream(input.into_iter().flat_map(|x| x.value))
+    }
+
+    #[inline]
+    fn into_parallel_byte_streams<const N: usize>(
+        input: impl IntoIterator<Item = [Self; N]>,
+    ) -> impl IntoIterator<Item = [u8; N]> {
+        E::into_parallel_byte_streams(
+            input
+                .into_iter()
+                .flat_map(|x| (0..D).map(move |i| array::from_fn(|j| x[j].value[i]))),
+        )
+    }
+
+    #[inline]
+    fn into_parallel_u32_streams<const N: usize>(
+        input: impl IntoIterator<Item = [Self; N]>,
+    ) -> impl IntoIterator<Item = [u32; N]> {
+        E::into_parallel_u32_streams(
+            input
+                .into_iter()
+                .flat_map(|x| (0..D).map(move |i| array::from_fn(|j| x[j].value[i]))),
+        )
+    }
+
+    #[inline]
+    fn into_parallel_u64_streams<const N: usize>(
+        input: impl IntoIterator<Item = [Self; N]>,
+    ) -> impl IntoIterator<Item = [u64; N]> {
+        E::into_parallel_u64_streams(
+            input
+                .into_iter()
+                .flat_map(|x| (0..D).map(move |i| array::from_fn(|j| x[j].value[i]))),
+        )
+    }
+}
+
+impl<F, E, const D: usize> Field for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    type Packing = Self;
+
+    const GENERATOR: Self = Self::new(F::EXT_GENERATOR);
+
+    fn try_inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+
+        Some(Self::new(polynomial_inv(&self.value, &F::MODULUS)))
+    }
+
+    #[inline]
+    fn order() -> BigUint {
+        E::order().pow(D as u32)
+    }
+}
+
+impl<F, E, const D: usize> Display for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            write!(f, "0")
+        } else {
+            let str = self
+                .value
+                .iter()
+                .enumerate()
+                .filter(|(_, x)| !x.is_zero())
+                .map(|(i, x)| match (i, x.is_one()) {
+                    (0, _) => format!("{x}"),
+                    (1, true) => "X".to_string(),
+                    (1, false) => format!("({x}) X"),
+                    (_, true) => format!("X^{i}"),
+                    (_, false) => format!("({x}) X^{i}"),
+                })
+                .join(" + ");
+            write!(f, "{str}")
+        }
+    }
+}
+
+impl<F, E, const D: usize> Neg for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(self.value.map(E::neg))
+    }
+}
+
+impl<F, E, const D: usize> Add for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(array::from_fn(|i| self.value[i] + rhs.value[i]))
+    }
+}
+
+impl<F, E, const D: usize> Add<F> for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: F) -> Self {
+        self.value[0] += rhs;
+        self
+    }
+}
+
+impl<F, E, const D: usize> AddAssign for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<F, E, const D: usize> AddAssign<F> for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: F) {
+        self.value[0] += rhs;
+    }
+}
+
+impl<F, E, const D: usize> Sum for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(|acc, x| acc + x).unwrap_or(Self::ZERO)
+    }
+}
+
+impl<F, E, const D: usize> Sub for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(array::from_fn(|i| self.value[i] - rhs.value[i]))
+    }
+}
+
+impl<F, E, const D: usize> Sub<F> for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: F) -> Self {
+        self.value[0] -= rhs;
+        self
+    }
+}
+
+impl<F, E, const D: usize> SubAssign for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<F, E, const D: usize> SubAssign<F> for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: F) {
+        self.value[0] -= rhs;
+    }
+}
+
+impl<F, E, const D: usize> Mul for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(polynomial_mul(&self.value, &rhs.value, &F::MODULUS))
+    }
+}
+
+impl<F, E, const D: usize> Mul<F> for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: F) -> Self {
+        Self::new(self.value.map(|x| x * rhs))
+    }
+}
+
+impl<F, E, const D: usize> MulAssign for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<F, E, const D: usize> MulAssign<F> for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: F) {
+        *self = *self * rhs;
+    }
+}
+
+impl<F, E, const D: usize> Product for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    #[inline]
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(|acc, x| acc * x).unwrap_or(Self::ONE)
+    }
+}
+
+impl<F, E, const D: usize> Div for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse()
+    }
+}
+
+impl<F, E, const D: usize> DivAssign for PolynomialExtensionField<F, D, E>
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+{
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<F, E, const D: usize> Distribution<PolynomialExtensionField<F, D, E>> for StandardUniform
+where
+    F: PolynomiallyExtendable<D, E>,
+    E: ExtensionField<F>,
+    Self: Distribution<E>,
+{
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> PolynomialExtensionField<F, D, E> {
+        PolynomialExtensionField::new(array::from_fn(|_| self.sample(rng)))
+    }
+}
+
+/// Multiply two polynomials of degree less than `D` and reduce the product modulo the monic
+/// polynomial `X^D + modulus[D - 1] X^{D - 1} + ... + modulus[0]`.
+#[inline]
+pub(super) fn polynomial_mul<E, R, R2, const D: usize>(
+    a: &[R; D],
+    b: &[R2; D],
+    modulus: &[E; D],
+) -> [R; D]
+where
+    E: Field,
+    R: Algebra<E> + Algebra<R2>,
+    R2: Algebra<E>,
+{
+    // Schoolbook multiplication, splitting the product into the coefficients of
+    // `1, X, ..., X^{D - 1}` and the coefficients of `X^D, ..., X^{2D - 2}`.
+    let mut lo: [R; D] = array::from_fn(|_| R::ZERO);
+    let mut hi: [R; D] = array::from_fn(|_| R::ZERO);
+    for i in 0..D {
+        for j in 0..D {
+            let prod = a[i].clone() * b[j].clone();
+            if i + j < D {
+                lo[i + j] += prod;
+            } else {
+                hi[i + j - D] += prod;
+            }
+        }
+    }
+
+    // Working down from the top, replace `X^{D + k}` by `-(modulus[0] X^k + ... + modulus[D - 1] X^{D - 1 + k})`.
+    // Each step only touches coefficients of lower degree so we never revisit a coefficient.
+    for k in (0..D.saturating_sub(1)).rev() {
+        let top = hi[k].clone();
+        for (i, &m) in modulus.iter().enumerate() {
+            // Most irreducible polynomials of interest are sparse.
+            if m.is_zero() {
+                continue;
+            }
+            let term = top.clone() * m;
+            if i + k < D {
+                lo[i + k] -= term;
+            } else {
+                hi[i + k - D] -= term;
+            }
+        }
+    }
+    lo
+}
+
+/// Compute the inverse of a nonzero polynomial of degree less than `D` modulo the monic
+/// irreducible polynomial `X^D + modulus[D - 1] X^{D - 1} + ... + modulus[0]`.
+///
+/// This uses the extended Euclidean algorithm which costs `O(D^2)` operations in `E`
+/// along with `O(D)` inversions in `E`.
+pub(super) fn polynomial_inv<E: Field, const D: usize>(a: &[E; D], modulus: &[E; D]) -> [E; D] {
+    // Throughout, we maintain the invariant `r_i = s_i * a mod P`.
+    let mut r0: Vec<E> = modulus.iter().copied().chain([E::ONE]).collect();
+    let mut r1 = poly_trim(a.to_vec());
+    let mut s0: Vec<E> = vec![];
+    let mut s1: Vec<E> = vec![E::ONE];
+    while r1.len() > 1 {
+        let (quotient, remainder) = poly_div_rem(&r0, &r1);
+        r0 = core::mem::replace(&mut r1, remainder);
+        let s = poly_sub(&s0, &poly_mul(&quotient, &s1));
+        s0 = core::mem::replace(&mut s1, s);
+    }
+
+    // As P is irreducible and a is nonzero, the gcd is a nonzero constant.
+    let scale = r1
+        .first()
+        .expect("The modulus of a polynomial extension must be irreducible")
+        .inverse();
+    array::from_fn(|i| s1.get(i).map_or(E::ZERO, |&c| c * scale))
+}
+
+/// Strip the zero coefficients of highest degree from a polynomial.
+fn poly_trim<E: Field>(mut a: Vec<E>) -> Vec<E> {
+    while a.last().is_some_and(E::is_zero) {
+        a.pop();
+    }
+    a
+}
+
+/// Multiply two polynomials.
+fn poly_mul<E: Field>(a: &[E], b: &[E]) -> Vec<E> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut res = vec![E::ZERO; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            res[i + j] += x * y;
+        }
+    }
+    poly_trim(res)
+}
+
+/// Subtract two polynomials.
+fn poly_sub<E: Field>(a: &[E], b: &[E]) -> Vec<E> {
+    let len = a.len().max(b.len());
+    let coeff = |p: &[E], i: usize| p.get(i).copied().unwrap_or(E::ZERO);
+    poly_trim((0..len).map(|i| coeff(a, i) - coeff(b, i)).collect())
+}
+
+/// Divide `num` by the nonzero polynomial `den`, returning the quotient and remainder.
+fn poly_div_rem<E: Field>(num: &[E], den: &[E]) -> (Vec<E>, Vec<E>) {
+    if num.len() < den.len() {
+        return (vec![], num.to_vec());
+    }
+    let lead_inv = den[den.len() - 1].inverse();
+    let mut rem = num.to_vec();
+    let mut quotient = vec![E::ZERO; num.len() - den.len() + 1];
+    for k in (0..quotient.len()).rev() {
+        let c = rem[k + den.len() - 1] * lead_inv;
+        quotient[k] = c;
+        for (j, &d) in den.iter().enumerate() {
+            rem[k + j] -= c * d;
+        }
+    }
+    rem.truncate(den.len() - 1);
+    (quotient, poly_trim(rem))
+}
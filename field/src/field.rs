@@ -12,7 +12,8 @@ use p3_util::{flatten_to_base, iter_array_chunks_padded};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 
-use crate::exponentiation::bits_u64;
+use crate::exponentiation::{bits_u64, exp_biguint};
+use crate::helpers::tonelli_shanks;
 use crate::integers::{QuotientMap, from_integer_types};
 use crate::packed::PackedField;
 use crate::{Packable, PackedFieldExtension, PackedValue};
@@ -827,6 +828,54 @@ pub trait Field:
         }
     }
 
+    /// The Legendre symbol of this element, i.e. whether it is zero, a nonzero square or a
+    /// non-square.
+    ///
+    /// The default implementation uses Euler's criterion, `x^((q - 1)/2) = ±1` for nonzero `x` in a
+    /// field of odd order `q`. In a field of characteristic two, every element is a square.
+    #[must_use]
+    fn legendre_symbol(&self) -> LegendreSymbol {
+        let q_minus_one = Self::order() - 1u32;
+        if q_minus_one.bit(0) {
+            // Squaring is the Frobenius automorphism, so every element is a square.
+            return if self.is_zero() {
+                LegendreSymbol::Zero
+            } else {
+                LegendreSymbol::QuadraticResidue
+            };
+        }
+        LegendreSymbol::from_euler_criterion(self, exp_biguint(*self, &(q_minus_one >> 1)))
+    }
+
+    /// Check if this element is a square, i.e. has a square root in the field.
+    ///
+    /// Note that `0` is a square.
+    #[must_use]
+    #[inline]
+    fn is_square(&self) -> bool {
+        self.legendre_symbol() != LegendreSymbol::QuadraticNonResidue
+    }
+
+    /// A square root of this element, if it exists.
+    ///
+    /// Which of the two roots `±r` is returned is unspecified. The default implementation runs the
+    /// Tonelli–Shanks algorithm with exponents derived from the order of the field, using the
+    /// generator of the multiplicative group as the non-square. Fields with a faster method, e.g.
+    /// `x^((p + 1)/4)` when `p = 3 mod 4`, should override it.
+    #[must_use]
+    fn sqrt(&self) -> Option<Self> {
+        // Write q - 1 = 2^s t with t odd.
+        let q_minus_one = Self::order() - 1u32;
+        let two_adicity = q_minus_one.trailing_zeros().unwrap_or(0) as usize;
+        let odd_factor = q_minus_one >> two_adicity;
+        tonelli_shanks(
+            *self,
+            two_adicity,
+            exp_biguint(*self, &(&odd_factor >> 1)),
+            exp_biguint(Self::GENERATOR, &odd_factor),
+        )
+    }
+
     /// The number of elements in the field.
     ///
     /// This will either be prime if the field is a PrimeField or a power of a
@@ -845,6 +894,32 @@ pub trait Field:
     }
 }
 
+/// The quadratic character of a field element.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LegendreSymbol {
+    Zero,
+    /// A nonzero square.
+    QuadraticResidue,
+    /// An element which is not a square.
+    QuadraticNonResidue,
+}
+
+impl LegendreSymbol {
+    /// The Legendre symbol of `x`, given `euler = x^((q - 1)/2)`, which is `1` for nonzero squares
+    /// and `-1` for non-squares.
+    #[must_use]
+    #[inline]
+    pub fn from_euler_criterion<F: Field>(x: &F, euler: F) -> Self {
+        if x.is_zero() {
+            Self::Zero
+        } else if euler.is_one() {
+            Self::QuadraticResidue
+        } else {
+            Self::QuadraticNonResidue
+        }
+    }
+}
+
 /// A field isomorphic to `ℤ/p` for some prime `p`.
 ///
 /// There is a natural map from `ℤ` to `ℤ/p` which sends an integer `r` to its conjugacy class `[r]`.
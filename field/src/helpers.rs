@@ -188,3 +188,44 @@ where
 {
     li.zip(ri).map(|(l, r)| l * r).sum()
 }
+
+/// Compute a square root of `x` with the Tonelli–Shanks algorithm.
+///
+/// The field order `q` is written as `q - 1 = 2^two_adicity * t` with `t` odd. The caller provides
+/// `x_pow = x^((t - 1)/2)` and `root_of_unity`, an element of order `2^two_adicity` such as `g^t`
+/// for a generator `g`, so that the exponentiations can be done with whichever exponent type
+/// suits the field.
+///
+/// Returns `None` if `x` is not a square.
+#[must_use]
+pub fn tonelli_shanks<F: Field>(x: F, two_adicity: usize, x_pow: F, root_of_unity: F) -> Option<F> {
+    if x.is_zero() {
+        return Some(F::ZERO);
+    }
+
+    // Throughout, `r^2 = x * b`, `c` has order `2^m` and the order of `b` divides `2^m`.
+    let mut r = x * x_pow;
+    let mut b = r * x_pow;
+    let mut c = root_of_unity;
+    let mut m = two_adicity;
+    while !b.is_one() {
+        // Find the order `2^i` of `b`. If it is `2^m`, then `x` is not a square.
+        let mut i = 0;
+        let mut b_pow = b;
+        while !b_pow.is_one() {
+            b_pow = b_pow.square();
+            i += 1;
+            if i == m {
+                return None;
+            }
+        }
+
+        // Multiplying `b` by `c^(2^(m - i))`, of order `2^i`, lowers its order.
+        let c_pow = c.exp_power_of_2(m - i - 1);
+        r *= c_pow;
+        c = c_pow.square();
+        b *= c;
+        m = i;
+    }
+    Some(r)
+}
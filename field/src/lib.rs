@@ -5,6 +5,7 @@ extern crate alloc;
 
 mod array;
 mod batch_inverse;
+mod constant_time;
 pub mod coset;
 pub mod exponentiation;
 pub mod extension;
@@ -16,6 +17,7 @@ mod packed;
 
 pub use array::*;
 pub use batch_inverse::*;
+pub use constant_time::*;
 pub use field::*;
 pub use helpers::*;
 pub use packed::*;
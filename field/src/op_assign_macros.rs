@@ -13,6 +13,12 @@
 //
 // impl<MP: MontyParameters> AddAssign for MontyField31<MP>
 // ...
+//
+// Some of the macros also accept a const generic parameter after the type parameter, so
+// `impl_add_assign!(MontyFieldN, (MontyParameters<N>, FP), const N: usize)` produces:
+//
+// impl<FP: MontyParameters<N>, const N: usize> AddAssign for MontyFieldN<FP, N>
+// ...
 
 /// Given a struct which implements `Add` implement `AddAssign<T>` for
 /// any type `T` which implements `Into<Self>`.
@@ -21,9 +27,9 @@
 /// and assigning the result to `*self`.
 #[macro_export]
 macro_rules! impl_add_assign {
-    ($type:ty $(, ($type_param:ty, $param_name:ty))?) => {
+    ($type:ty $(, ($type_param:ty, $param_name:ty) $(, const $const_name:ident: $const_type:ty)?)?) => {
         paste::paste! {
-            impl<$($param_name: $type_param,)? T: Into<Self>> AddAssign<T> for $type$(<$param_name>)? {
+            impl<$($param_name: $type_param, $(const $const_name: $const_type,)?)? T: Into<Self>> AddAssign<T> for $type$(<$param_name $(, $const_name)?>)? {
                 #[inline]
                 fn add_assign(&mut self, rhs: T) {
                     *self = *self + rhs.into();
@@ -38,9 +44,9 @@ macro_rules! impl_add_assign {
 /// `Sum` is implemented by just doing a reduce on the iterator.
 #[macro_export]
 macro_rules! ring_sum {
-    ($type:ty $(, ($type_param:ty, $param_name:ty))?) => {
+    ($type:ty $(, ($type_param:ty, $param_name:ty) $(, const $const_name:ident: $const_type:ty)?)?) => {
         paste::paste! {
-            impl$(<$param_name: $type_param>)? Sum for $type$(<$param_name>)? {
+            impl$(<$param_name: $type_param $(, const $const_name: $const_type)?>)? Sum for $type$(<$param_name $(, $const_name)?>)? {
                 #[inline]
                 fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
                     iter.reduce(|x, y| x + y).unwrap_or(Self::ZERO)
@@ -57,9 +63,9 @@ macro_rules! ring_sum {
 /// and assigning the result to `*self`.
 #[macro_export]
 macro_rules! impl_sub_assign {
-    ($type:ty $(, ($type_param:ty, $param_name:ty))?) => {
+    ($type:ty $(, ($type_param:ty, $param_name:ty) $(, const $const_name:ident: $const_type:ty)?)?) => {
         paste::paste! {
-            impl<$($param_name: $type_param,)? T: Into<Self>> SubAssign<T> for $type$(<$param_name>)? {
+            impl<$($param_name: $type_param, $(const $const_name: $const_type,)?)? T: Into<Self>> SubAssign<T> for $type$(<$param_name $(, $const_name)?>)? {
                 #[inline]
                 fn sub_assign(&mut self, rhs: T) {
                     *self = *self - rhs.into();
@@ -77,16 +83,16 @@ macro_rules! impl_sub_assign {
 /// in the similarly simple way of just doing a reduce on the iterator.
 #[macro_export]
 macro_rules! impl_mul_methods {
-    ($type:ty $(, ($type_param:ty, $param_name:ty))?) => {
+    ($type:ty $(, ($type_param:ty, $param_name:ty) $(, const $const_name:ident: $const_type:ty)?)?) => {
         paste::paste! {
-            impl<$($param_name: $type_param,)? T: Into<Self>> MulAssign<T> for $type$(<$param_name>)? {
+            impl<$($param_name: $type_param, $(const $const_name: $const_type,)?)? T: Into<Self>> MulAssign<T> for $type$(<$param_name $(, $const_name)?>)? {
                 #[inline]
                 fn mul_assign(&mut self, rhs: T) {
                     *self = *self * rhs.into();
                 }
             }
 
-            impl$(<$param_name: $type_param>)? Product for $type$(<$param_name>)? {
+            impl$(<$param_name: $type_param $(, const $const_name: $const_type)?>)? Product for $type$(<$param_name $(, $const_name)?>)? {
                 #[inline]
                 fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
                     iter.reduce(|x, y| x * y).unwrap_or(Self::ONE)
@@ -196,22 +202,22 @@ macro_rules! impl_mul_base_field {
 /// This can also be used with `Alg = Field` to implement `Div` and `DivAssign` for Field.
 #[macro_export]
 macro_rules! impl_div_methods {
-    ($alg_type:ty, $field_type:ty $(, ($type_param:ty, $param_name:ty))?) => {
+    ($alg_type:ty, $field_type:ty $(, ($type_param:ty, $param_name:ty) $(, const $const_name:ident: $const_type:ty)?)?) => {
         paste::paste! {
-            impl$(<$param_name: $type_param>)? Div<$field_type$(<$param_name>)?> for $alg_type$(<$param_name>)? {
+            impl$(<$param_name: $type_param $(, const $const_name: $const_type)?>)? Div<$field_type$(<$param_name $(, $const_name)?>)?> for $alg_type$(<$param_name $(, $const_name)?>)? {
                 type Output = Self;
 
                 #[inline]
                 #[allow(clippy::suspicious_arithmetic_impl)]
-                fn div(self, rhs: $field_type$(<$param_name>)?) -> Self {
+                fn div(self, rhs: $field_type$(<$param_name $(, $const_name)?>)?) -> Self {
                     self * Self::from(rhs.inverse())
                 }
             }
 
-            impl$(<$param_name: $type_param>)? DivAssign<$field_type$(<$param_name>)?> for $alg_type$(<$param_name>)? {
+            impl$(<$param_name: $type_param $(, const $const_name: $const_type)?>)? DivAssign<$field_type$(<$param_name $(, $const_name)?>)?> for $alg_type$(<$param_name $(, $const_name)?>)? {
                 #[inline]
                 #[allow(clippy::suspicious_op_assign_impl)]
-                fn div_assign(&mut self, rhs: $field_type$(<$param_name>)?) {
+                fn div_assign(&mut self, rhs: $field_type$(<$param_name $(, $const_name)?>)?) {
                     *self *= Self::from(rhs.inverse());
                 }
             }
@@ -219,6 +225,34 @@ macro_rules! impl_div_methods {
     };
 }
 
+/// Given a packed field `PF`, implement `Div<PF>` and `DivAssign<PF>` for `PF`.
+///
+/// Both are implemented by multiplying by the output of `PackedField::packed_inverse`.
+#[macro_export]
+macro_rules! impl_packed_div_methods {
+    ($packed_type:ty $(, ($type_param:ty, $param_name:ty))?) => {
+        paste::paste! {
+            impl$(<$param_name: $type_param>)? Div for $packed_type$(<$param_name>)? {
+                type Output = Self;
+
+                #[inline]
+                #[allow(clippy::suspicious_arithmetic_impl)]
+                fn div(self, rhs: Self) -> Self {
+                    self * $crate::PackedField::packed_inverse(&rhs)
+                }
+            }
+
+            impl$(<$param_name: $type_param>)? DivAssign for $packed_type$(<$param_name>)? {
+                #[inline]
+                #[allow(clippy::suspicious_op_assign_impl)]
+                fn div_assign(&mut self, rhs: Self) {
+                    *self *= $crate::PackedField::packed_inverse(&rhs);
+                }
+            }
+        }
+    };
+}
+
 /// Given two structs `Alg` and `Field` where `Alg` implements `From<Field>`, implement
 /// `Sum<Field> and Product<Field>` for `Alg`.
 ///
@@ -319,6 +353,6 @@ macro_rules! impl_packed_value {
 
 pub use {
     impl_add_assign, impl_add_base_field, impl_div_methods, impl_mul_base_field, impl_mul_methods,
-    impl_packed_value, impl_rng, impl_sub_assign, impl_sub_base_field, impl_sum_prod_base_field,
-    ring_sum,
+    impl_packed_div_methods, impl_packed_value, impl_rng, impl_sub_assign, impl_sub_base_field,
+    impl_sum_prod_base_field, ring_sum,
 };
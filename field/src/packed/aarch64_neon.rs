@@ -1,6 +1,7 @@
 //! A collection of helper methods when Neon is available
 
 use core::arch::aarch64::{self, uint32x4_t};
+use core::array;
 use core::mem::transmute;
 
 /// Convert a four element array of u32's into a packed vector.
@@ -21,6 +22,14 @@ fn uint32x4_to_array(input: uint32x4_t) -> [u32; 4] {
     unsafe { transmute::<uint32x4_t, [u32; 4]>(input) }
 }
 
+/// Copy up to four elements of `input` into a packed vector, padding the remainder with zeros.
+///
+/// As `0 + 0 = 0 - 0 = 0`, the padding does not affect the lanes we care about.
+#[inline(always)]
+fn pad_to_uint32x4(input: &[u32]) -> uint32x4_t {
+    array_to_uint32x4(array::from_fn(|i| input.get(i).copied().unwrap_or(0)))
+}
+
 /// Add the packed vectors `a` and `b` modulo `p`.
 ///
 /// This allows us to add 4 elements at once.
@@ -89,8 +98,6 @@ pub fn uint32x4_mod_sub(a: uint32x4_t, b: uint32x4_t, p: uint32x4_t) -> uint32x4
 ///
 /// Scalar add is assumed to be a function which implements `a + b % P` with the
 /// same specifications as above.
-///
-/// TODO: Add support for extensions of degree 2,3,6,7.
 #[inline(always)]
 pub fn packed_mod_add<const WIDTH: usize>(
     a: &[u32; WIDTH],
@@ -101,6 +108,17 @@ pub fn packed_mod_add<const WIDTH: usize>(
 ) {
     match WIDTH {
         1 => res[0] = scalar_add(a[0], b[0]),
+        2 | 3 => {
+            // Pad the inputs with zeros to fill a uint32x4_t element.
+            let out: [u32; 4] = unsafe {
+                let a = pad_to_uint32x4(a);
+                let b = pad_to_uint32x4(b);
+                let p: uint32x4_t = aarch64::vdupq_n_u32(p);
+                uint32x4_to_array(uint32x4_mod_add(a, b, p))
+            };
+
+            res.copy_from_slice(&out[..WIDTH]);
+        }
         4 => {
             // Perfectly fits into a uint32x4_t vector.
             let out: [u32; 4] = unsafe {
@@ -126,6 +144,24 @@ pub fn packed_mod_add<const WIDTH: usize>(
 
             res[..4].copy_from_slice(&out);
         }
+        6 | 7 => {
+            // Use one full uint32x4_t element and pad the remainder into a second.
+            let (out_lo, out_hi): ([u32; 4], [u32; 4]) = unsafe {
+                let p: uint32x4_t = aarch64::vdupq_n_u32(p);
+
+                let a_lo = array_to_uint32x4([a[0], a[1], a[2], a[3]]);
+                let b_lo = array_to_uint32x4([b[0], b[1], b[2], b[3]]);
+                let out_lo = uint32x4_to_array(uint32x4_mod_add(a_lo, b_lo, p));
+
+                let a_hi = pad_to_uint32x4(&a[4..]);
+                let b_hi = pad_to_uint32x4(&b[4..]);
+                let out_hi = uint32x4_to_array(uint32x4_mod_add(a_hi, b_hi, p));
+                (out_lo, out_hi)
+            };
+
+            res[..4].copy_from_slice(&out_lo);
+            res[4..].copy_from_slice(&out_hi[..WIDTH - 4]);
+        }
         8 => {
             // This perfectly fits into two uint32x4_t elements.
             let (out_lo, out_hi): ([u32; 4], [u32; 4]) = unsafe {
@@ -158,8 +194,6 @@ pub fn packed_mod_add<const WIDTH: usize>(
 ///
 /// Scalar sub is assumed to be a function which implements `a - b % P` with the
 /// same specifications as above.
-///
-/// TODO: Add support for extensions of degree 2,3,6,7.
 #[inline(always)]
 pub fn packed_mod_sub<const WIDTH: usize>(
     a: &[u32; WIDTH],
@@ -170,6 +204,17 @@ pub fn packed_mod_sub<const WIDTH: usize>(
 ) {
     match WIDTH {
         1 => res[0] = scalar_sub(a[0], b[0]),
+        2 | 3 => {
+            // Pad the inputs with zeros to fill a uint32x4_t element.
+            let out: [u32; 4] = unsafe {
+                let a = pad_to_uint32x4(a);
+                let b = pad_to_uint32x4(b);
+                let p: uint32x4_t = aarch64::vdupq_n_u32(p);
+                uint32x4_to_array(uint32x4_mod_sub(a, b, p))
+            };
+
+            res.copy_from_slice(&out[..WIDTH]);
+        }
         4 => {
             // Perfectly fits into a uint32x4_t vector.
             let out: [u32; 4] = unsafe {
@@ -195,6 +240,24 @@ pub fn packed_mod_sub<const WIDTH: usize>(
 
             res[..4].copy_from_slice(&out);
         }
+        6 | 7 => {
+            // Use one full uint32x4_t element and pad the remainder into a second.
+            let (out_lo, out_hi): ([u32; 4], [u32; 4]) = unsafe {
+                let p: uint32x4_t = aarch64::vdupq_n_u32(p);
+
+                let a_lo = array_to_uint32x4([a[0], a[1], a[2], a[3]]);
+                let b_lo = array_to_uint32x4([b[0], b[1], b[2], b[3]]);
+                let out_lo = uint32x4_to_array(uint32x4_mod_sub(a_lo, b_lo, p));
+
+                let a_hi = pad_to_uint32x4(&a[4..]);
+                let b_hi = pad_to_uint32x4(&b[4..]);
+                let out_hi = uint32x4_to_array(uint32x4_mod_sub(a_hi, b_hi, p));
+                (out_lo, out_hi)
+            };
+
+            res[..4].copy_from_slice(&out_lo);
+            res[4..].copy_from_slice(&out_hi[..WIDTH - 4]);
+        }
         8 => {
             // This perfectly fits into two uint32x4_t elements.
             let (out_lo, out_hi): ([u32; 4], [u32; 4]) = unsafe {
@@ -4,6 +4,7 @@ use core::mem::MaybeUninit;
 use core::ops::{Div, DivAssign};
 use core::{array, slice};
 
+use crate::batch_inverse::batch_multiplicative_inverse_general;
 use crate::field::Field;
 use crate::{Algebra, BasedVectorSpace, ExtensionField, Powers, PrimeCharacteristicRing};
 
@@ -206,9 +207,11 @@ unsafe impl<T: Packable, const WIDTH: usize> PackedValue for [T; WIDTH] {
 ///
 /// # Safety
 /// - See `PackedValue` above.
-pub unsafe trait PackedField: Algebra<Self::Scalar>
+pub unsafe trait PackedField:
+    Algebra<Self::Scalar>
     + PackedValue<Value = Self::Scalar>
-    // TODO: Implement packed / packed division
+    + Div<Self, Output = Self>
+    + DivAssign<Self>
     + Div<Self::Scalar, Output = Self>
     + DivAssign<Self::Scalar>
     + Sum<Self::Scalar>
@@ -241,6 +244,21 @@ pub unsafe trait PackedField: Algebra<Self::Scalar>
         }
     }
 
+    /// Compute the multiplicative inverse of every element of the packed vector.
+    ///
+    /// Uses Montgomery's trick across the lanes so only a single scalar inversion is needed.
+    ///
+    /// # Panics
+    /// This will panic if any of the elements is zero.
+    #[must_use]
+    fn packed_inverse(&self) -> Self {
+        let mut result = Self::ZERO;
+        batch_multiplicative_inverse_general(self.as_slice(), result.as_slice_mut(), |x| {
+            x.inverse()
+        });
+        result
+    }
+
     /// Compute a linear combination of a slice of base field elements and
     /// a slice of packed field elements. The slices must have equal length
     /// and it must be a compile time constant.
@@ -334,6 +352,21 @@ pub trait PackedFieldExtension<
         })
     }
 
+    /// Compute the multiplicative inverse of every extension field element in the packed vector.
+    ///
+    /// The elements are unpacked and inverted using Montgomery's trick so only a single
+    /// extension field inversion is needed.
+    ///
+    /// # Panics
+    /// This will panic if any of the elements is zero.
+    #[must_use]
+    fn packed_ext_inverse(&self) -> Self {
+        let elems: Vec<ExtField> = Self::to_ext_iter([self.clone()]).collect();
+        let mut inverses = ExtField::zero_vec(elems.len());
+        batch_multiplicative_inverse_general(&elems, &mut inverses, |x| x.inverse());
+        Self::from_ext_slice(&inverses)
+    }
+
     /// Similar to `packed_powers`, construct an iterator which returns
     /// powers of `base` packed into `PackedFieldExtension` elements.
     #[must_use]
@@ -3,6 +3,7 @@
 #[cfg(target_feature = "avx512f")]
 use core::arch::x86_64::__m512i;
 use core::arch::x86_64::{self, __m128i, __m256i};
+use core::array;
 use core::mem::transmute;
 
 // Goal: Compute r = lhs + rhs mod P for lhs, rhs <= P < 2^31
@@ -178,6 +179,14 @@ pub fn mm512_mod_sub(lhs: __m512i, rhs: __m512i, p: __m512i) -> __m512i {
     }
 }
 
+/// Copy `input` into the start of an array of length `N`, padding the remainder with zeros.
+///
+/// As `0 + 0 = 0 - 0 = 0`, the padding does not affect the lanes we care about.
+#[inline(always)]
+fn pad_with_zeros<const N: usize>(input: &[u32]) -> [u32; N] {
+    array::from_fn(|i| input.get(i).copied().unwrap_or(0))
+}
+
 /// Add two arrays of integers modulo `P` using packings.
 ///
 /// Assumes that `P` is less than `2^31` and `a + b <= 2P` for all array pairs `a, b`.
@@ -188,8 +197,6 @@ pub fn mm512_mod_sub(lhs: __m512i, rhs: __m512i, p: __m512i) -> __m512i {
 ///
 /// Scalar add is assumed to be a function which implements `a + b % P` with the
 /// same specifications as above.
-///
-/// TODO: Add support for extensions of degree 2,3,6,7.
 #[inline(always)]
 pub fn packed_mod_add<const WIDTH: usize>(
     a: &[u32; WIDTH],
@@ -200,6 +207,17 @@ pub fn packed_mod_add<const WIDTH: usize>(
 ) {
     match WIDTH {
         1 => res[0] = scalar_add(a[0], b[0]),
+        2 | 3 => {
+            // Pad the inputs with zeros to fill a m128i vector.
+            let out: [u32; 4] = unsafe {
+                let a: __m128i = transmute(pad_with_zeros::<4>(a));
+                let b: __m128i = transmute(pad_with_zeros::<4>(b));
+                let p: __m128i = x86_64::_mm_set1_epi32(p as i32);
+                transmute(mm128_mod_add(a, b, p))
+            };
+
+            res.copy_from_slice(&out[..WIDTH]);
+        }
         4 => {
             // Perfectly fits into a m128i vector. The compiler is good at
             // optimising this into AVX2 instructions in cases where we need to
@@ -229,6 +247,17 @@ pub fn packed_mod_add<const WIDTH: usize>(
 
             res[..4].copy_from_slice(&out[..4]);
         }
+        6 | 7 => {
+            // Pad the inputs with zeros to fill a m256i vector.
+            let out: [u32; 8] = unsafe {
+                let a: __m256i = transmute(pad_with_zeros::<8>(a));
+                let b: __m256i = transmute(pad_with_zeros::<8>(b));
+                let p: __m256i = x86_64::_mm256_set1_epi32(p as i32);
+                transmute(mm256_mod_add(a, b, p))
+            };
+
+            res.copy_from_slice(&out[..WIDTH]);
+        }
         8 => {
             // This perfectly fits into a single m256i vector.
             let out: [u32; 8] = unsafe {
@@ -254,8 +283,6 @@ pub fn packed_mod_add<const WIDTH: usize>(
 ///
 /// Scalar sub is assumed to be a function which implements `a - b % P` with the
 /// same specifications as above.
-///
-/// TODO: Add support for extensions of degree 2,3,6,7.
 #[inline(always)]
 pub fn packed_mod_sub<const WIDTH: usize>(
     a: &[u32; WIDTH],
@@ -266,6 +293,17 @@ pub fn packed_mod_sub<const WIDTH: usize>(
 ) {
     match WIDTH {
         1 => res[0] = scalar_sub(a[0], b[0]),
+        2 | 3 => {
+            // Pad the inputs with zeros to fill a m128i vector.
+            let out: [u32; 4] = unsafe {
+                let a: __m128i = transmute(pad_with_zeros::<4>(a));
+                let b: __m128i = transmute(pad_with_zeros::<4>(b));
+                let p: __m128i = x86_64::_mm_set1_epi32(p as i32);
+                transmute(mm128_mod_sub(a, b, p))
+            };
+
+            res.copy_from_slice(&out[..WIDTH]);
+        }
         4 => {
             // Perfectly fits into a m128i vector. The compiler is good at
             // optimising this into AVX2 instructions in cases where we need to
@@ -295,6 +333,17 @@ pub fn packed_mod_sub<const WIDTH: usize>(
 
             res[..4].copy_from_slice(&out[..4]);
         }
+        6 | 7 => {
+            // Pad the inputs with zeros to fill a m256i vector.
+            let out: [u32; 8] = unsafe {
+                let a: __m256i = transmute(pad_with_zeros::<8>(a));
+                let b: __m256i = transmute(pad_with_zeros::<8>(b));
+                let p: __m256i = x86_64::_mm256_set1_epi32(p as i32);
+                transmute(mm256_mod_sub(a, b, p))
+            };
+
+            res.copy_from_slice(&out[..WIDTH]);
+        }
         8 => {
             // This perfectly fits into a single m256i vector.
             let out: [u32; 8] = unsafe {
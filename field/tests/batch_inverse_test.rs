@@ -0,0 +1,68 @@
+mod batch_inverse {
+    use p3_baby_bear::BabyBear;
+    use p3_field::extension::BinomialExtensionField;
+    use p3_field::{
+        ExtensionField, Field, PackedFieldExtension, PackedValue, PrimeCharacteristicRing,
+        batch_multiplicative_inverse, batch_multiplicative_inverse_packed,
+        batch_multiplicative_inverse_packed_ext,
+    };
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    type F = BabyBear;
+    type P = <F as Field>::Packing;
+    type EF = BinomialExtensionField<F, 4>;
+    type Pef = <EF as ExtensionField<F>>::ExtensionPacking;
+
+    #[test]
+    fn test_batch_multiplicative_inverse_packed_matches_scalar() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let scalars: Vec<F> = (0..P::WIDTH * 300).map(|_| rng.random()).collect();
+
+        let packed_invs = batch_multiplicative_inverse_packed(P::pack_slice(&scalars));
+        let scalar_invs = batch_multiplicative_inverse(&scalars);
+
+        assert_eq!(P::unpack_slice(&packed_invs), scalar_invs.as_slice());
+    }
+
+    #[test]
+    fn test_batch_multiplicative_inverse_packed_ext_matches_scalar() {
+        let mut rng = SmallRng::seed_from_u64(2);
+        let scalars: Vec<EF> = (0..P::WIDTH * 300).map(|_| rng.random()).collect();
+        let packed: Vec<Pef> = scalars
+            .chunks_exact(P::WIDTH)
+            .map(Pef::from_ext_slice)
+            .collect();
+
+        let packed_invs = batch_multiplicative_inverse_packed_ext::<F, EF>(&packed);
+        let unpacked_invs: Vec<EF> =
+            <Pef as PackedFieldExtension<F, EF>>::to_ext_iter(packed_invs).collect();
+
+        assert_eq!(unpacked_invs, batch_multiplicative_inverse(&scalars));
+        assert!(
+            scalars
+                .iter()
+                .zip(&unpacked_invs)
+                .all(|(&x, &x_inv)| x * x_inv == EF::ONE)
+        );
+    }
+
+    #[test]
+    fn test_packed_division() {
+        let mut rng = SmallRng::seed_from_u64(3);
+        let a: P = rng.random();
+        let b: P = rng.random();
+
+        let quotient = a / b;
+        assert_eq!(quotient * b, a);
+        for i in 0..P::WIDTH {
+            assert_eq!(quotient.as_slice()[i], a.as_slice()[i] / b.as_slice()[i]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_batch_multiplicative_inverse_packed_zero() {
+        let _ = batch_multiplicative_inverse_packed(&[P::ONE, P::ZERO]);
+    }
+}
@@ -1,7 +1,8 @@
 use criterion::{Criterion, criterion_group, criterion_main};
 use p3_field::extension::BinomialExtensionField;
 use p3_field_testing::bench_func::{
-    benchmark_inv, benchmark_mul_latency, benchmark_mul_throughput, benchmark_square,
+    benchmark_add_throughput, benchmark_inv, benchmark_mul_latency, benchmark_mul_throughput,
+    benchmark_square, benchmark_sub_throughput,
 };
 use p3_field_testing::benchmark_mul;
 use p3_goldilocks::Goldilocks;
@@ -16,6 +17,8 @@ const L_REPS: usize = 10 * REPS;
 
 fn bench_quadratic_extension(c: &mut Criterion) {
     let name = "BinomialExtensionField<Goldilocks, 2>";
+    benchmark_add_throughput::<EF2, REPS>(c, name);
+    benchmark_sub_throughput::<EF2, REPS>(c, name);
     benchmark_square::<EF2>(c, name);
     benchmark_inv::<EF2>(c, name);
     benchmark_mul::<EF2>(c, name);
@@ -0,0 +1,155 @@
+use p3_mds::MdsPermutation;
+use p3_mds::util::apply_circulant;
+use p3_symmetric::Permutation;
+
+use crate::aarch64_neon::packing::PackedGoldilocksNeon;
+use crate::{
+    MATRIX_CIRC_MDS_8_SML_ROW, MATRIX_CIRC_MDS_12_SML_ROW, MATRIX_CIRC_MDS_16_SML_ROW,
+    MATRIX_CIRC_MDS_24_GOLDILOCKS, MdsMatrixGoldilocks,
+};
+const fn convert_array<const N: usize>(arr: [i64; N]) -> [u64; N] {
+    let mut result: [u64; N] = [0; N];
+    let mut i = 0;
+    while i < N {
+        result[i] = arr[i] as u64;
+        i += 1;
+    }
+    result
+}
+
+impl Permutation<[PackedGoldilocksNeon; 8]> for MdsMatrixGoldilocks {
+    fn permute(&self, input: [PackedGoldilocksNeon; 8]) -> [PackedGoldilocksNeon; 8] {
+        const MATRIX_CIRC_MDS_8_SML_ROW_U64: [u64; 8] = convert_array(MATRIX_CIRC_MDS_8_SML_ROW);
+        apply_circulant(&MATRIX_CIRC_MDS_8_SML_ROW_U64, input)
+    }
+
+    fn permute_mut(&self, input: &mut [PackedGoldilocksNeon; 8]) {
+        *input = self.permute(*input);
+    }
+}
+
+impl MdsPermutation<PackedGoldilocksNeon, 8> for MdsMatrixGoldilocks {}
+
+impl Permutation<[PackedGoldilocksNeon; 12]> for MdsMatrixGoldilocks {
+    fn permute(&self, input: [PackedGoldilocksNeon; 12]) -> [PackedGoldilocksNeon; 12] {
+        const MATRIX_CIRC_MDS_12_SML_ROW_U64: [u64; 12] = convert_array(MATRIX_CIRC_MDS_12_SML_ROW);
+        apply_circulant(&MATRIX_CIRC_MDS_12_SML_ROW_U64, input)
+    }
+
+    fn permute_mut(&self, input: &mut [PackedGoldilocksNeon; 12]) {
+        *input = self.permute(*input);
+    }
+}
+
+impl MdsPermutation<PackedGoldilocksNeon, 12> for MdsMatrixGoldilocks {}
+
+impl Permutation<[PackedGoldilocksNeon; 16]> for MdsMatrixGoldilocks {
+    fn permute(&self, input: [PackedGoldilocksNeon; 16]) -> [PackedGoldilocksNeon; 16] {
+        const MATRIX_CIRC_MDS_16_SML_ROW_U64: [u64; 16] = convert_array(MATRIX_CIRC_MDS_16_SML_ROW);
+        apply_circulant(&MATRIX_CIRC_MDS_16_SML_ROW_U64, input)
+    }
+
+    fn permute_mut(&self, input: &mut [PackedGoldilocksNeon; 16]) {
+        *input = self.permute(*input);
+    }
+}
+
+impl MdsPermutation<PackedGoldilocksNeon, 16> for MdsMatrixGoldilocks {}
+
+impl Permutation<[PackedGoldilocksNeon; 24]> for MdsMatrixGoldilocks {
+    fn permute(&self, input: [PackedGoldilocksNeon; 24]) -> [PackedGoldilocksNeon; 24] {
+        apply_circulant(&MATRIX_CIRC_MDS_24_GOLDILOCKS, input)
+    }
+
+    fn permute_mut(&self, input: &mut [PackedGoldilocksNeon; 24]) {
+        *input = self.permute(*input);
+    }
+}
+
+impl MdsPermutation<PackedGoldilocksNeon, 24> for MdsMatrixGoldilocks {}
+
+#[cfg(test)]
+mod tests {
+    use p3_poseidon::Poseidon;
+    use p3_symmetric::Permutation;
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::{Goldilocks, MdsMatrixGoldilocks, PackedGoldilocksNeon};
+
+    #[test]
+    fn test_neon_poseidon_width_8() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        type F = Goldilocks;
+        type Perm = Poseidon<F, MdsMatrixGoldilocks, 8, 7>;
+        let poseidon = Perm::new_from_rng(4, 22, MdsMatrixGoldilocks, &mut rng);
+
+        let input: [F; 8] = rng.random();
+
+        let mut expected = input;
+        poseidon.permute_mut(&mut expected);
+
+        let mut neon_input = input.map(Into::<PackedGoldilocksNeon>::into);
+        poseidon.permute_mut(&mut neon_input);
+
+        let neon_output = neon_input.map(|x| x.0[0]);
+        assert_eq!(neon_output, expected);
+    }
+
+    #[test]
+    fn test_neon_poseidon_width_12() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        type F = Goldilocks;
+        type Perm = Poseidon<F, MdsMatrixGoldilocks, 12, 7>;
+        let poseidon = Perm::new_from_rng(4, 22, MdsMatrixGoldilocks, &mut rng);
+
+        let input: [F; 12] = rng.random();
+
+        let mut expected = input;
+        poseidon.permute_mut(&mut expected);
+
+        let mut neon_input = input.map(Into::<PackedGoldilocksNeon>::into);
+        poseidon.permute_mut(&mut neon_input);
+
+        let neon_output = neon_input.map(|x| x.0[0]);
+        assert_eq!(neon_output, expected);
+    }
+
+    #[test]
+    fn test_neon_poseidon_width_16() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        type F = Goldilocks;
+        type Perm = Poseidon<F, MdsMatrixGoldilocks, 16, 7>;
+        let poseidon = Perm::new_from_rng(4, 22, MdsMatrixGoldilocks, &mut rng);
+
+        let input: [F; 16] = rng.random();
+
+        let mut expected = input;
+        poseidon.permute_mut(&mut expected);
+
+        let mut neon_input = input.map(Into::<PackedGoldilocksNeon>::into);
+        poseidon.permute_mut(&mut neon_input);
+
+        let neon_output = neon_input.map(|x| x.0[0]);
+        assert_eq!(neon_output, expected);
+    }
+
+    #[test]
+    fn test_neon_poseidon_width_24() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        type F = Goldilocks;
+        type Perm = Poseidon<F, MdsMatrixGoldilocks, 24, 7>;
+        let poseidon = Perm::new_from_rng(4, 22, MdsMatrixGoldilocks, &mut rng);
+
+        let input: [F; 24] = rng.random();
+
+        let mut expected = input;
+        poseidon.permute_mut(&mut expected);
+
+        let mut neon_input = input.map(Into::<PackedGoldilocksNeon>::into);
+        poseidon.permute_mut(&mut neon_input);
+
+        let neon_output = neon_input.map(|x| x.0[0]);
+        assert_eq!(neon_output, expected);
+    }
+}
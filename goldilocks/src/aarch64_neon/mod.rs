@@ -0,0 +1,3 @@
+mod mds;
+mod packing;
+pub use packing::*;
@@ -0,0 +1,326 @@
+use alloc::vec::Vec;
+use core::arch::aarch64::{self, uint64x2_t};
+use core::array;
+use core::fmt::Debug;
+use core::iter::{Product, Sum};
+use core::mem::transmute;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use p3_field::exponentiation::exp_10540996611094048183;
+use p3_field::op_assign_macros::{
+    impl_add_assign, impl_add_base_field, impl_div_methods, impl_mul_base_field, impl_mul_methods,
+    impl_packed_div_methods, impl_packed_value, impl_rng, impl_sub_assign, impl_sub_base_field,
+    impl_sum_prod_base_field, ring_sum,
+};
+use p3_field::{
+    Algebra, Field, InjectiveMonomial, PackedField, PackedFieldPow2, PackedValue,
+    PermutationMonomial, PrimeCharacteristicRing, PrimeField64,
+};
+use p3_util::reconstitute_from_base;
+use rand::Rng;
+use rand::distr::{Distribution, StandardUniform};
+
+use crate::{Goldilocks, P};
+
+const WIDTH: usize = 2;
+
+/// Vectorized NEON implementation of `Goldilocks` arithmetic.
+///
+/// Addition, subtraction, negation and halving are performed in NEON registers. NEON has no
+/// `64 x 64 -> 128` bit multiplication so products are computed lane by lane using the scalar
+/// `mul`/`umulh` pair, which is faster than emulating the wide product with 32-bit multiplies.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[repr(transparent)] // Needed to make `transmute`s safe.
+#[must_use]
+pub struct PackedGoldilocksNeon(pub [Goldilocks; WIDTH]);
+
+impl PackedGoldilocksNeon {
+    /// Get an arch-specific vector representing the packed values.
+    #[inline]
+    #[must_use]
+    pub(crate) fn to_vector(self) -> uint64x2_t {
+        unsafe {
+            // Safety: `Goldilocks` is `repr(transparent)` so it can be transmuted to `u64`. It
+            // follows that `[Goldilocks; WIDTH]` can be transmuted to `[u64; WIDTH]`, which can be
+            // transmuted to `uint64x2_t`, since arrays are guaranteed to be contiguous in memory.
+            // Finally `PackedGoldilocksNeon` is `repr(transparent)` so it can be transmuted to
+            // `[Goldilocks; WIDTH]`.
+            transmute(self)
+        }
+    }
+
+    /// Make a packed field vector from an arch-specific vector.
+    ///
+    /// Elements of `Goldilocks` are allowed to be arbitrary u64s so this function
+    /// is safe unlike the `Mersenne31/MontyField31` variants.
+    #[inline]
+    pub(crate) fn from_vector(vector: uint64x2_t) -> Self {
+        unsafe {
+            // Safety: `uint64x2_t` can be transmuted to `[u64; WIDTH]` (since arrays elements are
+            // contiguous in memory), which can be transmuted to `[Goldilocks; WIDTH]` (since
+            // `Goldilocks` is `repr(transparent)`), which in turn can be transmuted to
+            // `PackedGoldilocksNeon` (since `PackedGoldilocksNeon` is also `repr(transparent)`).
+            transmute(vector)
+        }
+    }
+
+    /// Copy `value` to all positions in a packed vector. This is the same as
+    /// `From<Goldilocks>::from`, but `const`.
+    #[inline]
+    const fn broadcast(value: Goldilocks) -> Self {
+        Self([value; WIDTH])
+    }
+}
+
+impl From<Goldilocks> for PackedGoldilocksNeon {
+    fn from(x: Goldilocks) -> Self {
+        Self::broadcast(x)
+    }
+}
+
+impl Add for PackedGoldilocksNeon {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::from_vector(add(self.to_vector(), rhs.to_vector()))
+    }
+}
+
+impl Sub for PackedGoldilocksNeon {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_vector(sub(self.to_vector(), rhs.to_vector()))
+    }
+}
+
+impl Neg for PackedGoldilocksNeon {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::from_vector(neg(self.to_vector()))
+    }
+}
+
+impl Mul for PackedGoldilocksNeon {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self(array::from_fn(|i| self.0[i] * rhs.0[i]))
+    }
+}
+
+impl_add_assign!(PackedGoldilocksNeon);
+impl_sub_assign!(PackedGoldilocksNeon);
+impl_mul_methods!(PackedGoldilocksNeon);
+ring_sum!(PackedGoldilocksNeon);
+impl_rng!(PackedGoldilocksNeon);
+
+impl PrimeCharacteristicRing for PackedGoldilocksNeon {
+    type PrimeSubfield = Goldilocks;
+
+    const ZERO: Self = Self::broadcast(Goldilocks::ZERO);
+    const ONE: Self = Self::broadcast(Goldilocks::ONE);
+    const TWO: Self = Self::broadcast(Goldilocks::TWO);
+    const NEG_ONE: Self = Self::broadcast(Goldilocks::NEG_ONE);
+
+    #[inline]
+    fn from_prime_subfield(f: Self::PrimeSubfield) -> Self {
+        f.into()
+    }
+
+    #[inline]
+    fn halve(&self) -> Self {
+        Self::from_vector(halve(self.to_vector()))
+    }
+
+    #[inline]
+    fn square(&self) -> Self {
+        Self(self.0.map(|x| x.square()))
+    }
+
+    #[inline]
+    fn zero_vec(len: usize) -> Vec<Self> {
+        // SAFETY: this is a repr(transparent) wrapper around an array.
+        unsafe { reconstitute_from_base(Goldilocks::zero_vec(len * WIDTH)) }
+    }
+}
+
+// Degree of the smallest permutation polynomial for Goldilocks.
+//
+// As p - 1 = 2^32 * 3 * 5 * 17 * ... the smallest choice for a degree D satisfying gcd(p - 1, D) = 1 is 7.
+impl InjectiveMonomial<7> for PackedGoldilocksNeon {}
+
+impl PermutationMonomial<7> for PackedGoldilocksNeon {
+    /// In the field `Goldilocks`, `a^{1/7}` is equal to a^{10540996611094048183}.
+    ///
+    /// This follows from the calculation `7*10540996611094048183 = 4*(2^64 - 2**32) + 1 = 1 mod (p - 1)`.
+    fn injective_exp_root_n(&self) -> Self {
+        exp_10540996611094048183(*self)
+    }
+}
+
+impl_add_base_field!(PackedGoldilocksNeon, Goldilocks);
+impl_sub_base_field!(PackedGoldilocksNeon, Goldilocks);
+impl_mul_base_field!(PackedGoldilocksNeon, Goldilocks);
+impl_div_methods!(PackedGoldilocksNeon, Goldilocks);
+impl_packed_div_methods!(PackedGoldilocksNeon);
+impl_sum_prod_base_field!(PackedGoldilocksNeon, Goldilocks);
+
+impl Algebra<Goldilocks> for PackedGoldilocksNeon {}
+
+impl_packed_value!(PackedGoldilocksNeon, Goldilocks, WIDTH);
+
+unsafe impl PackedField for PackedGoldilocksNeon {
+    type Scalar = Goldilocks;
+}
+
+unsafe impl PackedFieldPow2 for PackedGoldilocksNeon {
+    #[inline]
+    fn interleave(&self, other: Self, block_len: usize) -> (Self, Self) {
+        let (v0, v1) = (self.to_vector(), other.to_vector());
+        let (res0, res1) = match block_len {
+            // We want this to compile to:
+            //      trn1  res0.2d, v0.2d, v1.2d
+            //      trn2  res1.2d, v0.2d, v1.2d
+            1 => unsafe {
+                // Safety: If this code got compiled then NEON intrinsics are available.
+                (aarch64::vtrn1q_u64(v0, v1), aarch64::vtrn2q_u64(v0, v1))
+            },
+            WIDTH => (v0, v1),
+            _ => panic!("unsupported block_len"),
+        };
+        (Self::from_vector(res0), Self::from_vector(res1))
+    }
+}
+
+// Unlike AVX2, NEON has native unsigned 64-bit comparisons, so none of the shifting tricks
+// needed to emulate them on x86 are required here. Comparisons return all ones for true and
+// zero for false, so shifting a comparison mask right by 32 gives exactly 2^32 - 1 = 2^64 mod P
+// (or 0), which is the correction needed after a wrapping addition or subtraction.
+
+const P_VEC: uint64x2_t = unsafe { transmute([P; WIDTH]) };
+
+/// Equal to 2^32 - 1 = 2^64 mod P.
+const EPSILON: uint64x2_t = unsafe { transmute([Goldilocks::ORDER_U64.wrapping_neg(); WIDTH]) };
+
+/// Convert to canonical representation.
+///
+/// Input can be arbitrary, output is guaranteed to be less than `FIELD_ORDER`.
+#[inline]
+fn canonicalize(x: uint64x2_t) -> uint64x2_t {
+    unsafe {
+        // Safety: If this code got compiled then NEON intrinsics are available.
+        // If x >= FIELD_ORDER then the corresponding mask bits are all 1; otherwise all 0.
+        let mask = aarch64::vcgeq_u64(x, P_VEC);
+        // Adding EPSILON = 2^64 - FIELD_ORDER is the same as subtracting FIELD_ORDER.
+        let wrapback_amt = aarch64::vandq_u64(mask, EPSILON);
+        aarch64::vaddq_u64(x, wrapback_amt)
+    }
+}
+
+/// Goldilocks modular addition. Computes `x + y mod FIELD_ORDER`.
+///
+/// Inputs can be arbitrary, output is not guaranteed to be less than `FIELD_ORDER`.
+#[inline]
+fn add(x: uint64x2_t, y: uint64x2_t) -> uint64x2_t {
+    /*
+        We want this to compile to:
+            cmhs  mask.2d, y.2d, P.2d
+            and   wrap.16b, mask.16b, EPSILON.16b
+            add   y_c.2d, y.2d, wrap.2d
+            add   res_wrapped.2d, x.2d, y_c.2d
+            cmhi  overflow.2d, y_c.2d, res_wrapped.2d
+            usra  res.2d, overflow.2d, #32
+    */
+    unsafe {
+        // Safety: If this code got compiled then NEON intrinsics are available.
+        // As y_c < FIELD_ORDER, x + y_c < 2^64 + FIELD_ORDER so at most one correction is needed.
+        let y_c = canonicalize(y);
+        let res_wrapped = aarch64::vaddq_u64(x, y_c);
+        let mask = aarch64::vcltq_u64(res_wrapped, y_c); // -1 if overflowed else 0.
+        aarch64::vsraq_n_u64::<32>(res_wrapped, mask) // Adds EPSILON if overflowed else 0.
+    }
+}
+
+/// Goldilocks modular subtraction. Computes `x - y mod FIELD_ORDER`.
+///
+/// Inputs can be arbitrary, output is not guaranteed to be less than `FIELD_ORDER`.
+#[inline]
+fn sub(x: uint64x2_t, y: uint64x2_t) -> uint64x2_t {
+    unsafe {
+        // Safety: If this code got compiled then NEON intrinsics are available.
+        // If x < y_c then x - y_c + 2^64 > 2^64 - FIELD_ORDER = EPSILON so the correction cannot underflow.
+        let y_c = canonicalize(y);
+        let res_wrapped = aarch64::vsubq_u64(x, y_c);
+        let mask = aarch64::vcltq_u64(x, y_c); // -1 if underflowed else 0.
+        let wrapback_amt = aarch64::vshrq_n_u64::<32>(mask); // EPSILON if underflowed else 0.
+        aarch64::vsubq_u64(res_wrapped, wrapback_amt)
+    }
+}
+
+/// Goldilocks modular negation. Computes `-x mod FIELD_ORDER`.
+///
+/// Input can be arbitrary, output is not guaranteed to be less than `FIELD_ORDER`.
+#[inline]
+fn neg(y: uint64x2_t) -> uint64x2_t {
+    unsafe {
+        // Safety: If this code got compiled then NEON intrinsics are available.
+        aarch64::vsubq_u64(P_VEC, canonicalize(y))
+    }
+}
+
+/// Halve a vector of Goldilocks field elements.
+#[inline(always)]
+pub(crate) fn halve(input: uint64x2_t) -> uint64x2_t {
+    /*
+        We want this to compile to:
+            cmtst    is_odd, val, ONE
+            and      maybe_half, HALF, is_odd
+            usra     maybe_half, val, #1
+        throughput: .75 cyc/vec
+        latency: 5 cyc
+
+        Given an element val, we want to compute val/2 mod P.
+        If val is even: val/2 mod P = val/2 = val >> 1.
+        If val is odd: val/2 mod P = (val + P)/2 = (val >> 1) + (P + 1)/2
+        This holds for any u64 val, not just those in [0, P), and never overflows.
+    */
+    unsafe {
+        // Safety: If this code got compiled then NEON intrinsics are available.
+        let one = aarch64::vdupq_n_u64(1);
+        let half = aarch64::vdupq_n_u64(P.div_ceil(2));
+
+        // is_odd = 0xFFFF_FFFF_FFFF_FFFF when the least bit is set, else 0.
+        let is_odd = aarch64::vtstq_u64(input, one);
+        let maybe_half = aarch64::vandq_u64(half, is_odd);
+        aarch64::vsraq_n_u64::<1>(maybe_half, input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_field_testing::test_packed_field;
+
+    use super::{Goldilocks, PackedGoldilocksNeon, WIDTH};
+
+    const SPECIAL_VALS: [Goldilocks; WIDTH] =
+        Goldilocks::new_array([0xFFFF_FFFF_0000_0000, 0xFFFF_FFFF_FFFF_FFFF]);
+
+    const ZEROS: PackedGoldilocksNeon = PackedGoldilocksNeon(Goldilocks::new_array([
+        0x0000_0000_0000_0000,
+        0xFFFF_FFFF_0000_0001,
+    ]));
+
+    const ONES: PackedGoldilocksNeon = PackedGoldilocksNeon(Goldilocks::new_array([
+        0x0000_0000_0000_0001,
+        0xFFFF_FFFF_0000_0002,
+    ]));
+
+    test_packed_field!(
+        crate::PackedGoldilocksNeon,
+        &[super::ZEROS],
+        &[super::ONES],
+        crate::PackedGoldilocksNeon(super::SPECIAL_VALS)
+    );
+}
@@ -1,11 +1,30 @@
 use p3_field::extension::{
     BinomiallyExtendable, BinomiallyExtendableAlgebra, HasTwoAdicBinomialExtension,
+    packed_binomial_mul, packed_vector_add, packed_vector_sub,
 };
 use p3_field::{PrimeCharacteristicRing, TwoAdicField, field_to_array};
 
 use crate::Goldilocks;
 
-impl BinomiallyExtendableAlgebra<Goldilocks, 2> for Goldilocks {}
+// The 64-bit lanes of the Goldilocks packing hold the two coefficients of an element, so the
+// arithmetic goes through the generic packed helpers rather than the `u32` lane helpers used by the
+// 31-bit fields.
+impl BinomiallyExtendableAlgebra<Goldilocks, 2> for Goldilocks {
+    #[inline(always)]
+    fn binomial_mul(a: &[Self; 2], b: &[Self; 2], res: &mut [Self; 2], w: Self) {
+        packed_binomial_mul(a, b, res, w);
+    }
+
+    #[inline(always)]
+    fn binomial_add(a: &[Self; 2], b: &[Self; 2]) -> [Self; 2] {
+        packed_vector_add(a, b)
+    }
+
+    #[inline(always)]
+    fn binomial_sub(a: &[Self; 2], b: &[Self; 2]) -> [Self; 2] {
+        packed_vector_sub(a, b)
+    }
+}
 
 impl BinomiallyExtendable<2> for Goldilocks {
     // Verifiable in Sage with
@@ -78,7 +97,7 @@ mod test_quadratic_extension {
     use p3_field::extension::BinomialExtensionField;
     use p3_field::{ExtensionField, PrimeCharacteristicRing};
     use p3_field_testing::{
-        test_extension_field, test_field, test_packed_extension_field,
+        test_binomial_arithmetic, test_extension_field, test_field, test_packed_extension_field,
         test_two_adic_extension_field,
     };
 
@@ -122,6 +141,11 @@ mod test_quadratic_extension {
     const PACKED_ZEROS: [Pef; 1] = [Pef::ZERO];
     const PACKED_ONES: [Pef; 1] = [Pef::ONE];
     test_packed_extension_field!(super::Pef, &super::PACKED_ZEROS, &super::PACKED_ONES);
+
+    #[test]
+    fn test_packed_binomial_arithmetic() {
+        test_binomial_arithmetic::<F, 2>();
+    }
 }
 
 #[cfg(test)]
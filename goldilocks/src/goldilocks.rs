@@ -13,9 +13,10 @@ use p3_field::op_assign_macros::{
     impl_add_assign, impl_div_methods, impl_mul_methods, impl_sub_assign,
 };
 use p3_field::{
-    Field, InjectiveMonomial, Packable, PermutationMonomial, PrimeCharacteristicRing, PrimeField,
-    PrimeField64, RawDataSerializable, TwoAdicField, halve_u64, impl_raw_serializable_primefield64,
-    quotient_map_large_iint, quotient_map_large_uint, quotient_map_small_int,
+    ConstantTimeField, Field, InjectiveMonomial, LegendreSymbol, Packable, PermutationMonomial,
+    PrimeCharacteristicRing, PrimeField, PrimeField64, RawDataSerializable, TwoAdicField,
+    ct_mask_u64, halve_u64, impl_raw_serializable_primefield64, quotient_map_large_iint,
+    quotient_map_large_uint, quotient_map_small_int, tonelli_shanks,
 };
 use p3_util::{assume, branch_hint, flatten_to_base, gcd_inner};
 use rand::Rng;
@@ -307,8 +308,8 @@ impl RawDataSerializable for Goldilocks {
 }
 
 impl Field for Goldilocks {
-    // TODO: Add cfg-guarded Packing for NEON
-
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    type Packing = crate::PackedGoldilocksNeon;
     #[cfg(all(
         target_arch = "x86_64",
         target_feature = "avx2",
@@ -319,6 +320,7 @@ impl Field for Goldilocks {
     #[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
     type Packing = crate::PackedGoldilocksAVX512;
     #[cfg(not(any(
+        all(target_arch = "aarch64", target_feature = "neon"),
         all(
             target_arch = "x86_64",
             target_feature = "avx2",
@@ -343,6 +345,23 @@ impl Field for Goldilocks {
         Some(gcd_inversion(*self))
     }
 
+    #[inline]
+    fn legendre_symbol(&self) -> LegendreSymbol {
+        LegendreSymbol::from_euler_criterion(self, self.exp_u64((P - 1) >> 1))
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        // P - 1 = 2^32 (2^32 - 1).
+        const TWO_ADICITY: usize = 32;
+        const ODD_FACTOR: u64 = (P - 1) >> TWO_ADICITY;
+        tonelli_shanks(
+            *self,
+            TWO_ADICITY,
+            self.exp_u64(ODD_FACTOR >> 1),
+            Self::GENERATOR.exp_u64(ODD_FACTOR),
+        )
+    }
+
     #[inline]
     fn order() -> BigUint {
         P.into()
@@ -478,6 +497,41 @@ const fn const_add(lhs: Goldilocks, rhs: Goldilocks) -> Goldilocks {
     Goldilocks::new(sum)
 }
 
+impl ConstantTimeField for Goldilocks {
+    #[inline]
+    fn ct_add(&self, rhs: &Self) -> Self {
+        // As in `add`, but the corrections are masked rather than branched on. The masks go
+        // through `ct_mask_u64` as otherwise the compiler may turn them back into branches once
+        // this is inlined into a loop.
+        let (sum, over) = self.value.overflowing_add(rhs.value);
+        let (sum, over) = sum.overflowing_add(ct_mask_u64(over) & Self::NEG_ORDER);
+        Self::new(sum.wrapping_add(ct_mask_u64(over) & Self::NEG_ORDER))
+    }
+
+    #[inline]
+    fn ct_sub(&self, rhs: &Self) -> Self {
+        let (diff, under) = self.value.overflowing_sub(rhs.value);
+        let (diff, under) = diff.overflowing_sub(ct_mask_u64(under) & Self::NEG_ORDER);
+        Self::new(diff.wrapping_sub(ct_mask_u64(under) & Self::NEG_ORDER))
+    }
+
+    #[inline]
+    fn ct_neg(&self) -> Self {
+        Self::ZERO.ct_sub(self)
+    }
+
+    #[inline]
+    fn ct_mul(&self, rhs: &Self) -> Self {
+        ct_reduce128(u128::from(self.value) * u128::from(rhs.value))
+    }
+
+    #[inline]
+    fn ct_select(&self, rhs: &Self, choice: bool) -> Self {
+        let mask = ct_mask_u64(choice);
+        Self::new(self.value ^ ((self.value ^ rhs.value) & mask))
+    }
+}
+
 impl Add for Goldilocks {
     type Output = Self;
 
@@ -579,6 +633,23 @@ pub(crate) fn reduce128(x: u128) -> Goldilocks {
     Goldilocks::new(t2)
 }
 
+/// Constant-time version of [`reduce128`]. The result might not be in canonical form.
+///
+/// Both the borrow in the first subtraction and the carry in the final addition are corrected
+/// with masks rather than branches, so the running time does not depend on `x`.
+#[inline]
+fn ct_reduce128(x: u128) -> Goldilocks {
+    let (x_lo, x_hi) = split(x);
+    let x_hi_hi = x_hi >> 32;
+    let x_hi_lo = x_hi & Goldilocks::NEG_ORDER;
+
+    let (t0, borrow) = x_lo.overflowing_sub(x_hi_hi);
+    let t0 = t0.wrapping_sub(ct_mask_u64(borrow) & Goldilocks::NEG_ORDER); // Cannot underflow.
+    let t1 = x_hi_lo * Goldilocks::NEG_ORDER;
+    let (t2, carry) = t0.overflowing_add(t1);
+    Goldilocks::new(t2.wrapping_add(ct_mask_u64(carry) & Goldilocks::NEG_ORDER)) // Cannot overflow.
+}
+
 #[inline]
 #[allow(clippy::cast_possible_truncation)]
 const fn split(x: u128) -> (u64, u64) {
@@ -675,7 +746,8 @@ fn from_unusual_int(int: i64) -> Goldilocks {
 mod tests {
     use p3_field::extension::BinomialExtensionField;
     use p3_field_testing::{
-        test_field, test_field_dft, test_prime_field, test_prime_field_64, test_two_adic_field,
+        test_constant_time_field, test_field, test_field_dft, test_prime_field,
+        test_prime_field_64, test_two_adic_field,
     };
 
     use super::*;
@@ -748,6 +820,7 @@ mod tests {
     );
     test_prime_field!(crate::Goldilocks);
     test_prime_field_64!(crate::Goldilocks, &super::ZEROS, &super::ONES);
+    test_constant_time_field!(crate::Goldilocks, &super::ZEROS, &super::ONES);
     test_two_adic_field!(crate::Goldilocks);
 
     test_field_dft!(
@@ -757,6 +830,12 @@ mod tests {
         p3_dft::Radix2Dit<_>
     );
     test_field_dft!(bowers, crate::Goldilocks, super::EF, p3_dft::Radix2Bowers);
+    test_field_dft!(
+        four_step,
+        crate::Goldilocks,
+        super::EF,
+        p3_dft::FourStepDft<p3_dft::Radix2Dit<crate::Goldilocks>>
+    );
     test_field_dft!(
         parallel,
         crate::Goldilocks,
@@ -13,6 +13,11 @@ pub use goldilocks::*;
 pub use mds::*;
 pub use poseidon2::*;
 
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod aarch64_neon;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+pub use aarch64_neon::*;
+
 #[cfg(all(
     target_arch = "x86_64",
     target_feature = "avx2",
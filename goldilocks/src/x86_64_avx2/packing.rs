@@ -9,8 +9,8 @@ use p3_field::exponentiation::exp_10540996611094048183;
 use p3_field::interleave::{interleave_u64, interleave_u128};
 use p3_field::op_assign_macros::{
     impl_add_assign, impl_add_base_field, impl_div_methods, impl_mul_base_field, impl_mul_methods,
-    impl_packed_value, impl_rng, impl_sub_assign, impl_sub_base_field, impl_sum_prod_base_field,
-    ring_sum,
+    impl_packed_div_methods, impl_packed_value, impl_rng, impl_sub_assign, impl_sub_base_field,
+    impl_sum_prod_base_field, ring_sum,
 };
 use p3_field::{
     Algebra, Field, InjectiveMonomial, PackedField, PackedFieldPow2, PackedValue,
@@ -160,6 +160,7 @@ impl_add_base_field!(PackedGoldilocksAVX2, Goldilocks);
 impl_sub_base_field!(PackedGoldilocksAVX2, Goldilocks);
 impl_mul_base_field!(PackedGoldilocksAVX2, Goldilocks);
 impl_div_methods!(PackedGoldilocksAVX2, Goldilocks);
+impl_packed_div_methods!(PackedGoldilocksAVX2);
 impl_sum_prod_base_field!(PackedGoldilocksAVX2, Goldilocks);
 
 impl Algebra<Goldilocks> for PackedGoldilocksAVX2 {}
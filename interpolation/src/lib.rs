@@ -8,7 +8,8 @@ use alloc::vec::Vec;
 
 use p3_field::coset::TwoAdicMultiplicativeCoset;
 use p3_field::{
-    ExtensionField, TwoAdicField, batch_multiplicative_inverse, scale_slice_in_place_single_core,
+    ExtensionField, Field, TwoAdicField, batch_multiplicative_inverse,
+    scale_slice_in_place_single_core,
 };
 use p3_matrix::Matrix;
 use p3_maybe_rayon::prelude::*;
@@ -126,6 +127,167 @@ where
     evals
 }
 
+/// Given evaluations of a batch of polynomials over the given coset of the canonical power-of-two
+/// subgroup, evaluate the polynomials at each of `points`.
+///
+/// Returns one vector of column evaluations per point. This is equivalent to calling
+/// `interpolate_coset` for each point, but computes the coset only once and inverts the
+/// differences between all points and all coset elements with a single batch inversion.
+///
+/// This assumes none of the points are in the coset, otherwise the behavior is undefined.
+///
+/// The `coset_evals` must be given in standard (not bit-reversed) order.
+pub fn interpolate_coset_at_points<F, EF, Mat>(
+    coset_evals: &Mat,
+    shift: F,
+    points: &[EF],
+) -> Vec<Vec<EF>>
+where
+    F: TwoAdicField,
+    EF: ExtensionField<F>,
+    Mat: Matrix<F>,
+{
+    let height = coset_evals.height();
+    let log_height = log2_strict_usize(height);
+
+    let coset: Vec<F> = TwoAdicMultiplicativeCoset::new(shift, log_height)
+        .unwrap()
+        .iter()
+        .collect();
+
+    // Compute `1/(z_j - gh^i)` for every point and every element of the coset at once.
+    let diffs: Vec<EF> = points
+        .iter()
+        .flat_map(|&z| coset.iter().map(move |&g| z - g))
+        .collect();
+    let diff_invs = batch_multiplicative_inverse(&diffs);
+
+    points
+        .iter()
+        .zip(diff_invs.chunks_exact(height))
+        .map(|(&point, diff_invs)| {
+            interpolate_coset_with_precomputation(coset_evals, shift, point, &coset, diff_invs)
+        })
+        .collect()
+}
+
+/// Compute the barycentric weights `w_i = 1 / prod_{j != i} (x_i - x_j)` of the given points.
+///
+/// This takes `O(n^2)` operations, so callers interpolating repeatedly over the same points
+/// should compute the weights once and use `interpolate_lagrange_with_weights`.
+///
+/// # Panics
+///
+/// Panics if the points are not distinct.
+pub fn barycentric_weights<F: Field>(points: &[F]) -> Vec<F> {
+    let denominators: Vec<F> = points
+        .par_iter()
+        .enumerate()
+        .map(|(i, &x_i)| {
+            points
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, &x_j)| x_i - x_j)
+                .product()
+        })
+        .collect();
+    assert!(
+        denominators.iter().all(|d| !d.is_zero()),
+        "interpolation points must be distinct"
+    );
+    batch_multiplicative_inverse(&denominators)
+}
+
+/// Given evaluations of a batch of polynomials at arbitrary distinct `points`, with row `i` of
+/// `evals` holding the evaluations at `points[i]`, evaluate the polynomials at `point`.
+///
+/// The polynomials are taken to be of degree less than the number of points.
+///
+/// # Panics
+///
+/// Panics if the points are not distinct.
+pub fn interpolate_lagrange<F, EF, Mat>(points: &[F], evals: &Mat, point: EF) -> Vec<EF>
+where
+    F: Field,
+    EF: ExtensionField<F>,
+    Mat: Matrix<F>,
+{
+    let weights = barycentric_weights(points);
+    interpolate_lagrange_with_weights(points, &weights, evals, point)
+}
+
+/// Like `interpolate_lagrange`, but takes the precomputed `weights` of the points, as returned
+/// by `barycentric_weights`.
+pub fn interpolate_lagrange_with_weights<F, EF, Mat>(
+    points: &[F],
+    weights: &[F],
+    evals: &Mat,
+    point: EF,
+) -> Vec<EF>
+where
+    F: Field,
+    EF: ExtensionField<F>,
+    Mat: Matrix<F>,
+{
+    debug_assert_eq!(points.len(), weights.len());
+    debug_assert_eq!(points.len(), evals.height());
+
+    // The barycentric formula divides by zero at the points themselves.
+    if let Some(i) = points.iter().position(|&x| point == EF::from(x)) {
+        return evals.row(i).unwrap().into_iter().map(EF::from).collect();
+    }
+
+    // With `l(z) = prod_i (z - x_i)`, we have `f(z) = l(z) * sum_i w_i / (z - x_i) * f(x_i)`.
+    let diffs: Vec<EF> = points.iter().map(|&x| point - x).collect();
+    let l_at_point: EF = diffs.iter().copied().product();
+    let col_scale: Vec<EF> = batch_multiplicative_inverse(&diffs)
+        .into_iter()
+        .zip(weights)
+        .map(|(diff_inv, &w)| diff_inv * w)
+        .collect();
+
+    let mut evals = evals.columnwise_dot_product(&col_scale);
+    scale_slice_in_place_single_core(&mut evals, l_at_point);
+    evals
+}
+
+/// Compute the coefficients, lowest degree first, of the unique polynomial of degree less than
+/// `points.len()` taking the value `values[i]` at `points[i]`.
+///
+/// This takes `O(n^2)` operations.
+///
+/// # Panics
+///
+/// Panics if the points are not distinct, or if the numbers of points and values differ.
+pub fn lagrange_interpolation_coeffs<F: Field>(points: &[F], values: &[F]) -> Vec<F> {
+    assert_eq!(points.len(), values.len());
+    let n = points.len();
+    let weights = barycentric_weights(points);
+
+    // The coefficients of `l(x) = prod_i (x - x_i)`.
+    let mut l = F::zero_vec(n + 1);
+    l[0] = F::ONE;
+    for (k, &x) in points.iter().enumerate() {
+        for j in (1..=k + 1).rev() {
+            l[j] = l[j - 1] - x * l[j];
+        }
+        l[0] = -x * l[0];
+    }
+
+    // Add up `values[i] * w_i * l(x) / (x - x_i)`, dividing `l` synthetically.
+    let mut coeffs = F::zero_vec(n);
+    for ((&x, &y), w) in points.iter().zip(values).zip(weights) {
+        let scale = y * w;
+        let mut quotient_coeff = F::ZERO;
+        for j in (0..n).rev() {
+            quotient_coeff = l[j + 1] + x * quotient_coeff;
+            coeffs[j] += scale * quotient_coeff;
+        }
+    }
+    coeffs
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::vec;
@@ -133,11 +295,18 @@ mod tests {
 
     use p3_baby_bear::BabyBear;
     use p3_field::extension::BinomialExtensionField;
-    use p3_field::{Field, PrimeCharacteristicRing, TwoAdicField, batch_multiplicative_inverse};
+    use p3_field::{
+        BasedVectorSpace, ExtensionField, Field, PrimeCharacteristicRing, TwoAdicField,
+        batch_multiplicative_inverse,
+    };
     use p3_matrix::dense::RowMajorMatrix;
     use p3_util::log2_strict_usize;
 
-    use crate::{interpolate_coset, interpolate_coset_with_precomputation, interpolate_subgroup};
+    use crate::{
+        barycentric_weights, interpolate_coset, interpolate_coset_at_points,
+        interpolate_coset_with_precomputation, interpolate_lagrange,
+        interpolate_lagrange_with_weights, interpolate_subgroup, lagrange_interpolation_coeffs,
+    };
 
     #[test]
     fn test_interpolate_subgroup() {
@@ -280,4 +449,92 @@ mod tests {
 
         assert_eq!(result, vec![expected_f1, expected_f2]);
     }
+
+    #[test]
+    fn test_interpolate_coset_at_points() {
+        type F = BabyBear;
+        type EF4 = BinomialExtensionField<BabyBear, 4>;
+
+        let shift = F::GENERATOR;
+        let coset = F::two_adic_generator(4).shifted_powers(shift).collect_n(16);
+        let f1 = |x: EF4| x * x * x + x * F::TWO + F::from_u32(7);
+        let f2 = |x: EF4| x * x * F::from_u32(9) + F::ONE;
+        let evals: Vec<F> = coset
+            .iter()
+            .flat_map(|&x| {
+                let x = EF4::from(x);
+                [f1(x), f2(x)].map(|y| y.as_base().unwrap())
+            })
+            .collect();
+        let evals_mat = RowMajorMatrix::new(evals, 2);
+
+        let points = [EF4::from_u32(77), EF4::from_u32(5), EF4::GENERATOR];
+        let results = interpolate_coset_at_points(&evals_mat, shift, &points);
+        assert_eq!(results.len(), points.len());
+        for (&point, result) in points.iter().zip(results) {
+            assert_eq!(result, interpolate_coset(&evals_mat, shift, point));
+            assert_eq!(result, vec![f1(point), f2(point)]);
+        }
+    }
+
+    #[test]
+    fn test_interpolate_lagrange_arbitrary_points() {
+        type F = BabyBear;
+        type EF4 = BinomialExtensionField<BabyBear, 4>;
+
+        // f1(x) = x^2 + 2x + 3, f2(x) = 4x^2 + 5x + 6 at three arbitrary points.
+        let f1 = |x: F| x * x + x * F::TWO + F::from_u32(3);
+        let f2 = |x: F| x * x * F::from_u32(4) + x * F::from_u32(5) + F::from_u32(6);
+        let points = [11, 3, 1000].map(F::from_u32);
+        let evals: Vec<F> = points.iter().flat_map(|&x| [f1(x), f2(x)]).collect();
+        let evals_mat = RowMajorMatrix::new(evals, 2);
+
+        let point = F::from_u32(42);
+        assert_eq!(
+            interpolate_lagrange(&points, &evals_mat, point),
+            vec![f1(point), f2(point)]
+        );
+
+        // Extension field point, with precomputed weights.
+        let weights = barycentric_weights(&points);
+        let point = EF4::from_basis_coefficients_fn(|i| F::from_usize(i + 10));
+        let expected = |c: [u32; 3]| {
+            point * point * F::from_u32(c[0]) + point * F::from_u32(c[1]) + F::from_u32(c[2])
+        };
+        assert_eq!(
+            interpolate_lagrange_with_weights(&points, &weights, &evals_mat, point),
+            vec![expected([1, 2, 3]), expected([4, 5, 6])]
+        );
+
+        // At one of the points, the row itself is returned.
+        assert_eq!(
+            interpolate_lagrange(&points, &evals_mat, points[1]),
+            vec![f1(points[1]), f2(points[1])]
+        );
+    }
+
+    #[test]
+    fn test_lagrange_interpolation_coeffs() {
+        type F = BabyBear;
+
+        // x^3 + 2x^2 + 3x + 4
+        let coeffs = [4, 3, 2, 1].map(F::from_u32);
+        let f = |x: F| coeffs.iter().rev().fold(F::ZERO, |acc, &c| acc * x + c);
+        let points = [5, 17, 2, 99].map(F::from_u32);
+        let values = points.map(f);
+        assert_eq!(lagrange_interpolation_coeffs(&points, &values), coeffs);
+
+        // A single point gives a constant.
+        assert_eq!(
+            lagrange_interpolation_coeffs(&[F::TWO], &[F::from_u32(9)]),
+            vec![F::from_u32(9)]
+        );
+    }
+
+    #[test]
+    #[should_panic = "distinct"]
+    fn test_barycentric_weights_repeated_points() {
+        type F = BabyBear;
+        barycentric_weights(&[F::ONE, F::TWO, F::ONE]);
+    }
 }
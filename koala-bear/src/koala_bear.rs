@@ -109,8 +109,8 @@ mod tests {
     use p3_field::extension::BinomialExtensionField;
     use p3_field::{InjectiveMonomial, PermutationMonomial, PrimeField64, TwoAdicField};
     use p3_field_testing::{
-        test_field, test_field_dft, test_prime_field, test_prime_field_32, test_prime_field_64,
-        test_two_adic_field,
+        test_constant_time_field, test_field, test_field_dft, test_prime_field,
+        test_prime_field_32, test_prime_field_64, test_two_adic_field,
     };
 
     use super::*;
@@ -213,5 +213,6 @@ mod tests {
     );
     test_prime_field!(crate::KoalaBear);
     test_prime_field_64!(crate::KoalaBear, &super::ZEROS, &super::ONES);
+    test_constant_time_field!(crate::KoalaBear, &super::ZEROS, &super::ONES);
     test_prime_field_32!(crate::KoalaBear, &super::ZEROS, &super::ONES);
 }
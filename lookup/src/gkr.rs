@@ -0,0 +1,402 @@
+//! A GKR protocol for sums of fractions.
+//!
+//! Given multilinear numerators `p` and denominators `q` over `{0,1}^n`, the circuit computes
+//! ```text
+//! ∑_x p(x) / q(x)
+//! ```
+//! by adding fractions pairwise, layer by layer. Each layer halves the number of fractions:
+//! ```text
+//! p'(x) = p(0, x) q(1, x) + p(1, x) q(0, x),
+//! q'(x) = q(0, x) q(1, x),
+//! ```
+//! until a single fraction remains. The verifier starts from this root fraction and reduces a claim
+//! about `p', q'` at a random point to a claim about `p, q` at a random point with a degree three
+//! sumcheck, down to the input layer. What remains is a claim about the evaluations of the input
+//! numerators and denominators at a random point, which the caller checks.
+
+use alloc::vec::Vec;
+
+use p3_challenger::FieldChallenger;
+use p3_field::{ExtensionField, Field};
+use p3_maybe_rayon::prelude::*;
+use p3_multilinear_util::poly::{MultilinearPoly, eval_eq};
+use p3_sumcheck::{SumcheckError, SumcheckProof};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+/// A proof that a sum of fractions reduces to the root fraction.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FractionalSumProof<EF> {
+    /// The numerator of the root fraction.
+    pub numerator: EF,
+    /// The denominator of the root fraction.
+    pub denominator: EF,
+    /// The reductions from each layer to the next, starting from the root.
+    pub layers: Vec<GkrLayerProof<EF>>,
+}
+
+/// The reduction of a claim about one layer to a claim about the layer below it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GkrLayerProof<EF> {
+    /// The sumcheck of `eq(ρ, x) (p'(x) + λ q'(x))`, with round polynomials given by their
+    /// evaluations at `0, 1, 2, 3`.
+    pub sumcheck_proof: SumcheckProof<EF>,
+    /// The evaluations of `p(0, x), p(1, x)` at the sumcheck point.
+    pub numerators: [EF; 2],
+    /// The evaluations of `q(0, x), q(1, x)` at the sumcheck point.
+    pub denominators: [EF; 2],
+}
+
+/// The claim the verifier is left with once all layers have been reduced.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FractionalSumClaim<EF> {
+    /// The sum of all fractions, i.e. the root fraction.
+    pub sum: EF,
+    /// The random point the input layer is evaluated at.
+    pub point: Vec<EF>,
+    /// The claimed evaluation of the input numerators at `point`.
+    pub numerator: EF,
+    /// The claimed evaluation of the input denominators at `point`.
+    pub denominator: EF,
+}
+
+/// Defines errors that can occur during the verification of a GKR fractional sum proof.
+#[derive(Debug, PartialEq, Eq)]
+pub enum GkrError {
+    /// The proof does not have one layer per input variable.
+    InvalidProofShape,
+    /// The denominator of the root fraction is zero.
+    ZeroDenominator,
+    /// The sumcheck of a layer failed.
+    Sumcheck {
+        /// The index of the layer, starting from the root.
+        layer: usize,
+        /// The error returned by the sumcheck verifier.
+        error: SumcheckError,
+    },
+    /// The evaluations sent at the end of a layer are inconsistent with its sumcheck.
+    LayerMismatch {
+        /// The index of the layer, starting from the root.
+        layer: usize,
+    },
+}
+
+/// Prove the value of `∑_x p(x) / q(x)`.
+///
+/// The root fraction is observed by the challenger, so the transcript is bound to the sum. Returns
+/// the proof along with the random point where the input layer has to be evaluated.
+///
+/// # Panics
+///
+/// Panics if the numerators and denominators do not have the same number of variables.
+#[instrument(name = "prove fractional sum", skip_all, fields(num_vars = numerators.num_vars()))]
+pub fn prove_fractional_sum<F, EF, Challenger>(
+    numerators: MultilinearPoly<EF>,
+    denominators: MultilinearPoly<EF>,
+    challenger: &mut Challenger,
+) -> (FractionalSumProof<EF>, Vec<EF>)
+where
+    F: Field,
+    EF: ExtensionField<F>,
+    Challenger: FieldChallenger<F>,
+{
+    assert_eq!(numerators.num_vars(), denominators.num_vars());
+
+    // The layers of the circuit, from the input layer to the root.
+    let mut layers = Vec::with_capacity(numerators.num_vars() + 1);
+    layers.push((numerators, denominators));
+    while layers.last().unwrap().0.num_vars() > 0 {
+        let (p, q) = layers.last().unwrap();
+        layers.push(add_halves(p.evals(), q.evals()));
+    }
+
+    let (root, inner_layers) = layers.split_last().unwrap();
+    let numerator = root.0.evals()[0];
+    let denominator = root.1.evals()[0];
+    challenger.observe_algebra_element(numerator);
+    challenger.observe_algebra_element(denominator);
+
+    let mut point = Vec::new();
+    let layer_proofs = inner_layers
+        .iter()
+        .rev()
+        .map(|(p, q)| {
+            let (layer_proof, next_point) = prove_layer(p, q, &point, challenger);
+            point = next_point;
+            layer_proof
+        })
+        .collect();
+
+    (
+        FractionalSumProof {
+            numerator,
+            denominator,
+            layers: layer_proofs,
+        },
+        point,
+    )
+}
+
+/// Verify a proof of a sum of fractions over `{0,1}^num_vars`.
+///
+/// On success, returns the sum along with the claimed evaluations of the input numerators and
+/// denominators at a random point. The caller must still check these evaluations.
+pub fn verify_fractional_sum<F, EF, Challenger>(
+    proof: &FractionalSumProof<EF>,
+    num_vars: usize,
+    challenger: &mut Challenger,
+) -> Result<FractionalSumClaim<EF>, GkrError>
+where
+    F: Field,
+    EF: ExtensionField<F>,
+    Challenger: FieldChallenger<F>,
+{
+    if proof.layers.len() != num_vars {
+        return Err(GkrError::InvalidProofShape);
+    }
+    let sum = proof
+        .denominator
+        .try_inverse()
+        .ok_or(GkrError::ZeroDenominator)?
+        * proof.numerator;
+    challenger.observe_algebra_element(proof.numerator);
+    challenger.observe_algebra_element(proof.denominator);
+
+    let mut point = Vec::with_capacity(num_vars);
+    let mut numerator = proof.numerator;
+    let mut denominator = proof.denominator;
+    for (layer, layer_proof) in proof.layers.iter().enumerate() {
+        let lambda: EF = challenger.sample_algebra_element();
+        let subclaim = p3_sumcheck::verifier::verify(
+            &layer_proof.sumcheck_proof,
+            numerator + lambda * denominator,
+            layer,
+            3,
+            challenger,
+        )
+        .map_err(|error| GkrError::Sumcheck { layer, error })?;
+
+        let [p0, p1] = layer_proof.numerators;
+        let [q0, q1] = layer_proof.denominators;
+        let expected = eval_eq(&point, &subclaim.point) * (p0 * q1 + p1 * q0 + lambda * q0 * q1);
+        if expected != subclaim.expected_eval {
+            return Err(GkrError::LayerMismatch { layer });
+        }
+
+        for x in layer_proof
+            .numerators
+            .into_iter()
+            .chain(layer_proof.denominators)
+        {
+            challenger.observe_algebra_element(x);
+        }
+        let mu: EF = challenger.sample_algebra_element();
+        numerator = p0 + mu * (p1 - p0);
+        denominator = q0 + mu * (q1 - q0);
+        point.clear();
+        point.push(mu);
+        point.extend(subclaim.point);
+    }
+
+    Ok(FractionalSumClaim {
+        sum,
+        point,
+        numerator,
+        denominator,
+    })
+}
+
+/// Add the fractions of the two halves of a layer pairwise.
+fn add_halves<EF: Field>(p: &[EF], q: &[EF]) -> (MultilinearPoly<EF>, MultilinearPoly<EF>) {
+    let half = p.len() / 2;
+    let (p0, p1) = p.split_at(half);
+    let (q0, q1) = q.split_at(half);
+    let (numerators, denominators) = p0
+        .par_iter()
+        .zip(p1)
+        .zip(q0.par_iter().zip(q1))
+        .map(|((&p0, &p1), (&q0, &q1))| (p0 * q1 + p1 * q0, q0 * q1))
+        .unzip();
+    (
+        MultilinearPoly::new(numerators),
+        MultilinearPoly::new(denominators),
+    )
+}
+
+/// Reduce the claim about the layer above `p, q` at `point` to a claim about `p, q` at a new point,
+/// which is returned along with the proof.
+fn prove_layer<F, EF, Challenger>(
+    p: &MultilinearPoly<EF>,
+    q: &MultilinearPoly<EF>,
+    point: &[EF],
+    challenger: &mut Challenger,
+) -> (GkrLayerProof<EF>, Vec<EF>)
+where
+    F: Field,
+    EF: ExtensionField<F>,
+    Challenger: FieldChallenger<F>,
+{
+    let lambda: EF = challenger.sample_algebra_element();
+
+    let half = p.evals().len() / 2;
+    let (p0, p1) = p.evals().split_at(half);
+    let (q0, q1) = q.evals().split_at(half);
+    let mut tables = [p0, p1, q0, q1].map(|t| MultilinearPoly::new(t.to_vec()));
+    let mut eq = MultilinearPoly::eq(point);
+
+    let mut round_polys = Vec::with_capacity(point.len());
+    let mut sumcheck_point = Vec::with_capacity(point.len());
+    for _ in 0..point.len() {
+        let round_poly = round_evals(&tables, &eq, lambda);
+        for &eval in &round_poly {
+            challenger.observe_algebra_element(eval);
+        }
+        let r: EF = challenger.sample_algebra_element();
+        tables = tables.map(|t| t.fix_high_variables(&[r]));
+        eq = eq.fix_high_variables(&[r]);
+        round_polys.push(round_poly);
+        sumcheck_point.push(r);
+    }
+
+    let [p0, p1, q0, q1] = tables.map(|t| t.evals()[0]);
+    for x in [p0, p1, q0, q1] {
+        challenger.observe_algebra_element(x);
+    }
+    let mu: EF = challenger.sample_algebra_element();
+
+    let mut next_point = Vec::with_capacity(point.len() + 1);
+    next_point.push(mu);
+    next_point.extend(sumcheck_point);
+    (
+        GkrLayerProof {
+            sumcheck_proof: SumcheckProof { round_polys },
+            numerators: [p0, p1],
+            denominators: [q0, q1],
+        },
+        next_point,
+    )
+}
+
+/// Compute the evaluations at `0, 1, 2, 3` of the round polynomial
+/// `g(t) = ∑_{x'} eq(t, x') (p0 q1 + p1 q0 + λ q0 q1)(t, x')`.
+fn round_evals<EF: Field>(
+    tables: &[MultilinearPoly<EF>; 4],
+    eq: &MultilinearPoly<EF>,
+    lambda: EF,
+) -> Vec<EF> {
+    let half = eq.evals().len() / 2;
+    let [p0, p1, q0, q1] = tables.each_ref().map(|t| t.evals());
+    let eq = eq.evals();
+    (0..half)
+        .into_par_iter()
+        .par_fold_reduce(
+            || [EF::ZERO; 4],
+            |mut sums, i| {
+                // Walk along the line through the two halves of each table.
+                let line = |t: &[EF]| (t[i], t[half + i] - t[i]);
+                let mut values = [line(eq), line(p0), line(p1), line(q0), line(q1)];
+                for sum in &mut sums {
+                    let [e, a0, a1, b0, b1] = values.map(|(value, _)| value);
+                    *sum += e * (a0 * b1 + a1 * b0 + lambda * b0 * b1);
+                    for (value, diff) in &mut values {
+                        *value += *diff;
+                    }
+                }
+                sums
+            },
+            |a, b| core::array::from_fn(|k| a[k] + b[k]),
+        )
+        .to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_baby_bear::{BabyBear, Poseidon2BabyBear, default_babybear_poseidon2_16};
+    use p3_challenger::DuplexChallenger;
+    use p3_field::PrimeCharacteristicRing;
+    use p3_field::extension::BinomialExtensionField;
+    use p3_multilinear_util::poly::MultilinearPoly;
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+
+    type F = BabyBear;
+    type EF = BinomialExtensionField<F, 4>;
+    type Challenger = DuplexChallenger<F, Poseidon2BabyBear<16>, 16, 8>;
+
+    fn challenger() -> Challenger {
+        DuplexChallenger::new(default_babybear_poseidon2_16())
+    }
+
+    fn random_poly(rng: &mut SmallRng, num_vars: usize) -> MultilinearPoly<EF> {
+        MultilinearPoly::new((0..1 << num_vars).map(|_| rng.random()).collect())
+    }
+
+    #[test]
+    fn fractional_sum_is_proven() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        for num_vars in 0..8 {
+            let p = random_poly(&mut rng, num_vars);
+            let q = random_poly(&mut rng, num_vars);
+            let expected_sum: EF = p
+                .evals()
+                .iter()
+                .zip(q.evals())
+                .map(|(&p, &q)| p * q.inverse())
+                .sum();
+
+            let (proof, point) = prove_fractional_sum(p.clone(), q.clone(), &mut challenger());
+            let claim = verify_fractional_sum(&proof, num_vars, &mut challenger()).unwrap();
+            assert_eq!(claim.sum, expected_sum);
+            assert_eq!(claim.point, point);
+            assert_eq!(claim.numerator, p.evaluate(&point));
+            assert_eq!(claim.denominator, q.evaluate(&point));
+        }
+    }
+
+    #[test]
+    fn tampered_proofs_are_rejected() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let num_vars = 5;
+        let p = random_poly(&mut rng, num_vars);
+        let q = random_poly(&mut rng, num_vars);
+        let (proof, _) = prove_fractional_sum(p, q, &mut challenger());
+
+        let mut wrong = proof.clone();
+        wrong.numerator += EF::ONE;
+        assert_eq!(
+            verify_fractional_sum(&wrong, num_vars, &mut challenger()),
+            Err(GkrError::LayerMismatch { layer: 0 })
+        );
+
+        let mut wrong = proof.clone();
+        wrong.layers[3].numerators[1] += EF::ONE;
+        assert_eq!(
+            verify_fractional_sum(&wrong, num_vars, &mut challenger()),
+            Err(GkrError::LayerMismatch { layer: 3 })
+        );
+
+        let mut wrong = proof.clone();
+        wrong.layers[2].sumcheck_proof.round_polys[1][0] += EF::ONE;
+        assert_eq!(
+            verify_fractional_sum(&wrong, num_vars, &mut challenger()),
+            Err(GkrError::Sumcheck {
+                layer: 2,
+                error: SumcheckError::SumMismatch { round: 1 }
+            })
+        );
+
+        let mut wrong = proof.clone();
+        wrong.denominator = EF::ZERO;
+        assert_eq!(
+            verify_fractional_sum(&wrong, num_vars, &mut challenger()),
+            Err(GkrError::ZeroDenominator)
+        );
+
+        assert_eq!(
+            verify_fractional_sum(&proof, num_vars + 1, &mut challenger()),
+            Err(GkrError::InvalidProofShape)
+        );
+    }
+}
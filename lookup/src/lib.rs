@@ -4,7 +4,9 @@
 
 extern crate alloc;
 
+pub mod gkr;
 pub mod logup;
+pub mod logup_gkr;
 pub mod lookup_traits;
 #[cfg(test)]
 mod tests;
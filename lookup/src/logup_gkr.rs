@@ -0,0 +1,485 @@
+//! LogUp with a GKR proof of the fractional sums.
+//!
+//! Instead of accumulating the fractions `m_i / (α - e_i)` of each row in a running sum column,
+//! the prover runs the GKR protocol of [`crate::gkr`] over the multilinear extensions of the
+//! numerators and denominators:
+//! ```text
+//! p(row, i) = m_i(row),    q(row, i) = α - e_i(row),
+//! ```
+//! where `row` ranges over the rows of the trace and `i` over the tuples of the lookup, padded to a
+//! power of two with the fraction `0 / 1`. The GKR proof establishes `∑ p / q` and leaves a claim
+//! about `p` and `q` at a random point `(r_row, r_tuple)`. As long as the elements and
+//! multiplicities are affine in the local row of the main trace, this reduces to the evaluations of
+//! the main trace columns at `r_row`, which the caller obtains from the PCS.
+//!
+//! The AIR itself carries no constraint for the lookup and there is no auxiliary column to commit
+//! to, regardless of the number of lookups per row. The lookups are instead proven with
+//! [`LogUpGkrGadget::prove_lookups`] and checked with [`LogUpGkrGadget::verify_lookups`], which
+//! open the main trace at the GKR points through a [`MultilinearPcs`]. A univariate STARK relying on
+//! [`LookupGadget::eval_local_lookup`] or [`LookupGadget::eval_global_update`] alone would not check
+//! the lookup at all, so these methods panic.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter;
+
+use itertools::Itertools;
+use p3_air::{AirBuilderWithPublicValues, PairBuilder, PermutationAirBuilder};
+use p3_challenger::FieldChallenger;
+use p3_commit::MultilinearPcs;
+use p3_field::{Algebra, ExtensionField, Field};
+use p3_matrix::Matrix;
+use p3_matrix::dense::{DenseMatrix, DenseStorage};
+use p3_multilinear_util::poly::MultilinearPoly;
+use p3_uni_stark::{Entry, SymbolicExpression};
+use p3_util::log2_strict_usize;
+
+use crate::gkr::{
+    FractionalSumClaim, FractionalSumProof, prove_fractional_sum, verify_fractional_sum,
+};
+use crate::lookup_traits::{Kind, Lookup, LookupError, LookupGadget};
+
+/// LogUp gadget proving the fractional sums with GKR rather than with a running sum column.
+///
+/// For a lookup with tuples `e_i` and multiplicities `m_i`, this proves
+/// ```text
+/// ∑_row ∑_i m_i(row) / (α - combined_e_i(row)) = s
+/// ```
+/// where `combined_e_i = ∑_j e_{i, j} β^{n-1-j}` as in [`LogUpGadget`](crate::logup::LogUpGadget),
+/// and `s` is zero for local lookups. For global lookups, `s` is the cumulated value of the AIR,
+/// and the values of all AIRs are checked with [`LookupGadget::verify_global_final_value`].
+///
+/// The elements and multiplicities must be affine in the columns of the local row of the main
+/// trace, which may require adding main columns for higher degree expressions. The main trace is
+/// committed with a [`MultilinearPcs`], and the lookups of the AIR are proven with
+/// [`prove_lookups`](Self::prove_lookups) and checked with [`verify_lookups`](Self::verify_lookups).
+///
+/// Nothing is enforced by the AIR, so the [`LookupGadget`] evaluation methods panic rather than
+/// silently adding no constraints. A proof system using this gadget must run the protocol above
+/// alongside the constraint checks, on the same commitment to the main trace.
+#[derive(Debug, Clone, Default)]
+pub struct LogUpGkrGadget;
+
+/// A LogUp-GKR proof of the lookups of an AIR.
+#[derive(Clone, Debug)]
+pub struct LogUpGkrProof<EF, OpeningProof> {
+    /// The GKR proof of the fractional sum of each lookup.
+    pub fractional_sums: Vec<FractionalSumProof<EF>>,
+    /// For each lookup, the evaluations of the main trace columns at the point its GKR proof
+    /// reduces to.
+    pub main_evals: Vec<Vec<EF>>,
+    /// The proof of `main_evals` against the commitment to the main trace.
+    pub opening_proof: OpeningProof,
+}
+
+impl LogUpGkrGadget {
+    /// Creates a new LogUp-GKR gadget instance.
+    pub const fn new() -> Self {
+        Self {}
+    }
+
+    /// Computes the numerators and denominators of the fractions of a lookup over the trace.
+    ///
+    /// The tables are indexed by `row * num_tuples + i`, where `num_tuples` is the number of tuples
+    /// rounded up to a power of two. The padding fractions are `0 / 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the height of the trace is not a power of two, or if the lookup is not affine in
+    /// the local row of the main trace.
+    pub fn generate_fractions<F, EF, S>(
+        &self,
+        lookup: &Lookup<F>,
+        main: &DenseMatrix<F, S>,
+        public_values: &[F],
+        alpha: EF,
+        beta: EF,
+    ) -> (MultilinearPoly<EF>, MultilinearPoly<EF>)
+    where
+        F: Field,
+        EF: ExtensionField<F>,
+        S: DenseStorage<F>,
+    {
+        assert_affine(lookup);
+        assert!(main.height().is_power_of_two());
+        let num_tuples = lookup.element_exprs.len().next_power_of_two();
+
+        // The symbolic expressions are not `Sync`, so the rows are evaluated sequentially.
+        let (numerators, denominators): (Vec<_>, Vec<_>) = main
+            .row_slices()
+            .flat_map(|row| {
+                let (mut numerators, mut denominators) =
+                    row_fractions(lookup, row, public_values, alpha, beta);
+                numerators.resize(num_tuples, EF::ZERO);
+                denominators.resize(num_tuples, EF::ONE);
+                numerators.into_iter().zip(denominators)
+            })
+            .unzip();
+
+        (
+            MultilinearPoly::new(numerators),
+            MultilinearPoly::new(denominators),
+        )
+    }
+
+    /// Proves the fractional sum of a lookup.
+    ///
+    /// Returns the proof along with the random point it reduces to. The first `log_height`
+    /// coordinates of the point are where the main trace must be opened.
+    pub fn prove_lookup<F, EF, S, Challenger>(
+        &self,
+        lookup: &Lookup<F>,
+        main: &DenseMatrix<F, S>,
+        public_values: &[F],
+        alpha: EF,
+        beta: EF,
+        challenger: &mut Challenger,
+    ) -> (FractionalSumProof<EF>, Vec<EF>)
+    where
+        F: Field,
+        EF: ExtensionField<F>,
+        S: DenseStorage<F>,
+        Challenger: FieldChallenger<F>,
+    {
+        let (numerators, denominators) =
+            self.generate_fractions(lookup, main, public_values, alpha, beta);
+        prove_fractional_sum(numerators, denominators, challenger)
+    }
+
+    /// Verifies the GKR proof of the fractional sum of a lookup over a trace of height
+    /// `2^log_height`.
+    ///
+    /// For local lookups, this checks that the sum is zero. For global lookups, the sum in the
+    /// returned claim is the cumulated value of this AIR. In both cases, the claimed evaluations of
+    /// the fractions must still be checked with [`check_main_evals`](Self::check_main_evals).
+    pub(crate) fn verify_lookup<F, EF, Challenger>(
+        &self,
+        lookup: &Lookup<F>,
+        log_height: usize,
+        proof: &FractionalSumProof<EF>,
+        challenger: &mut Challenger,
+    ) -> Result<FractionalSumClaim<EF>, LookupError>
+    where
+        F: Field,
+        EF: ExtensionField<F>,
+        Challenger: FieldChallenger<F>,
+    {
+        let num_vars =
+            log_height + log2_strict_usize(lookup.element_exprs.len().next_power_of_two());
+        let claim = verify_fractional_sum(proof, num_vars, challenger)
+            .map_err(LookupError::FractionalSumError)?;
+
+        if lookup.kind == Kind::Local && !claim.sum.is_zero() {
+            return Err(LookupError::LocalSumMismatch);
+        }
+        Ok(claim)
+    }
+
+    /// Checks the claimed evaluations of the fractions against the evaluations of the main trace
+    /// columns at the first `log_height` coordinates of the claim's point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lookup is not affine in the local row of the main trace.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn check_main_evals<F, EF>(
+        &self,
+        lookup: &Lookup<F>,
+        log_height: usize,
+        claim: &FractionalSumClaim<EF>,
+        main_evals: &[EF],
+        public_values: &[F],
+        alpha: EF,
+        beta: EF,
+    ) -> Result<(), LookupError>
+    where
+        F: Field,
+        EF: ExtensionField<F>,
+    {
+        assert_affine(lookup);
+
+        // Since every expression is affine, its multilinear extension is the expression applied to
+        // the multilinear extensions of the columns.
+        let (numerators, denominators) =
+            row_fractions(lookup, main_evals, public_values, alpha, beta);
+
+        // Select the tuple with the remaining coordinates. The padding fractions are `0 / 1`.
+        let tuple_point = &claim.point[log_height..];
+        let eq = MultilinearPoly::eq(tuple_point);
+        let numerator: EF = eq
+            .evals()
+            .iter()
+            .zip(&numerators)
+            .map(|(&w, &p)| w * p)
+            .sum();
+        let denominator: EF = eq
+            .evals()
+            .iter()
+            .zip(denominators.into_iter().chain(iter::repeat(EF::ONE)))
+            .map(|(&w, q)| w * q)
+            .sum();
+
+        if numerator != claim.numerator || denominator != claim.denominator {
+            return Err(LookupError::MainEvalMismatch);
+        }
+        Ok(())
+    }
+
+    /// Proves the fractional sums of the lookups of an AIR, and opens the main trace at the points
+    /// the GKR proofs reduce to.
+    ///
+    /// The main trace must be the only matrix committed in `main_data`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lookups` is empty, or if the main trace cannot be used with
+    /// [`generate_fractions`](Self::generate_fractions).
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove_lookups<F, EF, Pcs, Challenger>(
+        &self,
+        pcs: &Pcs,
+        lookups: &[Lookup<F>],
+        main_data: &Pcs::ProverData,
+        public_values: &[F],
+        alpha: EF,
+        beta: EF,
+        challenger: &mut Challenger,
+    ) -> LogUpGkrProof<EF, Pcs::Proof>
+    where
+        F: Field,
+        EF: ExtensionField<F>,
+        Pcs: MultilinearPcs<F, EF, Challenger>,
+        Challenger: FieldChallenger<F>,
+    {
+        assert!(!lookups.is_empty(), "at least one lookup must be proven");
+        let main = pcs.get_evaluations(main_data, 0);
+        let log_height = log2_strict_usize(main.height());
+
+        let (fractional_sums, row_points): (Vec<_>, Vec<_>) = lookups
+            .iter()
+            .map(|lookup| {
+                let (proof, mut point) =
+                    self.prove_lookup(lookup, &main, public_values, alpha, beta, challenger);
+                point.truncate(log_height);
+                (proof, point)
+            })
+            .unzip();
+
+        let (mut opened_values, opening_proof) =
+            pcs.open(vec![(main_data, vec![row_points])], challenger);
+        let main_evals = opened_values.swap_remove(0).swap_remove(0);
+
+        LogUpGkrProof {
+            fractional_sums,
+            main_evals,
+            opening_proof,
+        }
+    }
+
+    /// Verifies a proof produced by [`prove_lookups`](Self::prove_lookups) for the lookups of an
+    /// AIR whose main trace of height `2^log_height` is committed in `main_commitment`.
+    ///
+    /// Returns the sum of the fractions of each lookup. For local lookups, this is checked to be
+    /// zero. For global lookups, it is the cumulated value of this AIR, to be passed to
+    /// [`LookupGadget::verify_global_final_value`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a lookup is not affine in the local row of the main trace.
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_lookups<F, EF, Pcs, Challenger>(
+        &self,
+        pcs: &Pcs,
+        lookups: &[Lookup<F>],
+        main_commitment: Pcs::Commitment,
+        log_height: usize,
+        public_values: &[F],
+        alpha: EF,
+        beta: EF,
+        proof: &LogUpGkrProof<EF, Pcs::Proof>,
+        challenger: &mut Challenger,
+    ) -> Result<Vec<EF>, LookupError>
+    where
+        F: Field,
+        EF: ExtensionField<F>,
+        Pcs: MultilinearPcs<F, EF, Challenger>,
+        Challenger: FieldChallenger<F>,
+    {
+        if lookups.is_empty()
+            || proof.fractional_sums.len() != lookups.len()
+            || proof.main_evals.len() != lookups.len()
+        {
+            return Err(LookupError::InvalidProofShape);
+        }
+
+        let claims = lookups
+            .iter()
+            .zip(&proof.fractional_sums)
+            .map(|(lookup, proof)| self.verify_lookup(lookup, log_height, proof, challenger))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let points_and_values = claims
+            .iter()
+            .zip(&proof.main_evals)
+            .map(|(claim, main_evals)| (claim.point[..log_height].to_vec(), main_evals.clone()))
+            .collect();
+        pcs.verify(
+            vec![(main_commitment, vec![(log_height, points_and_values)])],
+            &proof.opening_proof,
+            challenger,
+        )
+        .map_err(|_| LookupError::InvalidMainOpening)?;
+
+        for ((lookup, claim), main_evals) in lookups.iter().zip(&claims).zip(&proof.main_evals) {
+            self.check_main_evals(
+                lookup,
+                log_height,
+                claim,
+                main_evals,
+                public_values,
+                alpha,
+                beta,
+            )?;
+        }
+        Ok(claims.into_iter().map(|claim| claim.sum).collect())
+    }
+}
+
+impl LookupGadget for LogUpGkrGadget {
+    fn num_aux_cols(&self) -> usize {
+        0
+    }
+
+    fn num_challenges(&self) -> usize {
+        2
+    }
+
+    /// The fractional sum is proven by GKR outside of the AIR, so there is nothing to constrain.
+    ///
+    /// # Panics
+    ///
+    /// Always panics, as an AIR evaluated with this gadget would not check the lookup. Use
+    /// [`LogUpGkrGadget::prove_lookups`] and [`LogUpGkrGadget::verify_lookups`] instead.
+    fn eval_local_lookup<AB>(&self, _builder: &mut AB, _context: Lookup<AB::F>)
+    where
+        AB: PermutationAirBuilder + PairBuilder + AirBuilderWithPublicValues,
+    {
+        panic!("{NO_AIR_CONSTRAINTS}")
+    }
+
+    /// The fractional sum is proven by GKR outside of the AIR, so there is nothing to constrain.
+    ///
+    /// # Panics
+    ///
+    /// Always panics, as an AIR evaluated with this gadget would not check the lookup. Use
+    /// [`LogUpGkrGadget::prove_lookups`] and [`LogUpGkrGadget::verify_lookups`] instead, whose
+    /// returned sums are the expected cumulated values.
+    fn eval_global_update<AB>(
+        &self,
+        _builder: &mut AB,
+        _context: Lookup<AB::F>,
+        _expected_cumulated: AB::ExprEF,
+    ) where
+        AB: PermutationAirBuilder + PairBuilder + AirBuilderWithPublicValues,
+    {
+        panic!("{NO_AIR_CONSTRAINTS}")
+    }
+
+    fn verify_global_final_value<EF: Field>(
+        &self,
+        all_expected_cumulated: &[EF],
+    ) -> Result<(), LookupError> {
+        let total = all_expected_cumulated.iter().copied().sum::<EF>();
+
+        if !total.is_zero() {
+            return Err(LookupError::GlobalCumulativeMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// The lookup adds no constraint to the AIR.
+    fn constraint_degree<F: Field>(&self, _context: Lookup<F>) -> usize {
+        0
+    }
+}
+
+const NO_AIR_CONSTRAINTS: &str = "LogUp-GKR adds no AIR constraints: the lookups must be proven \
+    with `prove_lookups` and checked with `verify_lookups` by a multilinear backend";
+
+/// Computes the numerators `m_i` and denominators `α - combined_e_i` of the fractions of a lookup,
+/// given the values of the local row of the main trace.
+fn row_fractions<F, T, EF>(
+    lookup: &Lookup<F>,
+    main: &[T],
+    public_values: &[F],
+    alpha: EF,
+    beta: EF,
+) -> (Vec<EF>, Vec<EF>)
+where
+    F: Field,
+    T: Algebra<F> + Copy,
+    EF: ExtensionField<F> + Algebra<T>,
+{
+    let numerators = lookup
+        .multiplicities_exprs
+        .iter()
+        .map(|expr| EF::from(eval_local(expr, main, public_values)))
+        .collect();
+    let denominators = lookup
+        .element_exprs
+        .iter()
+        .map(|elts| {
+            let combined = elts.iter().fold(EF::ZERO, |acc, elt| {
+                acc * beta + eval_local(elt, main, public_values)
+            });
+            alpha - combined
+        })
+        .collect_vec();
+    (numerators, denominators)
+}
+
+/// Evaluates an expression over the local row of the main trace.
+fn eval_local<F, T>(expr: &SymbolicExpression<F>, main: &[T], public_values: &[F]) -> T
+where
+    F: Field,
+    T: Algebra<F> + Copy,
+{
+    match expr {
+        SymbolicExpression::Constant(c) => T::from(*c),
+        SymbolicExpression::Variable(v) => match v.entry {
+            Entry::Main { offset: 0 } => main[v.index],
+            Entry::Public => T::from(public_values[v.index]),
+            _ => panic!("LogUp-GKR only supports the local row of the main trace"),
+        },
+        SymbolicExpression::Add { x, y, .. } => {
+            eval_local(x, main, public_values) + eval_local(y, main, public_values)
+        }
+        SymbolicExpression::Sub { x, y, .. } => {
+            eval_local(x, main, public_values) - eval_local(y, main, public_values)
+        }
+        SymbolicExpression::Neg { x, .. } => -eval_local(x, main, public_values),
+        SymbolicExpression::Mul { x, y, .. } => {
+            eval_local(x, main, public_values) * eval_local(y, main, public_values)
+        }
+        SymbolicExpression::IsFirstRow
+        | SymbolicExpression::IsLastRow
+        | SymbolicExpression::IsTransition => {
+            panic!("LogUp-GKR does not support selectors in lookups")
+        }
+    }
+}
+
+/// Checks that all elements and multiplicities of a lookup have degree at most one, so that their
+/// multilinear extensions can be evaluated from those of the main trace columns.
+fn assert_affine<F: Field>(lookup: &Lookup<F>) {
+    assert!(
+        lookup
+            .element_exprs
+            .iter()
+            .flatten()
+            .chain(&lookup.multiplicities_exprs)
+            .all(|expr| expr.degree_multiple() <= 1),
+        "LogUp-GKR requires lookup expressions of degree at most one"
+    );
+}
@@ -7,11 +7,25 @@ use p3_field::Field;
 use p3_matrix::Matrix;
 use p3_uni_stark::{Entry, SymbolicExpression};
 
+use crate::gkr::GkrError;
+
 /// Defines errors that can occur during lookup verification.
 #[derive(Debug)]
 pub enum LookupError {
     /// Error indicating that the global cumulative sum is incorrect.
     GlobalCumulativeMismatch,
+    /// Error indicating that the sum of a local lookup is not zero.
+    LocalSumMismatch,
+    /// Error indicating that the GKR proof of a fractional sum is invalid.
+    FractionalSumError(GkrError),
+    /// Error indicating that the evaluations of the main trace do not match the fractions claimed
+    /// by the GKR proof.
+    MainEvalMismatch,
+    /// Error indicating that the opening of the main trace at the GKR points is invalid.
+    InvalidMainOpening,
+    /// Error indicating that a LogUp-GKR proof does not have one fractional sum proof and one set
+    /// of main trace evaluations per lookup.
+    InvalidProofShape,
 }
 
 /// A trait for lookup argument.
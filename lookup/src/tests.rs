@@ -7,18 +7,28 @@ use p3_air::{
     Air, AirBuilder, AirBuilderWithPublicValues, BaseAir, ExtensionBuilder, PairBuilder,
     PermutationAirBuilder,
 };
-use p3_baby_bear::BabyBear;
+use p3_baby_bear::{BabyBear, Poseidon2BabyBear, default_babybear_poseidon2_16};
+use p3_basefold::BasefoldPcs;
+use p3_challenger::{CanObserve, DuplexChallenger};
+use p3_commit::{ExtensionMmcs, MultilinearPcs};
+use p3_dft::Radix2DitParallel;
 use p3_field::extension::BinomialExtensionField;
 use p3_field::{Field, PrimeCharacteristicRing};
+use p3_fri::FriParameters;
 use p3_matrix::Matrix;
 use p3_matrix::dense::RowMajorMatrix;
-use p3_uni_stark::{SymbolicAirBuilder, SymbolicExpression};
+use p3_merkle_tree::MerkleTreeMmcs;
+use p3_multilinear_util::poly::MultilinearPoly;
+use p3_symmetric::{PaddingFreeSponge, TruncatedPermutation};
+use p3_uni_stark::{Entry, SymbolicAirBuilder, SymbolicExpression, SymbolicVariable};
+use p3_util::log2_strict_usize;
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
 
 use crate::logup::LogUpGadget;
+use crate::logup_gkr::{LogUpGkrGadget, LogUpGkrProof};
 use crate::lookup_traits::{
-    AirLookupHandler, Direction, Kind, Lookup, LookupGadget, symbolic_to_expr,
+    AirLookupHandler, Direction, Kind, Lookup, LookupError, LookupGadget, symbolic_to_expr,
 };
 
 /// Base field type for the test
@@ -1470,3 +1480,320 @@ fn test_global_lookup() {
         .verify_global_final_value(&[s_global_final1, s_global_final2])
         .expect("Global lookups final values should sum to 0.");
 }
+
+type Perm = Poseidon2BabyBear<16>;
+type Challenger = DuplexChallenger<F, Perm, 16, 8>;
+type ValMmcs = MerkleTreeMmcs<
+    <F as Field>::Packing,
+    <F as Field>::Packing,
+    PaddingFreeSponge<Perm, 16, 8, 8>,
+    TruncatedPermutation<Perm, 2, 8, 16>,
+    8,
+>;
+type MyPcs = BasefoldPcs<F, Radix2DitParallel<F>, ValMmcs, ExtensionMmcs<F, EF, ValMmcs>>;
+type GkrProof = LogUpGkrProof<EF, <MyPcs as MultilinearPcs<F, EF, Challenger>>::Proof>;
+
+fn multilinear_pcs() -> MyPcs {
+    let perm = default_babybear_poseidon2_16();
+    let val_mmcs = ValMmcs::new(
+        PaddingFreeSponge::new(perm.clone()),
+        TruncatedPermutation::new(perm),
+    );
+    let fri_params = FriParameters {
+        log_blowup: 1,
+        log_final_poly_len: 0,
+        num_queries: 10,
+        proof_of_work_bits: 8,
+        mmcs: ExtensionMmcs::new(val_mmcs.clone()),
+    };
+    MyPcs::new(Radix2DitParallel::default(), val_mmcs, fri_params)
+}
+
+/// Commits to the main trace with Basefold and proves the lookups of the AIR. Returns the
+/// commitment along with the proof.
+fn prove_logup_gkr(
+    pcs: &MyPcs,
+    lookups: &[Lookup<F>],
+    main_trace: &RowMajorMatrix<F>,
+    challenges: LogUpChallenges,
+) -> (
+    <MyPcs as MultilinearPcs<F, EF, Challenger>>::Commitment,
+    GkrProof,
+) {
+    let LogUpChallenges { alpha, beta } = challenges;
+    let (commit, data) = MultilinearPcs::<F, EF, Challenger>::commit(pcs, vec![main_trace.clone()]);
+    let mut challenger = Challenger::new(default_babybear_poseidon2_16());
+    challenger.observe(commit);
+    let proof =
+        LogUpGkrGadget::new().prove_lookups(pcs, lookups, &data, &[], alpha, beta, &mut challenger);
+    (commit, proof)
+}
+
+/// Verifies a proof produced by [`prove_logup_gkr`], returning the sum of each lookup.
+fn verify_logup_gkr(
+    pcs: &MyPcs,
+    lookups: &[Lookup<F>],
+    commit: <MyPcs as MultilinearPcs<F, EF, Challenger>>::Commitment,
+    log_height: usize,
+    challenges: LogUpChallenges,
+    proof: &GkrProof,
+) -> Result<Vec<EF>, LookupError> {
+    let LogUpChallenges { alpha, beta } = challenges;
+    let mut challenger = Challenger::new(default_babybear_poseidon2_16());
+    challenger.observe(commit);
+    LogUpGkrGadget::new().verify_lookups(
+        pcs,
+        lookups,
+        commit,
+        log_height,
+        &[],
+        alpha,
+        beta,
+        proof,
+        &mut challenger,
+    )
+}
+
+/// Proves and verifies a lookup with LogUp-GKR, opening the main trace with Basefold. Returns the
+/// sum of the fractions.
+fn prove_and_verify_logup_gkr(
+    lookup: &Lookup<F>,
+    main_trace: &RowMajorMatrix<F>,
+    challenges: LogUpChallenges,
+) -> Result<EF, LookupError> {
+    let pcs = multilinear_pcs();
+    let lookups = core::slice::from_ref(lookup);
+    let (commit, proof) = prove_logup_gkr(&pcs, lookups, main_trace, challenges);
+    let log_height = log2_strict_usize(main_trace.height());
+    let sums = verify_logup_gkr(&pcs, lookups, commit, log_height, challenges, &proof)?;
+    Ok(sums[0])
+}
+
+#[test]
+fn test_logup_gkr_tuple_lookup() {
+    let mut rng = SmallRng::seed_from_u64(1);
+    let mut air = AddAir::new();
+    let width = <AddAir as BaseAir<F>>::width(&air);
+    let (main_trace, _, challenges) = LookupTraceBuilder::new_with_width(width, &mut rng)
+        .row(vec![0, 1, 1], vec![0, 1, 1], 2)
+        .row(vec![0, 1, 1], vec![0, 0, 0], 1)
+        .row(vec![1, 1, 2], vec![1, 0, 1], 0)
+        .row(vec![0, 0, 0], vec![1, 1, 2], 1)
+        .build();
+
+    let lookups = <AddAir as AirLookupHandler<MockAirBuilder>>::get_lookups(&mut air);
+    let gadget = LogUpGkrGadget::new();
+    assert_eq!(gadget.num_aux_cols(), 0);
+    assert_eq!(gadget.constraint_degree(lookups[0].clone()), 0);
+
+    let sum = prove_and_verify_logup_gkr(&lookups[0], &main_trace, challenges).unwrap();
+    assert_eq!(sum, EF::ZERO);
+}
+
+#[test]
+fn test_logup_gkr_invalid_lookup() {
+    // The value 2 is read but never provided.
+    let mut rng = SmallRng::seed_from_u64(2);
+    let mut air = RangeCheckAir::new();
+    let (main_trace, _, challenges) = LookupTraceBuilder::new(&mut rng)
+        .row(vec![2], vec![0], 1)
+        .row(vec![1], vec![1], 1)
+        .build();
+
+    let lookups = <RangeCheckAir as AirLookupHandler<MockAirBuilder>>::get_lookups(&mut air);
+    assert!(matches!(
+        prove_and_verify_logup_gkr(&lookups[0], &main_trace, challenges),
+        Err(LookupError::LocalSumMismatch)
+    ));
+}
+
+#[test]
+#[should_panic(expected = "LogUp-GKR adds no AIR constraints")]
+fn test_logup_gkr_air_evaluation_panics() {
+    let mut rng = SmallRng::seed_from_u64(2);
+    let mut air = RangeCheckAir::new();
+    let (main_trace, aux_trace, challenges) = LookupTraceBuilder::new(&mut rng)
+        .row(vec![1], vec![1], 1)
+        .build();
+    let LogUpChallenges { alpha, beta } = challenges;
+    let mut builder = MockAirBuilder::new(main_trace, aux_trace, vec![alpha, beta]);
+
+    let lookups = <RangeCheckAir as AirLookupHandler<MockAirBuilder>>::get_lookups(&mut air);
+    LogUpGkrGadget::new().eval_local_lookup(&mut builder, lookups[0].clone());
+}
+
+#[test]
+fn test_logup_gkr_wrong_main_evals() {
+    let mut rng = SmallRng::seed_from_u64(3);
+    let mut air = RangeCheckAir::new();
+    let (main_trace, _, challenges) = LookupTraceBuilder::new(&mut rng)
+        .row(vec![1], vec![0], 0)
+        .row(vec![1], vec![1], 2)
+        .build();
+    let LogUpChallenges { alpha, beta } = challenges;
+
+    let lookups = <RangeCheckAir as AirLookupHandler<MockAirBuilder>>::get_lookups(&mut air);
+    let gadget = LogUpGkrGadget::new();
+    let challenger = Challenger::new(default_babybear_poseidon2_16());
+    let (proof, point) = gadget.prove_lookup(
+        &lookups[0],
+        &main_trace,
+        &[],
+        alpha,
+        beta,
+        &mut challenger.clone(),
+    );
+    let claim = gadget
+        .verify_lookup(&lookups[0], 1, &proof, &mut challenger.clone())
+        .unwrap();
+
+    // Evaluations of a different trace are rejected.
+    let other_trace = RowMajorMatrix::new(vec![F::ONE, F::ZERO, F::ONE, F::ONE, F::ONE, F::ONE], 3);
+    let main_evals = (0..3)
+        .map(|col| MultilinearPoly::from_matrix_column(&other_trace, col).evaluate(&point[..1]))
+        .collect::<Vec<_>>();
+    assert!(matches!(
+        gadget.check_main_evals(&lookups[0], 1, &claim, &main_evals, &[], alpha, beta),
+        Err(LookupError::MainEvalMismatch)
+    ));
+}
+
+#[test]
+fn test_logup_gkr_global_lookup() {
+    let mut rng = SmallRng::seed_from_u64(1);
+    let global_challenges = LogUpChallenges {
+        alpha: EF::from_u32(rng.random()),
+        beta: EF::from_u32(rng.random()),
+    };
+
+    // The two AIRs hold the same addition table in different orders, and exchange it.
+    let mut air1 = AddAir::new_with_global(Direction::Receive);
+    let width = <AddAir as BaseAir<F>>::width(&air1);
+    let (main_trace1, _, challenges1) = LookupTraceBuilder::new_with_width(width, &mut rng)
+        .row(vec![0, 1, 1], vec![0, 0, 0], 1)
+        .row(vec![0, 1, 1], vec![0, 1, 1], 2)
+        .row(vec![1, 1, 2], vec![1, 1, 2], 1)
+        .row(vec![0, 0, 0], vec![1, 0, 1], 0)
+        .build();
+
+    let mut air2 = AddAir::new_with_global(Direction::Send);
+    let (main_trace2, _, challenges2) = LookupTraceBuilder::new_with_width(width, &mut rng)
+        .row(vec![0, 1, 1], vec![0, 1, 1], 2)
+        .row(vec![0, 1, 1], vec![0, 0, 0], 1)
+        .row(vec![1, 1, 2], vec![1, 0, 1], 0)
+        .row(vec![0, 0, 0], vec![1, 1, 2], 1)
+        .build();
+
+    let lookups1 = <AddAir as AirLookupHandler<MockAirBuilder>>::get_lookups(&mut air1);
+    let lookups2 = <AddAir as AirLookupHandler<MockAirBuilder>>::get_lookups(&mut air2);
+
+    let local1 = prove_and_verify_logup_gkr(&lookups1[0], &main_trace1, challenges1).unwrap();
+    let local2 = prove_and_verify_logup_gkr(&lookups2[0], &main_trace2, challenges2).unwrap();
+    assert_eq!(local1, EF::ZERO);
+    assert_eq!(local2, EF::ZERO);
+
+    let global1 =
+        prove_and_verify_logup_gkr(&lookups1[1], &main_trace1, global_challenges).unwrap();
+    let global2 =
+        prove_and_verify_logup_gkr(&lookups2[1], &main_trace2, global_challenges).unwrap();
+    assert_ne!(global1, EF::ZERO);
+
+    let gadget = LogUpGkrGadget::new();
+    gadget
+        .verify_global_final_value(&[global1, global2])
+        .expect("Global lookups final values should sum to 0.");
+    assert!(matches!(
+        gadget.verify_global_final_value(&[global1, global1]),
+        Err(LookupError::GlobalCumulativeMismatch)
+    ));
+}
+
+#[test]
+fn test_logup_gkr_padded_tuples() {
+    // Three lookups per row: two reads against one provided value, so the tuples are padded to 4.
+    let mut rng = SmallRng::seed_from_u64(4);
+    let col = |i| SymbolicExpression::from(SymbolicVariable::new(Entry::Main { offset: 0 }, i));
+    let lookup = Lookup::new(
+        Kind::Local,
+        vec![vec![col(0)], vec![col(1)], vec![col(2)]],
+        vec![
+            SymbolicExpression::Constant(F::ONE),
+            SymbolicExpression::Constant(F::ONE),
+            -col(3),
+        ],
+        vec![],
+    );
+    let challenges = LogUpChallenges {
+        alpha: rng.random(),
+        beta: rng.random(),
+    };
+
+    // Columns: [read, read, provide, mult].
+    let rows = [[3, 1, 1, 3], [1, 2, 2, 2], [2, 3, 3, 3], [3, 1, 4, 0]];
+    let main_trace = RowMajorMatrix::new(rows.iter().flatten().map(|&x| F::new(x)).collect(), 4);
+    let sum = prove_and_verify_logup_gkr(&lookup, &main_trace, challenges).unwrap();
+    assert_eq!(sum, EF::ZERO);
+
+    // Dropping one of the provided values unbalances the lookup.
+    let mut main_trace = main_trace;
+    main_trace.values[7] = F::ONE;
+    assert!(matches!(
+        prove_and_verify_logup_gkr(&lookup, &main_trace, challenges),
+        Err(LookupError::LocalSumMismatch)
+    ));
+}
+
+#[test]
+fn test_logup_gkr_several_lookups() {
+    // Both lookups of each AIR are proven together, with a single opening of the main trace.
+    let mut rng = SmallRng::seed_from_u64(5);
+    let challenges = LogUpChallenges {
+        alpha: rng.random(),
+        beta: rng.random(),
+    };
+
+    let mut air1 = AddAir::new_with_global(Direction::Receive);
+    let width = <AddAir as BaseAir<F>>::width(&air1);
+    let (main_trace1, _, _) = LookupTraceBuilder::new_with_width(width, &mut rng)
+        .row(vec![0, 1, 1], vec![0, 0, 0], 1)
+        .row(vec![0, 1, 1], vec![0, 1, 1], 2)
+        .row(vec![1, 1, 2], vec![1, 1, 2], 1)
+        .row(vec![0, 0, 0], vec![1, 0, 1], 0)
+        .build();
+    let mut air2 = AddAir::new_with_global(Direction::Send);
+    let (main_trace2, _, _) = LookupTraceBuilder::new_with_width(width, &mut rng)
+        .row(vec![0, 1, 1], vec![0, 1, 1], 2)
+        .row(vec![0, 1, 1], vec![0, 0, 0], 1)
+        .row(vec![1, 1, 2], vec![1, 0, 1], 0)
+        .row(vec![0, 0, 0], vec![1, 1, 2], 1)
+        .build();
+
+    let lookups1 = <AddAir as AirLookupHandler<MockAirBuilder>>::get_lookups(&mut air1);
+    let lookups2 = <AddAir as AirLookupHandler<MockAirBuilder>>::get_lookups(&mut air2);
+
+    let pcs = multilinear_pcs();
+    let (commit1, proof1) = prove_logup_gkr(&pcs, &lookups1, &main_trace1, challenges);
+    let (commit2, proof2) = prove_logup_gkr(&pcs, &lookups2, &main_trace2, challenges);
+    let sums1 = verify_logup_gkr(&pcs, &lookups1, commit1, 2, challenges, &proof1).unwrap();
+    let sums2 = verify_logup_gkr(&pcs, &lookups2, commit2, 2, challenges, &proof2).unwrap();
+    assert_eq!(sums1[0], EF::ZERO);
+    assert_eq!(sums2[0], EF::ZERO);
+    LogUpGkrGadget::new()
+        .verify_global_final_value(&[sums1[1], sums2[1]])
+        .expect("Global lookups final values should sum to 0.");
+
+    // The main trace evaluations are checked against the commitment.
+    let mut wrong_proof = proof1.clone();
+    wrong_proof.main_evals[1][0] += EF::ONE;
+    assert!(matches!(
+        verify_logup_gkr(&pcs, &lookups1, commit1, 2, challenges, &wrong_proof),
+        Err(LookupError::InvalidMainOpening)
+    ));
+
+    let mut wrong_proof = proof1;
+    wrong_proof.main_evals.pop();
+    assert!(matches!(
+        verify_logup_gkr(&pcs, &lookups1, commit1, 2, challenges, &wrong_proof),
+        Err(LookupError::InvalidProofShape)
+    ));
+}
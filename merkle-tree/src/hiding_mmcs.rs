@@ -25,6 +25,9 @@ use crate::{MerkleTree, MerkleTreeError, MerkleTreeMmcs};
 /// `SALT_ELEMS` should be set such that the product of `SALT_ELEMS` with the size of the value
 /// (`P::Value`) is at least the target security parameter.
 ///
+/// The heights of committed matrices are subject to the same restrictions as in
+/// [`MerkleTreeMmcs`].
+///
 /// `R` should be an appropriately seeded cryptographically secure pseudorandom number generator
 /// (CSPRNG). Something like `ThreadRng` may work, although it relies on the operating system to
 /// provide sufficient entropy.
@@ -102,6 +105,10 @@ where
         let (openings, salts) = salted_openings
             .into_iter()
             .map(|row| {
+                // Matrices with no row at this index have an empty opening and no salt.
+                if row.is_empty() {
+                    return (Vec::new(), Vec::new());
+                }
                 let (a, b) = row.split_at(row.len() - SALT_ELEMS);
                 (a.to_vec(), b.to_vec())
             })
@@ -126,7 +133,14 @@ where
         let (opened_values, (salts, siblings)) = batch_opening.unpack();
 
         let opened_salted_values = zip_eq(opened_values, salts, MerkleTreeError::WrongBatchSize)?
-            .map(|(opened, salt)| opened.iter().chain(salt.iter()).copied().collect_vec())
+            .map(|(opened, salt)| {
+                // An empty opening without salt stands for a matrix with no row at this index,
+                // and must be passed on to the inner MMCS unchanged.
+                if opened.is_empty() && salt.is_empty() {
+                    return Vec::new();
+                }
+                opened.iter().chain(salt.iter()).copied().collect_vec()
+            })
             .collect_vec();
 
         self.inner.verify_batch(
@@ -172,17 +186,47 @@ mod tests {
     >;
 
     #[test]
-    #[should_panic]
-    fn mismatched_heights() {
+    fn mismatched_heights() -> Result<(), MerkleTreeError> {
         let mut rng = SmallRng::seed_from_u64(1);
         let perm = Perm::new_from_rng_128(&mut rng);
         let hash = MyHash::new(perm.clone());
         let compress = MyCompress::new(perm);
         let mmcs = MyMmcs::new(hash, compress, rng);
 
-        // attempt to commit to a mat with 8 rows and a mat with 7 rows. this should panic.
+        // Commit to a mat with 8 rows and a mat with 7 rows. The small mat has no row at index 7,
+        // so its opening there is empty.
         let large_mat = RowMajorMatrix::new([1, 2, 3, 4, 5, 6, 7, 8].map(F::from_u8).to_vec(), 1);
         let small_mat = RowMajorMatrix::new([1, 2, 3, 4, 5, 6, 7].map(F::from_u8).to_vec(), 1);
+        let dims = vec![large_mat.dimensions(), small_mat.dimensions()];
+        let (commit, prover_data) = mmcs.commit(vec![large_mat, small_mat]);
+
+        for index in 0..8 {
+            let batch_proof = mmcs.open_batch(index, &prover_data);
+            assert_eq!(batch_proof.opened_values[0], vec![F::from_usize(index + 1)]);
+            if index == 7 {
+                assert!(batch_proof.opened_values[1].is_empty());
+                assert!(batch_proof.opening_proof.0[1].is_empty());
+            } else {
+                assert_eq!(batch_proof.opened_values[1], vec![F::from_usize(index + 1)]);
+            }
+            mmcs.verify_batch(&commit, &dims, index, (&batch_proof).into())?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic]
+    fn unreachable_rows() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let perm = Perm::new_from_rng_128(&mut rng);
+        let hash = MyHash::new(perm.clone());
+        let compress = MyCompress::new(perm);
+        let mmcs = MyMmcs::new(hash, compress, rng);
+
+        // attempt to commit to a mat with 5 rows and a mat with 4 rows. this should panic, as
+        // only 3 rows of the small mat can be reached when opening one of the 5 leaves.
+        let large_mat = RowMajorMatrix::new([1, 2, 3, 4, 5].map(F::from_u8).to_vec(), 1);
+        let small_mat = RowMajorMatrix::new([1, 2, 3, 4].map(F::from_u8).to_vec(), 1);
         let _ = mmcs.commit(vec![large_mat, small_mat]);
     }
 
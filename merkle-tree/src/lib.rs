@@ -5,6 +5,8 @@ extern crate alloc;
 mod hiding_mmcs;
 mod merkle_tree;
 mod mmcs;
+#[cfg(feature = "std")]
+pub mod persist;
 
 pub use hiding_mmcs::*;
 pub use merkle_tree::*;
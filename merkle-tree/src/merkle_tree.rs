@@ -1,40 +1,46 @@
+use alloc::collections::BTreeSet;
 use alloc::vec;
 use alloc::vec::Vec;
 use core::array;
+use core::borrow::BorrowMut;
 use core::cmp::Reverse;
 use core::marker::PhantomData;
 
 use itertools::Itertools;
 use p3_field::PackedValue;
 use p3_matrix::Matrix;
+use p3_matrix::dense::{DenseMatrix, DenseStorage};
 use p3_maybe_rayon::prelude::*;
 use p3_symmetric::{CryptographicHasher, Hash, PseudoCompressionFunction};
+use p3_util::{log2_ceil_usize, log2_strict_usize};
 use serde::{Deserialize, Serialize};
 use tracing::instrument;
 
-/// A binary Merkle tree whose leaves are vectors of matrix rows.
+/// A Merkle tree whose leaves are vectors of matrix rows.
 ///
 /// * `F` – scalar element type inside each matrix row.
 /// * `W` – scalar element type of every digest word.
 /// * `M` – matrix type. Must implement [`Matrix<F>`].
 /// * `DIGEST_ELEMS` – number of `W` words in one digest.
+/// * `ARITY` – number of children of every internal node. Defaults to a binary tree.
 ///
 /// The tree is **balanced only at the digest layer**.
-/// Leaf matrices may have arbitrary heights as long as any two heights
-/// that round **up** to the same power-of-two are equal.
+/// Leaf matrices may have arbitrary heights, as long as every row can be reached by an opening.
+/// Trees of arity other than two place some further restrictions on the heights, see
+/// [`MerkleTree::new`].
 ///
 /// Use [`root`] to fetch the final digest once the tree is built.
 ///
 /// This generally shouldn't be used directly. If you're using a Merkle tree as an MMCS,
 /// see `MerkleTreeMmcs`.
 #[derive(Debug, Serialize, Deserialize)]
-pub struct MerkleTree<F, W, M, const DIGEST_ELEMS: usize> {
+pub struct MerkleTree<F, W, M, const DIGEST_ELEMS: usize, const ARITY: usize = 2> {
     /// All leaf matrices in insertion order.
     ///
     /// Each matrix contributes rows to one or more digest layers, depending on its height.
-    /// Specifically, only the tallest matrices are included in the first digest layer,
-    /// while shorter matrices are injected into higher digest layers at positions determined
-    /// by their padded heights.
+    /// Specifically, only the matrices whose heights round up to the same power of two as the
+    /// tallest one are included in the first digest layer, while shorter matrices are injected
+    /// into higher digest layers at positions determined by their padded heights.
     ///
     /// This vector is retained only for inspection or re-opening of the tree; it is not used
     /// after construction time.
@@ -43,8 +49,8 @@ pub struct MerkleTree<F, W, M, const DIGEST_ELEMS: usize> {
     /// All intermediate digest layers, index 0 being the first layer above
     /// the leaves and the last layer containing exactly one root digest.
     ///
-    /// Every inner vector holds contiguous digests `[left₀, right₀, left₁,
-    /// right₁, …]`; higher layers refer to these by index.
+    /// Every inner vector holds contiguous groups of `ARITY` sibling digests, e.g.
+    /// `[left₀, right₀, left₁, right₁, …]` for a binary tree; higher layers refer to these by index.
     ///
     /// Serialization requires that `[W; DIGEST_ELEMS]` implements `Serialize` and
     /// `Deserialize`. This is automatically satisfied when `W` is a fixed-size type.
@@ -54,47 +60,93 @@ pub struct MerkleTree<F, W, M, const DIGEST_ELEMS: usize> {
     )]
     pub(crate) digest_layers: Vec<Vec<[W; DIGEST_ELEMS]>>,
 
+    /// The number of digest layers, counted from the bottom of the tree, which are not stored.
+    ///
+    /// When this is `k > 0`, `digest_layers[0]` is the `k`'th layer above the leaves and the
+    /// lower layers are recomputed from the leaves whenever an opening proof needs them.
+    #[serde(default)]
+    pub(crate) pruned_layers: usize,
+
     /// Zero-sized marker that binds the generic `F` but occupies no space.
     _phantom: PhantomData<F>,
 }
 
-impl<F: Clone + Send + Sync, W: Clone, M: Matrix<F>, const DIGEST_ELEMS: usize>
-    MerkleTree<F, W, M, DIGEST_ELEMS>
+impl<F: Clone + Send + Sync, W: Clone, M: Matrix<F>, const DIGEST_ELEMS: usize, const ARITY: usize>
+    MerkleTree<F, W, M, DIGEST_ELEMS, ARITY>
 {
     /// Build a tree from **one or more matrices**.
     ///
     /// * `h` – hashing function used on raw rows.
-    /// * `c` – 2-to-1 compression function used on digests.
+    /// * `c` – `ARITY`-to-1 compression function used on digests.
     /// * `leaves` – matrices to commit to. Must be non-empty.
     ///
-    /// Matrices do **not** need to have power-of-two heights. However, any two matrices
-    /// whose heights **round up** to the same power-of-two must have **equal actual height**.
-    /// This ensures proper balancing when folding digests layer-by-layer.
+    /// Matrices do **not** need to have power-of-two heights, and matrices whose heights round
+    /// up to the same power of two may have different heights. No padding rows are needed: the
+    /// node for row `r` hashes together row `r` of every matrix which has such a row, and nodes
+    /// without any rows get a default digest.
     ///
     /// All matrices are hashed row-by-row with `h`. The resulting digests are
-    /// then folded upwards with `c` until a single root remains.
+    /// then folded upwards with `c` until a single root remains. The rows of shorter matrices
+    /// are injected into the layer whose nodes line up with the MMCS opening semantics, i.e.
+    /// where every node covers `2^(log2_ceil(max_height) - log2_ceil(height))` leaves. This
+    /// requires that:
+    /// * every row can be reached, i.e. `height <= ceil(max_height / 2^(log2_ceil(max_height) -
+    ///   log2_ceil(height)))`. For instance, heights `8` and `5` can share a tree but `5` and `4`
+    ///   cannot, as only two of the rows of the latter would be reachable from the `5` leaves.
+    /// * for an `ARITY = 2^b` tree, the two `log2_ceil` heights differ by a multiple of `b`. When
+    ///   `ARITY` is not a power of two, only matrices whose heights round up to the same power of
+    ///   two as the maximal height, or of height one (injected at the root), can be committed to.
     ///
     /// # Panics
     /// * If `leaves` is empty.
     /// * If the packing widths of `P` and `PW` differ.
-    /// * If two leaf heights *round up* to the same power-of-two but are not
-    ///   equal (violates balancing rule).
+    /// * If `ARITY < 2`.
+    /// * If a leaf height cannot be injected into the tree, as described above.
+    pub fn new<P, PW, H, C>(h: &H, c: &C, leaves: Vec<M>) -> Self
+    where
+        P: PackedValue<Value = F>,
+        PW: PackedValue<Value = W>,
+        H: CryptographicHasher<F, [W; DIGEST_ELEMS]>
+            + CryptographicHasher<P, [PW; DIGEST_ELEMS]>
+            + Sync,
+        C: PseudoCompressionFunction<[W; DIGEST_ELEMS], ARITY>
+            + PseudoCompressionFunction<[PW; DIGEST_ELEMS], ARITY>
+            + Sync,
+    {
+        Self::new_pruned::<P, PW, H, C>(h, c, leaves, 0)
+    }
+
+    /// Build a tree which only stores its upper digest layers.
+    ///
+    /// This behaves like [`MerkleTree::new`], except that the lowest `pruned_layers` digest
+    /// layers are discarded once the layer above them has been computed. Opening proofs then
+    /// recompute the subtree of `ARITY^pruned_layers` leaf rows containing the opened index.
+    ///
+    /// As the first digest layer holds one digest per row of the tallest matrices, each pruned
+    /// layer divides the memory used by the remaining digests by roughly `ARITY`, at the cost of
+    /// hashing `ARITY^pruned_layers` rows (and compressing as many digests) per opening.
+    ///
+    /// `pruned_layers` is capped so that the root is always stored.
+    ///
+    /// # Panics
+    /// Under the same conditions as [`MerkleTree::new`].
     #[instrument(name = "build merkle tree", level = "debug", skip_all,
                  fields(dimensions = alloc::format!("{:?}", leaves.iter().map(|l| l.dimensions()).collect::<Vec<_>>())))]
-    pub fn new<P, PW, H, C>(h: &H, c: &C, leaves: Vec<M>) -> Self
+    pub fn new_pruned<P, PW, H, C>(h: &H, c: &C, leaves: Vec<M>, pruned_layers: usize) -> Self
     where
         P: PackedValue<Value = F>,
         PW: PackedValue<Value = W>,
         H: CryptographicHasher<F, [W; DIGEST_ELEMS]>
             + CryptographicHasher<P, [PW; DIGEST_ELEMS]>
             + Sync,
-        C: PseudoCompressionFunction<[W; DIGEST_ELEMS], 2>
-            + PseudoCompressionFunction<[PW; DIGEST_ELEMS], 2>
+        C: PseudoCompressionFunction<[W; DIGEST_ELEMS], ARITY>
+            + PseudoCompressionFunction<[PW; DIGEST_ELEMS], ARITY>
             + Sync,
     {
         assert!(!leaves.is_empty(), "No matrices given?");
         const {
             assert!(P::WIDTH == PW::WIDTH, "Packing widths must match");
+            assert!(ARITY >= 2, "Merkle trees must have arity at least 2");
         }
 
         let mut leaves_largest_first = leaves
@@ -103,49 +155,72 @@ impl<F: Clone + Send + Sync, W: Clone, M: Matrix<F>, const DIGEST_ELEMS: usize>
             .peekable();
 
         // check height property
+        let max_height = leaves_largest_first.peek().unwrap().height();
         assert!(
-            leaves_largest_first
-                .clone()
-                .map(|m| m.height())
-                .tuple_windows()
-                .all(|(curr, next)| curr == next
-                    || curr.next_power_of_two() != next.next_power_of_two()),
-            "matrix heights that round up to the same power of two must be equal"
+            leaves
+                .iter()
+                .all(|m| injection_layer::<ARITY>(max_height, m.height()).is_some()),
+            "matrix heights must be injectable into a tree of arity {ARITY}"
         );
-
-        let max_height = leaves_largest_first.peek().unwrap().height();
         let tallest_matrices = leaves_largest_first
-            .peeking_take_while(|m| m.height() == max_height)
+            .peeking_take_while(|m| injection_layer::<ARITY>(max_height, m.height()) == Some(0))
             .collect_vec();
 
-        let mut digest_layers = vec![first_digest_layer::<P, _, _, _, DIGEST_ELEMS>(
-            h,
-            tallest_matrices,
-        )];
+        // The root lives at layer `tree_depth` and must always be kept.
+        let pruned_layers = pruned_layers.min(tree_depth::<ARITY>(max_height));
+
+        let mut digest_layers = Vec::new();
+        let mut layer_index = 0;
+        let mut prev_layer =
+            first_digest_layer::<P, _, _, _, DIGEST_ELEMS, ARITY>(h, tallest_matrices);
         loop {
-            let prev_layer = digest_layers.last().unwrap().as_slice();
             if prev_layer.len() == 1 {
+                digest_layers.push(prev_layer);
                 break;
             }
-            let next_layer_len = (prev_layer.len() / 2).next_power_of_two();
 
             // The matrices that get injected at this layer.
             let matrices_to_inject = leaves_largest_first
-                .peeking_take_while(|m| m.height().next_power_of_two() == next_layer_len)
+                .peeking_take_while(|m| {
+                    injection_layer::<ARITY>(max_height, m.height()) == Some(layer_index + 1)
+                })
                 .collect_vec();
 
-            let next_digests = compress_and_inject::<P, _, _, _, _, DIGEST_ELEMS>(
-                prev_layer,
+            let next_digests = compress_and_inject::<P, _, _, _, _, DIGEST_ELEMS, ARITY>(
+                &prev_layer,
                 matrices_to_inject,
                 h,
                 c,
             );
-            digest_layers.push(next_digests);
+            let prev_layer = core::mem::replace(&mut prev_layer, next_digests);
+            if layer_index >= pruned_layers {
+                digest_layers.push(prev_layer);
+            }
+            layer_index += 1;
+        }
+
+        Self {
+            leaves,
+            digest_layers,
+            pruned_layers,
+            _phantom: PhantomData,
         }
+    }
 
+    /// Assemble a tree from previously computed leaves and digest layers.
+    ///
+    /// No consistency checks are performed; the caller is responsible for providing the
+    /// digest layers which `new` would have produced for `leaves`.
+    #[cfg(feature = "std")]
+    pub(crate) const fn from_parts(
+        leaves: Vec<M>,
+        digest_layers: Vec<Vec<[W; DIGEST_ELEMS]>>,
+        pruned_layers: usize,
+    ) -> Self {
         Self {
             leaves,
             digest_layers,
+            pruned_layers,
             _phantom: PhantomData,
         }
     }
@@ -158,6 +233,378 @@ impl<F: Clone + Send + Sync, W: Clone, M: Matrix<F>, const DIGEST_ELEMS: usize>
     {
         self.digest_layers.last().unwrap()[0].into()
     }
+
+    /// Return the number of digest layers below the root.
+    pub(crate) const fn depth(&self) -> usize {
+        self.pruned_layers + self.digest_layers.len() - 1
+    }
+
+    /// Return the sibling digests on the path from leaf `index` up to the root.
+    ///
+    /// For every layer below the root, this contains the `ARITY - 1` siblings of the node on
+    /// this path, in the order they appear in the layer. If some of the lower layers were pruned,
+    /// they are recomputed using `h` and `c`, which must be the functions the tree was built with.
+    pub(crate) fn sibling_path<P, PW, H, C>(
+        &self,
+        h: &H,
+        c: &C,
+        index: usize,
+    ) -> Vec<[W; DIGEST_ELEMS]>
+    where
+        W: Copy + Default + Send + Sync,
+        P: PackedValue<Value = F>,
+        PW: PackedValue<Value = W>,
+        H: CryptographicHasher<F, [W; DIGEST_ELEMS]>
+            + CryptographicHasher<P, [PW; DIGEST_ELEMS]>
+            + Sync,
+        C: PseudoCompressionFunction<[W; DIGEST_ELEMS], ARITY>
+            + PseudoCompressionFunction<[PW; DIGEST_ELEMS], ARITY>
+            + Sync,
+    {
+        let pruned = self.pruned_layers;
+        let depth = self.depth();
+        let mut siblings = Vec::with_capacity(depth * (ARITY - 1));
+        let mut push_siblings = |layer: &[[W; DIGEST_ELEMS]], position: usize| {
+            let first = position - position % ARITY;
+            siblings.extend(
+                (first..first + ARITY)
+                    .filter(|&j| j != position)
+                    .map(|j| layer[j]),
+            );
+        };
+
+        if pruned > 0 {
+            let subtree_index = index / ARITY.pow(pruned as u32);
+            let subtree = self.recompute_subtree::<P, PW, H, C>(h, c, subtree_index);
+            for (i, layer) in subtree.iter().enumerate() {
+                // The subtree covers positions `subtree_index * ARITY^(pruned - i) ..` of layer `i`.
+                let offset = subtree_index * ARITY.pow((pruned - i) as u32);
+                push_siblings(layer, index / ARITY.pow(i as u32) - offset);
+            }
+        }
+
+        for i in pruned..depth {
+            push_siblings(&self.digest_layers[i - pruned], index / ARITY.pow(i as u32));
+        }
+        siblings
+    }
+
+    /// Recompute the pruned digest layers below the `subtree_index`'th node of the lowest
+    /// stored layer.
+    ///
+    /// Returns `pruned_layers` vectors, the `i`th holding the `ARITY^(pruned_layers - i)` digests
+    /// of layer `i` which lie below that node. This reproduces exactly the values `new` computed,
+    /// including the default digests used as padding.
+    fn recompute_subtree<P, PW, H, C>(
+        &self,
+        h: &H,
+        c: &C,
+        subtree_index: usize,
+    ) -> Vec<Vec<[W; DIGEST_ELEMS]>>
+    where
+        W: Copy + Default + Send + Sync,
+        P: PackedValue<Value = F>,
+        PW: PackedValue<Value = W>,
+        H: CryptographicHasher<F, [W; DIGEST_ELEMS]>
+            + CryptographicHasher<P, [PW; DIGEST_ELEMS]>
+            + Sync,
+        C: PseudoCompressionFunction<[W; DIGEST_ELEMS], ARITY>
+            + PseudoCompressionFunction<[PW; DIGEST_ELEMS], ARITY>
+            + Sync,
+    {
+        let pruned = self.pruned_layers;
+        let default_digest = [W::default(); DIGEST_ELEMS];
+
+        let mut leaves_largest_first = self
+            .leaves
+            .iter()
+            .sorted_by_key(|l| Reverse(l.height()))
+            .peekable();
+        let max_height = leaves_largest_first.peek().unwrap().height();
+        let tallest_matrices = leaves_largest_first
+            .peeking_take_while(|m| injection_layer::<ARITY>(max_height, m.height()) == Some(0))
+            .collect_vec();
+
+        // Rows past `max_height` only contribute default digests.
+        let subtree_size = ARITY.pow(pruned as u32);
+        let first_row = subtree_index * subtree_size;
+        let mut prev_layer = vec![default_digest; subtree_size];
+        let num_rows = max_height.saturating_sub(first_row).min(subtree_size);
+        hash_rows::<P, PW, H, M, DIGEST_ELEMS>(
+            h,
+            &tallest_matrices,
+            first_row,
+            &mut prev_layer[..num_rows],
+        );
+
+        // The full length of the previous layer, padding included, as computed by `new`.
+        let mut prev_layer_len = first_layer_len::<ARITY>(max_height);
+
+        let mut layers = Vec::with_capacity(pruned);
+        for i in 1..pruned {
+            let matrices_to_inject = leaves_largest_first
+                .peeking_take_while(|m| injection_layer::<ARITY>(max_height, m.height()) == Some(i))
+                .collect_vec();
+            let inject_height = matrices_to_inject.first().map_or(0, |m| m.height());
+
+            // Only the first `prev_layer_len / ARITY` digests are compressed; the rest are padding.
+            let num_compressed = prev_layer_len / ARITY;
+            let layer_size = ARITY.pow((pruned - i) as u32);
+            let first_node = subtree_index * layer_size;
+            let next_layer = (0..layer_size)
+                .into_par_iter()
+                .map(|j| {
+                    let node = first_node + j;
+                    if node >= num_compressed {
+                        return default_digest;
+                    }
+                    let digest = c.compress(array::from_fn(|k| prev_layer[ARITY * j + k]));
+                    if matrices_to_inject.is_empty() {
+                        return digest;
+                    }
+                    let rows_digest = if node < inject_height {
+                        hash_present_rows(h, &matrices_to_inject, node)
+                    } else {
+                        default_digest
+                    };
+                    c.compress(injection_input(digest, rows_digest, default_digest))
+                })
+                .collect();
+
+            layers.push(core::mem::replace(&mut prev_layer, next_layer));
+            prev_layer_len = next_layer_len::<ARITY>(prev_layer_len);
+        }
+        layers.push(prev_layer);
+        layers
+    }
+}
+
+impl<F, W, S, const DIGEST_ELEMS: usize, const ARITY: usize>
+    MerkleTree<F, W, DenseMatrix<F, S>, DIGEST_ELEMS, ARITY>
+where
+    F: Clone + Send + Sync,
+    W: Copy + Default + Send + Sync,
+    S: DenseStorage<F> + BorrowMut<[F]>,
+{
+    /// Overwrite rows of the leaf matrices in place and recompute the digests depending on them.
+    ///
+    /// Every update `(matrix, row, values)` replaces row `row` of the `matrix`'th leaf matrix by
+    /// `values`. Only the nodes on the paths from the updated rows up to the root are recomputed,
+    /// along with the pruned subtrees below them (if any), so updating `k` rows costs
+    /// `O(k * depth)` compressions instead of a full rebuild. `h` and `c` must be the functions
+    /// the tree was built with.
+    ///
+    /// # Panics
+    /// If a matrix index or row is out of bounds, or if `values` does not match the width of
+    /// the matrix.
+    #[instrument(name = "update merkle tree", level = "debug", skip_all)]
+    pub fn update_rows<P, PW, H, C>(
+        &mut self,
+        h: &H,
+        c: &C,
+        updates: impl IntoIterator<Item = (usize, usize, Vec<F>)>,
+    ) where
+        P: PackedValue<Value = F>,
+        PW: PackedValue<Value = W>,
+        H: CryptographicHasher<F, [W; DIGEST_ELEMS]>
+            + CryptographicHasher<P, [PW; DIGEST_ELEMS]>
+            + Sync,
+        C: PseudoCompressionFunction<[W; DIGEST_ELEMS], ARITY>
+            + PseudoCompressionFunction<[PW; DIGEST_ELEMS], ARITY>
+            + Sync,
+    {
+        let pruned = self.pruned_layers;
+        let depth = self.depth();
+        let max_height = self.leaves.iter().map(|m| m.height()).max().unwrap();
+
+        // The nodes to recompute in each layer. Nodes below the lowest stored layer are
+        // recomputed along with the pruned subtree containing them.
+        let mut dirty = vec![BTreeSet::new(); depth + 1];
+        for (matrix, row, values) in updates {
+            let leaf = &mut self.leaves[matrix];
+            assert!(
+                row < leaf.height(),
+                "row {row} of matrix {matrix} is out of bounds"
+            );
+            assert_eq!(values.len(), leaf.width(), "row has the wrong width");
+            leaf.row_mut(row).clone_from_slice(&values);
+
+            let layer = injection_layer::<ARITY>(max_height, leaf.height()).unwrap();
+            if layer < pruned {
+                dirty[pruned].insert(row / ARITY.pow((pruned - layer) as u32));
+            } else {
+                dirty[layer].insert(row);
+            }
+        }
+
+        // The matrices injected at each layer, tallest first as in `new`.
+        let leaves_largest_first = self
+            .leaves
+            .iter()
+            .sorted_by_key(|l| Reverse(l.height()))
+            .collect_vec();
+        let matrices_to_inject = (0..=depth)
+            .map(|layer| {
+                leaves_largest_first
+                    .iter()
+                    .copied()
+                    .filter(|m| injection_layer::<ARITY>(max_height, m.height()) == Some(layer))
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        for layer in pruned..=depth {
+            let nodes = core::mem::take(&mut dirty[layer]).into_iter().collect_vec();
+            let digests: Vec<_> = nodes
+                .par_iter()
+                .map(|&node| {
+                    self.recompute_node::<P, PW, H, C>(
+                        h,
+                        c,
+                        &matrices_to_inject[layer],
+                        layer,
+                        node,
+                    )
+                })
+                .collect();
+            for (node, digest) in nodes.into_iter().zip(digests) {
+                self.digest_layers[layer - pruned][node] = digest;
+                if layer < depth {
+                    dirty[layer + 1].insert(node / ARITY);
+                }
+            }
+        }
+    }
+
+    /// Recompute the `node`'th digest of a stored `layer` from the layer below it, or from the
+    /// leaves if the layer below was pruned.
+    fn recompute_node<P, PW, H, C>(
+        &self,
+        h: &H,
+        c: &C,
+        matrices_to_inject: &[&DenseMatrix<F, S>],
+        layer: usize,
+        node: usize,
+    ) -> [W; DIGEST_ELEMS]
+    where
+        P: PackedValue<Value = F>,
+        PW: PackedValue<Value = W>,
+        H: CryptographicHasher<F, [W; DIGEST_ELEMS]>
+            + CryptographicHasher<P, [PW; DIGEST_ELEMS]>
+            + Sync,
+        C: PseudoCompressionFunction<[W; DIGEST_ELEMS], ARITY>
+            + PseudoCompressionFunction<[PW; DIGEST_ELEMS], ARITY>
+            + Sync,
+    {
+        let pruned = self.pruned_layers;
+        let default_digest = [W::default(); DIGEST_ELEMS];
+
+        let digest = if layer == 0 {
+            return hash_present_rows(h, matrices_to_inject, node);
+        } else if layer == pruned {
+            let subtree = self.recompute_subtree::<P, PW, H, C>(h, c, node);
+            let children = subtree.last().unwrap();
+            c.compress(array::from_fn(|k| children[k]))
+        } else {
+            let prev_layer = &self.digest_layers[layer - 1 - pruned];
+            c.compress(array::from_fn(|k| prev_layer[ARITY * node + k]))
+        };
+        if matrices_to_inject.is_empty() {
+            return digest;
+        }
+        let rows_digest = if node < matrices_to_inject[0].height() {
+            hash_present_rows(h, matrices_to_inject, node)
+        } else {
+            default_digest
+        };
+        c.compress(injection_input(digest, rows_digest, default_digest))
+    }
+}
+
+/// The length of the first digest layer, padding included, for tallest matrices of height
+/// `max_height`.
+///
+/// This is always a multiple of `ARITY`, except when `max_height == 1`.
+pub(crate) const fn first_layer_len<const ARITY: usize>(max_height: usize) -> usize {
+    if max_height == 1 {
+        1
+    } else {
+        max_height.next_multiple_of(ARITY)
+    }
+}
+
+/// The length of the digest layer above one of length `prev_len`, padding included.
+///
+/// This is always a multiple of `ARITY`, except when it is the root layer.
+pub(crate) const fn next_layer_len<const ARITY: usize>(prev_len: usize) -> usize {
+    if prev_len == ARITY {
+        1
+    } else {
+        (prev_len / ARITY).next_multiple_of(ARITY)
+    }
+}
+
+/// The number of digest layers below the root of an `ARITY`-ary tree whose tallest matrices
+/// have height `max_height`.
+///
+/// For binary trees, this is `log2_ceil(max_height)`.
+pub(crate) const fn tree_depth<const ARITY: usize>(max_height: usize) -> usize {
+    let mut len = first_layer_len::<ARITY>(max_height);
+    let mut depth = 0;
+    while len > 1 {
+        len = next_layer_len::<ARITY>(len);
+        depth += 1;
+    }
+    depth
+}
+
+/// Return the digest layer at which the rows of a matrix of height `height` are injected into
+/// an `ARITY`-ary tree whose tallest matrices have height `max_height`.
+///
+/// When opening leaf `index`, the MMCS opens row `index >> (log2_ceil(max_height) -
+/// log2_ceil(height))` of every matrix. Rows must therefore be injected into a layer whose nodes
+/// each cover exactly `2^(log2_ceil(max_height) - log2_ceil(height))` leaves. Returns `None` if
+/// no such layer exists, or if some rows of the matrix could never be opened as
+/// `index < max_height`.
+pub(crate) fn injection_layer<const ARITY: usize>(
+    max_height: usize,
+    height: usize,
+) -> Option<usize> {
+    let shift = log2_ceil_usize(max_height).checked_sub(log2_ceil_usize(height))?;
+    if height > max_height.div_ceil(1 << shift) {
+        return None;
+    }
+    if shift == 0 {
+        return Some(0);
+    }
+    if height == 1 {
+        // The root covers every leaf, so the only row is always the one being opened.
+        return Some(tree_depth::<ARITY>(max_height));
+    }
+    if !ARITY.is_power_of_two() {
+        return None;
+    }
+    let bits_per_layer = log2_strict_usize(ARITY);
+    shift
+        .is_multiple_of(bits_per_layer)
+        .then_some(shift / bits_per_layer)
+}
+
+/// The input to the compression function when injecting `rows_digest` into the node `digest`.
+///
+/// For binary trees this is simply `[digest, rows_digest]`. Trees with larger arity fill the
+/// remaining inputs with `default_digest`.
+#[inline]
+pub(crate) fn injection_input<T: Copy, const ARITY: usize>(
+    digest: T,
+    rows_digest: T,
+    default_digest: T,
+) -> [T; ARITY] {
+    array::from_fn(|i| match i {
+        0 => digest,
+        1 => rows_digest,
+        _ => default_digest,
+    })
 }
 
 /// Hash every row of the tallest matrices and build the first digest layer.
@@ -168,8 +615,8 @@ impl<F: Clone + Send + Sync, W: Clone, M: Matrix<F>, const DIGEST_ELEMS: usize>
 /// as the base (leaf-level) nodes for the rest of the Merkle tree.
 ///
 /// # Details
-/// - We always return an *even number of digests* (except when height is 1), to
-///   ensure even pairing at higher layers.
+/// - We always return a *multiple of `ARITY` digests* (except when height is 1), to
+///   ensure complete groups of siblings at higher layers.
 /// - Matrices are "vertically packed" to allow SIMD-friendly parallel hashing,
 ///   meaning rows can be processed in batches.
 /// - If the total number of rows isn't a multiple of the SIMD packing width,
@@ -177,12 +624,12 @@ impl<F: Clone + Send + Sync, W: Clone, M: Matrix<F>, const DIGEST_ELEMS: usize>
 ///
 /// # Arguments
 /// - `h`: Reference to the cryptographic hasher.
-/// - `tallest_matrices`: References to the tallest matrices (all must have same height).
+/// - `tallest_matrices`: References to the matrices injected into the first layer, tallest first.
 ///
 /// # Returns
 /// A vector of `[PW::Value; DIGEST_ELEMS]`, containing the digests of each row.
 #[instrument(name = "first digest layer", level = "debug", skip_all)]
-fn first_digest_layer<P, PW, H, M, const DIGEST_ELEMS: usize>(
+fn first_digest_layer<P, PW, H, M, const DIGEST_ELEMS: usize, const ARITY: usize>(
     h: &H,
     tallest_matrices: Vec<&M>,
 ) -> Vec<[PW::Value; DIGEST_ELEMS]>
@@ -194,19 +641,12 @@ where
         + Sync,
     M: Matrix<P::Value>,
 {
-    // The number of rows to pack and hash together in one SIMD batch.
-    let width = PW::WIDTH;
-
-    // Get the height of the tallest matrices (they are guaranteed to be equal).
+    // Get the height of the tallest matrix (they are sorted tallest first).
     let max_height = tallest_matrices[0].height();
 
-    // Compute the padded height to ensure we end up with an even number of digests.
+    // Compute the padded height to ensure we end up with complete groups of siblings.
     // **Exception:** if there's only 1 row, we keep it as 1.
-    let max_height_padded = if max_height == 1 {
-        1
-    } else {
-        max_height + max_height % 2
-    };
+    let max_height_padded = first_layer_len::<ARITY>(max_height);
 
     // Prepare a default digest value to fill unused slots or padding.
     let default_digest = [PW::Value::default(); DIGEST_ELEMS];
@@ -214,20 +654,54 @@ where
     // Allocate the digest vector with padded size, initialized to default digest.
     let mut digests = vec![default_digest; max_height_padded];
 
+    hash_rows::<P, PW, H, M, DIGEST_ELEMS>(h, &tallest_matrices, 0, &mut digests[0..max_height]);
+
+    // Return the final digest vector (now fully populated).
+    digests
+}
+
+/// Hash rows `first_row..first_row + digests.len()` of the given matrices, writing the digest
+/// of row `first_row + i` to `digests[i]`.
+///
+/// The digest of a row combines that row of every matrix which is tall enough to have it. The
+/// caller must ensure that at least one matrix has each of the hashed rows.
+///
+/// Complete batches of `PW::WIDTH` rows which are present in every matrix are hashed in parallel
+/// using vertical packing, while any leftover rows are handled using a fallback scalar path.
+fn hash_rows<P, PW, H, M, const DIGEST_ELEMS: usize>(
+    h: &H,
+    matrices: &[&M],
+    first_row: usize,
+    digests: &mut [[PW::Value; DIGEST_ELEMS]],
+) where
+    P: PackedValue,
+    PW: PackedValue,
+    H: CryptographicHasher<P::Value, [PW::Value; DIGEST_ELEMS]>
+        + CryptographicHasher<P, [PW; DIGEST_ELEMS]>
+        + Sync,
+    M: Matrix<P::Value>,
+{
+    // The number of rows to pack and hash together in one SIMD batch.
+    let width = PW::WIDTH;
+
+    // The number of rows which are present in every matrix.
+    let min_height = matrices.iter().map(|m| m.height()).min().unwrap_or(0);
+    let num_full_rows = min_height.saturating_sub(first_row).min(digests.len());
+
     // Parallel loop: process complete batches of `width` rows at a time.
-    digests[0..max_height]
+    digests[..num_full_rows]
         .par_chunks_exact_mut(width)
         .enumerate()
         .for_each(|(i, digests_chunk)| {
             // Compute the starting row index for this chunk.
-            let first_row = i * width;
+            let chunk_first_row = first_row + i * width;
 
-            // Collect all vertically packed rows from each matrix at `first_row`.
+            // Collect all vertically packed rows from each matrix at `chunk_first_row`.
             // These packed rows are then hashed together using `h`.
             let packed_digest: [PW; DIGEST_ELEMS] = h.hash_iter(
-                tallest_matrices
+                matrices
                     .iter()
-                    .flat_map(|m| m.vertically_packed_row(first_row)),
+                    .flat_map(|m| m.vertically_packed_row(chunk_first_row)),
             );
 
             // Unpack the resulting packed digest into individual scalar digests.
@@ -237,25 +711,59 @@ where
             }
         });
 
-    // Handle leftover rows that do not form a full SIMD batch (if any).
-    #[allow(clippy::needless_range_loop)]
-    for i in ((max_height / width) * width)..max_height {
-        unsafe {
-            // Safety: The loop guarantees i < max_height == matrix height.
-            // Use `row_unchecked` to avoid bounds checks for performance.
-            digests[i] = h.hash_iter(tallest_matrices.iter().flat_map(|m| m.row_unchecked(i)));
-        }
+    // Handle leftover rows that do not form a full SIMD batch, or are missing from some of the
+    // shorter matrices (if any).
+    for (i, digest) in digests
+        .iter_mut()
+        .enumerate()
+        .skip((num_full_rows / width) * width)
+    {
+        *digest = hash_present_rows(h, matrices, first_row + i);
     }
+}
 
-    // Return the final digest vector (now fully populated).
-    digests
+/// Hash row `r` of every matrix which has such a row.
+#[inline]
+fn hash_present_rows<F, H, M, const DIGEST_ELEMS: usize, W>(
+    h: &H,
+    matrices: &[&M],
+    r: usize,
+) -> [W; DIGEST_ELEMS]
+where
+    F: Clone + Send + Sync,
+    H: CryptographicHasher<F, [W; DIGEST_ELEMS]>,
+    M: Matrix<F>,
+{
+    h.hash_iter(
+        matrices
+            .iter()
+            .filter(|m| r < m.height())
+            .flat_map(|m| unsafe {
+                // Safety: We just checked that r < m.height().
+                // Use `row_unchecked` to avoid bounds checks for performance.
+                m.row_unchecked(r)
+            }),
+    )
+}
+
+/// Pack the `ARITY` children of the nodes `first_node..first_node + P::WIDTH` of the next layer.
+#[inline]
+fn packed_children<P: PackedValue, const DIGEST_ELEMS: usize, const ARITY: usize>(
+    prev_layer: &[[P::Value; DIGEST_ELEMS]],
+    first_node: usize,
+) -> [[P; DIGEST_ELEMS]; ARITY] {
+    array::from_fn(|child| {
+        array::from_fn(|j| P::from_fn(|k| prev_layer[ARITY * (first_node + k) + child][j]))
+    })
 }
 
 /// Fold one digest layer into the next and, when present, mix in rows
-/// taken from smaller matrices whose padded height equals `prev_layer.len()/2`.
+/// taken from smaller matrices which are injected at the next layer.
+///
+/// The matrices to inject must be sorted tallest first.
 ///
-/// Pads the output so its length is even unless it becomes the root.
-fn compress_and_inject<P, PW, H, C, M, const DIGEST_ELEMS: usize>(
+/// Pads the output so its length is a multiple of `ARITY` unless it becomes the root.
+fn compress_and_inject<P, PW, H, C, M, const DIGEST_ELEMS: usize, const ARITY: usize>(
     prev_layer: &[[PW::Value; DIGEST_ELEMS]],
     matrices_to_inject: Vec<&M>,
     h: &H,
@@ -267,67 +775,58 @@ where
     H: CryptographicHasher<P::Value, [PW::Value; DIGEST_ELEMS]>
         + CryptographicHasher<P, [PW; DIGEST_ELEMS]>
         + Sync,
-    C: PseudoCompressionFunction<[PW::Value; DIGEST_ELEMS], 2>
-        + PseudoCompressionFunction<[PW; DIGEST_ELEMS], 2>
+    C: PseudoCompressionFunction<[PW::Value; DIGEST_ELEMS], ARITY>
+        + PseudoCompressionFunction<[PW; DIGEST_ELEMS], ARITY>
         + Sync,
     M: Matrix<P::Value>,
 {
     if matrices_to_inject.is_empty() {
-        return compress::<PW, _, DIGEST_ELEMS>(prev_layer, c);
+        return compress::<PW, _, DIGEST_ELEMS, ARITY>(prev_layer, c);
     }
 
     let width = PW::WIDTH;
-    let next_len = matrices_to_inject[0].height();
-    // We always want to return an even number of digests, except when it's the root.
-    let next_len_padded = if prev_layer.len() == 2 {
-        1
-    } else {
-        // Round prev_layer.len() / 2 up to the next even integer.
-        (prev_layer.len() / 2 + 1) & !1
-    };
-
+    // We always want to return complete groups of siblings, except when it's the root.
+    let next_len_padded = next_layer_len::<ARITY>(prev_layer.len());
+    // We only need go as far as `1 / ARITY` of the length of the previous layer.
+    let num_compressed = prev_layer.len() / ARITY;
+
+    // Hash the injected rows. The matrices are sorted tallest first, and no row past the first
+    // `num_compressed` nodes could ever be opened.
+    let inject_height = matrices_to_inject[0].height();
+    debug_assert!(inject_height <= num_compressed);
     let default_digest = [PW::Value::default(); DIGEST_ELEMS];
+    let mut rows_digests = vec![default_digest; inject_height];
+    hash_rows::<P, PW, H, M, DIGEST_ELEMS>(h, &matrices_to_inject, 0, &mut rows_digests);
+
+    // Once we exceed the height of the matrices to inject, we continue with default_digest in
+    // place of the rows digest.
+    let rows_digest = |i: usize| rows_digests.get(i).copied().unwrap_or(default_digest);
+
+    let packed_default_digest = [PW::from_fn(|_| PW::Value::default()); DIGEST_ELEMS];
     let mut next_digests = vec![default_digest; next_len_padded];
-    next_digests[0..next_len]
+    next_digests[0..num_compressed]
         .par_chunks_exact_mut(width)
         .enumerate()
         .for_each(|(i, digests_chunk)| {
             let first_row = i * width;
-            let left = array::from_fn(|j| PW::from_fn(|k| prev_layer[2 * (first_row + k)][j]));
-            let right = array::from_fn(|j| PW::from_fn(|k| prev_layer[2 * (first_row + k) + 1][j]));
-            let mut packed_digest = c.compress([left, right]);
-            let tallest_digest = h.hash_iter(
-                matrices_to_inject
-                    .iter()
-                    .flat_map(|m| m.vertically_packed_row(first_row)),
-            );
-            packed_digest = c.compress([packed_digest, tallest_digest]);
+            let children = packed_children::<PW, DIGEST_ELEMS, ARITY>(prev_layer, first_row);
+            let packed_rows_digest =
+                array::from_fn(|j| PW::from_fn(|k| rows_digest(first_row + k)[j]));
+            let packed_digest = c.compress(injection_input(
+                c.compress(children),
+                packed_rows_digest,
+                packed_default_digest,
+            ));
             for (dst, src) in digests_chunk.iter_mut().zip(unpack_array(packed_digest)) {
                 *dst = src;
             }
         });
 
-    // If our packing width did not divide next_len, fall back to single-threaded scalar code
-    // for the last bit.
-    for i in (next_len / width * width)..next_len {
-        let left = prev_layer[2 * i];
-        let right = prev_layer[2 * i + 1];
-        let digest = c.compress([left, right]);
-        let rows_digest = unsafe {
-            // Safety: Clearly i < next_len = m.height().
-            h.hash_iter(matrices_to_inject.iter().flat_map(|m| m.row_unchecked(i)))
-        };
-        next_digests[i] = c.compress([digest, rows_digest]);
-    }
-
-    // At this point, we've exceeded the height of the matrices to inject, so we continue the
-    // process above except with default_digest in place of an input digest.
-    // We only need go as far as half the length of the previous layer.
-    for i in next_len..(prev_layer.len() / 2) {
-        let left = prev_layer[2 * i];
-        let right = prev_layer[2 * i + 1];
-        let digest = c.compress([left, right]);
-        next_digests[i] = c.compress([digest, default_digest]);
+    // If our packing width did not divide num_compressed, fall back to single-threaded scalar
+    // code for the last bit.
+    for i in (num_compressed / width * width)..num_compressed {
+        let digest = c.compress(array::from_fn(|k| prev_layer[ARITY * i + k]));
+        next_digests[i] = c.compress(injection_input(digest, rows_digest(i), default_digest));
     }
 
     next_digests
@@ -335,30 +834,25 @@ where
 
 /// Pure compression step used when no extra rows are injected.
 ///
-/// Takes pairs of digests from `prev_layer`, feeds them to `c`,
+/// Takes groups of `ARITY` digests from `prev_layer`, feeds them to `c`,
 /// and writes the results in order.
 ///
-/// Pads with the zero digest so the caller always receives an even-sized
-/// slice, except when the tree has shrunk to its single root.
-fn compress<P, C, const DIGEST_ELEMS: usize>(
+/// Pads with the zero digest so the caller always receives a slice whose length is a
+/// multiple of `ARITY`, except when the tree has shrunk to its single root.
+fn compress<P, C, const DIGEST_ELEMS: usize, const ARITY: usize>(
     prev_layer: &[[P::Value; DIGEST_ELEMS]],
     c: &C,
 ) -> Vec<[P::Value; DIGEST_ELEMS]>
 where
     P: PackedValue,
-    C: PseudoCompressionFunction<[P::Value; DIGEST_ELEMS], 2>
-        + PseudoCompressionFunction<[P; DIGEST_ELEMS], 2>
+    C: PseudoCompressionFunction<[P::Value; DIGEST_ELEMS], ARITY>
+        + PseudoCompressionFunction<[P; DIGEST_ELEMS], ARITY>
         + Sync,
 {
     let width = P::WIDTH;
-    // Always return an even number of digests, except when it's the root.
-    let next_len_padded = if prev_layer.len() == 2 {
-        1
-    } else {
-        // Round prev_layer.len() / 2 up to the next even integer.
-        (prev_layer.len() / 2 + 1) & !1
-    };
-    let next_len = prev_layer.len() / 2;
+    // Always return complete groups of siblings, except when it's the root.
+    let next_len_padded = next_layer_len::<ARITY>(prev_layer.len());
+    let next_len = prev_layer.len() / ARITY;
 
     let default_digest = [P::Value::default(); DIGEST_ELEMS];
     let mut next_digests = vec![default_digest; next_len_padded];
@@ -368,9 +862,8 @@ where
         .enumerate()
         .for_each(|(i, digests_chunk)| {
             let first_row = i * width;
-            let left = array::from_fn(|j| P::from_fn(|k| prev_layer[2 * (first_row + k)][j]));
-            let right = array::from_fn(|j| P::from_fn(|k| prev_layer[2 * (first_row + k) + 1][j]));
-            let packed_digest = c.compress([left, right]);
+            let children = packed_children::<P, DIGEST_ELEMS, ARITY>(prev_layer, first_row);
+            let packed_digest = c.compress(children);
             for (dst, src) in digests_chunk.iter_mut().zip(unpack_array(packed_digest)) {
                 *dst = src;
             }
@@ -379,9 +872,7 @@ where
     // If our packing width did not divide next_len, fall back to single-threaded scalar code
     // for the last bit.
     for i in (next_len / width * width)..next_len {
-        let left = prev_layer[2 * i];
-        let right = prev_layer[2 * i + 1];
-        next_digests[i] = c.compress([left, right]);
+        next_digests[i] = c.compress(array::from_fn(|k| prev_layer[ARITY * i + k]));
     }
 
     // Everything has been initialized so we can safely cast.
@@ -429,7 +920,7 @@ mod tests {
             [0x03; 32], // 0x01 ^ 0x02
             [0x07; 32], // 0x03 ^ 0x04
         ];
-        let result = compress::<u8, DummyCompressionFunction, 32>(&prev_layer, &compressor);
+        let result = compress::<u8, DummyCompressionFunction, 32, 2>(&prev_layer, &compressor);
         assert_eq!(result, expected);
     }
 
@@ -441,7 +932,7 @@ mod tests {
             [0x03; 32], // 0x05 ^ 0x06
             [0x00; 32],
         ];
-        let result = compress::<u8, DummyCompressionFunction, 32>(&prev_layer, &compressor);
+        let result = compress::<u8, DummyCompressionFunction, 32, 2>(&prev_layer, &compressor);
         assert_eq!(result, expected);
     }
 
@@ -460,7 +951,7 @@ mod tests {
                 result
             })
             .collect();
-        let result = compress::<u8, DummyCompressionFunction, 32>(&prev_layer, &compressor);
+        let result = compress::<u8, DummyCompressionFunction, 32, 2>(&prev_layer, &compressor);
         assert_eq!(result, expected);
     }
 
@@ -473,7 +964,7 @@ mod tests {
         let prev_layer = [[0xAA; 32], [0x55; 32]];
         let compressor = DummyCompressionFunction;
         let expected = vec![[0xFF; 32]];
-        let result = compress::<u8, DummyCompressionFunction, 32>(&prev_layer, &compressor);
+        let result = compress::<u8, DummyCompressionFunction, 32, 2>(&prev_layer, &compressor);
         assert_eq!(result, expected);
     }
 
@@ -496,7 +987,7 @@ mod tests {
         // extra padded digest filled with 0
         expected.push([0x00; 32]);
 
-        let result = compress::<u8, DummyCompressionFunction, 32>(&prev_layer, &compressor);
+        let result = compress::<u8, DummyCompressionFunction, 32, 2>(&prev_layer, &compressor);
         assert_eq!(result, expected);
         // also validate the padding branch explicitly
         assert_eq!(result.len(), 4);
@@ -18,44 +18,69 @@
 //! E.g. we start by making a standard MerkleTree commitment for each row of M and then add in the rows of N when we
 //! get to the correct level. A proof for the values of say `M[5]` and `N[1]` consists of the siblings `H(M[4]), c23, c10`.
 //!
+//! Trees of higher arity are supported when the compression function takes `ARITY` digests at once, e.g. a
+//! Poseidon2 permutation of width 24 compressing 3 digests of 8 elements. Every node then has `ARITY` children,
+//! and an opening proof contains the `ARITY - 1` siblings of each node on the path to the root. Rows of shorter
+//! matrices are injected as `C(node, H(rows), 0, ..., 0)`.
+//!
 
 use alloc::vec::Vec;
+use core::array;
+use core::borrow::BorrowMut;
 use core::cmp::Reverse;
+use core::iter::Peekable;
 use core::marker::PhantomData;
 
-use itertools::Itertools;
+use itertools::{Itertools, izip, zip_eq};
 use p3_commit::{BatchOpening, BatchOpeningRef, Mmcs};
 use p3_field::PackedValue;
+use p3_matrix::dense::{DenseMatrix, DenseStorage};
 use p3_matrix::{Dimensions, Matrix};
 use p3_symmetric::{CryptographicHasher, Hash, PseudoCompressionFunction};
-use p3_util::{log2_ceil_usize, log2_strict_usize};
+use p3_util::log2_ceil_usize;
 use serde::{Deserialize, Serialize};
 
 use crate::MerkleTree;
 use crate::MerkleTreeError::{
-    EmptyBatch, IncompatibleHeights, RootMismatch, WrongBatchSize, WrongHeight,
+    EmptyBatch, IncompatibleHeights, RootMismatch, WrongBatchSize, WrongHeight, WrongWidth,
 };
+use crate::merkle_tree::{injection_input, injection_layer, tree_depth};
 
 /// A Merkle Tree-based commitment scheme for multiple matrices of potentially differing heights.
 ///
 /// `MerkleTreeMmcs` generalizes a classical Merkle Tree to support committing to a list of
-/// matrices by arranging their rows into a unified binary tree. The tallest matrix defines
+/// matrices by arranging their rows into a unified tree. The tallest matrix defines
 /// the maximum height, and smaller matrices are integrated at appropriate depths.
 ///
+/// Heights need not be powers of two, but not every combination of heights can share a tree:
+/// opening leaf `index` opens row `index >> (log2_ceil(max_height) - log2_ceil(height))` of each
+/// matrix, so every row of a shorter matrix must be reachable from some row of the tallest ones.
+/// For example, heights `8` and `7` can be committed to together, but `5` and `4` cannot, as
+/// only rows `0..3` of the shorter matrix can be reached from the `5` leaves. Committing to such
+/// heights panics, and `verify_batch` rejects them with [`MerkleTreeError::IncompatibleHeights`].
+/// See [`MerkleTree::new`] for the full conditions.
+///
 /// Type Parameters:
 /// - `P`: Packed leaf value (e.g. a field element or vector of elements)
 /// - `PW`: Packed digest element (used in the hash and compression output)
 /// - `H`: Cryptographic hash function (leaf hash)
 /// - `C`: Pseudo-compression function (internal node compression)
 /// - `DIGEST_ELEMS`: Number of elements in a single digest
+/// - `ARITY`: Number of children of every internal node, i.e. the number of digests `C` compresses
+///   at once. Defaults to a binary tree. See [`MerkleTree::new`] for the restrictions it places on
+///   the heights of committed matrices.
 #[derive(Copy, Clone, Debug)]
-pub struct MerkleTreeMmcs<P, PW, H, C, const DIGEST_ELEMS: usize> {
+pub struct MerkleTreeMmcs<P, PW, H, C, const DIGEST_ELEMS: usize, const ARITY: usize = 2> {
     /// The hash function used to hash individual matrix rows (leaf level).
     hash: H,
 
     /// The compression function used to hash internal tree nodes.
     compress: C,
 
+    /// The number of lower digest layers which committed trees do not store, see
+    /// [`MerkleTree::new_pruned`].
+    pruned_layers: usize,
+
     /// Phantom type to associate `P` and `PW` without storing values.
     _phantom: PhantomData<(P, PW)>,
 }
@@ -70,15 +95,18 @@ pub enum MerkleTreeError {
     WrongWidth,
 
     /// The number of proof nodes does not match the expected tree height.
+    ///
+    /// A valid proof contains `ARITY - 1` sibling hashes for each of the `log_max_height` layers.
     WrongHeight {
-        /// Expected log2 of the maximum matrix height.
+        /// Expected number of layers below the root. For binary trees, this is the log2 of the
+        /// maximum matrix height.
         log_max_height: usize,
 
         /// Actual number of sibling hashes provided in the proof.
         num_siblings: usize,
     },
 
-    /// Matrix heights are incompatible; they cannot share a common binary Merkle tree.
+    /// Matrix heights are incompatible; they cannot share a common Merkle tree.
     IncompatibleHeights,
 
     /// The computed Merkle root does not match the provided commitment.
@@ -88,32 +116,46 @@ pub enum MerkleTreeError {
     EmptyBatch,
 }
 
-impl<P, PW, H, C, const DIGEST_ELEMS: usize> MerkleTreeMmcs<P, PW, H, C, DIGEST_ELEMS> {
+impl<P, PW, H, C, const DIGEST_ELEMS: usize, const ARITY: usize>
+    MerkleTreeMmcs<P, PW, H, C, DIGEST_ELEMS, ARITY>
+{
     /// Create a new `MerkleTreeMmcs` with the given hash and compression functions.
     pub const fn new(hash: H, compress: C) -> Self {
         Self {
             hash,
             compress,
+            pruned_layers: 0,
             _phantom: PhantomData,
         }
     }
+
+    /// Only store the digest layers above the lowest `pruned_layers` in committed trees.
+    ///
+    /// This trades memory for time: each pruned layer divides the memory used by the digests of
+    /// a committed tree by roughly `ARITY`, while every call to `open_batch` has to rehash a
+    /// subtree of `ARITY^pruned_layers` rows. Commitments and opening proofs are unaffected.
+    #[must_use]
+    pub const fn with_pruned_layers(mut self, pruned_layers: usize) -> Self {
+        self.pruned_layers = pruned_layers;
+        self
+    }
 }
 
-impl<P, PW, H, C, const DIGEST_ELEMS: usize> Mmcs<P::Value>
-    for MerkleTreeMmcs<P, PW, H, C, DIGEST_ELEMS>
+impl<P, PW, H, C, const DIGEST_ELEMS: usize, const ARITY: usize> Mmcs<P::Value>
+    for MerkleTreeMmcs<P, PW, H, C, DIGEST_ELEMS, ARITY>
 where
     P: PackedValue,
     PW: PackedValue,
     H: CryptographicHasher<P::Value, [PW::Value; DIGEST_ELEMS]>
         + CryptographicHasher<P, [PW; DIGEST_ELEMS]>
         + Sync,
-    C: PseudoCompressionFunction<[PW::Value; DIGEST_ELEMS], 2>
-        + PseudoCompressionFunction<[PW; DIGEST_ELEMS], 2>
+    C: PseudoCompressionFunction<[PW::Value; DIGEST_ELEMS], ARITY>
+        + PseudoCompressionFunction<[PW; DIGEST_ELEMS], ARITY>
         + Sync,
     PW::Value: Eq,
     [PW::Value; DIGEST_ELEMS]: Serialize + for<'de> Deserialize<'de>,
 {
-    type ProverData<M> = MerkleTree<P::Value, PW::Value, M, DIGEST_ELEMS>;
+    type ProverData<M> = MerkleTree<P::Value, PW::Value, M, DIGEST_ELEMS, ARITY>;
     type Commitment = Hash<P::Value, PW::Value, DIGEST_ELEMS>;
     type Proof = Vec<[PW::Value; DIGEST_ELEMS]>;
     type Error = MerkleTreeError;
@@ -122,7 +164,12 @@ where
         &self,
         inputs: Vec<M>,
     ) -> (Self::Commitment, Self::ProverData<M>) {
-        let tree = MerkleTree::new::<P, PW, H, C>(&self.hash, &self.compress, inputs);
+        let tree = MerkleTree::new_pruned::<P, PW, H, C>(
+            &self.hash,
+            &self.compress,
+            inputs,
+            self.pruned_layers,
+        );
         let root = tree.root();
         (root, tree)
     }
@@ -137,7 +184,7 @@ where
     fn open_batch<M: Matrix<P::Value>>(
         &self,
         index: usize,
-        prover_data: &MerkleTree<P::Value, PW::Value, M, DIGEST_ELEMS>,
+        prover_data: &MerkleTree<P::Value, PW::Value, M, DIGEST_ELEMS, ARITY>,
     ) -> BatchOpening<P::Value, Self> {
         let max_height = self.get_max_height(prover_data);
         let log_max_height = log2_ceil_usize(max_height);
@@ -150,14 +197,17 @@ where
                 let log2_height = log2_ceil_usize(matrix.height());
                 let bits_reduced = log_max_height - log2_height;
                 let reduced_index = index >> bits_reduced;
-                matrix.row(reduced_index).unwrap().into_iter().collect()
+                // Matrices which are shorter than the others of their padded height have no row
+                // to open at the end, which we represent by an empty opening.
+                matrix
+                    .row(reduced_index)
+                    .map_or_else(Vec::new, |row| row.into_iter().collect())
             })
             .collect_vec();
 
         // Get all the siblings nodes corresponding to the path from the root to the given leaf index.
-        let proof = (0..log_max_height)
-            .map(|i| prover_data.digest_layers[i][(index >> i) ^ 1])
-            .collect();
+        let proof = prover_data.sibling_path::<P, PW, H, C>(&self.hash, &self.compress, index);
+        debug_assert_eq!(proof.len(), prover_data.depth() * (ARITY - 1));
 
         BatchOpening::new(openings, proof)
     }
@@ -176,9 +226,11 @@ where
     /// - `index`: The index of a leaf in the tree.
     /// - `opened_values`: A vector of matrix rows. Assume that the tallest matrix committed
     ///   to has height `2^n >= M_tall.height() > 2^{n - 1}` and the `j`th matrix has height
-    ///   `2^m >= Mj.height() > 2^{m - 1}`. Then `j`'th value of opened values must be the row `Mj[index >> (m - n)]`.
-    /// - `proof`: A vector of sibling nodes. The `i`th element should be the node at level `i`
-    ///   with index `(index << i) ^ 1`.
+    ///   `2^m >= Mj.height() > 2^{m - 1}`. Then `j`'th value of opened values must be the row `Mj[index >> (m - n)]`,
+    ///   or empty if `Mj` has no such row.
+    /// - `proof`: A vector of sibling nodes. For a binary tree, the `i`th element should be the
+    ///   node at level `i` with index `(index >> i) ^ 1`. For higher arities, the proof contains
+    ///   `ARITY - 1` consecutive siblings per level, in the order they appear in that level.
     ///
     /// Returns nothing if the verification is successful, otherwise returns an error.
     fn verify_batch(
@@ -188,6 +240,10 @@ where
         mut index: usize,
         batch_proof: BatchOpeningRef<P::Value, Self>,
     ) -> Result<(), Self::Error> {
+        const {
+            assert!(ARITY >= 2, "Merkle trees must have arity at least 2");
+        }
+
         let (opened_values, opening_proof) = batch_proof.unpack();
         // Check that the openings have the correct shape.
         if dimensions.len() != opened_values.len() {
@@ -207,74 +263,93 @@ where
             .sorted_by_key(|(_, dims)| Reverse(dims.height))
             .peekable();
 
-        // Matrix heights that round up to the same power of two must be equal
-        if !heights_tallest_first
-            .clone()
-            .map(|(_, dims)| dims.height)
-            .tuple_windows()
-            .all(|(curr, next)| {
-                curr == next || curr.next_power_of_two() != next.next_power_of_two()
-            })
+        // Get the maximum height. As heights_tallest_first is sorted, this is the first height.
+        // Returns an error if either:
+        //              1. some height cannot be injected into a tree of this arity.
+        //              2. proof.len() does not match the depth of the tree.
+        //              3. heights_tallest_first is empty.
+        let max_height = match heights_tallest_first.peek() {
+            Some((_, dims)) => dims.height,
+            None => return Err(EmptyBatch),
+        };
+        if dimensions
+            .iter()
+            .any(|dims| injection_layer::<ARITY>(max_height, dims.height).is_none())
         {
             return Err(IncompatibleHeights);
         }
 
-        // Get the initial height padded to a power of two. As heights_tallest_first is sorted,
-        // the initial height will be the maximum height.
-        // Returns an error if either:
-        //              1. proof.len() != log_max_height
-        //              2. heights_tallest_first is empty.
-        let mut curr_height_padded = match heights_tallest_first.peek() {
-            Some((_, dims)) => {
-                let max_height = dims.height.next_power_of_two();
-                let log_max_height = log2_strict_usize(max_height);
-                if opening_proof.len() != log_max_height {
-                    return Err(WrongHeight {
-                        log_max_height,
-                        num_siblings: opening_proof.len(),
-                    });
-                }
-                max_height
+        // A matrix which has no row at the opened index must have an empty opening, as it is not
+        // included in the hash.
+        let log_max_height = log2_ceil_usize(max_height);
+        let leaf_index = index;
+        let has_row = |dims: &Dimensions| {
+            let bits_reduced = log_max_height - log2_ceil_usize(dims.height);
+            (leaf_index >> bits_reduced) < dims.height
+        };
+        if zip_eq(dimensions, opened_values).any(|(dims, row)| !has_row(dims) && !row.is_empty()) {
+            return Err(WrongWidth);
+        }
+
+        let depth = tree_depth::<ARITY>(max_height);
+        if opening_proof.len() != depth * (ARITY - 1) {
+            return Err(WrongHeight {
+                log_max_height: depth,
+                num_siblings: opening_proof.len(),
+            });
+        }
+
+        let default_digest = [PW::Value::default(); DIGEST_ELEMS];
+        // Hash the openings of the matrices injected at the given layer which have a row at the
+        // opened index. If there are none, the prover used the default digest instead.
+        let hash_layer_openings = |heights: &mut Peekable<_>, layer: usize| {
+            let rows = heights
+                .peeking_take_while(|(_, dims): &(usize, &Dimensions)| {
+                    injection_layer::<ARITY>(max_height, dims.height) == Some(layer)
+                })
+                .filter(|(_, dims)| has_row(dims))
+                .map(|(i, _)| opened_values[i].as_slice())
+                .collect_vec();
+            if rows.is_empty() {
+                default_digest
+            } else {
+                self.hash.hash_iter_slices(rows)
             }
-            None => return Err(EmptyBatch),
         };
 
         // Hash all matrix openings at the current height.
-        let mut root = self.hash.hash_iter_slices(
-            heights_tallest_first
-                .peeking_take_while(|(_, dims)| {
-                    dims.height.next_power_of_two() == curr_height_padded
-                })
-                .map(|(i, _)| opened_values[i].as_slice()),
-        );
+        let mut root = hash_layer_openings(&mut heights_tallest_first, 0);
+
+        for (layer, siblings) in opening_proof.chunks_exact(ARITY - 1).enumerate() {
+            // The position of the current node among its siblings.
+            let position = index % ARITY;
+            let mut siblings = siblings.iter();
+            let children = array::from_fn(|j| {
+                if j == position {
+                    root
+                } else {
+                    *siblings.next().unwrap()
+                }
+            });
 
-        for &sibling in opening_proof {
-            // The last bit of index informs us whether the current node is on the left or right.
-            let (left, right) = if index & 1 == 0 {
-                (root, sibling)
-            } else {
-                (sibling, root)
-            };
-
-            // Combine the current node with the sibling node to get the parent node.
-            root = self.compress.compress([left, right]);
-            index >>= 1;
-            curr_height_padded >>= 1;
-
-            // Check if there are any new matrix rows to inject at the next height.
-            let next_height = heights_tallest_first
-                .peek()
-                .map(|(_, dims)| dims.height)
-                .filter(|h| h.next_power_of_two() == curr_height_padded);
-            if let Some(next_height) = next_height {
-                // If there are new matrix rows, hash the rows together and then combine with the current root.
-                let next_height_openings_digest = self.hash.hash_iter_slices(
-                    heights_tallest_first
-                        .peeking_take_while(|(_, dims)| dims.height == next_height)
-                        .map(|(i, _)| opened_values[i].as_slice()),
-                );
+            // Combine the current node with the sibling nodes to get the parent node.
+            root = self.compress.compress(children);
+            index /= ARITY;
 
-                root = self.compress.compress([root, next_height_openings_digest]);
+            // Check if there are any new matrices to inject at the next height.
+            let inject_next = heights_tallest_first.peek().is_some_and(|(_, dims)| {
+                injection_layer::<ARITY>(max_height, dims.height) == Some(layer + 1)
+            });
+            if inject_next {
+                // If there are new matrix rows, hash the rows together and then combine with the current root.
+                let next_height_openings_digest =
+                    hash_layer_openings(&mut heights_tallest_first, layer + 1);
+
+                root = self.compress.compress(injection_input(
+                    root,
+                    next_height_openings_digest,
+                    default_digest,
+                ));
             }
         }
 
@@ -287,9 +362,113 @@ where
     }
 }
 
+impl<P, PW, H, C, const DIGEST_ELEMS: usize, const ARITY: usize>
+    MerkleTreeMmcs<P, PW, H, C, DIGEST_ELEMS, ARITY>
+where
+    P: PackedValue,
+    PW: PackedValue,
+    H: CryptographicHasher<P::Value, [PW::Value; DIGEST_ELEMS]>
+        + CryptographicHasher<P, [PW; DIGEST_ELEMS]>
+        + Sync,
+    C: PseudoCompressionFunction<[PW::Value; DIGEST_ELEMS], ARITY>
+        + PseudoCompressionFunction<[PW; DIGEST_ELEMS], ARITY>
+        + Sync,
+    PW::Value: Eq,
+    [PW::Value; DIGEST_ELEMS]: Serialize + for<'de> Deserialize<'de>,
+{
+    /// Replace the rows opened at `index` by `new_rows`, updating the committed tree in place.
+    ///
+    /// `new_rows` must have the shape of the opened values returned by `open_batch(index, ..)`:
+    /// a row of the same width for every committed matrix, or an empty row for matrices which have
+    /// no row at `index`. Only the paths from the updated rows to the root are recomputed.
+    ///
+    /// Returns the new commitment, along with an update proof which [`Self::verify_update`]
+    /// checks against the old and the new commitment. This is the opening of the old rows: the
+    /// sibling nodes on the path are not affected by the update.
+    ///
+    /// # Panics
+    /// If `new_rows` does not have the shape described above.
+    #[allow(clippy::type_complexity)]
+    pub fn update<S>(
+        &self,
+        prover_data: &mut MerkleTree<
+            P::Value,
+            PW::Value,
+            DenseMatrix<P::Value, S>,
+            DIGEST_ELEMS,
+            ARITY,
+        >,
+        index: usize,
+        new_rows: Vec<Vec<P::Value>>,
+    ) -> (
+        Hash<P::Value, PW::Value, DIGEST_ELEMS>,
+        BatchOpening<P::Value, Self>,
+    )
+    where
+        S: DenseStorage<P::Value> + BorrowMut<[P::Value]>,
+    {
+        let update_proof = self.open_batch(index, prover_data);
+        assert_eq!(
+            new_rows.len(),
+            update_proof.opened_values.len(),
+            "expected a row for every committed matrix"
+        );
+
+        let log_max_height = log2_ceil_usize(self.get_max_height(prover_data));
+        let updates = izip!(
+            0..,
+            new_rows,
+            &update_proof.opened_values,
+            self.get_matrix_heights(prover_data),
+        )
+        .filter_map(|(matrix, new_row, old_row, height)| {
+            assert_eq!(new_row.len(), old_row.len(), "row has the wrong width");
+            let reduced_index = index >> (log_max_height - log2_ceil_usize(height));
+            (reduced_index < height).then_some((matrix, reduced_index, new_row))
+        })
+        .collect_vec();
+        prover_data.update_rows::<P, PW, H, C>(&self.hash, &self.compress, updates);
+
+        (prover_data.root(), update_proof)
+    }
+
+    /// Verify that `new_commit` results from replacing the rows at `index` in `old_commit` by
+    /// `new_rows`, leaving the rest of the committed matrices unchanged.
+    ///
+    /// `update_proof` is the proof returned by [`Self::update`], i.e. the opening of the old rows
+    /// at `index` with respect to `old_commit`.
+    pub fn verify_update(
+        &self,
+        old_commit: &Hash<P::Value, PW::Value, DIGEST_ELEMS>,
+        new_commit: &Hash<P::Value, PW::Value, DIGEST_ELEMS>,
+        dimensions: &[Dimensions],
+        index: usize,
+        update_proof: BatchOpeningRef<P::Value, Self>,
+        new_rows: &[Vec<P::Value>],
+    ) -> Result<(), MerkleTreeError> {
+        let (old_rows, opening_proof) = update_proof.unpack();
+        if new_rows.len() != old_rows.len() {
+            return Err(WrongBatchSize);
+        }
+        if zip_eq(old_rows, new_rows).any(|(old_row, new_row)| old_row.len() != new_row.len()) {
+            return Err(WrongWidth);
+        }
+
+        // As the same siblings lead to both roots, nothing but the rows at `index` changed.
+        self.verify_batch(old_commit, dimensions, index, update_proof)?;
+        self.verify_batch(
+            new_commit,
+            dimensions,
+            index,
+            BatchOpeningRef::new(new_rows, opening_proof),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::vec;
+    use core::array;
 
     use itertools::Itertools;
     use p3_baby_bear::{BabyBear, Poseidon2BabyBear};
@@ -300,10 +479,12 @@ mod tests {
     use p3_symmetric::{
         CryptographicHasher, PaddingFreeSponge, PseudoCompressionFunction, TruncatedPermutation,
     };
-    use rand::SeedableRng;
+    use p3_util::log2_ceil_usize;
     use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
 
     use super::MerkleTreeMmcs;
+    use crate::MerkleTreeError;
 
     type F = BabyBear;
 
@@ -313,6 +494,19 @@ mod tests {
     type MyMmcs =
         MerkleTreeMmcs<<F as Field>::Packing, <F as Field>::Packing, MyHash, MyCompress, 8>;
 
+    // A 4-ary tree with (insecurely) small digests, so that 4 of them fit into the width-16 permutation.
+    type Hash4 = PaddingFreeSponge<Perm, 16, 8, 4>;
+    type Compress4 = TruncatedPermutation<Perm, 4, 4, 16>;
+    type Mmcs4 =
+        MerkleTreeMmcs<<F as Field>::Packing, <F as Field>::Packing, Hash4, Compress4, 4, 4>;
+
+    // A 3-ary tree using the width-24 permutation.
+    type Perm24 = Poseidon2BabyBear<24>;
+    type Hash3 = PaddingFreeSponge<Perm24, 24, 16, 8>;
+    type Compress3 = TruncatedPermutation<Perm24, 3, 8, 24>;
+    type Mmcs3 =
+        MerkleTreeMmcs<<F as Field>::Packing, <F as Field>::Packing, Hash3, Compress3, 8, 3>;
+
     #[test]
     fn commit_single_1x8() {
         let mut rng = SmallRng::seed_from_u64(1);
@@ -525,7 +719,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "matrix heights must be injectable")]
     fn mismatched_heights() {
         let mut rng = SmallRng::seed_from_u64(1);
         let perm = Perm::new_from_rng_128(&mut rng);
@@ -533,10 +727,123 @@ mod tests {
         let compress = MyCompress::new(perm);
         let mmcs = MyMmcs::new(hash, compress);
 
-        // attempt to commit to a mat with 8 rows and a mat with 7 rows. this should panic.
+        // attempt to commit to a mat with 5 rows and a mat with 4 rows. this should panic, as
+        // only 3 rows of the small mat can be reached when opening one of the 5 leaves.
+        let large_mat = RowMajorMatrix::new([1, 2, 3, 4, 5].map(F::from_u8).to_vec(), 1);
+        let small_mat = RowMajorMatrix::new([1, 2, 3, 4].map(F::from_u8).to_vec(), 1);
+        let _ = mmcs.commit(vec![large_mat, small_mat]);
+    }
+
+    #[test]
+    #[should_panic(expected = "matrix heights must be injectable")]
+    fn unreachable_rows_of_shorter_matrix() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let perm = Perm::new_from_rng_128(&mut rng);
+        let mmcs = MyMmcs::new(MyHash::new(perm.clone()), MyCompress::new(perm));
+
+        // Openings of the 9 leaves reach rows `index >> 2 <= 2` of the small mat, so its last
+        // row could never be opened.
+        let _ = mmcs.commit(vec![
+            RowMajorMatrix::<F>::rand(&mut rng, 9, 1),
+            RowMajorMatrix::<F>::rand(&mut rng, 4, 1),
+        ]);
+    }
+
+    #[test]
+    fn heights_rounding_to_the_same_power_of_two() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let perm = Perm::new_from_rng_128(&mut rng);
+        let mmcs = MyMmcs::new(MyHash::new(perm.clone()), MyCompress::new(perm));
+
+        // The heights round up to the same power of two but differ, so both matrices are hashed
+        // into the leaf layer and the last leaf only covers a row of the taller matrix.
         let large_mat = RowMajorMatrix::new([1, 2, 3, 4, 5, 6, 7, 8].map(F::from_u8).to_vec(), 1);
         let small_mat = RowMajorMatrix::new([1, 2, 3, 4, 5, 6, 7].map(F::from_u8).to_vec(), 1);
-        let _ = mmcs.commit(vec![large_mat, small_mat]);
+        let dims = [large_mat.dimensions(), small_mat.dimensions()];
+        let (commit, prover_data) = mmcs.commit(vec![large_mat, small_mat]);
+
+        for index in [0, 6, 7] {
+            let opening = mmcs.open_batch(index, &prover_data);
+            mmcs.verify_batch(&commit, &dims, index, (&opening).into())
+                .expect("expected verification to succeed");
+        }
+    }
+
+    #[test]
+    fn commit_unequal_heights() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let perm = Perm::new_from_rng_128(&mut rng);
+        let hash = MyHash::new(perm.clone());
+        let compress = MyCompress::new(perm);
+        let mmcs = MyMmcs::new(hash.clone(), compress.clone());
+
+        // Heights 4 and 3 round up to the same power of two, so both mats are hashed into the
+        // leaves. The last leaf only contains the last row of the large mat.
+        let large_mat = RowMajorMatrix::<F>::rand(&mut rng, 4, 2);
+        let small_mat = RowMajorMatrix::<F>::rand(&mut rng, 3, 1);
+        let leaf = |r: usize| {
+            hash.hash_iter(
+                large_mat
+                    .row(r)
+                    .unwrap()
+                    .into_iter()
+                    .chain(small_mat.row(r).into_iter().flatten()),
+            )
+        };
+        let expected_result = compress.compress([
+            compress.compress([leaf(0), leaf(1)]),
+            compress.compress([leaf(2), leaf(3)]),
+        ]);
+
+        let dims = [large_mat.dimensions(), small_mat.dimensions()];
+        let (commit, prover_data) = mmcs.commit(vec![large_mat, small_mat]);
+        assert_eq!(commit, expected_result);
+
+        // The small mat has no row to open at the last leaf.
+        let mut batch_opening = mmcs.open_batch(3, &prover_data);
+        assert!(batch_opening.opened_values[1].is_empty());
+        mmcs.verify_batch(&commit, &dims, 3, (&batch_opening).into())
+            .expect("expected verification to succeed");
+
+        // Claiming a value for the missing row must be rejected.
+        batch_opening.opened_values[1].push(F::ONE);
+        assert!(matches!(
+            mmcs.verify_batch(&commit, &dims, 3, (&batch_opening).into()),
+            Err(MerkleTreeError::WrongWidth)
+        ));
+    }
+
+    #[test]
+    fn verify_unequal_heights() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let perm = Perm::new_from_rng_128(&mut rng);
+        let hash = MyHash::new(perm.clone());
+        let compress = MyCompress::new(perm);
+        let mmcs = MyMmcs::new(hash, compress);
+
+        // Every pair of heights which round up to the same power of two differs.
+        let heights = [1000, 600, 500, 70, 33, 8, 5, 1];
+        let mats = heights
+            .iter()
+            .enumerate()
+            .map(|(i, &height)| RowMajorMatrix::<F>::rand(&mut rng, height, i + 1))
+            .collect_vec();
+        let dims = mats.iter().map(|m| m.dimensions()).collect_vec();
+
+        for pruned_layers in [0, 4] {
+            let mmcs = mmcs.clone().with_pruned_layers(pruned_layers);
+            let (commit, prover_data) = mmcs.commit(mats.clone());
+            for index in (0..1000).step_by(37).chain([999]) {
+                let batch_opening = mmcs.open_batch(index, &prover_data);
+                for (dims, opened) in dims.iter().zip(&batch_opening.opened_values) {
+                    let shift = 10 - log2_ceil_usize(dims.height);
+                    let has_row = index >> shift < dims.height;
+                    assert_eq!(opened.is_empty(), !has_row);
+                }
+                mmcs.verify_batch(&commit, &dims, index, (&batch_opening).into())
+                    .expect("expected verification to succeed");
+            }
+        }
     }
 
     #[test]
@@ -630,6 +937,130 @@ mod tests {
         .expect("expected verification to succeed");
     }
 
+    #[test]
+    fn pruned_layers_give_same_openings() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let perm = Perm::new_from_rng_128(&mut rng);
+        let hash = MyHash::new(perm.clone());
+        let compress = MyCompress::new(perm);
+        let mmcs = MyMmcs::new(hash, compress);
+
+        // Mixed heights, so that rows get injected both below and above the pruned layers.
+        let mats = [1000, 1000, 70, 33, 8, 1]
+            .into_iter()
+            .map(|height| RowMajorMatrix::<F>::rand(&mut rng, height, 5))
+            .collect_vec();
+        let dims = mats.iter().map(|m| m.dimensions()).collect_vec();
+
+        let (commit, prover_data) = mmcs.commit(mats.clone());
+
+        // The tree has 10 digest layers below the root, so 12 gets capped.
+        for pruned_layers in [1, 3, 7, 10, 12] {
+            let pruned_mmcs = mmcs.clone().with_pruned_layers(pruned_layers);
+            let (pruned_commit, pruned_data) = pruned_mmcs.commit(mats.clone());
+            assert_eq!(pruned_commit, commit);
+            assert!(pruned_data.digest_layers.len() <= prover_data.digest_layers.len());
+
+            for index in [0, 1, 63, 64, 300, 527] {
+                let expected = mmcs.open_batch(index, &prover_data);
+                let opening = pruned_mmcs.open_batch(index, &pruned_data);
+                assert_eq!(opening.opened_values, expected.opened_values);
+                assert_eq!(opening.opening_proof, expected.opening_proof);
+                mmcs.verify_batch(&commit, &dims, index, (&opening).into())
+                    .expect("expected verification to succeed");
+            }
+        }
+    }
+
+    #[test]
+    fn update_matches_fresh_commit() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let perm = Perm::new_from_rng_128(&mut rng);
+        let hash = MyHash::new(perm.clone());
+        let compress = MyCompress::new(perm);
+        let mmcs = MyMmcs::new(hash, compress);
+
+        let mut mats = [100, 100, 50, 13, 1]
+            .into_iter()
+            .map(|height| RowMajorMatrix::<F>::rand(&mut rng, height, 3))
+            .collect_vec();
+        let dims = mats.iter().map(|m| m.dimensions()).collect_vec();
+
+        for pruned_layers in [0, 3] {
+            let mmcs = mmcs.clone().with_pruned_layers(pruned_layers);
+            let (mut commit, mut prover_data) = mmcs.commit(mats.clone());
+            for index in [0, 37, 99, 37] {
+                let new_rows = dims
+                    .iter()
+                    .map(|_| (0..3).map(|_| rng.random()).collect_vec())
+                    .collect_vec();
+                let (new_commit, update_proof) =
+                    mmcs.update(&mut prover_data, index, new_rows.clone());
+                mmcs.verify_update(
+                    &commit,
+                    &new_commit,
+                    &dims,
+                    index,
+                    (&update_proof).into(),
+                    &new_rows,
+                )
+                .expect("expected verification to succeed");
+
+                // A different update must not verify.
+                let mut wrong_rows = new_rows.clone();
+                wrong_rows[2][0] += F::ONE;
+                assert!(matches!(
+                    mmcs.verify_update(
+                        &commit,
+                        &new_commit,
+                        &dims,
+                        index,
+                        (&update_proof).into(),
+                        &wrong_rows,
+                    ),
+                    Err(MerkleTreeError::RootMismatch)
+                ));
+
+                // The updated tree matches a freshly committed one.
+                for (mat, new_row) in mats.iter_mut().zip(new_rows) {
+                    let shift = 7 - log2_ceil_usize(mat.height());
+                    mat.row_mut(index >> shift).copy_from_slice(&new_row);
+                }
+                let (fresh_commit, _) = mmcs.commit(mats.clone());
+                assert_eq!(new_commit, fresh_commit);
+                commit = new_commit;
+            }
+        }
+    }
+
+    #[test]
+    fn update_rows_arity_4() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let perm = Perm::new_from_rng_128(&mut rng);
+        let mmcs = Mmcs4::new(Hash4::new(perm.clone()), Compress4::new(perm)).with_pruned_layers(2);
+
+        let mut mats = [64, 60, 16, 1]
+            .into_iter()
+            .map(|height| RowMajorMatrix::<F>::rand(&mut rng, height, 2))
+            .collect_vec();
+        let (_, mut prover_data) = mmcs.commit(mats.clone());
+
+        // Several rows of different matrices at once, some of which share a path.
+        let updates = [(0, 5), (0, 6), (1, 59), (2, 3), (3, 0)]
+            .map(|(matrix, row)| (matrix, row, vec![rng.random(), rng.random()]));
+        for (matrix, row, values) in &updates {
+            mats[*matrix].row_mut(*row).copy_from_slice(values);
+        }
+        prover_data.update_rows::<<F as Field>::Packing, <F as Field>::Packing, Hash4, Compress4>(
+            &mmcs.hash,
+            &mmcs.compress,
+            updates,
+        );
+
+        let (fresh_commit, _) = mmcs.commit(mats);
+        assert_eq!(prover_data.root(), fresh_commit);
+    }
+
     #[test]
     fn different_widths() {
         let mut rng = SmallRng::seed_from_u64(1);
@@ -649,4 +1080,153 @@ mod tests {
         mmcs.verify_batch(&commit, &dims, 17, (&batch_opening).into())
             .expect("expected verification to succeed");
     }
+
+    #[test]
+    fn commit_arity_4() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let perm = Perm::new_from_rng_128(&mut rng);
+        let hash = Hash4::new(perm.clone());
+        let compress = Compress4::new(perm);
+        let mmcs = Mmcs4::new(hash.clone(), compress.clone());
+        let default_digest = [F::ZERO; 4];
+
+        let v = (0..8).map(F::from_u8).collect_vec();
+        let (commit, _) = mmcs.commit_vec(v.clone());
+
+        // Eight leaves fill two of the four children of the root.
+        let leaf_hashes = v.iter().map(|&x| hash.hash_item(x)).collect_vec();
+        let expected_result = compress.compress([
+            compress.compress(leaf_hashes[0..4].try_into().unwrap()),
+            compress.compress(leaf_hashes[4..8].try_into().unwrap()),
+            default_digest,
+            default_digest,
+        ]);
+        assert_eq!(commit, expected_result);
+    }
+
+    #[test]
+    fn commit_arity_4_mixed() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let perm = Perm::new_from_rng_128(&mut rng);
+        let hash = Hash4::new(perm.clone());
+        let compress = Compress4::new(perm);
+        let mmcs = Mmcs4::new(hash.clone(), compress.clone());
+        let default_digest = [F::ZERO; 4];
+
+        // A 4 row matrix gets injected into the single layer below the root of a 16 leaf tree.
+        let mat_1 = RowMajorMatrix::<F>::rand(&mut rng, 16, 2);
+        let mat_2 = RowMajorMatrix::<F>::rand(&mut rng, 4, 3);
+        let (commit, prover_data) = mmcs.commit(vec![mat_1.clone(), mat_2.clone()]);
+
+        let layer_1 = (0..4)
+            .map(|i| {
+                let children = array::from_fn(|j| hash.hash_iter(mat_1.row(4 * i + j).unwrap()));
+                let injected = hash.hash_iter(mat_2.row(i).unwrap());
+                compress.compress([
+                    compress.compress(children),
+                    injected,
+                    default_digest,
+                    default_digest,
+                ])
+            })
+            .collect_vec();
+        assert_eq!(commit, compress.compress(layer_1.try_into().unwrap()));
+
+        let batch_opening = mmcs.open_batch(13, &prover_data);
+        assert_eq!(batch_opening.opening_proof.len(), 2 * 3);
+        assert_eq!(
+            batch_opening.opened_values,
+            vec![
+                mat_1.row_slice(13).unwrap().to_vec(),
+                mat_2.row_slice(3).unwrap().to_vec()
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_arity_4() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let perm = Perm::new_from_rng_128(&mut rng);
+        let mmcs = Mmcs4::new(Hash4::new(perm.clone()), Compress4::new(perm));
+
+        // Log heights 10, 8, 4 and 0 all differ from the maximum by a multiple of 2.
+        let mats = [1000, 1000, 200, 16, 1]
+            .into_iter()
+            .map(|height| RowMajorMatrix::<F>::rand(&mut rng, height, 3))
+            .collect_vec();
+        let dims = mats.iter().map(|m| m.dimensions()).collect_vec();
+
+        let (commit, prover_data) = mmcs.commit(mats.clone());
+        let pruned_mmcs = mmcs.clone().with_pruned_layers(3);
+        let (pruned_commit, pruned_data) = pruned_mmcs.commit(mats);
+        assert_eq!(pruned_commit, commit);
+
+        for index in [0, 1, 255, 256, 777, 799] {
+            let batch_opening = mmcs.open_batch(index, &prover_data);
+            mmcs.verify_batch(&commit, &dims, index, (&batch_opening).into())
+                .expect("expected verification to succeed");
+
+            let pruned_opening = pruned_mmcs.open_batch(index, &pruned_data);
+            assert_eq!(pruned_opening.opening_proof, batch_opening.opening_proof);
+
+            let mut tampered = batch_opening.clone();
+            tampered.opening_proof[4][0] += F::ONE;
+            assert!(matches!(
+                mmcs.verify_batch(&commit, &dims, index, (&tampered).into()),
+                Err(MerkleTreeError::RootMismatch)
+            ));
+        }
+    }
+
+    #[test]
+    fn verify_arity_3() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let perm = Perm24::new_from_rng_128(&mut rng);
+        let mmcs = Mmcs3::new(Hash3::new(perm.clone()), Compress3::new(perm));
+
+        let mats = vec![
+            RowMajorMatrix::<F>::rand(&mut rng, 100, 5),
+            RowMajorMatrix::<F>::rand(&mut rng, 100, 2),
+            RowMajorMatrix::<F>::rand(&mut rng, 1, 4),
+        ];
+        let dims = mats.iter().map(|m| m.dimensions()).collect_vec();
+        let (commit, prover_data) = mmcs.commit(mats);
+
+        for index in [0, 2, 3, 50, 99] {
+            let batch_opening = mmcs.open_batch(index, &prover_data);
+            // 100 leaves need 5 layers of a ternary tree, with 2 siblings per layer.
+            assert_eq!(batch_opening.opening_proof.len(), 10);
+            mmcs.verify_batch(&commit, &dims, index, (&batch_opening).into())
+                .expect("expected verification to succeed");
+        }
+
+        // Only full-height and height-1 matrices line up with the nodes of a ternary tree.
+        let wrong_dims = [
+            dims[0],
+            Dimensions {
+                height: 50,
+                width: 2,
+            },
+            dims[2],
+        ];
+        let batch_opening = mmcs.open_batch(3, &prover_data);
+        assert!(matches!(
+            mmcs.verify_batch(&commit, &wrong_dims, 3, (&batch_opening).into()),
+            Err(MerkleTreeError::IncompatibleHeights)
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn arity_4_incompatible_heights() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let perm = Perm::new_from_rng_128(&mut rng);
+        let mmcs = Mmcs4::new(Hash4::new(perm.clone()), Compress4::new(perm));
+
+        // Log heights 4 and 3 cannot share a 4-ary tree.
+        let _ = mmcs.commit(vec![
+            RowMajorMatrix::<F>::rand(&mut rng, 16, 1),
+            RowMajorMatrix::<F>::rand(&mut rng, 8, 1),
+        ]);
+    }
 }
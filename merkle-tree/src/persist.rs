@@ -0,0 +1,612 @@
+//! A flat, memory-mappable file layout for [`MerkleTree`] prover data.
+//!
+//! Long-running provers often commit to a batch of matrices, then spend a long time on other
+//! work before they need to open the commitment. Rather than keeping every digest layer and
+//! leaf matrix resident for that whole period, the tree can be written out with
+//! [`MerkleTree::write_to`] and reconstructed later, either as an owned tree with
+//! [`MerkleTree::read_from`] or directly on top of a memory-mapped file with
+//! [`MerkleTree::from_mapped_bytes`].
+//!
+//! The layout is (all integers are little-endian `u64`s):
+//!
+//! ```text
+//! magic (8 bytes) | DIGEST_ELEMS | ARITY | size_of::<F>() | size_of::<W>()
+//! pruned_layers | num_digest_layers | num_leaves
+//! len(layer_0) .. len(layer_{n-1})
+//! (width_0, height_0) .. (width_{m-1}, height_{m-1})
+//! padding to SECTION_ALIGN
+//! layer_0 | padding | .. | layer_{n-1} | padding
+//! leaf_0  | padding | .. | leaf_{m-1}  | padding
+//! ```
+//!
+//! Digests and leaf values are stored in their in-memory representation, and each section
+//! starts at a multiple of [`SECTION_ALIGN`] bytes. As `mmap` returns page-aligned memory, this
+//! means that the leaf sections of a mapped file can be reinterpreted in place as slices of `F`.
+
+extern crate std;
+
+use alloc::vec::Vec;
+use core::{iter, slice};
+use std::io::{self, Read, Write};
+
+use p3_matrix::Matrix;
+use p3_matrix::dense::{RowMajorMatrix, RowMajorMatrixView};
+
+use crate::MerkleTree;
+use crate::merkle_tree::{first_layer_len, injection_layer, next_layer_len};
+
+/// Magic bytes identifying a serialized Merkle tree, including a format version.
+const MAGIC: [u8; 8] = *b"P3MRKL02";
+
+/// Every section of the file starts at an offset which is a multiple of this many bytes.
+pub const SECTION_ALIGN: usize = 64;
+
+/// The shape information stored in the header of a serialized tree.
+#[derive(Debug, PartialEq, Eq)]
+struct Layout {
+    pruned_layers: usize,
+    layer_lens: Vec<usize>,
+    leaf_dims: Vec<(usize, usize)>,
+}
+
+impl Layout {
+    /// The number of bytes used by the header, including the trailing padding.
+    fn header_len(&self) -> usize {
+        let words = 7 + self.layer_lens.len() + 2 * self.leaf_dims.len();
+        padded_len(MAGIC.len() + 8 * words)
+    }
+
+    fn write<Wr: Write>(
+        &self,
+        writer: &mut Wr,
+        digest_elems: usize,
+        arity: usize,
+        value_size: usize,
+        word_size: usize,
+    ) -> io::Result<()> {
+        let mut header = Vec::with_capacity(self.header_len());
+        header.extend_from_slice(&MAGIC);
+        let words = [
+            digest_elems,
+            arity,
+            value_size,
+            word_size,
+            self.pruned_layers,
+            self.layer_lens.len(),
+            self.leaf_dims.len(),
+        ]
+        .into_iter()
+        .chain(self.layer_lens.iter().copied())
+        .chain(self.leaf_dims.iter().flat_map(|&(w, h)| [w, h]));
+        for word in words {
+            header.extend_from_slice(&(word as u64).to_le_bytes());
+        }
+        header.resize(self.header_len(), 0);
+        writer.write_all(&header)
+    }
+
+    /// Read a header written by [`Layout::write`] for an `ARITY`-ary tree, checking that it
+    /// describes a tree which [`MerkleTree::new_pruned`] could have built.
+    fn read<R: Read, const ARITY: usize>(
+        reader: &mut R,
+        digest_elems: usize,
+        value_size: usize,
+        word_size: usize,
+    ) -> io::Result<Self> {
+        let mut magic = [0; MAGIC.len()];
+        reader.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(invalid_data("not a serialized Merkle tree"));
+        }
+
+        let mut read_word = || -> io::Result<usize> {
+            let mut bytes = [0; 8];
+            reader.read_exact(&mut bytes)?;
+            usize::try_from(u64::from_le_bytes(bytes))
+                .map_err(|_| invalid_data("length does not fit in usize"))
+        };
+
+        for expected in [digest_elems, ARITY, value_size, word_size] {
+            if read_word()? != expected {
+                return Err(invalid_data("tree was serialized with different types"));
+            }
+        }
+
+        let pruned_layers = read_word()?;
+        let num_layers = read_word()?;
+        let num_leaves = read_word()?;
+        let layer_lens = (0..num_layers)
+            .map(|_| read_word())
+            .collect::<io::Result<Vec<_>>>()?;
+        let leaf_dims = (0..num_leaves)
+            .map(|_| Ok((read_word()?, read_word()?)))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let layout = Self {
+            pruned_layers,
+            layer_lens,
+            leaf_dims,
+        };
+        layout.validate::<ARITY>()?;
+        let read_so_far = MAGIC.len() + 8 * (7 + num_layers + 2 * num_leaves);
+        skip(reader, layout.header_len() - read_so_far)?;
+        Ok(layout)
+    }
+
+    /// Check that the leaf heights can share an `ARITY`-ary tree, and that the stored digest
+    /// layers are exactly those left by [`MerkleTree::new_pruned`] after pruning
+    /// `pruned_layers` layers.
+    fn validate<const ARITY: usize>(&self) -> io::Result<()> {
+        let max_height = self.leaf_dims.iter().map(|&(_, h)| h).max().unwrap_or(0);
+        if max_height == 0 {
+            return Err(invalid_data(
+                "the tallest leaf matrix must have at least one row",
+            ));
+        }
+        if self
+            .leaf_dims
+            .iter()
+            .any(|&(_, h)| injection_layer::<ARITY>(max_height, h).is_none())
+        {
+            return Err(invalid_data(
+                "leaf heights cannot be injected into the tree",
+            ));
+        }
+
+        let full_layer_lens: Vec<usize> =
+            iter::successors(Some(first_layer_len::<ARITY>(max_height)), |&len| {
+                (len > 1).then(|| next_layer_len::<ARITY>(len))
+            })
+            .collect();
+        // The root is never pruned.
+        if self.pruned_layers >= full_layer_lens.len() {
+            return Err(invalid_data("too many pruned digest layers"));
+        }
+        if self.layer_lens != full_layer_lens[self.pruned_layers..] {
+            return Err(invalid_data(
+                "digest layer lengths do not match the leaf heights",
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<F: Clone + Send + Sync, W: Clone, M: Matrix<F>, const DIGEST_ELEMS: usize, const ARITY: usize>
+    MerkleTree<F, W, M, DIGEST_ELEMS, ARITY>
+{
+    /// Write the digest layers and leaf matrices of this tree to `writer`.
+    ///
+    /// See the [module documentation](crate::persist) for a description of the layout. Values are
+    /// written in their in-memory representation, so the output should only be read back on a
+    /// machine with the same endianness, using the same `F`, `W`, `DIGEST_ELEMS` and `ARITY`.
+    ///
+    /// # Safety
+    ///
+    /// `F` and `W` must not contain any padding bytes, as the raw bytes of every value are
+    /// written out. This holds for all field types and digest words in this repository.
+    pub unsafe fn write_to<Wr: Write>(&self, writer: &mut Wr) -> io::Result<()>
+    where
+        F: Copy,
+        W: Copy,
+    {
+        let layout = Layout {
+            pruned_layers: self.pruned_layers,
+            layer_lens: self.digest_layers.iter().map(Vec::len).collect(),
+            leaf_dims: self
+                .leaves
+                .iter()
+                .map(|m| (m.width(), m.height()))
+                .collect(),
+        };
+        layout.write(writer, DIGEST_ELEMS, ARITY, size_of::<F>(), size_of::<W>())?;
+
+        for layer in &self.digest_layers {
+            let bytes = unsafe {
+                // Safety: `W` has no padding bytes, as guaranteed by the caller, so neither
+                // does `[W; DIGEST_ELEMS]`.
+                as_bytes(layer)
+            };
+            writer.write_all(bytes)?;
+            write_padding(writer, bytes.len())?;
+        }
+
+        for matrix in &self.leaves {
+            for r in 0..matrix.height() {
+                let row = unsafe {
+                    // Safety: `r < matrix.height()`.
+                    matrix.row_slice_unchecked(r)
+                };
+                writer.write_all(unsafe {
+                    // Safety: `F` has no padding bytes, as guaranteed by the caller.
+                    as_bytes(&row)
+                })?;
+            }
+            write_padding(writer, size_of::<F>() * matrix.width() * matrix.height())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<F: Copy + Send + Sync, W: Copy, const DIGEST_ELEMS: usize, const ARITY: usize>
+    MerkleTree<F, W, RowMajorMatrix<F>, DIGEST_ELEMS, ARITY>
+{
+    /// Read a tree previously written by [`MerkleTree::write_to`], copying all of its data into
+    /// owned buffers.
+    ///
+    /// The result has the same type as the prover data of a `MerkleTreeMmcs` commitment to
+    /// `RowMajorMatrix`s (e.g. the prover data of `TwoAdicFriPcs`), so it can be passed straight
+    /// to `open_batch`.
+    ///
+    /// Returns an error if the header is malformed, i.e. if it was written for different types or
+    /// if its digest layer lengths do not match its leaf dimensions, or if `reader` ends early.
+    ///
+    /// # Safety
+    ///
+    /// The data must have been produced by [`MerkleTree::write_to`] for a tree with the same
+    /// `F`, `W`, `DIGEST_ELEMS` and `ARITY` on a machine with the same endianness. The stored bytes are
+    /// reinterpreted as `F` and `W` values without any validation, so corrupted input may produce
+    /// values which violate the invariants of those types.
+    pub unsafe fn read_from<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let layout =
+            Layout::read::<_, ARITY>(reader, DIGEST_ELEMS, size_of::<F>(), size_of::<W>())?;
+
+        let digest_layers = layout
+            .layer_lens
+            .iter()
+            .map(|&len| unsafe {
+                // Safety: Guaranteed by the caller.
+                read_section::<_, [W; DIGEST_ELEMS]>(reader, len)
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let leaves = layout
+            .leaf_dims
+            .iter()
+            .map(|&(width, height)| {
+                let len = checked_area(width, height)?;
+                let values = unsafe {
+                    // Safety: Guaranteed by the caller.
+                    read_section::<_, F>(reader, len)?
+                };
+                Ok(RowMajorMatrix::new(values, width))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        Ok(Self::from_parts(
+            leaves,
+            digest_layers,
+            layout.pruned_layers,
+        ))
+    }
+}
+
+impl<'a, F: Copy + Send + Sync, W: Copy, const DIGEST_ELEMS: usize, const ARITY: usize>
+    MerkleTree<F, W, RowMajorMatrixView<'a, F>, DIGEST_ELEMS, ARITY>
+{
+    /// Reconstruct a tree on top of the bytes written by [`MerkleTree::write_to`], typically a
+    /// memory-mapped file.
+    ///
+    /// The digest layers are copied into owned buffers, while the leaf matrices are borrowed
+    /// directly from `bytes`. This way the (usually much larger) leaf data is only paged in for
+    /// the rows which are actually opened.
+    ///
+    /// Returns an error if the header is malformed, if `bytes` is too short, or if `bytes` is not
+    /// suitably aligned for `F`. Page-aligned memory, as returned by `mmap`, is always suitably
+    /// aligned.
+    ///
+    /// # Safety
+    ///
+    /// The same requirements as for [`MerkleTree::read_from`] apply.
+    pub unsafe fn from_mapped_bytes(bytes: &'a [u8]) -> io::Result<Self> {
+        let mut reader = bytes;
+        let layout =
+            Layout::read::<_, ARITY>(&mut reader, DIGEST_ELEMS, size_of::<F>(), size_of::<W>())?;
+
+        let digest_layers = layout
+            .layer_lens
+            .iter()
+            .map(|&len| unsafe {
+                // Safety: Guaranteed by the caller.
+                read_section::<_, [W; DIGEST_ELEMS]>(&mut reader, len)
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let leaves = layout
+            .leaf_dims
+            .iter()
+            .map(|&(width, height)| {
+                let len = checked_area(width, height)?;
+                let byte_len = len
+                    .checked_mul(size_of::<F>())
+                    .ok_or_else(|| invalid_data("leaf matrix is too large"))?;
+                if reader.len() < byte_len {
+                    return Err(io::ErrorKind::UnexpectedEof.into());
+                }
+                let (section, rest) = reader.split_at(byte_len);
+                if !(section.as_ptr() as usize).is_multiple_of(align_of::<F>()) {
+                    return Err(invalid_data("leaf data is not aligned"));
+                }
+                let values = unsafe {
+                    // Safety: We checked the length and alignment above, and the validity of
+                    // the values is guaranteed by the caller.
+                    slice::from_raw_parts(section.as_ptr().cast::<F>(), len)
+                };
+                reader = rest;
+                skip(&mut reader, padding_len(byte_len))?;
+                Ok(RowMajorMatrixView::new(values, width))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        Ok(Self::from_parts(
+            leaves,
+            digest_layers,
+            layout.pruned_layers,
+        ))
+    }
+}
+
+/// Reinterpret a slice of `T`s as its underlying bytes.
+///
+/// # Safety
+///
+/// `T` must not contain any padding bytes.
+unsafe fn as_bytes<T>(values: &[T]) -> &[u8] {
+    unsafe { slice::from_raw_parts(values.as_ptr().cast::<u8>(), size_of_val(values)) }
+}
+
+/// The maximum number of bytes [`read_section`] allocates ahead of the data it has actually read.
+const READ_CHUNK_LEN: usize = 1 << 20;
+
+/// Read `len` values of type `T` followed by the padding which ends their section.
+///
+/// As `len` comes from an untrusted header, the values are read in chunks of at most
+/// [`READ_CHUNK_LEN`] bytes and the buffer only grows as data arrives. A corrupt length then
+/// results in an `UnexpectedEof` error rather than a huge up-front allocation.
+///
+/// # Safety
+///
+/// Any sequence of bytes produced by [`as_bytes`] on a slice of `T`s must be read back.
+unsafe fn read_section<R: Read, T: Copy>(reader: &mut R, len: usize) -> io::Result<Vec<T>> {
+    let byte_len = len
+        .checked_mul(size_of::<T>())
+        .ok_or_else(|| invalid_data("section is too large"))?;
+    let chunk_values = (READ_CHUNK_LEN / size_of::<T>().max(1)).max(1);
+
+    let mut values = Vec::<T>::new();
+    while values.len() < len {
+        let chunk = chunk_values.min(len - values.len());
+        values.reserve_exact(chunk);
+        unsafe {
+            // Safety: The buffer has spare capacity for `chunk` values. We zero it before
+            // handing it out as a byte slice so that `read_exact` only sees initialized memory.
+            let ptr = values.as_mut_ptr().add(values.len()).cast::<u8>();
+            let chunk_bytes = chunk * size_of::<T>();
+            ptr.write_bytes(0, chunk_bytes);
+            reader.read_exact(slice::from_raw_parts_mut(ptr, chunk_bytes))?;
+            // Safety: The new bytes have been initialized with data written by `as_bytes`, which
+            // the caller guarantees is a valid representation of `chunk` values of type `T`.
+            values.set_len(values.len() + chunk);
+        }
+    }
+
+    skip(reader, padding_len(byte_len))?;
+    Ok(values)
+}
+
+/// Write the zero bytes needed to pad a section of `section_len` bytes.
+fn write_padding<Wr: Write>(writer: &mut Wr, section_len: usize) -> io::Result<()> {
+    writer.write_all(&[0; SECTION_ALIGN][..padding_len(section_len)])
+}
+
+/// Discard `n` bytes from `reader`.
+fn skip<R: Read>(reader: &mut R, n: usize) -> io::Result<()> {
+    let skipped = io::copy(&mut reader.take(n as u64), &mut io::sink())?;
+    if skipped == n as u64 {
+        Ok(())
+    } else {
+        Err(io::ErrorKind::UnexpectedEof.into())
+    }
+}
+
+const fn padding_len(section_len: usize) -> usize {
+    padded_len(section_len) - section_len
+}
+
+const fn padded_len(section_len: usize) -> usize {
+    section_len.next_multiple_of(SECTION_ALIGN)
+}
+
+fn checked_area(width: usize, height: usize) -> io::Result<usize> {
+    width
+        .checked_mul(height)
+        .ok_or_else(|| invalid_data("leaf matrix is too large"))
+}
+
+fn invalid_data(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use p3_baby_bear::{BabyBear, Poseidon2BabyBear};
+    use p3_commit::Mmcs;
+    use p3_field::Field;
+    use p3_symmetric::{PaddingFreeSponge, TruncatedPermutation};
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    use super::*;
+    use crate::MerkleTreeMmcs;
+
+    type F = BabyBear;
+    type Perm = Poseidon2BabyBear<16>;
+    type MyHash = PaddingFreeSponge<Perm, 16, 8, 8>;
+    type MyCompress = TruncatedPermutation<Perm, 2, 8, 16>;
+    type MyMmcs =
+        MerkleTreeMmcs<<F as Field>::Packing, <F as Field>::Packing, MyHash, MyCompress, 8>;
+
+    fn setup() -> (MyMmcs, Vec<RowMajorMatrix<F>>) {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let perm = Perm::new_from_rng_128(&mut rng);
+        let mmcs = MyMmcs::new(MyHash::new(perm.clone()), MyCompress::new(perm));
+        let mats = vec![
+            RowMajorMatrix::<F>::rand(&mut rng, 37, 5),
+            RowMajorMatrix::<F>::rand(&mut rng, 37, 3),
+            RowMajorMatrix::<F>::rand(&mut rng, 9, 7),
+            RowMajorMatrix::<F>::rand(&mut rng, 1, 2),
+        ];
+        (mmcs, mats)
+    }
+
+    #[test]
+    fn write_then_read_round_trip() {
+        let (mmcs, mats) = setup();
+        let dims = mats.iter().map(|m| m.dimensions()).collect::<Vec<_>>();
+        let (commit, tree) = mmcs.commit(mats);
+
+        let mut bytes = Vec::new();
+        unsafe { tree.write_to(&mut bytes) }.unwrap();
+        assert!(bytes.len().is_multiple_of(SECTION_ALIGN));
+
+        let restored =
+            unsafe { MerkleTree::<F, F, RowMajorMatrix<F>, 8>::read_from(&mut &*bytes) }.unwrap();
+        assert_eq!(restored.root(), commit);
+        assert_eq!(restored.digest_layers, tree.digest_layers);
+        assert_eq!(restored.leaves, tree.leaves);
+
+        for index in [0, 5, 17, 35] {
+            let opening = mmcs.open_batch(index, &restored);
+            let expected = mmcs.open_batch(index, &tree);
+            assert_eq!(opening.opened_values, expected.opened_values);
+            assert_eq!(opening.opening_proof, expected.opening_proof);
+            mmcs.verify_batch(&commit, &dims, index, (&opening).into())
+                .expect("expected verification to succeed");
+        }
+    }
+
+    #[test]
+    fn mapped_bytes_borrow_leaves() {
+        let (mmcs, mats) = setup();
+        let (commit, tree) = mmcs.commit(mats);
+
+        let mut bytes = Vec::new();
+        unsafe { tree.write_to(&mut bytes) }.unwrap();
+
+        // Emulate the alignment of a memory-mapped file.
+        let mut aligned = vec![0u64; bytes.len().div_ceil(8)];
+        let aligned_bytes = unsafe { as_bytes_mut(&mut aligned) };
+        aligned_bytes[..bytes.len()].copy_from_slice(&bytes);
+
+        let mapped = unsafe {
+            MerkleTree::<F, F, RowMajorMatrixView<'_, F>, 8>::from_mapped_bytes(aligned_bytes)
+        }
+        .unwrap();
+        assert_eq!(mapped.root(), commit);
+        for (m, original) in mapped.leaves.iter().zip(&tree.leaves) {
+            assert_eq!(m.values, &original.values[..]);
+        }
+        assert_eq!(
+            mmcs.open_batch(20, &mapped).opened_values,
+            mmcs.open_batch(20, &tree).opened_values
+        );
+    }
+
+    #[test]
+    fn rejects_mismatched_types() {
+        let (mmcs, mats) = setup();
+        let (_, tree) = mmcs.commit(mats);
+
+        let mut bytes = Vec::new();
+        unsafe { tree.write_to(&mut bytes) }.unwrap();
+
+        let wrong_digest =
+            unsafe { MerkleTree::<F, F, RowMajorMatrix<F>, 4>::read_from(&mut &*bytes) };
+        assert_eq!(wrong_digest.unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        bytes[0] ^= 1;
+        let bad_magic =
+            unsafe { MerkleTree::<F, F, RowMajorMatrix<F>, 8>::read_from(&mut &*bytes) };
+        assert_eq!(bad_magic.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn rejects_truncated_input() {
+        let (mmcs, mats) = setup();
+        let (_, tree) = mmcs.commit(mats);
+
+        let mut bytes = Vec::new();
+        unsafe { tree.write_to(&mut bytes) }.unwrap();
+        bytes.truncate(bytes.len() - SECTION_ALIGN - 1);
+
+        let truncated =
+            unsafe { MerkleTree::<F, F, RowMajorMatrix<F>, 8>::read_from(&mut &*bytes) };
+        assert_eq!(truncated.unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn rejects_huge_section_length() {
+        let (mmcs, mats) = setup();
+        let (_, tree) = mmcs.commit(mats);
+
+        let mut bytes = Vec::new();
+        unsafe { tree.write_to(&mut bytes) }.unwrap();
+
+        // Overwrite the width of the first leaf matrix, which directly follows the seven fixed
+        // header words and the digest layer lengths, with an absurd value. Widths are not tied
+        // to the rest of the header, so only the missing data reveals the corruption.
+        let offset = MAGIC.len() + 8 * (7 + tree.digest_layers.len());
+        bytes[offset..offset + 8].copy_from_slice(&(1u64 << 40).to_le_bytes());
+
+        let corrupt = unsafe { MerkleTree::<F, F, RowMajorMatrix<F>, 8>::read_from(&mut &*bytes) };
+        assert_eq!(corrupt.unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn rejects_inconsistent_header() {
+        let (mmcs, mats) = setup();
+        let (_, tree) = mmcs.commit(mats);
+
+        let mut bytes = Vec::new();
+        unsafe { tree.write_to(&mut bytes) }.unwrap();
+
+        let pruned_offset = MAGIC.len() + 8 * 4;
+        let first_layer_offset = MAGIC.len() + 8 * 7;
+        let first_height_offset = first_layer_offset + 8 * (tree.digest_layers.len() + 1);
+        let third_height_offset = first_height_offset + 8 * 4;
+        let num_layers = tree.digest_layers.len() as u64;
+        for (offset, value) in [
+            // A first digest layer one digest short.
+            (first_layer_offset, 37),
+            // Pruning the root.
+            (pruned_offset, num_layers),
+            // Pruning a layer which is still stored.
+            (pruned_offset, 1),
+            // A taller first matrix, which needs more digest layers.
+            (first_height_offset, 80),
+            // A height of which some rows cannot be reached from the tallest matrices.
+            (third_height_offset, 20),
+        ] {
+            let mut corrupt = bytes.clone();
+            corrupt[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+
+            let read =
+                unsafe { MerkleTree::<F, F, RowMajorMatrix<F>, 8>::read_from(&mut &*corrupt) };
+            assert_eq!(read.unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+            let mut aligned = vec![0u64; corrupt.len().div_ceil(8)];
+            let aligned_bytes = unsafe { as_bytes_mut(&mut aligned) };
+            aligned_bytes[..corrupt.len()].copy_from_slice(&corrupt);
+            let mapped = unsafe {
+                MerkleTree::<F, F, RowMajorMatrixView<'_, F>, 8>::from_mapped_bytes(aligned_bytes)
+            };
+            assert_eq!(mapped.unwrap_err().kind(), io::ErrorKind::InvalidData);
+        }
+    }
+
+    unsafe fn as_bytes_mut(values: &mut [u64]) -> &mut [u8] {
+        unsafe { slice::from_raw_parts_mut(values.as_mut_ptr().cast::<u8>(), size_of_val(values)) }
+    }
+}
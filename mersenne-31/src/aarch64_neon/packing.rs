@@ -8,8 +8,8 @@ use p3_field::exponentiation::exp_1717986917;
 use p3_field::interleave::{interleave_u32, interleave_u64};
 use p3_field::op_assign_macros::{
     impl_add_assign, impl_add_base_field, impl_div_methods, impl_mul_base_field, impl_mul_methods,
-    impl_packed_value, impl_rng, impl_sub_assign, impl_sub_base_field, impl_sum_prod_base_field,
-    ring_sum,
+    impl_packed_div_methods, impl_packed_value, impl_rng, impl_sub_assign, impl_sub_base_field,
+    impl_sum_prod_base_field, ring_sum,
 };
 use p3_field::{
     Algebra, Field, InjectiveMonomial, PackedField, PackedFieldPow2, PackedValue,
@@ -23,7 +23,7 @@ use rand::distr::{Distribution, StandardUniform};
 use crate::Mersenne31;
 
 const WIDTH: usize = 4;
-const P: uint32x4_t = unsafe { transmute::<[u32; WIDTH], _>([0x7fffffff; WIDTH]) };
+pub(crate) const P: uint32x4_t = unsafe { transmute::<[u32; WIDTH], _>([0x7fffffff; WIDTH]) };
 
 /// Vectorized NEON implementation of `Mersenne31` arithmetic.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -35,7 +35,7 @@ impl PackedMersenne31Neon {
     #[inline]
     #[must_use]
     /// Get an arch-specific vector representing the packed values.
-    fn to_vector(self) -> uint32x4_t {
+    pub(crate) fn to_vector(self) -> uint32x4_t {
         unsafe {
             // Safety: `Mersenne31` is `repr(transparent)` so it can be transmuted to `u32`. It
             // follows that `[Mersenne31; WIDTH]` can be transmuted to `[u32; WIDTH]`, which can be
@@ -52,7 +52,7 @@ impl PackedMersenne31Neon {
     /// SAFETY: The caller must ensure that each element of `vector` represents a valid
     /// `Mersenne31`.  In particular, each element of vector must be in `0..=P` (i.e. it fits in 31
     /// bits).
-    unsafe fn from_vector(vector: uint32x4_t) -> Self {
+    pub(crate) unsafe fn from_vector(vector: uint32x4_t) -> Self {
         // Safety: It is up to the user to ensure that elements of `vector` represent valid
         // `Mersenne31` values. We must only reason about memory representations. `uint32x4_t` can
         // be transmuted to `[u32; WIDTH]` (since arrays elements are contiguous in memory), which
@@ -194,6 +194,7 @@ impl_add_base_field!(PackedMersenne31Neon, Mersenne31);
 impl_sub_base_field!(PackedMersenne31Neon, Mersenne31);
 impl_mul_base_field!(PackedMersenne31Neon, Mersenne31);
 impl_div_methods!(PackedMersenne31Neon, Mersenne31);
+impl_packed_div_methods!(PackedMersenne31Neon);
 impl_sum_prod_base_field!(PackedMersenne31Neon, Mersenne31);
 
 impl Algebra<Mersenne31> for PackedMersenne31Neon {}
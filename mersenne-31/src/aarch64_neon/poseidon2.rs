@@ -1,67 +1,258 @@
-//! Eventually this will hold a vectorized Neon implementation of Poseidon2 for PackedMersenne31Neon
-//! Currently this is essentially a placeholder to allow compilation on Neon devices.
-//!
-//! Converting the AVX2/AVX512 code across to Neon is on the TODO list.
+//! Vectorized Neon implementation of Poseidon2 for PackedMersenne31Neon.
 
 use alloc::vec::Vec;
+use core::arch::aarch64::{self, uint32x4_t};
 
+use p3_field::{PrimeCharacteristicRing, uint32x4_mod_add};
 use p3_poseidon2::{
-    ExternalLayer, ExternalLayerConstants, ExternalLayerConstructor, GenericPoseidon2LinearLayers,
-    InternalLayer, InternalLayerConstructor, MDSMat4, add_rc_and_sbox_generic,
-    external_initial_permute_state, external_terminal_permute_state,
+    ExternalLayer, ExternalLayerConstants, ExternalLayerConstructor, InternalLayer,
+    InternalLayerConstructor, MDSMat4, external_initial_permute_state,
+    external_terminal_permute_state,
 };
 
-use crate::{GenericPoseidon2LinearLayersMersenne31, Mersenne31, PackedMersenne31Neon};
+use crate::{Mersenne31, P, PackedMersenne31Neon};
 
-/// The internal layers of the Poseidon2 permutation.
+/// The internal layers of the Poseidon2 permutation for Mersenne31.
+///
+/// The packed constants are stored as 4 copies of each constant in a `uint32x4_t`.
 #[derive(Debug, Clone)]
 pub struct Poseidon2InternalLayerMersenne31 {
     pub(crate) internal_constants: Vec<Mersenne31>,
+    packed_internal_constants: Vec<uint32x4_t>,
 }
 
-/// The external layers of the Poseidon2 permutation.
+impl InternalLayerConstructor<Mersenne31> for Poseidon2InternalLayerMersenne31 {
+    fn new_from_constants(internal_constants: Vec<Mersenne31>) -> Self {
+        Self::new_from_constants(internal_constants)
+    }
+}
+
+/// The external layers of the Poseidon2 permutation for Mersenne31.
+///
+/// The packed constants are stored as 4 copies of each constant in a `uint32x4_t`.
 #[derive(Clone)]
 pub struct Poseidon2ExternalLayerMersenne31<const WIDTH: usize> {
     pub(crate) external_constants: ExternalLayerConstants<Mersenne31, WIDTH>,
+    packed_initial_external_constants: Vec<[uint32x4_t; WIDTH]>,
+    packed_terminal_external_constants: Vec<[uint32x4_t; WIDTH]>,
 }
 
-impl InternalLayerConstructor<Mersenne31> for Poseidon2InternalLayerMersenne31 {
+impl<const WIDTH: usize> ExternalLayerConstructor<Mersenne31, WIDTH>
+    for Poseidon2ExternalLayerMersenne31<WIDTH>
+{
+    fn new_from_constants(external_constants: ExternalLayerConstants<Mersenne31, WIDTH>) -> Self {
+        Self::new_from_constants(external_constants)
+    }
+}
+
+/// Copy a constant into every lane of a vector.
+fn convert_to_vec(input: Mersenne31) -> uint32x4_t {
+    unsafe {
+        // Safety: If this code got compiled then NEON intrinsics are available.
+        aarch64::vdupq_n_u32(input.value)
+    }
+}
+
+impl Poseidon2InternalLayerMersenne31 {
+    /// Construct an instance of Poseidon2InternalLayerMersenne31 from a vector containing
+    /// the constants for each round. Internally, the constants are broadcast into vectors.
     fn new_from_constants(internal_constants: Vec<Mersenne31>) -> Self {
-        Self { internal_constants }
+        let packed_internal_constants = internal_constants
+            .iter()
+            .map(|&constant| convert_to_vec(constant))
+            .collect();
+        Self {
+            internal_constants,
+            packed_internal_constants,
+        }
     }
 }
 
-impl<const WIDTH: usize> ExternalLayerConstructor<Mersenne31, WIDTH>
-    for Poseidon2ExternalLayerMersenne31<WIDTH>
-{
+impl<const WIDTH: usize> Poseidon2ExternalLayerMersenne31<WIDTH> {
+    /// Construct an instance of Poseidon2ExternalLayerMersenne31 from an array of
+    /// vectors containing the constants for each round. Internally, the constants
+    /// are broadcast into vectors.
     fn new_from_constants(external_constants: ExternalLayerConstants<Mersenne31, WIDTH>) -> Self {
-        Self { external_constants }
+        let packed_initial_external_constants = external_constants
+            .get_initial_constants()
+            .iter()
+            .map(|array| array.map(convert_to_vec))
+            .collect();
+        let packed_terminal_external_constants = external_constants
+            .get_terminal_constants()
+            .iter()
+            .map(|array| array.map(convert_to_vec))
+            .collect();
+        Self {
+            external_constants,
+            packed_initial_external_constants,
+            packed_terminal_external_constants,
+        }
     }
 }
 
-impl<const WIDTH: usize, const D: u64> InternalLayer<PackedMersenne31Neon, WIDTH, D>
-    for Poseidon2InternalLayerMersenne31
-where
-    GenericPoseidon2LinearLayersMersenne31: GenericPoseidon2LinearLayers<WIDTH>,
-{
+/// Compute the map `x -> 2^I x` on Mersenne-31 field elements.
+///
+/// `x` must be represented as a value in `[0, P]`.
+/// This requires 2 generic parameters, `I` and `I_PRIME` satisfying `I + I_PRIME = 31`.
+/// If the inputs do not conform to this representations, the result is undefined.
+#[inline(always)]
+fn mul_2exp_i<const I: i32, const I_PRIME: i32>(val: PackedMersenne31Neon) -> PackedMersenne31Neon {
+    /*
+        We want this to compile to:
+            ushr  lo,       val,      #(31 - I)
+            sli   lo,       val,      #I
+            and   res,      lo,       P
+        throughput: .75 cyc/vec
+        latency: 6 cyc
+    */
+    const {
+        assert!(I + I_PRIME == 31);
+    }
+    unsafe {
+        // Safety: If this code got compiled then NEON intrinsics are available.
+        let input = val.to_vector();
+
+        // In M31, multiplication by 2^n corresponds to a cyclic rotation which
+        // is much faster than the naive multiplication method.
+
+        // Shift the high bits down. As input < 2^31, lo_bits < 2^I.
+        let lo_bits = aarch64::vshrq_n_u32::<I_PRIME>(input);
+
+        // Shift the low bits up and insert them above lo_bits. This also shifts something
+        // unwanted into the sign bit so we mark it dirty.
+        let output_dirty = aarch64::vsliq_n_u32::<I>(lo_bits, input);
+
+        // Clear the sign bit.
+        let output = aarch64::vandq_u32(output_dirty, P);
+        PackedMersenne31Neon::from_vector(output)
+    }
+}
+
+/// We hard code multiplication by the diagonal minus 1 of our internal matrix (1 + Diag(V))
+/// In the Mersenne31, WIDTH = 16 case, the diagonal minus 1 is:
+/// [-2] + 1 << [0, 1, 2, 3, 4, 5, 6, 7, 8, 10, 12, 13, 14, 15, 16]
+/// i.e. The first entry is -2 and all other entries are powers of 2.
+#[inline(always)]
+fn diagonal_mul_16(state: &mut [PackedMersenne31Neon; 16]) {
+    // The first three entries involve multiplication by -2, 1, 2 which are simple:
+    // state[0] -> -2*state[0] is handled by the calling code.
+    state[2] = state[2] + state[2];
+
+    // For the remaining entries we use our fast shift code.
+    state[3] = mul_2exp_i::<2, 29>(state[3]);
+    state[4] = mul_2exp_i::<3, 28>(state[4]);
+    state[5] = mul_2exp_i::<4, 27>(state[5]);
+    state[6] = mul_2exp_i::<5, 26>(state[6]);
+    state[7] = mul_2exp_i::<6, 25>(state[7]);
+    state[8] = mul_2exp_i::<7, 24>(state[8]);
+    state[9] = mul_2exp_i::<8, 23>(state[9]);
+    state[10] = mul_2exp_i::<10, 21>(state[10]);
+    state[11] = mul_2exp_i::<12, 19>(state[11]);
+    state[12] = mul_2exp_i::<13, 18>(state[12]);
+    state[13] = mul_2exp_i::<14, 17>(state[13]);
+    state[14] = mul_2exp_i::<15, 16>(state[14]);
+    state[15] = mul_2exp_i::<16, 15>(state[15]);
+}
+
+/// We hard code multiplication by the diagonal minus 1 of our internal matrix (1 + Diag(V))
+/// In the Mersenne31, WIDTH = 24 case, the diagonal minus 1 is:
+/// [-2] + 1 << [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22]
+/// i.e. The first entry is -2 and all other entries are powers of 2.
+#[inline(always)]
+fn diagonal_mul_24(state: &mut [PackedMersenne31Neon; 24]) {
+    // The first three entries involve multiplication by -2, 1, 2 which are simple:
+    // state[0] -> -2*state[0] is handled by the calling code.
+    state[2] = state[2] + state[2];
+
+    // For the remaining entries we use our fast shift code.
+    state[3] = mul_2exp_i::<2, 29>(state[3]);
+    state[4] = mul_2exp_i::<3, 28>(state[4]);
+    state[5] = mul_2exp_i::<4, 27>(state[5]);
+    state[6] = mul_2exp_i::<5, 26>(state[6]);
+    state[7] = mul_2exp_i::<6, 25>(state[7]);
+    state[8] = mul_2exp_i::<7, 24>(state[8]);
+    state[9] = mul_2exp_i::<8, 23>(state[9]);
+    state[10] = mul_2exp_i::<9, 22>(state[10]);
+    state[11] = mul_2exp_i::<10, 21>(state[11]);
+    state[12] = mul_2exp_i::<11, 20>(state[12]);
+    state[13] = mul_2exp_i::<12, 19>(state[13]);
+    state[14] = mul_2exp_i::<13, 18>(state[14]);
+    state[15] = mul_2exp_i::<14, 17>(state[15]);
+    state[16] = mul_2exp_i::<15, 16>(state[16]);
+    state[17] = mul_2exp_i::<16, 15>(state[17]);
+    state[18] = mul_2exp_i::<17, 14>(state[18]);
+    state[19] = mul_2exp_i::<18, 13>(state[19]);
+    state[20] = mul_2exp_i::<19, 12>(state[20]);
+    state[21] = mul_2exp_i::<20, 11>(state[21]);
+    state[22] = mul_2exp_i::<21, 10>(state[22]);
+    state[23] = mul_2exp_i::<22, 9>(state[23]);
+}
+
+/// Compute the map x -> (x + rc)^5 on Mersenne-31 field elements.
+/// x and rc must be represented as values in {0..P}.
+/// If the inputs do not conform to these representations, the result is undefined.
+/// The output will be represented as a value in {0..P}.
+#[inline(always)]
+fn add_rc_and_sbox(input: &mut PackedMersenne31Neon, rc: uint32x4_t) {
+    // As input, rc <= P, input + rc <= 2P which is the range uint32x4_mod_add supports.
+    let input_plus_rc = uint32x4_mod_add(input.to_vector(), rc, P);
+    let input_plus_rc = unsafe {
+        // Safety: `uint32x4_mod_add` returns values in {0..P} when given values in {0..P}.
+        PackedMersenne31Neon::from_vector(input_plus_rc)
+    };
+    *input = input_plus_rc.exp_const_u64::<5>();
+}
+
+/// Compute a single Poseidon2 internal layer on a state of width 16.
+#[inline(always)]
+fn internal_16(state: &mut [PackedMersenne31Neon; 16], rc: uint32x4_t) {
+    add_rc_and_sbox(&mut state[0], rc);
+    let sum_tail = PackedMersenne31Neon::sum_array::<15>(&state[1..]);
+    let sum = sum_tail + state[0];
+    state[0] = sum_tail - state[0];
+    diagonal_mul_16(state);
+    state[1..].iter_mut().for_each(|x| *x += sum);
+}
+
+impl InternalLayer<PackedMersenne31Neon, 16, 5> for Poseidon2InternalLayerMersenne31 {
+    /// Perform the internal layers of the Poseidon2 permutation on the given state.
+    fn permute_state(&self, state: &mut [PackedMersenne31Neon; 16]) {
+        self.packed_internal_constants
+            .iter()
+            .for_each(|&rc| internal_16(state, rc))
+    }
+}
+
+/// Compute a single Poseidon2 internal layer on a state of width 24.
+#[inline(always)]
+fn internal_24(state: &mut [PackedMersenne31Neon; 24], rc: uint32x4_t) {
+    add_rc_and_sbox(&mut state[0], rc);
+    let sum_tail = PackedMersenne31Neon::sum_array::<23>(&state[1..]);
+    let sum = sum_tail + state[0];
+    state[0] = sum_tail - state[0];
+    diagonal_mul_24(state);
+    state[1..].iter_mut().for_each(|x| *x += sum);
+}
+
+impl InternalLayer<PackedMersenne31Neon, 24, 5> for Poseidon2InternalLayerMersenne31 {
     /// Perform the internal layers of the Poseidon2 permutation on the given state.
-    fn permute_state(&self, state: &mut [PackedMersenne31Neon; WIDTH]) {
-        self.internal_constants.iter().for_each(|&rc| {
-            add_rc_and_sbox_generic(&mut state[0], rc);
-            GenericPoseidon2LinearLayersMersenne31::internal_linear_layer(state);
-        })
+    fn permute_state(&self, state: &mut [PackedMersenne31Neon; 24]) {
+        self.packed_internal_constants
+            .iter()
+            .for_each(|&rc| internal_24(state, rc))
     }
 }
 
-impl<const D: u64, const WIDTH: usize> ExternalLayer<PackedMersenne31Neon, WIDTH, D>
+impl<const WIDTH: usize> ExternalLayer<PackedMersenne31Neon, WIDTH, 5>
     for Poseidon2ExternalLayerMersenne31<WIDTH>
 {
     /// Perform the initial external layers of the Poseidon2 permutation on the given state.
     fn permute_state_initial(&self, state: &mut [PackedMersenne31Neon; WIDTH]) {
         external_initial_permute_state(
             state,
-            self.external_constants.get_initial_constants(),
-            add_rc_and_sbox_generic,
+            &self.packed_initial_external_constants,
+            add_rc_and_sbox,
             &MDSMat4,
         );
     }
@@ -70,8 +261,8 @@ impl<const D: u64, const WIDTH: usize> ExternalLayer<PackedMersenne31Neon, WIDTH
     fn permute_state_terminal(&self, state: &mut [PackedMersenne31Neon; WIDTH]) {
         external_terminal_permute_state(
             state,
-            self.external_constants.get_terminal_constants(),
-            add_rc_and_sbox_generic,
+            &self.packed_terminal_external_constants,
+            add_rc_and_sbox,
             &MDSMat4,
         );
     }
@@ -1,12 +1,71 @@
 use p3_field::extension::{
     BinomiallyExtendable, BinomiallyExtendableAlgebra, Complex, HasComplexBinomialExtension,
-    HasTwoAdicComplexBinomialExtension,
+    HasTwoAdicComplexBinomialExtension, PolynomiallyExtendable, packed_binomial_mul,
+};
+use p3_field::{
+    PrimeCharacteristicRing, PrimeField32, TwoAdicField, field_to_array, packed_mod_add,
+    packed_mod_sub,
 };
-use p3_field::{PrimeCharacteristicRing, TwoAdicField, field_to_array};
 
 use crate::Mersenne31;
 
-impl BinomiallyExtendableAlgebra<Mersenne31, 3> for Mersenne31 {}
+/// Add two extension field elements coefficient-wise using the packed helpers of `p3_field`.
+#[inline(always)]
+fn packed_binomial_add<const D: usize>(
+    a: &[Mersenne31; D],
+    b: &[Mersenne31; D],
+) -> [Mersenne31; D] {
+    let mut res = [Mersenne31::ZERO; D];
+    unsafe {
+        // Safe as Mersenne31 is repr(transparent) and stores a single u32.
+        let a: &[u32; D] = &*(a.as_ptr() as *const [u32; D]);
+        let b: &[u32; D] = &*(b.as_ptr() as *const [u32; D]);
+        let res: &mut [u32; D] = &mut *(res.as_mut_ptr() as *mut [u32; D]);
+
+        // Every value fits in 31 bits so a + b <= 2P as required.
+        packed_mod_add(a, b, res, Mersenne31::ORDER_U32, |a, b| {
+            (Mersenne31::new(a) + Mersenne31::new(b)).value
+        });
+    }
+    res
+}
+
+/// Subtract two extension field elements coefficient-wise using the packed helpers of `p3_field`.
+#[inline(always)]
+fn packed_binomial_sub<const D: usize>(
+    a: &[Mersenne31; D],
+    b: &[Mersenne31; D],
+) -> [Mersenne31; D] {
+    let mut res = [Mersenne31::ZERO; D];
+    unsafe {
+        // Safe as Mersenne31 is repr(transparent) and stores a single u32.
+        let a: &[u32; D] = &*(a.as_ptr() as *const [u32; D]);
+        let b: &[u32; D] = &*(b.as_ptr() as *const [u32; D]);
+        let res: &mut [u32; D] = &mut *(res.as_mut_ptr() as *mut [u32; D]);
+
+        packed_mod_sub(a, b, res, Mersenne31::ORDER_U32, |a, b| {
+            (Mersenne31::new(a) - Mersenne31::new(b)).value
+        });
+    }
+    res
+}
+
+impl BinomiallyExtendableAlgebra<Mersenne31, 3> for Mersenne31 {
+    #[inline(always)]
+    fn binomial_mul(a: &[Self; 3], b: &[Self; 3], res: &mut [Self; 3], w: Self) {
+        packed_binomial_mul(a, b, res, w);
+    }
+
+    #[inline(always)]
+    fn binomial_add(a: &[Self; 3], b: &[Self; 3]) -> [Self; 3] {
+        packed_binomial_add(a, b)
+    }
+
+    #[inline(always)]
+    fn binomial_sub(a: &[Self; 3], b: &[Self; 3]) -> [Self; 3] {
+        packed_binomial_sub(a, b)
+    }
+}
 
 impl BinomiallyExtendable<3> for Mersenne31 {
     // ```sage
@@ -28,6 +87,54 @@ impl BinomiallyExtendable<3> for Mersenne31 {
     const EXT_GENERATOR: [Self; 3] = [Self::new(10), Self::ONE, Self::ZERO];
 }
 
+impl BinomiallyExtendableAlgebra<Mersenne31, 7> for Mersenne31 {
+    #[inline(always)]
+    fn binomial_mul(a: &[Self; 7], b: &[Self; 7], res: &mut [Self; 7], w: Self) {
+        packed_binomial_mul(a, b, res, w);
+    }
+
+    #[inline(always)]
+    fn binomial_add(a: &[Self; 7], b: &[Self; 7]) -> [Self; 7] {
+        packed_binomial_add(a, b)
+    }
+
+    #[inline(always)]
+    fn binomial_sub(a: &[Self; 7], b: &[Self; 7]) -> [Self; 7] {
+        packed_binomial_sub(a, b)
+    }
+}
+
+impl BinomiallyExtendable<7> for Mersenne31 {
+    // ```sage
+    // p = 2^31 - 1
+    // F = GF(p)
+    // R.<x> = F[]
+    // assert (x^7 - 3).is_irreducible()
+    // ```
+    const W: Self = Self::new(3);
+
+    // ```sage
+    // F(3)^((p-1)/7)
+    // ```
+    const DTH_ROOT: Self = Self::new(1752599774);
+
+    // ```sage
+    // K.<u> = F.extension(x^7 - 3)
+    // g = u + 2
+    // for f, _ in factor(p^7 - 1):
+    //   assert g^((p^7 - 1) // f) != 1
+    // ```
+    const EXT_GENERATOR: [Self; 7] = [
+        Self::new(2),
+        Self::ONE,
+        Self::ZERO,
+        Self::ZERO,
+        Self::ZERO,
+        Self::ZERO,
+        Self::ZERO,
+    ];
+}
+
 impl HasComplexBinomialExtension<2> for Mersenne31 {
     // Verifiable in Sage with
     // ```sage
@@ -108,12 +215,72 @@ impl HasTwoAdicComplexBinomialExtension<3> for Mersenne31 {
     }
 }
 
+// As 5 does not divide p - 1, there is no irreducible binomial of degree 5 so we use a trinomial.
+// Safety: `Mersenne31` and its packings are their own single basis coefficient.
+unsafe impl PolynomiallyExtendable<5> for Mersenne31 {
+    // Verifiable in Sage with
+    // ```sage
+    // p = 2**31 - 1  # Mersenne31
+    // F = GF(p)  # The base field GF(p)
+    // R.<x> = F[]  # The polynomial ring over F
+    // assert (x^5 + x + 3).is_irreducible()
+    // ```
+    const MODULUS: [Self; 5] = [Self::new(3), Self::ONE, Self::ZERO, Self::ZERO, Self::ZERO];
+
+    // Verifiable in Sage with
+    // ```sage
+    // K.<u> = F.extension(x^5 + x + 3)
+    // g = u + 2
+    // for f in factor(p^5 - 1):
+    //   assert g^((p^5-1) // f[0]) != 1
+    // ```
+    const EXT_GENERATOR: [Self; 5] = [Self::TWO, Self::ONE, Self::ZERO, Self::ZERO, Self::ZERO];
+}
+
+// A degree 8 extension built as a tower on top of the complex extension.
+// Safety: `Complex<Mersenne31>` and its packing are `repr(transparent)` wrappers around the arrays
+// of their basis coefficients.
+unsafe impl PolynomiallyExtendable<4, Complex<Self>> for Mersenne31 {
+    // Verifiable in Sage with
+    // ```sage
+    // p = 2**31 - 1  # Mersenne31
+    // F = GF(p)  # The base field GF(p)
+    // R.<x> = F[]  # The polynomial ring over F
+    // K.<i> = F.extension(x^2 + 1)  # The complex extension field
+    // R2.<y> = K[]
+    // f2 = y^4 - i - 2
+    // assert f2.is_irreducible()
+    // ```
+    const MODULUS: [Complex<Self>; 4] = [
+        Complex::new_complex(Self::new(2147483645), Self::new(2147483646)),
+        Complex::ZERO,
+        Complex::ZERO,
+        Complex::ZERO,
+    ];
+
+    // Verifiable in Sage with
+    // ```sage
+    // K2.<j> = K.extension(f2)
+    // g = j + 4
+    // for f in factor(p^8 - 1):
+    //   assert g^((p^8-1) // f[0]) != 1
+    // ```
+    const EXT_GENERATOR: [Complex<Self>; 4] = [
+        Complex::new_real(Self::new(4)),
+        Complex::ONE,
+        Complex::ZERO,
+        Complex::ZERO,
+    ];
+}
+
 #[cfg(test)]
 mod test_cubic_extension {
     use num_bigint::BigUint;
     use p3_field::extension::BinomialExtensionField;
     use p3_field::{ExtensionField, PrimeCharacteristicRing};
-    use p3_field_testing::{test_extension_field, test_field, test_packed_extension_field};
+    use p3_field_testing::{
+        test_binomial_arithmetic, test_extension_field, test_field, test_packed_extension_field,
+    };
 
     use crate::Mersenne31;
 
@@ -154,6 +321,68 @@ mod test_cubic_extension {
     const PACKED_ZEROS: [Pef; 1] = [Pef::ZERO];
     const PACKED_ONES: [Pef; 1] = [Pef::ONE];
     test_packed_extension_field!(super::Pef, &super::PACKED_ZEROS, &super::PACKED_ONES);
+
+    #[test]
+    fn test_packed_binomial_arithmetic() {
+        test_binomial_arithmetic::<F, 3>();
+    }
+}
+
+#[cfg(test)]
+mod test_septic_extension {
+    use num_bigint::BigUint;
+    use p3_field::extension::BinomialExtensionField;
+    use p3_field::{ExtensionField, PrimeCharacteristicRing};
+    use p3_field_testing::{
+        test_binomial_arithmetic, test_extension_field, test_field, test_packed_extension_field,
+    };
+
+    use crate::Mersenne31;
+
+    type F = Mersenne31;
+    type EF = BinomialExtensionField<F, 7>;
+
+    // There is a redundant representation of zero but we already tested it
+    // when testing the base field.
+    const ZEROS: [EF; 1] = [EF::ZERO];
+    const ONES: [EF; 1] = [EF::ONE];
+
+    // Get the prime factorization of the order of the multiplicative group.
+    // i.e. the prime factorization of P^7 - 1.
+    fn multiplicative_group_prime_factorization() -> [(BigUint, u32); 11] {
+        [
+            (BigUint::from(2u8), 1),
+            (BigUint::from(3u8), 2),
+            (BigUint::from(7u8), 2),
+            (BigUint::from(11u8), 1),
+            (BigUint::from(29u8), 1),
+            (BigUint::from(31u8), 1),
+            (BigUint::from(151u8), 1),
+            (BigUint::from(331u16), 1),
+            (BigUint::from(4243u16), 1),
+            (BigUint::from(791948526123957812537327u128), 1),
+            (BigUint::from(143784862988552073346720159u128), 1),
+        ]
+    }
+
+    test_extension_field!(super::F, super::EF);
+
+    test_field!(
+        super::EF,
+        &super::ZEROS,
+        &super::ONES,
+        &super::multiplicative_group_prime_factorization()
+    );
+
+    type Pef = <EF as ExtensionField<F>>::ExtensionPacking;
+    const PACKED_ZEROS: [Pef; 1] = [Pef::ZERO];
+    const PACKED_ONES: [Pef; 1] = [Pef::ONE];
+    test_packed_extension_field!(super::Pef, &super::PACKED_ZEROS, &super::PACKED_ONES);
+
+    #[test]
+    fn test_packed_binomial_arithmetic() {
+        test_binomial_arithmetic::<F, 7>();
+    }
 }
 
 #[cfg(test)]
@@ -269,3 +498,148 @@ mod test_quadratic_complex_extension {
     const PACKED_ONES: [Pef; 1] = [Pef::ONE];
     test_packed_extension_field!(super::Pef, &super::PACKED_ZEROS, &super::PACKED_ONES);
 }
+
+#[cfg(test)]
+mod test_quintic_extension {
+    use num_bigint::BigUint;
+    use p3_field::extension::{HasFrobenius, PolynomialExtensionField};
+    use p3_field::{ExtensionField, PrimeCharacteristicRing, PrimeField64};
+    use p3_field_testing::{test_extension_field, test_field, test_packed_extension_field};
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::Mersenne31;
+
+    type F = Mersenne31;
+    type EF = PolynomialExtensionField<F, 5>;
+
+    // There is a redundant representation of zero but we already tested it
+    // when testing the base field.
+    const ZEROS: [EF; 1] = [EF::ZERO];
+    const ONES: [EF; 1] = [EF::ONE];
+
+    // Get the prime factorization of the order of the multiplicative group.
+    // i.e. the prime factorization of P^5 - 1.
+    fn multiplicative_group_prime_factorization() -> [(BigUint, u32); 10] {
+        [
+            (BigUint::from(2u8), 1),
+            (BigUint::from(3u8), 2),
+            (BigUint::from(7u8), 1),
+            (BigUint::from(11u8), 1),
+            (BigUint::from(31u8), 1),
+            (BigUint::from(61u8), 1),
+            (BigUint::from(151u8), 1),
+            (BigUint::from(271u16), 1),
+            (BigUint::from(331u16), 1),
+            (
+                BigUint::parse_bytes(b"1286531238451883917612880674872731", 10).unwrap(),
+                1,
+            ),
+        ]
+    }
+
+    test_extension_field!(super::F, super::EF);
+
+    test_field!(
+        super::EF,
+        &super::ZEROS,
+        &super::ONES,
+        &super::multiplicative_group_prime_factorization()
+    );
+
+    type Pef = <EF as ExtensionField<F>>::ExtensionPacking;
+    const PACKED_ZEROS: [Pef; 1] = [Pef::ZERO];
+    const PACKED_ONES: [Pef; 1] = [Pef::ONE];
+    test_packed_extension_field!(super::Pef, &super::PACKED_ZEROS, &super::PACKED_ONES);
+
+    #[test]
+    fn test_frobenius() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let x: EF = rng.random();
+
+        assert_eq!(x.frobenius(), x.exp_u64(F::ORDER_U64));
+        assert_eq!(x.repeated_frobenius(2), x.frobenius().frobenius());
+        assert_eq!(x.repeated_frobenius(5), x);
+        assert_eq!(x.pseudo_inv() * x, EF::ONE);
+        assert_eq!(EF::ZERO.pseudo_inv(), EF::ZERO);
+    }
+}
+
+#[cfg(test)]
+mod test_octic_complex_tower_extension {
+    use num_bigint::BigUint;
+    use p3_field::extension::{Complex, PolynomialExtensionField};
+    use p3_field::{BasedVectorSpace, ExtensionField, PrimeCharacteristicRing};
+    use p3_field_testing::{test_extension_field, test_field, test_packed_extension_field};
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::Mersenne31;
+
+    type F = Mersenne31;
+    type EF = PolynomialExtensionField<F, 4, Complex<F>>;
+
+    // There is a redundant representation of zero but we already tested it
+    // when testing the base field.
+    const ZEROS: [EF; 1] = [EF::ZERO];
+    const ONES: [EF; 1] = [EF::ONE];
+
+    // Get the prime factorization of the order of the multiplicative group.
+    // i.e. the prime factorization of P^8 - 1.
+    fn multiplicative_group_prime_factorization() -> [(BigUint, u32); 15] {
+        [
+            (BigUint::from(2u8), 34),
+            (BigUint::from(3u8), 2),
+            (BigUint::from(5u8), 1),
+            (BigUint::from(7u8), 1),
+            (BigUint::from(11u8), 1),
+            (BigUint::from(17u8), 1),
+            (BigUint::from(31u8), 1),
+            (BigUint::from(41u8), 1),
+            (BigUint::from(151u8), 1),
+            (BigUint::from(331u16), 1),
+            (BigUint::from(733u16), 1),
+            (BigUint::from(1709u16), 1),
+            (BigUint::from(21529u16), 1),
+            (BigUint::from(368140581013u64), 1),
+            (
+                BigUint::parse_bytes(b"708651694622727115232673724657", 10).unwrap(),
+                1,
+            ),
+        ]
+    }
+
+    // The tower only implements `ExtensionField<F>`: `Complex<F>` holds its coefficients but is not
+    // exposed as an intermediate subfield, so there is no `ExtensionField<Complex<F>>` to test.
+    test_extension_field!(super::F, super::EF);
+
+    test_field!(
+        super::EF,
+        &super::ZEROS,
+        &super::ONES,
+        &super::multiplicative_group_prime_factorization()
+    );
+
+    type Pef = <EF as ExtensionField<F>>::ExtensionPacking;
+    const PACKED_ZEROS: [Pef; 1] = [Pef::ZERO];
+    const PACKED_ONES: [Pef; 1] = [Pef::ONE];
+    test_packed_extension_field!(super::Pef, &super::PACKED_ZEROS, &super::PACKED_ONES);
+
+    #[test]
+    fn test_tower_basis() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let x: EF = rng.random();
+        let y: EF = rng.random();
+
+        // The basis over the base field is the tower basis `1, i, X, i X, ...`.
+        let coeffs: &[F] = x.as_basis_coefficients_slice();
+        assert_eq!(coeffs.len(), 8);
+        assert_eq!(EF::from_basis_coefficients_slice(coeffs), Some(x));
+
+        // Multiplication by an element of the complex subfield acts on each coefficient.
+        let i = EF::from_basis_coefficients_fn(|j| F::from_bool(j == 1));
+        let i_squared = i * i;
+        assert_eq!(i_squared, EF::NEG_ONE);
+        assert_eq!((x + y) * i, x * i + y * i);
+    }
+}
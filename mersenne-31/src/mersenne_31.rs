@@ -13,9 +13,10 @@ use p3_field::op_assign_macros::{
     impl_add_assign, impl_div_methods, impl_mul_methods, impl_sub_assign,
 };
 use p3_field::{
-    Field, InjectiveMonomial, Packable, PermutationMonomial, PrimeCharacteristicRing, PrimeField,
-    PrimeField32, PrimeField64, RawDataSerializable, halve_u32, impl_raw_serializable_primefield32,
-    quotient_map_large_iint, quotient_map_large_uint, quotient_map_small_int,
+    ConstantTimeField, Field, InjectiveMonomial, LegendreSymbol, Packable, PermutationMonomial,
+    PrimeCharacteristicRing, PrimeField, PrimeField32, PrimeField64, RawDataSerializable,
+    ct_mask_u32, halve_u32, impl_raw_serializable_primefield32, quotient_map_large_iint,
+    quotient_map_large_uint, quotient_map_small_int,
 };
 use p3_util::{flatten_to_base, gcd_inversion_prime_field_32};
 use rand::Rng;
@@ -325,6 +326,18 @@ impl Field for Mersenne31 {
         Some(Self::from_int(inverse_i64).div_2exp_u64(60))
     }
 
+    #[inline]
+    fn legendre_symbol(&self) -> LegendreSymbol {
+        // (P - 1)/2 = 2^30 - 1.
+        LegendreSymbol::from_euler_criterion(self, self.exp_u64((P as u64 - 1) >> 1))
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        // As P = 3 mod 4, a square root of a square `x` is `x^((P + 1)/4) = x^(2^29)`.
+        let root = self.exp_power_of_2(29);
+        (root.square() == *self).then_some(root)
+    }
+
     #[inline]
     fn order() -> BigUint {
         P.into()
@@ -452,6 +465,47 @@ impl PrimeField64 for Mersenne31 {
     }
 }
 
+impl ConstantTimeField for Mersenne31 {
+    #[inline]
+    fn ct_add(&self, rhs: &Self) -> Self {
+        Self::new(ct_fold(self.value + rhs.value))
+    }
+
+    #[inline]
+    fn ct_sub(&self, rhs: &Self) -> Self {
+        // rhs.value <= P so P - rhs.value is a valid representative of -rhs.
+        Self::new(ct_fold(self.value + (P - rhs.value)))
+    }
+
+    #[inline]
+    fn ct_neg(&self) -> Self {
+        // Identical to `neg`, which is already branch free.
+        Self::new(P - self.value)
+    }
+
+    #[inline]
+    fn ct_mul(&self, rhs: &Self) -> Self {
+        let prod = u64::from(self.value) * u64::from(rhs.value);
+        let prod_lo = (prod & u64::from(P)) as u32;
+        let prod_hi = (prod >> 31) as u32;
+        Self::new(ct_fold(prod_lo + prod_hi))
+    }
+
+    #[inline]
+    fn ct_select(&self, rhs: &Self, choice: bool) -> Self {
+        let mask = ct_mask_u32(choice);
+        Self::new(self.value ^ ((self.value ^ rhs.value) & mask))
+    }
+}
+
+/// Reduce a `u32` in `[0, 2P]` to a value in `[0, P]` without branching.
+///
+/// This uses `2^31 = 1 mod P` to fold the top bit back into the bottom 31 bits.
+#[inline(always)]
+const fn ct_fold(input: u32) -> u32 {
+    (input & P) + (input >> 31)
+}
+
 impl Add for Mersenne31 {
     type Output = Self;
 
@@ -541,7 +595,8 @@ mod tests {
     use num_bigint::BigUint;
     use p3_field::{InjectiveMonomial, PermutationMonomial, PrimeCharacteristicRing};
     use p3_field_testing::{
-        test_field, test_prime_field, test_prime_field_32, test_prime_field_64,
+        test_constant_time_field, test_field, test_prime_field, test_prime_field_32,
+        test_prime_field_64,
     };
 
     use crate::Mersenne31;
@@ -586,5 +641,6 @@ mod tests {
     );
     test_prime_field!(crate::Mersenne31);
     test_prime_field_64!(crate::Mersenne31, &super::ZEROS, &super::ONES);
+    test_constant_time_field!(crate::Mersenne31, &super::ZEROS, &super::ONES);
     test_prime_field_32!(crate::Mersenne31, &super::ZEROS, &super::ONES);
 }
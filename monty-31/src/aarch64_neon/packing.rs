@@ -1,6 +1,7 @@
 use alloc::vec::Vec;
 use core::arch::aarch64::{self, int32x4_t, uint32x4_t};
 use core::arch::asm;
+use core::array;
 use core::hint::unreachable_unchecked;
 use core::iter::{Product, Sum};
 use core::mem::transmute;
@@ -9,8 +10,8 @@ use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAss
 use p3_field::interleave::{interleave_u32, interleave_u64};
 use p3_field::op_assign_macros::{
     impl_add_assign, impl_add_base_field, impl_div_methods, impl_mul_base_field, impl_mul_methods,
-    impl_packed_value, impl_rng, impl_sub_assign, impl_sub_base_field, impl_sum_prod_base_field,
-    ring_sum,
+    impl_packed_div_methods, impl_packed_value, impl_rng, impl_sub_assign, impl_sub_base_field,
+    impl_sum_prod_base_field, ring_sum,
 };
 use p3_field::{
     Algebra, Field, InjectiveMonomial, PackedField, PackedFieldPow2, PackedValue,
@@ -276,6 +277,7 @@ impl_mul_base_field!(
     (PackedMontyParameters, PMP)
 );
 impl_div_methods!(PackedMontyField31Neon, MontyField31, (FieldParameters, FP));
+impl_packed_div_methods!(PackedMontyField31Neon, (FieldParameters, FP));
 impl_sum_prod_base_field!(PackedMontyField31Neon, MontyField31, (FieldParameters, FP));
 
 impl<FP: FieldParameters> Algebra<MontyField31<FP>> for PackedMontyField31Neon<FP> {}
@@ -1010,6 +1012,63 @@ pub(crate) fn octic_mul_packed<FP, const WIDTH: usize>(
     res[4..].copy_from_slice(&dot_1);
 }
 
+/// Multiplication in a binomial extension field of degree `2, 3, 6` or `7`.
+///
+/// Writing `D = WIDTH`, the `k`'th coefficient of the product is
+/// `a0*bk + ... + ak*b0 + w(a(k+1)*b(D-1) + ... + a(D-1)*b(k+1))`.
+/// Hence, broadcasting `ai` and placing either `b(k-i)` or `w*b(D+k-i)` in lane `k` of the `i`'th
+/// right hand side, this is a `D`-term dot product. The unused lanes are set to zero and, when `D > 4`,
+/// the coefficients are split across two vectors.
+#[inline]
+pub(crate) fn small_binomial_mul_packed<FP, const WIDTH: usize>(
+    a: &[MontyField31<FP>; WIDTH],
+    b: &[MontyField31<FP>; WIDTH],
+    res: &mut [MontyField31<FP>; WIDTH],
+) where
+    FP: FieldParameters + BinomialExtensionData<WIDTH>,
+{
+    assert!(WIDTH < 8);
+    let zero = MontyField31::<FP>::ZERO;
+    let padded_b: [MontyField31<FP>; 8] = array::from_fn(|k| if k < WIDTH { b[k] } else { zero });
+    let w_b_lo = FP::mul_w(PackedMontyField31Neon([
+        padded_b[0],
+        padded_b[1],
+        padded_b[2],
+        padded_b[3],
+    ]))
+    .0;
+    let w_b_hi = FP::mul_w(PackedMontyField31Neon([
+        padded_b[4],
+        padded_b[5],
+        padded_b[6],
+        padded_b[7],
+    ]))
+    .0;
+    let w_b = [
+        w_b_lo[0], w_b_lo[1], w_b_lo[2], w_b_lo[3], w_b_hi[0], w_b_hi[1], w_b_hi[2], w_b_hi[3],
+    ];
+
+    let lhs: [PackedMontyField31Neon<FP>; WIDTH] = a.map(Into::into);
+    let rhs = |offset: usize| -> [PackedMontyField31Neon<FP>; WIDTH] {
+        array::from_fn(|i| {
+            PackedMontyField31Neon(array::from_fn(|j| match offset + j {
+                k if k >= WIDTH => zero,
+                k if k >= i => b[k - i],
+                k => w_b[WIDTH + k - i],
+            }))
+        })
+    };
+
+    let dot_0 = PackedMontyField31Neon::dot_product(&lhs, &rhs(0)).0;
+    if WIDTH <= 4 {
+        res.copy_from_slice(&dot_0[..WIDTH]);
+    } else {
+        let dot_1 = PackedMontyField31Neon::dot_product(&lhs, &rhs(4)).0;
+        res[..4].copy_from_slice(&dot_0);
+        res[4..].copy_from_slice(&dot_1[..WIDTH - 4]);
+    }
+}
+
 /// Multiplication by a base field element in a binomial extension field.
 #[inline]
 pub(crate) fn base_mul_packed<FP, const WIDTH: usize>(
@@ -1036,6 +1095,29 @@ pub(crate) fn base_mul_packed<FP, const WIDTH: usize>(
 
             res[..4].copy_from_slice(&out.0[..4]);
         }
+        2 | 3 => {
+            let zero = MontyField31::<FP>::ZERO;
+            let lhs =
+                PackedMontyField31Neon(array::from_fn(|i| if i < WIDTH { a[i] } else { zero }));
+
+            let out = lhs * b;
+
+            res.copy_from_slice(&out.0[..WIDTH]);
+        }
+        6 | 7 => {
+            let zero = MontyField31::<FP>::ZERO;
+            let lhs_lo = PackedMontyField31Neon([a[0], a[1], a[2], a[3]]);
+            let lhs_hi =
+                PackedMontyField31Neon(array::from_fn(
+                    |i| if i + 4 < WIDTH { a[i + 4] } else { zero },
+                ));
+
+            let out_lo = lhs_lo * b;
+            let out_hi = lhs_hi * b;
+
+            res[..4].copy_from_slice(&out_lo.0);
+            res[4..].copy_from_slice(&out_hi.0[..WIDTH - 4]);
+        }
         8 => {
             let lhs_lo = PackedMontyField31Neon([a[0], a[1], a[2], a[3]]);
             let lhs_hi = PackedMontyField31Neon([a[4], a[5], a[6], a[7]]);
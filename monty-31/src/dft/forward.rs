@@ -9,7 +9,8 @@ extern crate alloc;
 use alloc::vec::Vec;
 
 use itertools::izip;
-use p3_field::{Field, PackedFieldPow2, PackedValue, PrimeCharacteristicRing, TwoAdicField};
+use p3_dft::TwiddleCache;
+use p3_field::{Field, PackedFieldPow2, PackedValue, PrimeCharacteristicRing};
 use p3_util::log2_strict_usize;
 
 use crate::utils::monty_reduce;
@@ -23,16 +24,15 @@ impl<MP: FieldParameters + TwoAdicData> MontyField31<MP> {
     /// negatives of the other roots (using g_i^{i/2} = -1).  The
     /// value gen^0 = 1 is included to aid consistency between the
     /// packed and non-packed variants.
+    #[deprecated(
+        note = "use `p3_dft::TwiddleCache`, which computes the same layers and shares them between threads"
+    )]
     pub fn roots_of_unity_table(n: usize) -> Vec<Vec<Self>> {
-        let lg_n = log2_strict_usize(n);
-        let generator = Self::two_adic_generator(lg_n);
-        let half_n = 1 << (lg_n - 1);
-        // nth_roots = [1, g, g^2, g^3, ..., g^{n/2 - 1}]
-        let nth_roots = generator.powers().collect_n(half_n);
-
-        (0..(lg_n - 1))
-            .map(|i| nth_roots.iter().step_by(1 << i).copied().collect())
-            .collect()
+        // The layers for block lengths `n` down to `4`, as used by `RecursiveDft`.
+        TwiddleCache::new(2, false)
+            .get(log2_strict_usize(n))
+            .twiddles()
+            .to_vec()
     }
 }
 
@@ -1,18 +1,17 @@
 //! An implementation of the FFT for `MontyField31`
 extern crate alloc;
 
+use alloc::sync::Arc;
 use alloc::vec::Vec;
-use core::cell::RefCell;
-use core::iter;
 
 use itertools::izip;
-use p3_dft::TwoAdicSubgroupDft;
-use p3_field::{Field, PrimeCharacteristicRing};
+use p3_dft::{TwiddleCache, TwiddleTable, TwoAdicSubgroupDft};
+use p3_field::{PrimeCharacteristicRing, TwoAdicField};
 use p3_matrix::Matrix;
 use p3_matrix::bitrev::{BitReversedMatrixView, BitReversibleMatrix};
 use p3_matrix::dense::RowMajorMatrix;
 use p3_maybe_rayon::prelude::*;
-use p3_util::log2_ceil_usize;
+use p3_util::{log2_ceil_usize, log2_strict_usize, reverse_bits_len};
 use tracing::{debug_span, instrument};
 
 mod backward;
@@ -20,9 +19,15 @@ mod forward;
 
 use crate::{FieldParameters, MontyField31, MontyParameters, TwoAdicData};
 
-/// Multiply each element of column `j` of `mat` by `shift**j`.
+/// Multiply each element of column `j` of `mat` by `scale * shift_k**j` for every block `k`,
+/// writing the result to block `k` of the corresponding row of `out`.
+///
+/// Block `k` of a row of `out` holds `ncols` entries, and `shift_k = shift * g^rev(k)` where `g`
+/// generates the subgroup of order `out_ncols` and `rev` reverses the bits of the block index.
+/// Running a DIF DFT of length `ncols` on each block then yields the evaluations over the coset
+/// `shift * g^rev(k) * H`, which make up block `k` of the bit-reversed evaluations over `shift * K`.
 #[instrument(level = "debug", skip_all)]
-fn coset_shift_and_scale_rows<F: Field>(
+fn coset_twist_and_scale_rows<F: TwoAdicField>(
     out: &mut [F],
     out_ncols: usize,
     mat: &[F],
@@ -30,35 +35,50 @@ fn coset_shift_and_scale_rows<F: Field>(
     shift: F,
     scale: F,
 ) {
-    let powers = shift.shifted_powers(scale).collect_n(ncols);
+    let log_num_blocks = log2_strict_usize(out_ncols / ncols);
+    let g_big = F::two_adic_generator(log2_strict_usize(out_ncols));
+    let block_powers = (0..1 << log_num_blocks)
+        .map(|block| {
+            let block_shift = shift * g_big.exp_u64(reverse_bits_len(block, log_num_blocks) as u64);
+            block_shift.shifted_powers(scale).collect_n(ncols)
+        })
+        .collect::<Vec<_>>();
     out.par_chunks_exact_mut(out_ncols)
         .zip(mat.par_chunks_exact(ncols))
         .for_each(|(out_row, in_row)| {
-            izip!(out_row.iter_mut(), in_row, &powers).for_each(|(out, &coeff, &weight)| {
-                *out = coeff * weight;
-            });
+            for (out_block, powers) in out_row.chunks_exact_mut(ncols).zip(&block_powers) {
+                izip!(out_block.iter_mut(), in_row, powers).for_each(|(out, &coeff, &weight)| {
+                    *out = coeff * weight;
+                });
+            }
         });
 }
 
 /// Recursive DFT, decimation-in-frequency in the forward direction,
 /// decimation-in-time in the backward (inverse) direction.
-#[derive(Clone, Debug, Default)]
+///
+/// The twiddle factors are shared by all clones of an instance, which may be used from
+/// several threads at once.
+#[derive(Clone, Debug)]
 pub struct RecursiveDft<F> {
-    /// Memoized twiddle factors for each length log_n.
+    /// Memoized twiddle factors for each length log_n, along with their inverses.
     ///
-    /// TODO: The use of RefCell means this can't be shared across
-    /// threads; consider using RwLock or finding a better design
-    /// instead.
-    twiddles: RefCell<Vec<Vec<F>>>,
-    inv_twiddles: RefCell<Vec<Vec<F>>>,
+    /// We don't save the twiddles for the final layer where the only
+    /// twiddle is 1.
+    twiddles: TwiddleCache<F>,
+}
+
+impl<F> Default for RecursiveDft<F> {
+    fn default() -> Self {
+        Self {
+            twiddles: TwiddleCache::new(2, false),
+        }
+    }
 }
 
 impl<MP: FieldParameters + TwoAdicData> RecursiveDft<MontyField31<MP>> {
     pub fn new(n: usize) -> Self {
-        let res = Self {
-            twiddles: RefCell::default(),
-            inv_twiddles: RefCell::default(),
-        };
+        let res = Self::default();
         res.update_twiddles(n);
         res
     }
@@ -97,37 +117,8 @@ impl<MP: FieldParameters + TwoAdicData> RecursiveDft<MontyField31<MP>> {
 
     /// Compute twiddle factors, or take memoized ones if already available.
     #[instrument(skip_all)]
-    fn update_twiddles(&self, fft_len: usize) {
-        // TODO: This recomputes the entire table from scratch if we
-        // need it to be bigger, which is wasteful.
-
-        // As we don't save the twiddles for the final layer where
-        // the only twiddle is 1, roots_of_unity_table(fft_len)
-        // returns a vector of twiddles of length log_2(fft_len) - 1.
-        let curr_max_fft_len = 2 << self.twiddles.borrow().len();
-        if fft_len > curr_max_fft_len {
-            let new_twiddles = MontyField31::roots_of_unity_table(fft_len);
-            // We can obtain the inverse twiddles by reversing and
-            // negating the twiddles.
-            let new_inv_twiddles = new_twiddles
-                .iter()
-                .map(|ts| {
-                    // The first twiddle is still one, we reverse and negate the rest...
-                    iter::once(MontyField31::ONE)
-                        .chain(
-                            ts[1..]
-                                .iter()
-                                .rev()
-                                // A twiddle t is never zero, so negation simplifies
-                                // to P - t.
-                                .map(|&t| MontyField31::new_monty(MP::PRIME - t.value)),
-                        )
-                        .collect()
-                })
-                .collect();
-            self.twiddles.replace(new_twiddles);
-            self.inv_twiddles.replace(new_inv_twiddles);
-        }
+    fn update_twiddles(&self, fft_len: usize) -> Arc<TwiddleTable<MontyField31<MP>>> {
+        self.twiddles.get(log2_ceil_usize(fft_len))
     }
 }
 
@@ -176,15 +167,15 @@ impl<MP: MontyParameters + FieldParameters + TwoAdicData> TwoAdicSubgroupDft<Mon
         let mut scratch = debug_span!("allocate scratch space")
             .in_scope(|| RowMajorMatrix::default(nrows, ncols));
 
-        self.update_twiddles(nrows);
-        let twiddles = self.twiddles.borrow();
+        let twiddles = self.update_twiddles(nrows);
 
         // transpose input
         debug_span!("pre-transpose", nrows, ncols)
             .in_scope(|| transpose::transpose(&mat.values, &mut scratch.values, ncols, nrows));
 
-        debug_span!("dft batch", n_dfts = ncols, fft_len = nrows)
-            .in_scope(|| Self::decimation_in_freq_dft(&mut scratch.values, nrows, &twiddles));
+        debug_span!("dft batch", n_dfts = ncols, fft_len = nrows).in_scope(|| {
+            Self::decimation_in_freq_dft(&mut scratch.values, nrows, twiddles.twiddles())
+        });
 
         // transpose output
         debug_span!("post-transpose", nrows = ncols, ncols = nrows)
@@ -210,15 +201,15 @@ impl<MP: MontyParameters + FieldParameters + TwoAdicData> TwoAdicSubgroupDft<Mon
         let mut mat =
             debug_span!("initial bitrev").in_scope(|| mat.bit_reverse_rows().to_row_major_matrix());
 
-        self.update_twiddles(nrows);
-        let inv_twiddles = self.inv_twiddles.borrow();
+        let twiddles = self.update_twiddles(nrows);
+        let inv_twiddles = twiddles.inv_twiddles();
 
         // transpose input
         debug_span!("pre-transpose", nrows, ncols)
             .in_scope(|| transpose::transpose(&mat.values, &mut scratch.values, ncols, nrows));
 
         debug_span!("idft", n_dfts = ncols, fft_len = nrows)
-            .in_scope(|| Self::decimation_in_time_dft(&mut scratch.values, nrows, &inv_twiddles));
+            .in_scope(|| Self::decimation_in_time_dft(&mut scratch.values, nrows, inv_twiddles));
 
         // transpose output
         debug_span!("post-transpose", nrows = ncols, ncols = nrows)
@@ -254,11 +245,11 @@ impl<MP: MontyParameters + FieldParameters + TwoAdicData> TwoAdicSubgroupDft<Mon
         let mat = mat.bit_reverse_rows().to_row_major_matrix();
 
         // Allocate space for the output and the intermediate state.
-        let (mut output, mut padded) = debug_span!("allocate scratch space").in_scope(|| {
+        let (mut output, mut twisted) = debug_span!("allocate scratch space").in_scope(|| {
             // Safety: These are pretty dodgy, but work because MontyField31 is #[repr(transparent)]
             let output = MontyField31::<MP>::zero_vec(output_size);
-            let padded = MontyField31::<MP>::zero_vec(output_size);
-            (output, padded)
+            let twisted = MontyField31::<MP>::zero_vec(output_size);
+            (output, twisted)
         });
 
         // `coeffs` will hold the result of the inverse FFT; use the
@@ -269,31 +260,31 @@ impl<MP: MontyParameters + FieldParameters + TwoAdicData> TwoAdicSubgroupDft<Mon
             .in_scope(|| transpose::transpose(&mat.values, coeffs, ncols, nrows));
 
         // Apply inverse DFT; result is not yet normalised.
-        self.update_twiddles(result_nrows);
-        let inv_twiddles = self.inv_twiddles.borrow();
+        // Only DFTs of length `nrows` are needed, see below.
+        let twiddles = self.update_twiddles(nrows);
         debug_span!("inverse dft batch", n_dfts = ncols, fft_len = nrows)
-            .in_scope(|| Self::decimation_in_time_dft(coeffs, nrows, &inv_twiddles));
+            .in_scope(|| Self::decimation_in_time_dft(coeffs, nrows, twiddles.inv_twiddles()));
 
         // At this point the inverse FFT of each column of `mat` appears
         // as a row in `coeffs`.
 
-        // Normalise inverse DFT and coset shift in one go.
+        // Rather than zero padding the coefficients and running DFTs of length `result_nrows`,
+        // we evaluate each polynomial over the `2^added_bits` cosets of the subgroup of order
+        // `nrows` separately. Writing these evaluations (in bit-reversed order) one after the
+        // other gives exactly the bit-reversed evaluations over the whole LDE domain.
+        //
+        // Normalise inverse DFT and twist by each coset shift in one go.
         let log_rows = log2_ceil_usize(nrows);
         let inv_len = MontyField31::ONE.div_2exp_u64(log_rows as u64);
-        coset_shift_and_scale_rows(&mut padded, result_nrows, coeffs, nrows, shift, inv_len);
-
-        // `padded` is implicitly zero padded since it was initialised
-        // to zeros when declared above.
-
-        let twiddles = self.twiddles.borrow();
+        coset_twist_and_scale_rows(&mut twisted, result_nrows, coeffs, nrows, shift, inv_len);
 
-        // Apply DFT
-        debug_span!("dft batch", n_dfts = ncols, fft_len = result_nrows)
-            .in_scope(|| Self::decimation_in_freq_dft(&mut padded, result_nrows, &twiddles));
+        // Apply DFT to every block of `nrows` twisted coefficients
+        debug_span!("dft batch", n_dfts = ncols << added_bits, fft_len = nrows)
+            .in_scope(|| Self::decimation_in_freq_dft(&mut twisted, nrows, twiddles.twiddles()));
 
         // transpose output
         debug_span!("post-transpose", nrows = ncols, ncols = result_nrows)
-            .in_scope(|| transpose::transpose(&padded, &mut output, result_nrows, ncols));
+            .in_scope(|| transpose::transpose(&twisted, &mut output, result_nrows, ncols));
 
         RowMajorMatrix::new(output, ncols).bit_reverse_rows()
     }
@@ -8,7 +8,7 @@ use p3_field::{
 use crate::utils::{add, sub};
 use crate::{
     BinomialExtensionData, FieldParameters, MontyField31, TwoAdicData, base_mul_packed,
-    octic_mul_packed, quartic_mul_packed, quintic_mul_packed,
+    octic_mul_packed, quartic_mul_packed, quintic_mul_packed, small_binomial_mul_packed,
 };
 
 // If a field implements BinomialExtensionData<WIDTH> then there is a natural
@@ -29,6 +29,7 @@ where
         _w: MontyField31<FP>,
     ) {
         match WIDTH {
+            2 | 3 | 6 | 7 => small_binomial_mul_packed(a, b, res),
             4 => quartic_mul_packed(a, b, res),
             5 => quintic_mul_packed(a, b, res),
             8 => octic_mul_packed(a, b, res),
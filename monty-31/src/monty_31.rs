@@ -15,9 +15,10 @@ use p3_field::op_assign_macros::{
     impl_add_assign, impl_div_methods, impl_mul_methods, impl_sub_assign,
 };
 use p3_field::{
-    Field, InjectiveMonomial, Packable, PermutationMonomial, PrimeCharacteristicRing, PrimeField,
-    PrimeField32, PrimeField64, RawDataSerializable, TwoAdicField,
-    impl_raw_serializable_primefield32, quotient_map_small_int,
+    ConstantTimeField, Field, InjectiveMonomial, LegendreSymbol, Packable, PermutationMonomial,
+    PrimeCharacteristicRing, PrimeField, PrimeField32, PrimeField64, RawDataSerializable,
+    TwoAdicField, ct_mask_u32, impl_raw_serializable_primefield32, quotient_map_small_int,
+    tonelli_shanks,
 };
 use p3_util::{flatten_to_base, gcd_inversion_prime_field_32};
 use rand::Rng;
@@ -25,8 +26,8 @@ use rand::distr::{Distribution, StandardUniform};
 use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::utils::{
-    add, from_monty, halve_u32, large_monty_reduce, monty_reduce, monty_reduce_u128, sub, to_monty,
-    to_monty_64, to_monty_64_signed, to_monty_signed,
+    add, ct_add, ct_monty_reduce, ct_sub, from_monty, halve_u32, large_monty_reduce, monty_reduce,
+    monty_reduce_u128, sub, to_monty, to_monty_64, to_monty_64_signed, to_monty_signed,
 };
 use crate::{FieldParameters, MontyParameters, RelativelyPrimePower, TwoAdicData};
 
@@ -438,6 +439,23 @@ impl<FP: FieldParameters> Field for MontyField31<FP> {
         Some(uncorrected_value.mul_2exp_u64((3 * FP::MONTY_BITS - (2 * NUM_PRIME_BITS - 2)) as u64))
     }
 
+    #[inline]
+    fn legendre_symbol(&self) -> LegendreSymbol {
+        LegendreSymbol::from_euler_criterion(self, self.exp_u64(((FP::PRIME - 1) >> 1) as u64))
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        // Write P - 1 = 2^s t with t odd.
+        let two_adicity = (FP::PRIME - 1).trailing_zeros() as usize;
+        let odd_factor = ((FP::PRIME - 1) >> two_adicity) as u64;
+        tonelli_shanks(
+            *self,
+            two_adicity,
+            self.exp_u64(odd_factor >> 1),
+            Self::GENERATOR.exp_u64(odd_factor),
+        )
+    }
+
     #[inline]
     fn order() -> BigUint {
         FP::PRIME.into()
@@ -646,6 +664,35 @@ impl<FP: FieldParameters + TwoAdicData> TwoAdicField for MontyField31<FP> {
     }
 }
 
+impl<FP: FieldParameters> ConstantTimeField for MontyField31<FP> {
+    #[inline]
+    fn ct_add(&self, rhs: &Self) -> Self {
+        Self::new_monty(ct_add::<FP>(self.value, rhs.value))
+    }
+
+    #[inline]
+    fn ct_sub(&self, rhs: &Self) -> Self {
+        Self::new_monty(ct_sub::<FP>(self.value, rhs.value))
+    }
+
+    #[inline]
+    fn ct_neg(&self) -> Self {
+        Self::new_monty(ct_sub::<FP>(0, self.value))
+    }
+
+    #[inline]
+    fn ct_mul(&self, rhs: &Self) -> Self {
+        let long_prod = self.value as u64 * rhs.value as u64;
+        Self::new_monty(ct_monty_reduce::<FP>(long_prod))
+    }
+
+    #[inline]
+    fn ct_select(&self, rhs: &Self, choice: bool) -> Self {
+        let mask = ct_mask_u32(choice);
+        Self::new_monty(self.value ^ ((self.value ^ rhs.value) & mask))
+    }
+}
+
 impl<FP: MontyParameters> Add for MontyField31<FP> {
     type Output = Self;
 
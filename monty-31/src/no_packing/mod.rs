@@ -43,6 +43,34 @@ pub(crate) fn octic_mul_packed<FP, const WIDTH: usize>(
     octic_mul(a, b, res, FP::W);
 }
 
+/// Multiplication in a binomial extension field of degree `2, 3, 6` or `7`.
+///
+/// Writing `D = WIDTH`, the `k`'th coefficient of the product is
+/// `a0*bk + ... + ak*b0 + w(a(k+1)*b(D-1) + ... + a(D-1)*b(k+1))`.
+#[inline]
+pub(crate) fn small_binomial_mul_packed<FP, const WIDTH: usize>(
+    a: &[MontyField31<FP>; WIDTH],
+    b: &[MontyField31<FP>; WIDTH],
+    res: &mut [MontyField31<FP>; WIDTH],
+) where
+    FP: FieldParameters + BinomialExtensionData<WIDTH>,
+{
+    let w_b = b.map(|b_i| b_i * FP::W);
+    for (k, r) in res.iter_mut().enumerate() {
+        *r = a
+            .iter()
+            .enumerate()
+            .map(|(i, &a_i)| {
+                if i <= k {
+                    a_i * b[k - i]
+                } else {
+                    a_i * w_b[WIDTH + k - i]
+                }
+            })
+            .sum();
+    }
+}
+
 /// Multiplication by a base field element in a binomial extension field.
 #[inline]
 pub(crate) fn base_mul_packed<FP, const WIDTH: usize>(
@@ -85,6 +85,35 @@ pub(crate) fn sub<MP: MontyParameters>(lhs: u32, rhs: u32) -> u32 {
     diff
 }
 
+/// Add two integers modulo `P = MP::PRIME` in constant time.
+///
+/// Assumes that `P` is less than `2^31` and that both inputs lie in `[0, P)`.
+/// The output will also be in `[0, P)`.
+///
+/// Unlike [`add`], the final correction is computed from the sign bit of `a + b - P`
+/// so no branch or data-dependent select is needed.
+#[inline]
+#[must_use]
+pub(crate) const fn ct_add<MP: MontyParameters>(lhs: u32, rhs: u32) -> u32 {
+    // As lhs + rhs < 2P < 2^32, lhs + rhs - P has its top bit set if and only if lhs + rhs < P.
+    let corr_sum = (lhs + rhs).wrapping_sub(MP::PRIME);
+    let mask = (corr_sum >> 31).wrapping_neg();
+    corr_sum.wrapping_add(MP::PRIME & mask)
+}
+
+/// Subtract two integers modulo `P = MP::PRIME` in constant time.
+///
+/// Assumes that `P` is less than `2^31` and that both inputs lie in `[0, P)`.
+/// The output will also be in `[0, P)`.
+#[inline]
+#[must_use]
+pub(crate) const fn ct_sub<MP: MontyParameters>(lhs: u32, rhs: u32) -> u32 {
+    // As |lhs - rhs| < P < 2^31, lhs - rhs has its top bit set if and only if lhs < rhs.
+    let diff = lhs.wrapping_sub(rhs);
+    let mask = (diff >> 31).wrapping_neg();
+    diff.wrapping_add(MP::PRIME & mask)
+}
+
 /// Given an element `x` from a 31 bit field `F` compute `x/2`.
 /// The input must be in `[0, P)`.
 /// The output will also be in `[0, P)`.
@@ -124,6 +153,27 @@ pub(crate) const fn monty_reduce<MP: MontyParameters>(x: u64) -> u32 {
     x_sub_u_hi.wrapping_add(corr)
 }
 
+/// Constant-time Montgomery reduction of a value in `0..P << MONTY_BITS`.
+///
+/// The input must be in `[0, MONTY * P)`.
+/// The output will be in `[0, P)`.
+///
+/// This agrees with [`monty_reduce`] but derives the final correction from the sign bit of
+/// `x - u` instead of an overflow flag, so the compiler has no reason to emit a branch.
+#[inline]
+#[must_use]
+pub(crate) const fn ct_monty_reduce<MP: MontyParameters>(x: u64) -> u32 {
+    let t = x.wrapping_mul(MP::MONTY_MU as u64) & (MP::MONTY_MASK as u64);
+    let u = t * (MP::PRIME as u64);
+
+    // Both x and u are less than MONTY * P < 2^63 so x - u is negative if and only if
+    // its top bit is set.
+    let x_sub_u = x.wrapping_sub(u);
+    let x_sub_u_hi = (x_sub_u >> MP::MONTY_BITS) as u32;
+    let mask = ((x_sub_u >> 63) as u32).wrapping_neg();
+    x_sub_u_hi.wrapping_add(MP::PRIME & mask)
+}
+
 /// Montgomery reduction of a value in `0..P << MONTY_BITS`.
 /// The input must be in [0, 2 * MONTY * P).
 /// The output will be in [0, P).
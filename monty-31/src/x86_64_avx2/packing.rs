@@ -8,8 +8,8 @@ use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAss
 use p3_field::interleave::{interleave_u32, interleave_u64, interleave_u128};
 use p3_field::op_assign_macros::{
     impl_add_assign, impl_add_base_field, impl_div_methods, impl_mul_base_field, impl_mul_methods,
-    impl_packed_value, impl_rng, impl_sub_assign, impl_sub_base_field, impl_sum_prod_base_field,
-    ring_sum,
+    impl_packed_div_methods, impl_packed_value, impl_rng, impl_sub_assign, impl_sub_base_field,
+    impl_sum_prod_base_field, ring_sum,
 };
 use p3_field::{
     Algebra, Field, InjectiveMonomial, PackedField, PackedFieldPow2, PackedValue,
@@ -263,6 +263,7 @@ impl_mul_base_field!(
     (PackedMontyParameters, PMP)
 );
 impl_div_methods!(PackedMontyField31AVX2, MontyField31, (FieldParameters, FP));
+impl_packed_div_methods!(PackedMontyField31AVX2, (FieldParameters, FP));
 impl_sum_prod_base_field!(PackedMontyField31AVX2, MontyField31, (FieldParameters, FP));
 
 impl<FP: FieldParameters> Algebra<MontyField31<FP>> for PackedMontyField31AVX2<FP> {}
@@ -1224,6 +1225,39 @@ pub(crate) fn octic_mul_packed<FP, const WIDTH: usize>(
     res[..].copy_from_slice(&dot);
 }
 
+/// Multiplication in a binomial extension field of degree `2, 3, 6` or `7`.
+///
+/// Writing `D = WIDTH`, the `k`'th coefficient of the product is
+/// `a0*bk + ... + ak*b0 + w(a(k+1)*b(D-1) + ... + a(D-1)*b(k+1))`.
+/// Hence, broadcasting `ai` and placing either `b(k-i)` or `w*b(D+k-i)` in lane `k` of the `i`'th
+/// right hand side, this is a single `D`-term dot product. The unused lanes are set to zero.
+#[inline]
+pub(crate) fn small_binomial_mul_packed<FP, const WIDTH: usize>(
+    a: &[MontyField31<FP>; WIDTH],
+    b: &[MontyField31<FP>; WIDTH],
+    res: &mut [MontyField31<FP>; WIDTH],
+) where
+    FP: FieldParameters + BinomialExtensionData<WIDTH>,
+{
+    assert!(WIDTH < 8);
+    let zero = MontyField31::<FP>::ZERO;
+    let packed_b = PackedMontyField31AVX2(array::from_fn(|k| if k < WIDTH { b[k] } else { zero }));
+    let w_b = FP::mul_w(packed_b).0;
+
+    let lhs: [PackedMontyField31AVX2<FP>; WIDTH] = a.map(Into::into);
+    let rhs: [PackedMontyField31AVX2<FP>; WIDTH] = array::from_fn(|i| {
+        PackedMontyField31AVX2(array::from_fn(|k| match k {
+            _ if k >= WIDTH => zero,
+            _ if k >= i => b[k - i],
+            _ => w_b[WIDTH + k - i],
+        }))
+    });
+
+    let dot = PackedMontyField31AVX2::dot_product(&lhs, &rhs).0;
+
+    res.copy_from_slice(&dot[..WIDTH]);
+}
+
 /// Multiplication by a base field element in a binomial extension field.
 #[inline]
 pub(crate) fn base_mul_packed<FP, const WIDTH: usize>(
@@ -1252,6 +1286,15 @@ pub(crate) fn base_mul_packed<FP, const WIDTH: usize>(
 
             res[..4].copy_from_slice(&out.0[..4]);
         }
+        2 | 3 | 6 | 7 => {
+            let zero = MontyField31::<FP>::ZERO;
+            let lhs =
+                PackedMontyField31AVX2(array::from_fn(|i| if i < WIDTH { a[i] } else { zero }));
+
+            let out = lhs * b;
+
+            res.copy_from_slice(&out.0[..WIDTH]);
+        }
         8 => {
             let lhs = PackedMontyField31AVX2([a[0], a[1], a[2], a[3], a[4], a[5], a[6], a[7]]);
 
@@ -14,8 +14,8 @@ use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAss
 use p3_field::interleave::{interleave_u32, interleave_u64, interleave_u128, interleave_u256};
 use p3_field::op_assign_macros::{
     impl_add_assign, impl_add_base_field, impl_div_methods, impl_mul_base_field, impl_mul_methods,
-    impl_packed_value, impl_rng, impl_sub_assign, impl_sub_base_field, impl_sum_prod_base_field,
-    ring_sum,
+    impl_packed_div_methods, impl_packed_value, impl_rng, impl_sub_assign, impl_sub_base_field,
+    impl_sum_prod_base_field, ring_sum,
 };
 use p3_field::{
     Algebra, Field, InjectiveMonomial, PackedField, PackedFieldPow2, PackedValue,
@@ -288,6 +288,7 @@ impl_div_methods!(
     MontyField31,
     (FieldParameters, FP)
 );
+impl_packed_div_methods!(PackedMontyField31AVX512, (FieldParameters, FP));
 impl_sum_prod_base_field!(
     PackedMontyField31AVX512,
     MontyField31,
@@ -1533,6 +1534,39 @@ pub(crate) fn octic_mul_packed<FP, const WIDTH: usize>(
     res[7] = total.0[14];
 }
 
+/// Multiplication in a binomial extension field of degree `2, 3, 6` or `7`.
+///
+/// Writing `D = WIDTH`, the `k`'th coefficient of the product is
+/// `a0*bk + ... + ak*b0 + w(a(k+1)*b(D-1) + ... + a(D-1)*b(k+1))`.
+/// Hence, broadcasting `ai` and placing either `b(k-i)` or `w*b(D+k-i)` in lane `k` of the `i`'th
+/// right hand side, this is a single `D`-term dot product. The unused lanes are set to zero.
+#[inline]
+pub(crate) fn small_binomial_mul_packed<FP, const WIDTH: usize>(
+    a: &[MontyField31<FP>; WIDTH],
+    b: &[MontyField31<FP>; WIDTH],
+    res: &mut [MontyField31<FP>; WIDTH],
+) where
+    FP: FieldParameters + BinomialExtensionData<WIDTH>,
+{
+    assert!(WIDTH < 8);
+    let zero = MontyField31::<FP>::ZERO;
+    let packed_b = PackedMontyField31AVX512::from_monty_array(*b);
+    let w_b = FP::mul_w(packed_b).0;
+
+    let lhs: [PackedMontyField31AVX512<FP>; WIDTH] = a.map(Into::into);
+    let rhs: [PackedMontyField31AVX512<FP>; WIDTH] = array::from_fn(|i| {
+        PackedMontyField31AVX512(array::from_fn(|k| match k {
+            _ if k >= WIDTH => zero,
+            _ if k >= i => b[k - i],
+            _ => w_b[WIDTH + k - i],
+        }))
+    });
+
+    let dot = PackedMontyField31AVX512::dot_product(&lhs, &rhs).0;
+
+    res.copy_from_slice(&dot[..WIDTH]);
+}
+
 /// Multiplication by a base field element in a binomial extension field.
 #[inline]
 pub(crate) fn base_mul_packed<FP, const WIDTH: usize>(
@@ -1569,6 +1603,11 @@ pub(crate) fn base_mul_packed<FP, const WIDTH: usize>(
 
             res.copy_from_slice(&out[..5]);
         }
+        2 | 3 | 6 | 7 => {
+            let out = PackedMontyField31AVX512::from_monty_array(a) * b;
+
+            res.copy_from_slice(&out.0[..WIDTH]);
+        }
         8 => {
             // This could likely be sped up by a completely custom implementation of mul.
             let out: [MontyField31<FP>; 8] = unsafe {
@@ -0,0 +1,85 @@
+use core::fmt::Debug;
+use core::hash::Hash;
+
+use p3_field::PrimeCharacteristicRing;
+
+use crate::MontyField64;
+
+/// MontyParameters contains the prime P along with constants needed to convert elements into and out of MONTY form.
+/// The MONTY constant is fixed to be 2^64.
+pub trait MontyParameters:
+    Copy + Clone + Default + Debug + Eq + PartialEq + Sync + Send + Hash + 'static
+{
+    // An odd prime satisfying 2^32 < P < 2^64.
+    const PRIME: u64;
+
+    // We define MONTY_MU = PRIME^-1 (mod 2^64). As in the 31-bit case, this differs from the usual
+    // convention (MONTY_MU = -PRIME^-1 (mod 2^64)) but it avoids a carry.
+    //
+    // This is computed from PRIME by Newton iteration and should not need to be overridden.
+    const MONTY_MU: u64 = {
+        // PRIME * PRIME = 1 mod 8 so PRIME is its own inverse to 3 bits of precision.
+        // Every iteration doubles the number of correct bits: 3 -> 6 -> 12 -> 24 -> 48 -> 96.
+        let mut inv = Self::PRIME;
+        let mut i = 0;
+        while i < 5 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(Self::PRIME.wrapping_mul(inv)));
+            i += 1;
+        }
+        inv
+    };
+}
+
+/// FieldParameters contains constants and methods needed to imply PrimeCharacteristicRing, Field and PrimeField64 for MontyField64.
+pub trait FieldParameters: MontyParameters + Sized {
+    // Simple field constants.
+    const MONTY_ZERO: MontyField64<Self> = MontyField64::new(0);
+    const MONTY_ONE: MontyField64<Self> = MontyField64::new(1);
+    const MONTY_TWO: MontyField64<Self> = MontyField64::new(2);
+    const MONTY_NEG_ONE: MontyField64<Self> = MontyField64::new(Self::PRIME - 1);
+
+    // A generator of the fields multiplicative group. Needs to be given in Monty Form.
+    const MONTY_GEN: MontyField64<Self>;
+
+    const HALF_P_PLUS_1: u64 = (Self::PRIME >> 1) + 1;
+}
+
+/// An integer `D` such that `gcd(D, p - 1) = 1`.
+pub trait RelativelyPrimePower<const D: u64> {
+    /// Compute `x -> x^{1/D}` using the modular inverse
+    /// of `D mod p - 1`.
+    fn exp_root_d<R: PrimeCharacteristicRing>(val: R) -> R;
+}
+
+/// TwoAdicData contains constants needed to imply TwoAdicField for Monty64 fields.
+pub trait TwoAdicData: MontyParameters {
+    /// Largest n such that 2^n divides p - 1.
+    const TWO_ADICITY: usize;
+
+    /// ArrayLike should usually be `&'static [MontyField64]`.
+    type ArrayLike: AsRef<[MontyField64<Self>]> + Sized;
+
+    /// A list of generators of 2-adic subgroups.
+    /// The i'th element must be a 2^i root of unity and the i'th element squared must be the i-1'th element.
+    const TWO_ADIC_GENERATORS: Self::ArrayLike;
+}
+
+/// This allows us to implement Binomial Extensions over Monty64 fields.
+pub trait BinomialExtensionData<const DEG: usize>: MontyParameters + Sized {
+    /// W is a value such that (x^DEG - W) is irreducible.
+    const W: MontyField64<Self>;
+
+    /// DTH_ROOT = W^((p - 1)/DEG)
+    const DTH_ROOT: MontyField64<Self>;
+
+    /// A generator of the extension fields multiplicative group.
+    const EXT_GENERATOR: [MontyField64<Self>; DEG];
+
+    const EXT_TWO_ADICITY: usize;
+
+    /// ArrayLike should usually be [[MontyField64; DEG]; EXT_TWO_ADICITY - TWO_ADICITY].
+    type ArrayLike: AsRef<[[MontyField64<Self>; DEG]]> + Sized;
+
+    /// A list of generators of 2-adic subgroups not contained in the base field.
+    const TWO_ADIC_EXTENSION_GENERATORS: Self::ArrayLike;
+}
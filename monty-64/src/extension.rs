@@ -0,0 +1,45 @@
+use p3_field::extension::{
+    BinomiallyExtendable, BinomiallyExtendableAlgebra, HasTwoAdicBinomialExtension,
+};
+use p3_field::{TwoAdicField, field_to_array};
+
+use crate::{BinomialExtensionData, FieldParameters, MontyField64, TwoAdicData};
+
+// If a field implements BinomialExtensionData<WIDTH> then there is a natural
+// field extension of degree WIDTH we can define.
+// We perform no checks to make sure the data given in BinomialExtensionData<WIDTH> is valid and
+// corresponds to an actual field extension. Ensuring that is left to the implementer.
+
+impl<const WIDTH: usize, FP> BinomiallyExtendableAlgebra<MontyField64<FP>, WIDTH>
+    for MontyField64<FP>
+where
+    FP: BinomialExtensionData<WIDTH> + FieldParameters,
+{
+}
+
+impl<const WIDTH: usize, FP> BinomiallyExtendable<WIDTH> for MontyField64<FP>
+where
+    FP: BinomialExtensionData<WIDTH> + FieldParameters,
+{
+    const W: Self = FP::W;
+
+    const DTH_ROOT: Self = FP::DTH_ROOT;
+
+    const EXT_GENERATOR: [Self; WIDTH] = FP::EXT_GENERATOR;
+}
+
+impl<const WIDTH: usize, FP> HasTwoAdicBinomialExtension<WIDTH> for MontyField64<FP>
+where
+    FP: BinomialExtensionData<WIDTH> + TwoAdicData + FieldParameters,
+{
+    const EXT_TWO_ADICITY: usize = FP::EXT_TWO_ADICITY;
+
+    fn ext_two_adic_generator(bits: usize) -> [Self; WIDTH] {
+        assert!(bits <= Self::EXT_TWO_ADICITY);
+        if bits <= FP::TWO_ADICITY {
+            field_to_array(Self::two_adic_generator(bits))
+        } else {
+            FP::TWO_ADIC_EXTENSION_GENERATORS.as_ref()[bits - FP::TWO_ADICITY - 1]
+        }
+    }
+}
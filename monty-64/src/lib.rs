@@ -0,0 +1,33 @@
+//! A generic prime field `F_p` with `2^32 < p < 2^64`, using Montgomery arithmetic.
+
+#![no_std]
+
+extern crate alloc;
+
+mod data_traits;
+mod extension;
+mod monty_64;
+mod utils;
+pub use data_traits::*;
+pub use monty_64::*;
+
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "avx2",
+    not(target_feature = "avx512f")
+))]
+mod x86_64_avx2;
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "avx2",
+    not(target_feature = "avx512f")
+))]
+pub use x86_64_avx2::*;
+
+#[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+mod x86_64_avx512;
+#[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+pub use x86_64_avx512::*;
+
+#[cfg(test)]
+mod tests;
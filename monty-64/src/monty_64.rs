@@ -0,0 +1,449 @@
+//! An abstraction of 64-bit fields which use a MONTY approach for faster multiplication.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::array;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::Hash;
+use core::iter::{Product, Sum};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num_bigint::BigUint;
+use p3_field::integers::QuotientMap;
+use p3_field::op_assign_macros::{
+    impl_add_assign, impl_div_methods, impl_mul_methods, impl_sub_assign,
+};
+use p3_field::{
+    Field, InjectiveMonomial, LegendreSymbol, Packable, PermutationMonomial,
+    PrimeCharacteristicRing, PrimeField, PrimeField64, RawDataSerializable, TwoAdicField,
+    impl_raw_serializable_primefield64, quotient_map_small_int, tonelli_shanks,
+};
+use p3_util::flatten_to_base;
+use rand::Rng;
+use rand::distr::{Distribution, StandardUniform};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::utils::{add, from_monty, halve_u64, monty_reduce, sub, to_monty, to_monty_signed};
+use crate::{FieldParameters, MontyParameters, RelativelyPrimePower, TwoAdicData};
+
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+#[repr(transparent)] // Important for reasoning about memory layout.
+#[must_use]
+pub struct MontyField64<MP: MontyParameters> {
+    /// The MONTY form of the field element, saved as a positive integer less than `P`.
+    ///
+    /// This is `pub(crate)` for tests and delayed reduction strategies. If you're accessing `value` outside of those, you're
+    /// likely doing something fishy.
+    pub(crate) value: u64,
+    _phantom: PhantomData<MP>,
+}
+
+impl<MP: MontyParameters> MontyField64<MP> {
+    /// The standard way to create a new element.
+    /// Note that `new` converts the input into MONTY form so should be avoided in performance critical implementations.
+    #[inline(always)]
+    pub const fn new(value: u64) -> Self {
+        Self {
+            value: to_monty::<MP>(value),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Create a new field element from something already in MONTY form.
+    /// This is `pub(crate)` for tests and delayed reduction strategies. If you're using it outside of those, you're
+    /// likely doing something fishy.
+    #[inline(always)]
+    pub(crate) const fn new_monty(value: u64) -> Self {
+        Self {
+            value,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Produce a u64 in range [0, P) from a field element corresponding to the true value.
+    #[inline(always)]
+    pub(crate) const fn to_u64(elem: &Self) -> u64 {
+        from_monty::<MP>(elem.value)
+    }
+
+    /// Convert a constant u64 array into a constant array of field elements.
+    /// Constant version of array.map(MontyField64::new).
+    #[inline]
+    pub const fn new_array<const N: usize>(input: [u64; N]) -> [Self; N] {
+        let mut output = [Self::new_monty(0); N];
+        let mut i = 0;
+        while i < N {
+            output[i] = Self::new(input[i]);
+            i += 1;
+        }
+        output
+    }
+
+    /// Convert a constant 2d u64 array into a constant 2d array of field elements.
+    /// Constant version of array.map(MontyField64::new_array).
+    #[inline]
+    pub const fn new_2d_array<const N: usize, const M: usize>(
+        input: [[u64; N]; M],
+    ) -> [[Self; N]; M] {
+        let mut output = [[Self::new_monty(0); N]; M];
+        let mut i = 0;
+        while i < M {
+            output[i] = Self::new_array(input[i]);
+            i += 1;
+        }
+        output
+    }
+}
+
+impl<FP: MontyParameters> Ord for MontyField64<FP> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        Self::to_u64(self).cmp(&Self::to_u64(other))
+    }
+}
+
+impl<FP: MontyParameters> PartialOrd for MontyField64<FP> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<FP: MontyParameters> Display for MontyField64<FP> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&Self::to_u64(self), f)
+    }
+}
+
+impl<FP: MontyParameters> Debug for MontyField64<FP> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&Self::to_u64(self), f)
+    }
+}
+
+impl<FP: MontyParameters> Distribution<MontyField64<FP>> for StandardUniform {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> MontyField64<FP> {
+        // Trim the random value to the bit length of P to keep the rejection rate below 1/2.
+        let shift = FP::PRIME.leading_zeros();
+        loop {
+            let next_u64 = rng.next_u64() >> shift;
+            let is_canonical = next_u64 < FP::PRIME;
+            if is_canonical {
+                return MontyField64::new_monty(next_u64);
+            }
+        }
+    }
+}
+
+impl<FP: FieldParameters> Serialize for MontyField64<FP> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // It's faster to Serialize and Deserialize in monty form.
+        serializer.serialize_u64(self.value)
+    }
+}
+
+impl<'de, FP: FieldParameters> Deserialize<'de> for MontyField64<FP> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        // It's faster to Serialize and Deserialize in monty form.
+        let val = u64::deserialize(d)?;
+        if val < FP::PRIME {
+            Ok(Self::new_monty(val))
+        } else {
+            Err(serde::de::Error::custom("Value is out of range"))
+        }
+    }
+}
+
+impl<MP: MontyParameters> Packable for MontyField64<MP> {}
+
+impl<FP: FieldParameters> PrimeCharacteristicRing for MontyField64<FP> {
+    type PrimeSubfield = Self;
+
+    const ZERO: Self = FP::MONTY_ZERO;
+    const ONE: Self = FP::MONTY_ONE;
+    const TWO: Self = FP::MONTY_TWO;
+    const NEG_ONE: Self = FP::MONTY_NEG_ONE;
+
+    #[inline(always)]
+    fn from_prime_subfield(f: Self) -> Self {
+        f
+    }
+
+    #[inline]
+    fn halve(&self) -> Self {
+        Self::new_monty(halve_u64::<FP>(self.value))
+    }
+
+    #[inline]
+    fn zero_vec(len: usize) -> Vec<Self> {
+        // SAFETY:
+        // Due to `#[repr(transparent)]`, MontyField64 and u64 have the same size, alignment
+        // and memory layout making `flatten_to_base` safe. This this will create
+        // a vector MontyField64 elements with value set to 0 which is the
+        // MONTY form of 0.
+        unsafe { flatten_to_base(vec![0u64; len]) }
+    }
+}
+
+impl<FP: FieldParameters + RelativelyPrimePower<D>, const D: u64> InjectiveMonomial<D>
+    for MontyField64<FP>
+{
+}
+
+impl<FP: FieldParameters + RelativelyPrimePower<D>, const D: u64> PermutationMonomial<D>
+    for MontyField64<FP>
+{
+    fn injective_exp_root_n(&self) -> Self {
+        FP::exp_root_d(*self)
+    }
+}
+
+impl<FP: FieldParameters> RawDataSerializable for MontyField64<FP> {
+    impl_raw_serializable_primefield64!();
+}
+
+impl<FP: FieldParameters> Field for MontyField64<FP> {
+    #[cfg(all(
+        target_arch = "x86_64",
+        target_feature = "avx2",
+        not(target_feature = "avx512f")
+    ))]
+    type Packing = crate::PackedMontyField64AVX2<FP>;
+    #[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+    type Packing = crate::PackedMontyField64AVX512<FP>;
+    #[cfg(not(any(
+        all(
+            target_arch = "x86_64",
+            target_feature = "avx2",
+            not(target_feature = "avx512f")
+        ),
+        all(target_arch = "x86_64", target_feature = "avx512f"),
+    )))]
+    type Packing = Self;
+
+    const GENERATOR: Self = FP::MONTY_GEN;
+
+    fn try_inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+
+        // As P is an arbitrary prime there is no special structure for us to exploit, so we use
+        // Fermat's little theorem: x^{-1} = x^{P - 2}.
+        Some(self.exp_u64(FP::PRIME - 2))
+    }
+
+    #[inline]
+    fn legendre_symbol(&self) -> LegendreSymbol {
+        LegendreSymbol::from_euler_criterion(self, self.exp_u64((FP::PRIME - 1) >> 1))
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        // Write P - 1 = 2^s t with t odd.
+        let two_adicity = (FP::PRIME - 1).trailing_zeros() as usize;
+        let odd_factor = (FP::PRIME - 1) >> two_adicity;
+        tonelli_shanks(
+            *self,
+            two_adicity,
+            self.exp_u64(odd_factor >> 1),
+            Self::GENERATOR.exp_u64(odd_factor),
+        )
+    }
+
+    #[inline]
+    fn order() -> BigUint {
+        FP::PRIME.into()
+    }
+}
+
+quotient_map_small_int!(MontyField64, u64, FieldParameters, [u8, u16, u32]);
+quotient_map_small_int!(MontyField64, i64, FieldParameters, [i8, i16, i32]);
+
+impl<FP: FieldParameters> QuotientMap<u64> for MontyField64<FP> {
+    /// Convert a given `u64` integer into an element of the `MontyField64` field.
+    #[inline]
+    fn from_int(int: u64) -> Self {
+        Self::new(int)
+    }
+
+    /// Convert a given `u64` integer into an element of the `MontyField64` field.
+    ///
+    /// Returns `None` if the given integer is greater than the Prime.
+    #[inline]
+    fn from_canonical_checked(int: u64) -> Option<Self> {
+        (int < FP::PRIME).then(|| Self::new(int))
+    }
+
+    /// Convert a given `u64` integer into an element of the `MontyField64` field.
+    ///
+    /// # Safety
+    /// This is always safe as the conversion to monty form can accept any `u64`.
+    #[inline(always)]
+    unsafe fn from_canonical_unchecked(int: u64) -> Self {
+        Self::new(int)
+    }
+}
+
+impl<FP: FieldParameters> QuotientMap<i64> for MontyField64<FP> {
+    /// Convert a given `i64` integer into an element of the `MontyField64` field.
+    #[inline]
+    fn from_int(int: i64) -> Self {
+        Self::new_monty(to_monty_signed::<FP>(int))
+    }
+
+    /// Convert a given `i64` integer into an element of the `MontyField64` field.
+    ///
+    /// Returns `None` if the given integer does not lie in the range `[(1 - P)/2, (P - 1)/2]`.
+    #[inline]
+    fn from_canonical_checked(int: i64) -> Option<Self> {
+        let bound = (FP::PRIME >> 1) as i64;
+        if int <= bound {
+            (int >= (-bound)).then(|| Self::new_monty(to_monty_signed::<FP>(int)))
+        } else {
+            None
+        }
+    }
+
+    /// Convert a given `i64` integer into an element of the `MontyField64` field.
+    ///
+    /// # Safety
+    /// This is always safe as the conversion to monty form can accept any `i64`.
+    #[inline(always)]
+    unsafe fn from_canonical_unchecked(int: i64) -> Self {
+        Self::new_monty(to_monty_signed::<FP>(int))
+    }
+}
+
+impl<FP: FieldParameters> QuotientMap<u128> for MontyField64<FP> {
+    /// Convert a given `u128` integer into an element of the `MontyField64` field.
+    fn from_int(int: u128) -> Self {
+        Self::new((int % (FP::PRIME as u128)) as u64)
+    }
+
+    /// Convert a given `u128` integer into an element of the `MontyField64` field.
+    ///
+    /// Returns `None` if the given integer is greater than the Prime.
+    fn from_canonical_checked(int: u128) -> Option<Self> {
+        (int < FP::PRIME as u128).then(|| Self::new(int as u64))
+    }
+
+    /// Convert a given `u128` integer into an element of the `MontyField64` field.
+    ///
+    /// # Safety
+    /// The input must be a valid `u64` element.
+    unsafe fn from_canonical_unchecked(int: u128) -> Self {
+        Self::new(int as u64)
+    }
+}
+
+impl<FP: FieldParameters> QuotientMap<i128> for MontyField64<FP> {
+    /// Convert a given `i128` integer into an element of the `MontyField64` field.
+    fn from_int(int: i128) -> Self {
+        Self::new(int.rem_euclid(FP::PRIME as i128) as u64)
+    }
+
+    /// Convert a given `i128` integer into an element of the `MontyField64` field.
+    ///
+    /// Returns `None` if the given integer does not lie in the range `[(1 - P)/2, (P - 1)/2]`.
+    fn from_canonical_checked(int: i128) -> Option<Self> {
+        let bound = (FP::PRIME >> 1) as i128;
+        if int <= bound {
+            (int >= (-bound)).then(|| Self::new_monty(to_monty_signed::<FP>(int as i64)))
+        } else {
+            None
+        }
+    }
+
+    /// Convert a given `i128` integer into an element of the `MontyField64` field.
+    ///
+    /// # Safety
+    /// The input must be a valid `i64` element.
+    unsafe fn from_canonical_unchecked(int: i128) -> Self {
+        Self::new_monty(to_monty_signed::<FP>(int as i64))
+    }
+}
+
+impl<FP: FieldParameters> PrimeField for MontyField64<FP> {
+    fn as_canonical_biguint(&self) -> BigUint {
+        self.as_canonical_u64().into()
+    }
+}
+
+impl<FP: FieldParameters> PrimeField64 for MontyField64<FP> {
+    const ORDER_U64: u64 = FP::PRIME;
+
+    #[inline]
+    fn as_canonical_u64(&self) -> u64 {
+        Self::to_u64(self)
+    }
+
+    #[inline]
+    fn to_unique_u64(&self) -> u64 {
+        // The internal representation is already a unique u64 for each field element.
+        // It's fine to hash things in monty form.
+        self.value
+    }
+}
+
+impl<FP: FieldParameters + TwoAdicData> TwoAdicField for MontyField64<FP> {
+    const TWO_ADICITY: usize = FP::TWO_ADICITY;
+    fn two_adic_generator(bits: usize) -> Self {
+        assert!(bits <= Self::TWO_ADICITY);
+        FP::TWO_ADIC_GENERATORS.as_ref()[bits]
+    }
+}
+
+impl<FP: MontyParameters> Add for MontyField64<FP> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new_monty(add::<FP>(self.value, rhs.value))
+    }
+}
+
+impl<FP: MontyParameters> Sub for MontyField64<FP> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new_monty(sub::<FP>(self.value, rhs.value))
+    }
+}
+
+impl<FP: FieldParameters> Neg for MontyField64<FP> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::ZERO - self
+    }
+}
+
+impl<FP: MontyParameters> Mul for MontyField64<FP> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let long_prod = self.value as u128 * rhs.value as u128;
+        Self::new_monty(monty_reduce::<FP>(long_prod))
+    }
+}
+
+impl_add_assign!(MontyField64, (MontyParameters, MP));
+impl_sub_assign!(MontyField64, (MontyParameters, MP));
+impl_mul_methods!(MontyField64, (FieldParameters, FP));
+impl_div_methods!(MontyField64, MontyField64, (FieldParameters, FP));
+
+impl<FP: MontyParameters> Sum for MontyField64<FP> {
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        // This is faster than iter.reduce(|x, y| x + y).unwrap_or(Self::ZERO) for iterators of length > 2.
+
+        // This sum will not overflow so long as iter.len() < 2^64.
+        let sum = iter.map(|x| x.value as u128).sum::<u128>();
+        Self::new_monty((sum % FP::PRIME as u128) as u64)
+    }
+}
@@ -0,0 +1,233 @@
+//! Tests for `MontyField64`, instantiated with two example primes.
+//!
+//! - The Goldilocks prime `2^64 - 2^32 + 1`, which lets us compare against `p3-goldilocks`.
+//! - The largest 64-bit prime `2^64 - 59`, which exercises the overflow handling in addition.
+
+use num_bigint::BigUint;
+use p3_field::exponentiation::exp_10540996611094048183;
+use p3_field::{PrimeCharacteristicRing, PrimeField64};
+
+use crate::{
+    BinomialExtensionData, FieldParameters, MontyField64, MontyParameters, RelativelyPrimePower,
+    TwoAdicData,
+};
+
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub(crate) struct GoldilocksParameters;
+
+pub(crate) type MontyGoldilocks = MontyField64<GoldilocksParameters>;
+
+impl MontyParameters for GoldilocksParameters {
+    const PRIME: u64 = 0xFFFF_FFFF_0000_0001;
+}
+
+impl FieldParameters for GoldilocksParameters {
+    const MONTY_GEN: MontyGoldilocks = MontyGoldilocks::new(7);
+}
+
+impl RelativelyPrimePower<7> for GoldilocksParameters {
+    /// `7 * 10540996611094048183 = 1 mod (p - 1)`.
+    fn exp_root_d<R: PrimeCharacteristicRing>(val: R) -> R {
+        exp_10540996611094048183(val)
+    }
+}
+
+impl TwoAdicData for GoldilocksParameters {
+    const TWO_ADICITY: usize = 32;
+
+    type ArrayLike = [MontyGoldilocks; 33];
+
+    const TWO_ADIC_GENERATORS: Self::ArrayLike = MontyGoldilocks::new_array([
+        0x0000000000000001,
+        0xffffffff00000000,
+        0x0001000000000000,
+        0xfffffffeff000001,
+        0xefffffff00000001,
+        0x00003fffffffc000,
+        0x0000008000000000,
+        0xf80007ff08000001,
+        0xbf79143ce60ca966,
+        0x1905d02a5c411f4e,
+        0x9d8f2ad78bfed972,
+        0x0653b4801da1c8cf,
+        0xf2c35199959dfcb6,
+        0x1544ef2335d17997,
+        0xe0ee099310bba1e2,
+        0xf6b2cffe2306baac,
+        0x54df9630bf79450e,
+        0xabd0a6e8aa3d8a0e,
+        0x81281a7b05f9beac,
+        0xfbd41c6b8caa3302,
+        0x30ba2ecd5e93e76d,
+        0xf502aef532322654,
+        0x4b2a18ade67246b5,
+        0xea9d5a1336fbc98b,
+        0x86cdcc31c307e171,
+        0x4bbaf5976ecfefd8,
+        0xed41d05b78d6e286,
+        0x10d78dd8915a171d,
+        0x59049500004a4485,
+        0xdfa8c93ba46d2666,
+        0x7e9bd009b86a0845,
+        0x400a7f755588e659,
+        0x185629dcda58878c,
+    ]);
+}
+
+impl BinomialExtensionData<2> for GoldilocksParameters {
+    const W: MontyGoldilocks = MontyGoldilocks::new(7);
+    const DTH_ROOT: MontyGoldilocks = MontyGoldilocks::new(18446744069414584320);
+    const EXT_GENERATOR: [MontyGoldilocks; 2] =
+        MontyGoldilocks::new_array([18081566051660590251, 16121475356294670766]);
+    const EXT_TWO_ADICITY: usize = 33;
+
+    type ArrayLike = [[MontyGoldilocks; 2]; 1];
+
+    const TWO_ADIC_EXTENSION_GENERATORS: Self::ArrayLike =
+        MontyGoldilocks::new_2d_array([[0, 15659105665374529263]]);
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub(crate) struct LargeParameters;
+
+pub(crate) type MontyLarge = MontyField64<LargeParameters>;
+
+impl MontyParameters for LargeParameters {
+    const PRIME: u64 = 0xFFFF_FFFF_FFFF_FFC5;
+}
+
+impl FieldParameters for LargeParameters {
+    const MONTY_GEN: MontyLarge = MontyLarge::new(2);
+}
+
+impl RelativelyPrimePower<3> for LargeParameters {
+    /// `3 * 12297829382473034371 = 1 mod (p - 1)`.
+    fn exp_root_d<R: PrimeCharacteristicRing>(val: R) -> R {
+        val.exp_u64(12297829382473034371)
+    }
+}
+
+impl TwoAdicData for LargeParameters {
+    const TWO_ADICITY: usize = 2;
+
+    type ArrayLike = [MontyLarge; 3];
+
+    const TWO_ADIC_GENERATORS: Self::ArrayLike =
+        MontyLarge::new_array([1, 0xFFFF_FFFF_FFFF_FFC4, 2296021864060584341]);
+}
+
+mod goldilocks {
+    use num_bigint::BigUint;
+    use p3_field::extension::BinomialExtensionField;
+    use p3_field::{Field, PrimeCharacteristicRing, PrimeField64};
+    use p3_field_testing::{
+        test_field, test_prime_field, test_prime_field_64, test_two_adic_field,
+    };
+    use p3_goldilocks::Goldilocks;
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::MontyGoldilocks as F;
+
+    type EF = BinomialExtensionField<F, 2>;
+
+    #[test]
+    fn test_monty_mu() {
+        use crate::MontyParameters;
+        let p = super::GoldilocksParameters::PRIME;
+        assert_eq!(p.wrapping_mul(super::GoldilocksParameters::MONTY_MU), 1);
+    }
+
+    #[test]
+    fn test_agrees_with_goldilocks() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let to_goldilocks = |x: F| Goldilocks::from_u64(x.as_canonical_u64());
+        for _ in 0..1000 {
+            let a: F = rng.random();
+            let b: F = rng.random();
+            let (ga, gb) = (to_goldilocks(a), to_goldilocks(b));
+            assert_eq!(to_goldilocks(a + b), ga + gb);
+            assert_eq!(to_goldilocks(a - b), ga - gb);
+            assert_eq!(to_goldilocks(a * b), ga * gb);
+            assert_eq!(to_goldilocks(-a), -ga);
+            assert_eq!(to_goldilocks(a.halve()), ga.halve());
+            assert_eq!(to_goldilocks(a.inverse()), ga.inverse());
+        }
+    }
+
+    const ZEROS: [F; 1] = [F::ZERO];
+    const ONES: [F; 1] = [F::ONE];
+
+    fn multiplicative_group_prime_factorization() -> [(BigUint, u32); 6] {
+        super::GOLDILOCKS_FACTORS.map(|(p, e)| (BigUint::from(p), e))
+    }
+
+    test_field!(
+        super::F,
+        &super::ZEROS,
+        &super::ONES,
+        &super::multiplicative_group_prime_factorization()
+    );
+    test_prime_field!(super::F);
+    test_prime_field_64!(super::F, &super::ZEROS, &super::ONES);
+    test_two_adic_field!(super::F);
+
+    mod extension {
+        use p3_field_testing::{test_extension_field, test_two_adic_extension_field};
+
+        use super::{EF, F};
+
+        test_extension_field!(super::F, super::EF);
+        test_two_adic_extension_field!(super::F, super::EF);
+    }
+}
+
+mod large {
+    use p3_field::PrimeCharacteristicRing;
+    use p3_field_testing::{
+        test_field, test_prime_field, test_prime_field_64, test_two_adic_field,
+    };
+
+    use super::MontyLarge as F;
+
+    const ZEROS: [F; 1] = [F::ZERO];
+    const ONES: [F; 1] = [F::ONE];
+
+    test_field!(
+        super::F,
+        &super::ZEROS,
+        &super::ONES,
+        &super::super::large_factorization()
+    );
+    test_prime_field!(super::F);
+    test_prime_field_64!(super::F, &super::ZEROS, &super::ONES);
+    test_two_adic_field!(super::F);
+}
+
+const GOLDILOCKS_FACTORS: [(u64, u32); 6] =
+    [(2, 32), (3, 1), (5, 1), (17, 1), (257, 1), (65537, 1)];
+
+/// The prime factorization of `2^64 - 60`, the order of the multiplicative group of `MontyLarge`.
+fn large_factorization() -> [(BigUint, u32); 5] {
+    [
+        (BigUint::from(2u8), 2),
+        (BigUint::from(11u8), 1),
+        (BigUint::from(137u8), 1),
+        (BigUint::from(547u16), 1),
+        (BigUint::from(5594472617641u64), 1),
+    ]
+}
+
+#[test]
+fn test_edge_cases_near_two_to_the_64() {
+    // With P = 2^64 - 59, the sum of two canonical values can overflow a u64.
+    let p = LargeParameters::PRIME;
+    let a = MontyLarge::from_u64(p - 1);
+    let b = MontyLarge::from_u64(p - 2);
+    assert_eq!((a + b).as_canonical_u64(), p - 3);
+    assert_eq!((b - a).as_canonical_u64(), p - 1);
+    assert_eq!((a * b).as_canonical_u64(), 2);
+    assert_eq!(a.halve().as_canonical_u64(), (p - 1) / 2);
+    assert_eq!(MontyLarge::from_u64(u64::MAX).as_canonical_u64(), 58);
+    assert_eq!(MontyLarge::from_i64(-1), MontyLarge::NEG_ONE);
+}
@@ -0,0 +1,92 @@
+use crate::{FieldParameters, MontyParameters};
+
+/// Convert a u64 into MONTY form.
+/// There are no constraints on the input.
+/// The output will be a u64 in range `[0, P)`.
+#[inline]
+pub(crate) const fn to_monty<MP: MontyParameters>(x: u64) -> u64 {
+    (((x as u128) << 64) % MP::PRIME as u128) as u64
+}
+
+/// Convert an i64 into MONTY form.
+/// There are no constraints on the input.
+/// The output will be a u64 in range `[0, P)`.
+#[inline]
+pub(crate) const fn to_monty_signed<MP: MontyParameters>(x: i64) -> u64 {
+    let red = ((x as i128) << 64) % MP::PRIME as i128;
+    if red >= 0 {
+        red as u64
+    } else {
+        (red + MP::PRIME as i128) as u64
+    }
+}
+
+/// Convert a u64 out of MONTY form.
+/// There are no constraints on the input.
+/// The output will be a u64 in range `[0, P)`.
+#[inline]
+#[must_use]
+pub(crate) const fn from_monty<MP: MontyParameters>(x: u64) -> u64 {
+    monty_reduce::<MP>(x as u128)
+}
+
+/// Add two integers modulo `P = MP::PRIME`.
+///
+/// Assumes that `a, b < P`. As `P` may be larger than `2^63`, the sum `a + b` may overflow a `u64`
+/// and so we need to check both the carry and the comparison with `P`.
+/// The result will be in the range `[0, P)` and equal to `(a + b) mod P`.
+#[inline]
+#[must_use]
+pub(crate) const fn add<MP: MontyParameters>(lhs: u64, rhs: u64) -> u64 {
+    let (sum, carry) = lhs.overflowing_add(rhs);
+    let (corr_sum, borrow) = sum.overflowing_sub(MP::PRIME);
+    if carry || !borrow { corr_sum } else { sum }
+}
+
+/// Subtract two integers modulo `P = MP::PRIME`.
+///
+/// Assumes that `a, b < P`.
+/// The result will be in the range `[0, P)` and equal to `(a - b) mod P`.
+#[inline]
+#[must_use]
+pub(crate) const fn sub<MP: MontyParameters>(lhs: u64, rhs: u64) -> u64 {
+    let (diff, borrow) = lhs.overflowing_sub(rhs);
+    let corr = if borrow { MP::PRIME } else { 0 };
+    diff.wrapping_add(corr)
+}
+
+/// Given an element `x` from a 64 bit field `F` compute `x/2`.
+/// The input must be in `[0, P)`.
+/// The output will also be in `[0, P)`.
+#[inline]
+pub(crate) const fn halve_u64<FP: FieldParameters>(input: u64) -> u64 {
+    let shr = input >> 1;
+    let lo_bit = input & 1;
+    let shr_corr = shr + FP::HALF_P_PLUS_1;
+    if lo_bit == 0 { shr } else { shr_corr }
+}
+
+/// Montgomery reduction of a value in `0..P << 64`.
+///
+/// The input must be in `[0, 2^64 * P)`.
+/// The output will be in `[0, P)`.
+#[inline]
+#[must_use]
+pub(crate) const fn monty_reduce<MP: MontyParameters>(x: u128) -> u64 {
+    // t = x * MONTY_MU mod 2^64
+    let t = (x as u64).wrapping_mul(MP::MONTY_MU);
+
+    // u = t * P
+    let u = (t as u128) * (MP::PRIME as u128);
+
+    // Thus:
+    // 1. x - u = x - t * P = x mod P
+    // 2. x - u = x - x * MONTY_MU * P mod 2^64 = 0 mod 2^64
+    // For the second point note that MONTY_MU = P^{-1} mod 2^64.
+
+    // As the low 64 bits of x and u agree, (x - u) / 2^64 is just the difference of the high halves.
+    // Additionally, u < 2^64 * P so -P < (x - u)/2^64 < P and we just add P if it is negative.
+    let (x_sub_u_hi, over) = ((x >> 64) as u64).overflowing_sub((u >> 64) as u64);
+    let corr = if over { MP::PRIME } else { 0 };
+    x_sub_u_hi.wrapping_add(corr)
+}
@@ -0,0 +1,3 @@
+mod packing;
+
+pub use packing::*;
@@ -0,0 +1,413 @@
+use alloc::vec::Vec;
+use core::arch::x86_64::*;
+use core::iter::{Product, Sum};
+use core::mem::transmute;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use p3_field::interleave::{interleave_u64, interleave_u128};
+use p3_field::op_assign_macros::{
+    impl_add_assign, impl_add_base_field, impl_div_methods, impl_mul_base_field, impl_mul_methods,
+    impl_packed_div_methods, impl_packed_value, impl_rng, impl_sub_assign, impl_sub_base_field,
+    impl_sum_prod_base_field, ring_sum,
+};
+use p3_field::{
+    Algebra, Field, InjectiveMonomial, PackedField, PackedFieldPow2, PackedValue,
+    PermutationMonomial, PrimeCharacteristicRing, impl_packed_field_pow_2,
+};
+use p3_util::reconstitute_from_base;
+use rand::Rng;
+use rand::distr::{Distribution, StandardUniform};
+
+use crate::{FieldParameters, MontyField64, MontyParameters, RelativelyPrimePower};
+
+const WIDTH: usize = 4;
+
+/// Vectorized AVX2 implementation of `MontyField64<FP>` arithmetic.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(transparent)] // This is needed to make `transmute`s safe.
+#[must_use]
+pub struct PackedMontyField64AVX2<MP: MontyParameters>(pub [MontyField64<MP>; WIDTH]);
+
+impl<MP: MontyParameters> PackedMontyField64AVX2<MP> {
+    #[inline]
+    #[must_use]
+    /// Get an arch-specific vector representing the packed values.
+    pub(crate) fn to_vector(self) -> __m256i {
+        unsafe {
+            // Safety: `MontyField64<MP>` is `repr(transparent)` so it can be transmuted to `u64`. It
+            // follows that `[MontyField64<MP>; WIDTH]` can be transmuted to `[u64; WIDTH]`, which can be
+            // transmuted to `__m256i`, since arrays are guaranteed to be contiguous in memory.
+            // Finally `PackedMontyField64AVX2<MP>` is `repr(transparent)` so it can be transmuted to
+            // `[MontyField64<MP>; WIDTH]`.
+            transmute(self)
+        }
+    }
+
+    #[inline]
+    /// Make a packed field vector from an arch-specific vector.
+    ///
+    /// SAFETY: The caller must ensure that each element of `vector` represents a valid `MontyField64<MP>`.
+    /// In particular, each element of vector must be in `0..P` (canonical form).
+    pub(crate) unsafe fn from_vector(vector: __m256i) -> Self {
+        unsafe {
+            // Safety: It is up to the user to ensure that elements of `vector` represent valid
+            // `MontyField64<MP>` values. We must only reason about memory representations. `__m256i` can be
+            // transmuted to `[u64; WIDTH]` (since arrays elements are contiguous in memory), which can
+            // be transmuted to `[MontyField64<MP>; WIDTH]` (since `MontyField64<MP>` is `repr(transparent)`), which in
+            // turn can be transmuted to `PackedMontyField64AVX2<MP>` (since `PackedMontyField64AVX2<MP>` is also
+            // `repr(transparent)`).
+            transmute(vector)
+        }
+    }
+
+    /// Copy `value` to all positions in a packed vector. This is the same as
+    /// `From<MontyField64<MP>>::from`, but `const`.
+    #[inline]
+    const fn broadcast(value: MontyField64<MP>) -> Self {
+        Self([value; WIDTH])
+    }
+}
+
+impl<MP: MontyParameters> From<MontyField64<MP>> for PackedMontyField64AVX2<MP> {
+    #[inline]
+    fn from(value: MontyField64<MP>) -> Self {
+        Self::broadcast(value)
+    }
+}
+
+impl<MP: MontyParameters> Add for PackedMontyField64AVX2<MP> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        let res = add::<MP>(self.to_vector(), rhs.to_vector());
+        unsafe {
+            // Safety: `add` returns values in canonical form when given values in canonical form.
+            Self::from_vector(res)
+        }
+    }
+}
+
+impl<MP: MontyParameters> Sub for PackedMontyField64AVX2<MP> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        let res = sub::<MP>(self.to_vector(), rhs.to_vector());
+        unsafe {
+            // Safety: `sub` returns values in canonical form when given values in canonical form.
+            Self::from_vector(res)
+        }
+    }
+}
+
+impl<MP: MontyParameters> Neg for PackedMontyField64AVX2<MP> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        let res = unsafe { sub::<MP>(_mm256_setzero_si256(), self.to_vector()) };
+        unsafe {
+            // Safety: `sub` returns values in canonical form when given values in canonical form.
+            Self::from_vector(res)
+        }
+    }
+}
+
+impl<MP: MontyParameters> Mul for PackedMontyField64AVX2<MP> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let res = mul::<MP>(self.to_vector(), rhs.to_vector());
+        unsafe {
+            // Safety: `mul` returns values in canonical form when given values in canonical form.
+            Self::from_vector(res)
+        }
+    }
+}
+
+impl_add_assign!(PackedMontyField64AVX2, (MontyParameters, MP));
+impl_sub_assign!(PackedMontyField64AVX2, (MontyParameters, MP));
+impl_mul_methods!(PackedMontyField64AVX2, (FieldParameters, FP));
+ring_sum!(PackedMontyField64AVX2, (FieldParameters, FP));
+impl_rng!(PackedMontyField64AVX2, (MontyParameters, MP));
+
+impl<FP: FieldParameters> PrimeCharacteristicRing for PackedMontyField64AVX2<FP> {
+    type PrimeSubfield = MontyField64<FP>;
+
+    const ZERO: Self = Self::broadcast(MontyField64::ZERO);
+    const ONE: Self = Self::broadcast(MontyField64::ONE);
+    const TWO: Self = Self::broadcast(MontyField64::TWO);
+    const NEG_ONE: Self = Self::broadcast(MontyField64::NEG_ONE);
+
+    #[inline]
+    fn from_prime_subfield(f: Self::PrimeSubfield) -> Self {
+        f.into()
+    }
+
+    #[inline]
+    fn halve(&self) -> Self {
+        let res = halve::<FP>(self.to_vector());
+        unsafe {
+            // Safety: `halve` returns values in canonical form when given values in canonical form.
+            Self::from_vector(res)
+        }
+    }
+
+    #[inline]
+    fn zero_vec(len: usize) -> Vec<Self> {
+        // SAFETY: this is a repr(transparent) wrapper around an array.
+        unsafe { reconstitute_from_base(MontyField64::<FP>::zero_vec(len * WIDTH)) }
+    }
+}
+
+impl_add_base_field!(PackedMontyField64AVX2, MontyField64, (MontyParameters, MP));
+impl_sub_base_field!(PackedMontyField64AVX2, MontyField64, (MontyParameters, MP));
+impl_mul_base_field!(PackedMontyField64AVX2, MontyField64, (MontyParameters, MP));
+impl_div_methods!(PackedMontyField64AVX2, MontyField64, (FieldParameters, FP));
+impl_packed_div_methods!(PackedMontyField64AVX2, (FieldParameters, FP));
+impl_sum_prod_base_field!(PackedMontyField64AVX2, MontyField64, (FieldParameters, FP));
+
+impl<FP: FieldParameters> Algebra<MontyField64<FP>> for PackedMontyField64AVX2<FP> {}
+
+impl<FP: FieldParameters + RelativelyPrimePower<D>, const D: u64> InjectiveMonomial<D>
+    for PackedMontyField64AVX2<FP>
+{
+}
+
+impl<FP: FieldParameters + RelativelyPrimePower<D>, const D: u64> PermutationMonomial<D>
+    for PackedMontyField64AVX2<FP>
+{
+    fn injective_exp_root_n(&self) -> Self {
+        FP::exp_root_d(*self)
+    }
+}
+
+impl_packed_value!(
+    PackedMontyField64AVX2,
+    MontyField64,
+    WIDTH,
+    (MontyParameters, MP)
+);
+
+unsafe impl<FP: FieldParameters> PackedField for PackedMontyField64AVX2<FP> {
+    type Scalar = MontyField64<FP>;
+}
+
+impl_packed_field_pow_2!(
+    PackedMontyField64AVX2, (FieldParameters, FP);
+    [
+        (1, interleave_u64),
+        (2, interleave_u128)
+    ],
+    WIDTH
+);
+
+// AVX2 has neither unsigned 64-bit comparisons nor a 64-bit multiplication, so both need to be
+// emulated. We follow the approach of the Goldilocks AVX2 implementation:
+//
+// 1. Unsigned comparisons are emulated with signed comparisons by flipping the sign bit of both
+//    arguments, as a <u b iff (a ^ 2^63) <s (b ^ 2^63).
+// 2. 64 x 64 -> 128 bit multiplication is built out of four 32 x 32 -> 64 bit multiplications.
+//
+// Unlike Goldilocks, a generic prime has no special form, so we reduce products using the same
+// Montgomery reduction as the scalar code:
+//
+// Constants: 2^32 < P < 2^64, prime
+//            B = 2^64
+//            μ = P^-1 mod B
+// Input: 0 <= C < P B
+// Output: 0 <= R < P such that R = C B^-1 (mod P)
+//   1. Q := μ C mod B
+//   2. D := (C - Q P) / B = C_hi - (Q P)_hi
+//   3. R := if D < 0 then D + P else D
+//
+// The low halves of C and Q P agree, so step 2 only needs the high half of Q P.
+
+const SIGN_BIT: __m256i = unsafe { transmute([i64::MIN; WIDTH]) };
+const LO_32_BITS: __m256i = unsafe { transmute([u32::MAX as u64; WIDTH]) };
+
+/// Returns a mask which is all ones in the lanes where `x < y` as unsigned integers, and zero otherwise.
+#[inline]
+fn lt_u64(x: __m256i, y: __m256i) -> __m256i {
+    unsafe {
+        let x_s = _mm256_xor_si256(x, SIGN_BIT);
+        let y_s = _mm256_xor_si256(y, SIGN_BIT);
+        _mm256_cmpgt_epi64(y_s, x_s)
+    }
+}
+
+/// Modular addition. Computes `x + y mod P`.
+///
+/// Inputs must be in canonical form, and the output will also be in canonical form.
+#[inline]
+fn add<MP: MontyParameters>(x: __m256i, y: __m256i) -> __m256i {
+    unsafe {
+        let p = _mm256_set1_epi64x(MP::PRIME as i64); // Compiler should realise this is constant.
+
+        // As P may exceed 2^63 the sum may wrap around. We need to subtract P exactly when
+        // the sum overflowed or when it is at least P.
+        let sum = _mm256_add_epi64(x, y);
+        let overflow = lt_u64(sum, x);
+        let not_reduced = lt_u64(sum, p);
+        let corr = _mm256_andnot_si256(_mm256_andnot_si256(overflow, not_reduced), p);
+        _mm256_sub_epi64(sum, corr)
+    }
+}
+
+/// Modular subtraction. Computes `x - y mod P`.
+///
+/// Inputs must be in canonical form, and the output will also be in canonical form.
+#[inline]
+fn sub<MP: MontyParameters>(x: __m256i, y: __m256i) -> __m256i {
+    unsafe {
+        let p = _mm256_set1_epi64x(MP::PRIME as i64);
+        let diff = _mm256_sub_epi64(x, y);
+        let underflow = lt_u64(x, y);
+        _mm256_add_epi64(diff, _mm256_and_si256(underflow, p))
+    }
+}
+
+/// Halve a vector of field elements.
+///
+/// Inputs must be in canonical form, and the output will also be in canonical form.
+#[inline]
+fn halve<FP: FieldParameters>(input: __m256i) -> __m256i {
+    // Given an element val in [0, P), we want to compute val/2 mod P.
+    // If val is even: val/2 mod P = val/2 = val >> 1.
+    // If val is odd: val/2 mod P = (val + P)/2 = (val >> 1) + (P + 1)/2
+    unsafe {
+        const ONE: __m256i = unsafe { transmute([1_i64; WIDTH]) };
+        let half = _mm256_set1_epi64x(FP::HALF_P_PLUS_1 as i64);
+
+        let least_bit = _mm256_and_si256(input, ONE);
+        let t = _mm256_srli_epi64::<1>(input);
+        // Either 0 (all bits 0) or -1 (all bits 1) depending on the parity.
+        let neg_least_bit = _mm256_sub_epi64(_mm256_setzero_si256(), least_bit);
+        let maybe_half = _mm256_and_si256(half, neg_least_bit);
+        _mm256_add_epi64(t, maybe_half)
+    }
+}
+
+/// Full 64-bit by 64-bit multiplication, returning the high and low halves of the product.
+#[inline]
+fn mul64_64(x: __m256i, y: __m256i) -> (__m256i, __m256i) {
+    unsafe {
+        // Move the high 32 bits to the low position. The multiplication instruction ignores
+        // the high 32 bits, so it's ok to just duplicate it into the low position.
+        let x_hi = _mm256_castps_si256(_mm256_movehdup_ps(_mm256_castsi256_ps(x)));
+        let y_hi = _mm256_castps_si256(_mm256_movehdup_ps(_mm256_castsi256_ps(y)));
+
+        // All four pairwise multiplications
+        let mul_ll = _mm256_mul_epu32(x, y);
+        let mul_lh = _mm256_mul_epu32(x, y_hi);
+        let mul_hl = _mm256_mul_epu32(x_hi, y);
+        let mul_hh = _mm256_mul_epu32(x_hi, y_hi);
+
+        // Bignum addition
+        // Extract high 32 bits of mul_ll and add to mul_hl. This cannot overflow.
+        let mul_ll_hi = _mm256_srli_epi64::<32>(mul_ll);
+        let t0 = _mm256_add_epi64(mul_hl, mul_ll_hi);
+        // Extract low 32 bits of t0 and add to mul_lh. Again, this cannot overflow.
+        // Also, extract high 32 bits of t0 and add to mul_hh.
+        let t0_lo = _mm256_and_si256(t0, LO_32_BITS);
+        let t0_hi = _mm256_srli_epi64::<32>(t0);
+        let t1 = _mm256_add_epi64(mul_lh, t0_lo);
+        let t2 = _mm256_add_epi64(mul_hh, t0_hi);
+        // Lastly, extract the high 32 bits of t1 and add to t2.
+        let t1_hi = _mm256_srli_epi64::<32>(t1);
+        let res_hi = _mm256_add_epi64(t2, t1_hi);
+
+        // Form res_lo by combining the low half of mul_ll with the low half of t1 (shifted into high
+        // position).
+        let t1_lo = _mm256_castps_si256(_mm256_moveldup_ps(_mm256_castsi256_ps(t1)));
+        let res_lo = _mm256_blend_epi32::<0xaa>(mul_ll, t1_lo);
+
+        (res_hi, res_lo)
+    }
+}
+
+/// The low 64 bits of the product `x * y`.
+#[inline]
+fn mul64_64_lo(x: __m256i, y: __m256i) -> __m256i {
+    unsafe {
+        let x_hi = _mm256_srli_epi64::<32>(x);
+        let y_hi = _mm256_srli_epi64::<32>(y);
+
+        // The product x_hi * y_hi only contributes to the high 64 bits, so we can skip it.
+        let mul_ll = _mm256_mul_epu32(x, y);
+        let mul_lh = _mm256_mul_epu32(x, y_hi);
+        let mul_hl = _mm256_mul_epu32(x_hi, y);
+        let cross = _mm256_slli_epi64::<32>(_mm256_add_epi64(mul_lh, mul_hl));
+        _mm256_add_epi64(mul_ll, cross)
+    }
+}
+
+/// Modular multiplication. Computes `x * y * 2^{-64} mod P`, which is the Monty form of the
+/// product of the field elements with Monty forms `x` and `y`.
+///
+/// Inputs must be in canonical form, and the output will also be in canonical form.
+#[inline]
+fn mul<MP: MontyParameters>(x: __m256i, y: __m256i) -> __m256i {
+    unsafe {
+        let p = _mm256_set1_epi64x(MP::PRIME as i64);
+        let mu = _mm256_set1_epi64x(MP::MONTY_MU as i64);
+
+        let (c_hi, c_lo) = mul64_64(x, y);
+        let q = mul64_64_lo(c_lo, mu);
+        let (q_p_hi, _) = mul64_64(q, p);
+
+        let d = _mm256_sub_epi64(c_hi, q_p_hi);
+        let underflow = lt_u64(c_hi, q_p_hi);
+        _mm256_add_epi64(d, _mm256_and_si256(underflow, p))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_field::PrimeCharacteristicRing;
+    use p3_field_testing::test_packed_field;
+
+    use super::WIDTH;
+    use crate::tests::{GoldilocksParameters, LargeParameters};
+    use crate::{FieldParameters, MontyField64, PackedMontyField64AVX2};
+
+    fn special_vals<FP: FieldParameters>() -> PackedMontyField64AVX2<FP> {
+        let vals: [MontyField64<FP>; WIDTH] = [
+            MontyField64::ZERO,
+            MontyField64::ONE,
+            MontyField64::NEG_ONE,
+            MontyField64::new(FP::PRIME >> 1),
+        ];
+        PackedMontyField64AVX2(vals)
+    }
+
+    mod goldilocks {
+        use super::*;
+
+        type P = PackedMontyField64AVX2<GoldilocksParameters>;
+
+        const ZEROS: P = P::ZERO;
+        const ONES: P = P::ONE;
+
+        test_packed_field!(
+            super::P,
+            &[super::ZEROS],
+            &[super::ONES],
+            super::special_vals::<super::GoldilocksParameters>()
+        );
+    }
+
+    mod large {
+        use super::*;
+
+        type P = PackedMontyField64AVX2<LargeParameters>;
+
+        const ZEROS: P = P::ZERO;
+        const ONES: P = P::ONE;
+
+        test_packed_field!(
+            super::P,
+            &[super::ZEROS],
+            &[super::ONES],
+            super::special_vals::<super::LargeParameters>()
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use p3_field::InjectiveMonomial;
+
+use crate::{FieldParameters, MontyFieldN, MontyParameters, TwoAdicData};
+
+/// The scalar field of the BLS12-381 curve, defined as `F_P` where
+/// `P = 52435875175126190479447740508185965837690552500527637822603658699938581184513`.
+pub type Bls12_381Fr = MontyFieldN<Bls12_381FrParameters, 4>;
+
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq)]
+pub struct Bls12_381FrParameters;
+
+impl MontyParameters<4> for Bls12_381FrParameters {
+    const PRIME: [u64; 4] = [
+        0xffffffff00000001,
+        0x53bda402fffe5bfe,
+        0x3339d80809a1d805,
+        0x73eda753299d7d48,
+    ];
+}
+
+impl FieldParameters<4> for Bls12_381FrParameters {
+    const GENERATOR: [u64; 4] = [7, 0, 0, 0];
+}
+
+impl TwoAdicData<4> for Bls12_381FrParameters {
+    const TWO_ADICITY: usize = 32;
+
+    /// Equal to `7^{(P - 1)/2^32} = 10238227357739495823651030575849232062558860180284477541189508159991286009131`.
+    const TWO_ADIC_GENERATOR: [u64; 4] = [
+        0x3829971f439f0d2b,
+        0xb63683508c2280b9,
+        0xd09b681922c813b4,
+        0x16a2a19edfe81f20,
+    ];
+}
+
+/// Degree of the smallest permutation polynomial for BLS12-381 Fr.
+///
+/// As p - 1 is divisible by 2 and 3 the smallest choice for a degree D satisfying gcd(p - 1, D) = 1 is 5.
+impl InjectiveMonomial<5> for Bls12_381Fr {}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use p3_field::{Field, PrimeCharacteristicRing, PrimeField, TwoAdicField};
+    use p3_field_testing::{test_field, test_prime_field, test_two_adic_field};
+
+    use super::*;
+
+    type F = Bls12_381Fr;
+
+    #[test]
+    fn test_bls12_381fr() {
+        let p: BigUint =
+            "52435875175126190479447740508185965837690552500527637822603658699938581184513"
+                .parse()
+                .unwrap();
+        assert_eq!(F::order(), p);
+        assert_eq!(F::GENERATOR.as_canonical_biguint(), BigUint::from(7u32));
+        assert_eq!(F::two_adic_generator(1), F::NEG_ONE);
+
+        // R mod P and the smallest reduced value larger than the Montgomery constant.
+        let r = BigUint::from(1u32) << 256;
+        assert_eq!(
+            F::from_biguint(&(&r - 1u32))
+                .unwrap()
+                .as_canonical_biguint(),
+            (&r - 1u32) % &p
+        );
+        assert_eq!(F::from_biguint(&r), None);
+    }
+
+    const ZERO: F = F::ZERO;
+    const ONE: F = F::ONE;
+
+    // Get the prime factorization of the order of the multiplicative group.
+    // i.e. the prime factorization of P - 1.
+    fn multiplicative_group_prime_factorization() -> [(BigUint, u32); 12] {
+        [
+            (BigUint::from(2u8), 32),
+            (BigUint::from(3u8), 1),
+            (BigUint::from(11u8), 1),
+            (BigUint::from(19u8), 1),
+            (BigUint::from(10177u16), 1),
+            (BigUint::from(125527u32), 1),
+            (BigUint::from(859267u32), 1),
+            (BigUint::from(906349u32), 2),
+            (BigUint::from(2508409u32), 1),
+            (BigUint::from(2529403u32), 1),
+            (BigUint::from(52437899u32), 1),
+            (BigUint::from(254760293u32), 2),
+        ]
+    }
+    test_field!(
+        crate::Bls12_381Fr,
+        &[super::ZERO],
+        &[super::ONE],
+        &super::multiplicative_group_prime_factorization()
+    );
+
+    test_prime_field!(crate::Bls12_381Fr);
+    test_two_adic_field!(crate::Bls12_381Fr);
+}
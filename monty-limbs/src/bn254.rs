@@ -0,0 +1,124 @@
+use p3_field::InjectiveMonomial;
+
+use crate::{FieldParameters, MontyFieldN, MontyParameters, TwoAdicData};
+
+/// The scalar field of the BN254 curve, defined as `F_P` where
+/// `P = 21888242871839275222246405745257275088548364400416034343698204186575808495617`.
+pub type Bn254Fr = MontyFieldN<Bn254FrParameters, 4>;
+
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq)]
+pub struct Bn254FrParameters;
+
+impl MontyParameters<4> for Bn254FrParameters {
+    const PRIME: [u64; 4] = [
+        0x43e1f593f0000001,
+        0x2833e84879b97091,
+        0xb85045b68181585d,
+        0x30644e72e131a029,
+    ];
+}
+
+impl FieldParameters<4> for Bn254FrParameters {
+    const GENERATOR: [u64; 4] = [5, 0, 0, 0];
+}
+
+impl TwoAdicData<4> for Bn254FrParameters {
+    const TWO_ADICITY: usize = 28;
+
+    /// Equal to `5^{(P - 1)/2^28} = 19103219067921713944291392827692070036145651957329286315305642004821462161904`.
+    const TWO_ADIC_GENERATOR: [u64; 4] = [
+        0x9bd61b6e725b19f0,
+        0x402d111e41112ed4,
+        0x00e0a7eb8ef62abc,
+        0x2a3c09f0a58a7e85,
+    ];
+}
+
+/// Degree of the smallest permutation polynomial for BN254 Fr.
+///
+/// As p - 1 is divisible by 2 and 3 the smallest choice for a degree D satisfying gcd(p - 1, D) = 1 is 5.
+impl InjectiveMonomial<5> for Bn254Fr {}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use p3_field::{Field, PrimeCharacteristicRing, PrimeField};
+    use p3_field_testing::{test_field, test_prime_field, test_two_adic_field};
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+
+    type F = Bn254Fr;
+
+    #[test]
+    fn test_against_p3_bn254() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        for _ in 0..1000 {
+            let a: p3_bn254::Bn254 = rng.random();
+            let b: p3_bn254::Bn254 = rng.random();
+            let a_limbs = F::from_biguint(&a.as_canonical_biguint()).unwrap();
+            let b_limbs = F::from_biguint(&b.as_canonical_biguint()).unwrap();
+            assert_eq!(
+                (a_limbs * b_limbs).as_canonical_biguint(),
+                (a * b).as_canonical_biguint()
+            );
+            assert_eq!(
+                (a_limbs + b_limbs).as_canonical_biguint(),
+                (a + b).as_canonical_biguint()
+            );
+            assert_eq!(
+                (a_limbs - b_limbs).as_canonical_biguint(),
+                (a - b).as_canonical_biguint()
+            );
+        }
+        assert_eq!(
+            F::GENERATOR.as_canonical_biguint(),
+            p3_bn254::Bn254::GENERATOR.as_canonical_biguint()
+        );
+    }
+
+    #[test]
+    fn test_bn254fr() {
+        let f_100 = F::from_u8(100);
+        assert_eq!(f_100.as_canonical_biguint(), BigUint::from(100u32));
+        assert_eq!(F::GENERATOR.as_canonical_biguint(), BigUint::from(5u32));
+
+        let f_serialized = serde_json::to_string(&f_100).unwrap();
+        let f_deserialized: F = serde_json::from_str(&f_serialized).unwrap();
+        assert_eq!(f_100, f_deserialized);
+
+        let f_neg_one_serialized = serde_json::to_string(&F::NEG_ONE).unwrap();
+        let f_neg_one_deserialized: F = serde_json::from_str(&f_neg_one_serialized).unwrap();
+        assert_eq!(F::NEG_ONE, f_neg_one_deserialized);
+    }
+
+    const ZERO: F = F::ZERO;
+    const ONE: F = F::ONE;
+
+    // Get the prime factorization of the order of the multiplicative group.
+    // i.e. the prime factorization of P - 1.
+    fn multiplicative_group_prime_factorization() -> [(BigUint, u32); 10] {
+        [
+            (BigUint::from(2u8), 28),
+            (BigUint::from(3u8), 2),
+            (BigUint::from(13u8), 1),
+            (BigUint::from(29u8), 1),
+            (BigUint::from(983u16), 1),
+            (BigUint::from(11003u16), 1),
+            (BigUint::from(237073u32), 1),
+            (BigUint::from(405928799u32), 1),
+            (BigUint::from(1670836401704629u64), 1),
+            (BigUint::from(13818364434197438864469338081u128), 1),
+        ]
+    }
+    test_field!(
+        crate::Bn254Fr,
+        &[super::ZERO],
+        &[super::ONE],
+        &super::multiplicative_group_prime_factorization()
+    );
+
+    test_prime_field!(crate::Bn254Fr);
+    test_two_adic_field!(crate::Bn254Fr);
+}
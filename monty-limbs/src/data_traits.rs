@@ -0,0 +1,49 @@
+use core::fmt::Debug;
+use core::hash::Hash;
+
+use crate::utils::{can_skip_carry, monty_neg_inverse, pow_2_mod};
+
+/// MontyParameters contains the prime P along with constants needed to convert elements into and out of MONTY form.
+/// The MONTY constant is fixed to be `R = 2^{64N}`.
+///
+/// Only `PRIME` needs to be specified, the remaining constants are derived from it at compile time.
+pub trait MontyParameters<const N: usize>:
+    Copy + Clone + Default + Debug + Eq + PartialEq + Sync + Send + Hash + 'static
+{
+    /// An odd prime satisfying `2^128 < P < 2^{64N}` saved as a little-endian array of u64's.
+    const PRIME: [u64; N];
+
+    /// The value `-P^{-1} mod 2^64`.
+    ///
+    /// Unlike the 31 and 64-bit fields we follow the usual convention here as the CIOS algorithm
+    /// needs to add multiples of `P` to cancel out the lowest limb.
+    const MONTY_MU: u64 = monty_neg_inverse(Self::PRIME[0]);
+
+    /// The value `R mod P`. This is the MONTY form of `1`.
+    const MONTY_R: [u64; N] = pow_2_mod(64 * N, Self::PRIME);
+
+    /// The value `R^2 mod P`, used to convert elements into MONTY form.
+    const MONTY_R_SQ: [u64; N] = pow_2_mod(128 * N, Self::PRIME);
+
+    /// Whether the top limb of `P` leaves enough space to use the faster variant of CIOS
+    /// multiplication which does not track carries out of the top limb.
+    const NO_CARRY: bool = can_skip_carry(Self::PRIME);
+}
+
+/// FieldParameters contains constants needed to imply PrimeCharacteristicRing, Field and PrimeField for MontyFieldN.
+pub trait FieldParameters<const N: usize>: MontyParameters<N> + Sized {
+    /// A generator of the fields multiplicative group, given as a canonical (non MONTY form) integer.
+    const GENERATOR: [u64; N];
+}
+
+/// TwoAdicData contains constants needed to imply TwoAdicField for MontyFieldN.
+pub trait TwoAdicData<const N: usize>: FieldParameters<N> {
+    /// Largest n such that 2^n divides p - 1.
+    const TWO_ADICITY: usize;
+
+    /// A generator of the subgroup of order `2^TWO_ADICITY`, given as a canonical (non MONTY form) integer.
+    ///
+    /// This should be `GENERATOR^{(P - 1)/2^TWO_ADICITY}`. Generators of smaller subgroups are
+    /// found by repeated squaring.
+    const TWO_ADIC_GENERATOR: [u64; N];
+}
@@ -0,0 +1,29 @@
+//! Generic prime fields `F_p` with `p > 2^128` stored as `N` 64-bit limbs, using Montgomery arithmetic.
+//!
+//! This is aimed at the large fields used by elliptic curve based SNARKs. Instances are provided for
+//! the scalar fields of BN254 and BLS12-381, the two Pasta fields and the base field of secp256k1.
+//!
+//! This crate does not replace `p3-bn254`, whose `Bn254` type remains the BN254 scalar field used
+//! by Poseidon2 and the rest of the workspace. [`Bn254Fr`] is tested against it, and the
+//! `bench_field` benchmarks of both crates compare them: the CIOS multiplication here is faster,
+//! but addition is slower and inversion, which uses exponentiation rather than a binary GCD, is
+//! much slower. Switching `p3-bn254` over would first need those two operations to catch up.
+
+#![no_std]
+
+extern crate alloc;
+
+mod bls12_381;
+mod bn254;
+mod data_traits;
+mod monty_limbs;
+mod pasta;
+mod secp256k1;
+mod utils;
+
+pub use bls12_381::*;
+pub use bn254::*;
+pub use data_traits::*;
+pub use monty_limbs::*;
+pub use pasta::*;
+pub use secp256k1::*;
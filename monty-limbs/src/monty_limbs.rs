@@ -0,0 +1,491 @@
+//! An abstraction of large prime fields, stored as `N` 64-bit limbs, which use a MONTY approach for faster multiplication.
+
+use alloc::vec::Vec;
+use core::array;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::Hash;
+use core::iter::{Product, Sum};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num_bigint::BigUint;
+use p3_field::integers::QuotientMap;
+use p3_field::{
+    Field, Packable, PrimeCharacteristicRing, PrimeField, RawDataSerializable, TwoAdicField,
+    impl_add_assign, impl_div_methods, impl_mul_methods, impl_sub_assign, ring_sum,
+};
+use rand::Rng;
+use rand::distr::{Distribution, StandardUniform};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::utils::{
+    add_mod, halve_mod, is_zero, less_than, monty_mul, sub_mod, to_biguint, wrapping_sub,
+};
+use crate::{FieldParameters, MontyParameters, TwoAdicData};
+
+/// A prime field element stored as `N` little-endian u64 limbs in MONTY form.
+///
+/// The prime and all derived constants are supplied by the parameter type `FP`. The prime must
+/// be larger than `2^128` so that every `u128` and `i128` is a canonical representative.
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+#[must_use]
+pub struct MontyFieldN<FP: MontyParameters<N>, const N: usize> {
+    /// The MONTY form of the field element, an integer less than `P` saved as a collection of u64's using a little-endian order.
+    ///
+    /// This is `pub(crate)` for tests and delayed reduction strategies. If you're accessing `value` outside of those, you're
+    /// likely doing something fishy.
+    pub(crate) value: [u64; N],
+    _phantom: PhantomData<FP>,
+}
+
+impl<FP: MontyParameters<N>, const N: usize> MontyFieldN<FP, N> {
+    /// The standard way to create a new element.
+    ///
+    /// The input is a little-endian array of limbs which must correspond to an integer less than `P`.
+    /// Note that `new` converts the input into MONTY form so should be avoided in performance critical implementations.
+    #[inline]
+    pub const fn new(value: [u64; N]) -> Self {
+        Self::new_monty(monty_mul(
+            FP::MONTY_R_SQ,
+            value,
+            FP::PRIME,
+            FP::MONTY_MU,
+            FP::NO_CARRY,
+        ))
+    }
+
+    /// Create a new field element from something already in MONTY form.
+    /// This is `pub(crate)` for tests and delayed reduction strategies. If you're using it outside of those, you're
+    /// likely doing something fishy.
+    #[inline(always)]
+    pub(crate) const fn new_monty(value: [u64; N]) -> Self {
+        Self {
+            value,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Produce the canonical little-endian limbs, in the range `[0, P)`, of the value represented by this element.
+    #[inline]
+    pub const fn to_limbs(&self) -> [u64; N] {
+        // `monty_mul` strips out a factor of `R` so multiplying by `1` converts a montgomery
+        // representation into a canonical representation.
+        let mut one = [0; N];
+        one[0] = 1;
+        monty_mul(self.value, one, FP::PRIME, FP::MONTY_MU, FP::NO_CARRY)
+    }
+
+    /// Convert a `BigUint` into a field element, reducing it modulo `P`.
+    ///
+    /// Returns `None` if the input does not fit in `N` limbs.
+    #[inline]
+    pub fn from_biguint(value: &BigUint) -> Option<Self> {
+        let digits = value.to_u64_digits();
+        let num_dig = digits.len();
+        (num_dig <= N).then(|| {
+            let mut inner = [0; N];
+            inner[..num_dig].copy_from_slice(&digits);
+
+            // We don't need to check that the value is less than the prime as, provided
+            // the lhs entry of `monty_mul` is less than `P`, the result will be less than `P`.
+            Self::new(inner)
+        })
+    }
+
+    /// Converts a byte array in little-endian order to a field element.
+    ///
+    /// Assumes the bytes correspond to the Montgomery form of the desired field element.
+    ///
+    /// Returns None if the byte array is not exactly `8N` bytes long or if the value
+    /// represented by the byte array is not less than `P`.
+    #[inline]
+    pub(crate) fn from_bytes_monty(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 8 * N {
+            return None;
+        }
+        let value: [u64; N] = array::from_fn(|i| {
+            // This unwrap is safe due to the length check above.
+            u64::from_le_bytes(bytes[8 * i..8 * (i + 1)].try_into().unwrap())
+        });
+        less_than(value, FP::PRIME).then(|| Self::new_monty(value))
+    }
+
+    /// Compute `self^exp` where the exponent is given as a little-endian array of limbs.
+    #[inline]
+    fn exp_limbs(&self, exp: [u64; N]) -> Self
+    where
+        FP: FieldParameters<N>,
+    {
+        let mut acc = Self::ONE;
+        for limb in exp.into_iter().rev() {
+            for i in (0..64).rev() {
+                acc = acc.square();
+                if (limb >> i) & 1 == 1 {
+                    acc *= *self;
+                }
+            }
+        }
+        acc
+    }
+}
+
+impl<FP: MontyParameters<N>, const N: usize> Default for MontyFieldN<FP, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new_monty([0; N])
+    }
+}
+
+impl<FP: MontyParameters<N>, const N: usize> Ord for MontyFieldN<FP, N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_limbs()
+            .iter()
+            .rev()
+            .cmp(other.to_limbs().iter().rev())
+    }
+}
+
+impl<FP: MontyParameters<N>, const N: usize> PartialOrd for MontyFieldN<FP, N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<FP: MontyParameters<N>, const N: usize> Display for MontyFieldN<FP, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&to_biguint(self.to_limbs()), f)
+    }
+}
+
+impl<FP: MontyParameters<N>, const N: usize> Debug for MontyFieldN<FP, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&to_biguint(self.to_limbs()), f)
+    }
+}
+
+impl<FP: MontyParameters<N>, const N: usize> Distribution<MontyFieldN<FP, N>> for StandardUniform {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> MontyFieldN<FP, N> {
+        // Simple implementation of rejection sampling. Masking off the unused top bits of P
+        // ensures that each trial succeeds with probability at least 1/2.
+        let mask = u64::MAX >> FP::PRIME[N - 1].leading_zeros();
+        loop {
+            let mut trial: [u64; N] = array::from_fn(|_| rng.next_u64());
+            trial[N - 1] &= mask;
+            if less_than(trial, FP::PRIME) {
+                return MontyFieldN::new_monty(trial);
+            }
+        }
+    }
+}
+
+impl<FP: FieldParameters<N>, const N: usize> Serialize for MontyFieldN<FP, N> {
+    /// Serializes to raw bytes, which correspond to the Montgomery representation of the field element.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let bytes: Vec<u8> = self.into_bytes().into_iter().collect();
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+impl<'de, FP: FieldParameters<N>, const N: usize> Deserialize<'de> for MontyFieldN<FP, N> {
+    /// Deserializes from raw bytes, which correspond to the Montgomery representation of the field element.
+    /// Performs a check that the deserialized field element corresponds to a value less than the field modulus, and
+    /// returns an error otherwise.
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let bytes: Vec<u8> = Deserialize::deserialize(d)?;
+        Self::from_bytes_monty(&bytes)
+            .ok_or_else(|| serde::de::Error::custom("Invalid field element"))
+    }
+}
+
+impl<FP: MontyParameters<N>, const N: usize> Packable for MontyFieldN<FP, N> {}
+
+impl<FP: FieldParameters<N>, const N: usize> PrimeCharacteristicRing for MontyFieldN<FP, N> {
+    type PrimeSubfield = Self;
+
+    const ZERO: Self = Self::new_monty([0; N]);
+    const ONE: Self = Self::new_monty(FP::MONTY_R);
+    const TWO: Self = Self::new_monty(add_mod(FP::MONTY_R, FP::MONTY_R, FP::PRIME));
+    const NEG_ONE: Self = Self::new_monty(sub_mod([0; N], FP::MONTY_R, FP::PRIME));
+
+    #[inline(always)]
+    fn from_prime_subfield(f: Self) -> Self {
+        f
+    }
+
+    #[inline]
+    fn halve(&self) -> Self {
+        Self::new_monty(halve_mod(self.value, FP::PRIME))
+    }
+}
+
+impl<FP: FieldParameters<N>, const N: usize> RawDataSerializable for MontyFieldN<FP, N> {
+    const NUM_BYTES: usize = 8 * N;
+
+    #[inline]
+    fn into_bytes(self) -> impl IntoIterator<Item = u8> {
+        self.value.into_iter().flat_map(u64::to_le_bytes)
+    }
+
+    #[inline]
+    fn into_u32_stream(input: impl IntoIterator<Item = Self>) -> impl IntoIterator<Item = u32> {
+        input.into_iter().flat_map(|x| {
+            x.value
+                .into_iter()
+                .flat_map(|limb| [limb as u32, (limb >> 32) as u32])
+        })
+    }
+
+    #[inline]
+    fn into_u64_stream(input: impl IntoIterator<Item = Self>) -> impl IntoIterator<Item = u64> {
+        input.into_iter().flat_map(|x| x.value)
+    }
+
+    #[inline]
+    fn into_parallel_byte_streams<const M: usize>(
+        input: impl IntoIterator<Item = [Self; M]>,
+    ) -> impl IntoIterator<Item = [u8; M]> {
+        input.into_iter().flat_map(|vector| {
+            let limbs = vector.map(|elem| elem.value);
+            (0..Self::NUM_BYTES)
+                .map(move |i| array::from_fn(|j| (limbs[j][i / 8] >> (8 * (i % 8))) as u8))
+        })
+    }
+
+    #[inline]
+    fn into_parallel_u32_streams<const M: usize>(
+        input: impl IntoIterator<Item = [Self; M]>,
+    ) -> impl IntoIterator<Item = [u32; M]> {
+        input.into_iter().flat_map(|vector| {
+            let limbs = vector.map(|elem| elem.value);
+            (0..(Self::NUM_BYTES / 4))
+                .map(move |i| array::from_fn(|j| (limbs[j][i / 2] >> (32 * (i % 2))) as u32))
+        })
+    }
+
+    #[inline]
+    fn into_parallel_u64_streams<const M: usize>(
+        input: impl IntoIterator<Item = [Self; M]>,
+    ) -> impl IntoIterator<Item = [u64; M]> {
+        input.into_iter().flat_map(|vector| {
+            let limbs = vector.map(|elem| elem.value);
+            (0..N).map(move |i| array::from_fn(|j| limbs[j][i]))
+        })
+    }
+}
+
+impl<FP: FieldParameters<N>, const N: usize> Field for MontyFieldN<FP, N> {
+    type Packing = Self;
+
+    const GENERATOR: Self = Self::new(FP::GENERATOR);
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        is_zero(self.value)
+    }
+
+    fn try_inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+
+        // As P is prime, Fermat's little theorem gives x^{-1} = x^{P - 2}.
+        let mut two = [0; N];
+        two[0] = 2;
+        Some(self.exp_limbs(wrapping_sub(FP::PRIME, two).0))
+    }
+
+    #[inline]
+    fn order() -> BigUint {
+        to_biguint(FP::PRIME)
+    }
+}
+
+/// Convert a `u128` into a little-endian array of limbs.
+#[inline]
+const fn u128_to_limbs<const N: usize>(int: u128) -> [u64; N] {
+    let mut limbs = [0; N];
+    limbs[0] = int as u64;
+    limbs[1] = (int >> 64) as u64;
+    limbs
+}
+
+/// Implement `QuotientMap` for integer types of at most 128 bits.
+///
+/// As `P > 2^128` every such integer, and the absolute value of every such signed integer,
+/// is less than `P` so no reduction is needed.
+macro_rules! quotient_map_limbs {
+    ($($int:ty => |$arg:ident| $convert:expr),* $(,)?) => {
+        $(
+            impl<FP: FieldParameters<N>, const N: usize> QuotientMap<$int> for MontyFieldN<FP, N> {
+                /// As `P > 2^128`, the input value is always canonical.
+                #[inline]
+                fn from_int($arg: $int) -> Self {
+                    $convert
+                }
+
+                /// As `P > 2^128`, the input value is always canonical.
+                #[inline]
+                fn from_canonical_checked(int: $int) -> Option<Self> {
+                    Some(Self::from_int(int))
+                }
+
+                /// As `P > 2^128`, the input value is always canonical.
+                #[inline]
+                unsafe fn from_canonical_unchecked(int: $int) -> Self {
+                    Self::from_int(int)
+                }
+            }
+        )*
+    };
+}
+
+quotient_map_limbs!(
+    u8 => |int| Self::new(u128_to_limbs(int as u128)),
+    u16 => |int| Self::new(u128_to_limbs(int as u128)),
+    u32 => |int| Self::new(u128_to_limbs(int as u128)),
+    u64 => |int| Self::new(u128_to_limbs(int as u128)),
+    u128 => |int| Self::new(u128_to_limbs(int)),
+    i8 => |int| Self::from_int(int as i128),
+    i16 => |int| Self::from_int(int as i128),
+    i32 => |int| Self::from_int(int as i128),
+    i64 => |int| Self::from_int(int as i128),
+    i128 => |int| {
+        // Nothing better than just branching based on the sign of int.
+        let abs = Self::new(u128_to_limbs(int.unsigned_abs()));
+        if int >= 0 { abs } else { -abs }
+    },
+);
+
+impl<FP: FieldParameters<N>, const N: usize> PrimeField for MontyFieldN<FP, N> {
+    #[inline]
+    fn as_canonical_biguint(&self) -> BigUint {
+        to_biguint(self.to_limbs())
+    }
+}
+
+impl<FP: TwoAdicData<N>, const N: usize> TwoAdicField for MontyFieldN<FP, N> {
+    const TWO_ADICITY: usize = FP::TWO_ADICITY;
+
+    #[inline]
+    fn two_adic_generator(bits: usize) -> Self {
+        assert!(bits <= Self::TWO_ADICITY);
+        let mut omega = Self::new(FP::TWO_ADIC_GENERATOR);
+        for _ in bits..Self::TWO_ADICITY {
+            omega = omega.square();
+        }
+        omega
+    }
+}
+
+impl<FP: MontyParameters<N>, const N: usize> Add for MontyFieldN<FP, N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new_monty(add_mod(self.value, rhs.value, FP::PRIME))
+    }
+}
+
+impl<FP: MontyParameters<N>, const N: usize> Sub for MontyFieldN<FP, N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new_monty(sub_mod(self.value, rhs.value, FP::PRIME))
+    }
+}
+
+impl<FP: MontyParameters<N>, const N: usize> Neg for MontyFieldN<FP, N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::new_monty(sub_mod([0; N], self.value, FP::PRIME))
+    }
+}
+
+impl<FP: MontyParameters<N>, const N: usize> Mul for MontyFieldN<FP, N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new_monty(monty_mul(
+            self.value,
+            rhs.value,
+            FP::PRIME,
+            FP::MONTY_MU,
+            FP::NO_CARRY,
+        ))
+    }
+}
+
+impl_add_assign!(MontyFieldN, (MontyParameters<N>, FP), const N: usize);
+impl_sub_assign!(MontyFieldN, (MontyParameters<N>, FP), const N: usize);
+impl_mul_methods!(MontyFieldN, (FieldParameters<N>, FP), const N: usize);
+ring_sum!(MontyFieldN, (FieldParameters<N>, FP), const N: usize);
+impl_div_methods!(MontyFieldN, MontyFieldN, (FieldParameters<N>, FP), const N: usize);
+
+#[cfg(test)]
+mod tests {
+    use p3_field::{PrimeCharacteristicRing, PrimeField};
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+    use crate::{
+        Bls12_381FrParameters, Bn254FrParameters, PastaFpParameters, PastaFqParameters,
+        Secp256k1FpParameters,
+    };
+
+    /// Check the field operations against the same operations on `BigUint`s.
+    fn test_against_biguint<FP: FieldParameters<N>, const N: usize>() {
+        let p = to_biguint(FP::PRIME);
+        let mut rng = SmallRng::seed_from_u64(1);
+        let edge_cases = [
+            MontyFieldN::<FP, N>::ZERO,
+            MontyFieldN::ONE,
+            MontyFieldN::TWO,
+            MontyFieldN::NEG_ONE,
+            MontyFieldN::NEG_ONE.halve(),
+        ];
+        let randoms: [MontyFieldN<FP, N>; 50] = rng.random();
+        let elems: Vec<_> = edge_cases.into_iter().chain(randoms).collect();
+        for &a in &elems {
+            let a_big = a.as_canonical_biguint();
+            assert!(a_big < p);
+            assert_eq!(MontyFieldN::from_biguint(&a_big), Some(a));
+            assert_eq!((-a).as_canonical_biguint(), (&p - &a_big) % &p);
+            for &b in &elems {
+                let b_big = b.as_canonical_biguint();
+                assert_eq!((a + b).as_canonical_biguint(), (&a_big + &b_big) % &p);
+                assert_eq!((a - b).as_canonical_biguint(), (&a_big + &p - &b_big) % &p);
+                assert_eq!((a * b).as_canonical_biguint(), (&a_big * &b_big) % &p);
+            }
+        }
+    }
+
+    #[test]
+    fn test_against_biguint_bn254() {
+        test_against_biguint::<Bn254FrParameters, 4>();
+    }
+
+    #[test]
+    fn test_against_biguint_bls12_381() {
+        test_against_biguint::<Bls12_381FrParameters, 4>();
+    }
+
+    #[test]
+    fn test_against_biguint_pasta() {
+        test_against_biguint::<PastaFpParameters, 4>();
+        test_against_biguint::<PastaFqParameters, 4>();
+    }
+
+    #[test]
+    fn test_against_biguint_secp256k1() {
+        test_against_biguint::<Secp256k1FpParameters, 4>();
+    }
+}
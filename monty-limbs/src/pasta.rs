@@ -0,0 +1,181 @@
+//! The two fields of the Pasta cycle of curves.
+//!
+//! Pallas is defined over `PastaFp` and has `PastaFq` as its scalar field, Vesta is the other way around.
+
+use p3_field::InjectiveMonomial;
+
+use crate::{FieldParameters, MontyFieldN, MontyParameters, TwoAdicData};
+
+/// The base field of the Pallas curve, defined as `F_P` where
+/// `P = 28948022309329048855892746252171976963363056481941560715954676764349967630337`.
+pub type PastaFp = MontyFieldN<PastaFpParameters, 4>;
+
+/// The base field of the Vesta curve, defined as `F_Q` where
+/// `Q = 28948022309329048855892746252171976963363056481941647379679742748393362948097`.
+pub type PastaFq = MontyFieldN<PastaFqParameters, 4>;
+
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq)]
+pub struct PastaFpParameters;
+
+impl MontyParameters<4> for PastaFpParameters {
+    const PRIME: [u64; 4] = [
+        0x992d30ed00000001,
+        0x224698fc094cf91b,
+        0x0000000000000000,
+        0x4000000000000000,
+    ];
+}
+
+impl FieldParameters<4> for PastaFpParameters {
+    const GENERATOR: [u64; 4] = [5, 0, 0, 0];
+}
+
+impl TwoAdicData<4> for PastaFpParameters {
+    const TWO_ADICITY: usize = 32;
+
+    /// Equal to `5^{(P - 1)/2^32} = 19814229590243028906643993866117402072516588566294623396325693409366934201135`.
+    const TWO_ADIC_GENERATOR: [u64; 4] = [
+        0xbdad6fabd87ea32f,
+        0xea322bf2b7bb7584,
+        0x362120830561f81a,
+        0x2bce74deac30ebda,
+    ];
+}
+
+/// Degree of the smallest permutation polynomial for the Pallas base field.
+///
+/// As p - 1 is divisible by 2 and 3 the smallest choice for a degree D satisfying gcd(p - 1, D) = 1 is 5.
+impl InjectiveMonomial<5> for PastaFp {}
+
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq)]
+pub struct PastaFqParameters;
+
+impl MontyParameters<4> for PastaFqParameters {
+    const PRIME: [u64; 4] = [
+        0x8c46eb2100000001,
+        0x224698fc0994a8dd,
+        0x0000000000000000,
+        0x4000000000000000,
+    ];
+}
+
+impl FieldParameters<4> for PastaFqParameters {
+    const GENERATOR: [u64; 4] = [5, 0, 0, 0];
+}
+
+impl TwoAdicData<4> for PastaFqParameters {
+    const TWO_ADICITY: usize = 32;
+
+    /// Equal to `5^{(Q - 1)/2^32} = 20761624379169977859705911634190121761503565370703356079647768903521299517535`.
+    const TWO_ADIC_GENERATOR: [u64; 4] = [
+        0xa70e2c1102b6d05f,
+        0x9bb97ea3c106f049,
+        0x9e5c4dfd492ae26e,
+        0x2de6a9b8746d3f58,
+    ];
+}
+
+/// Degree of the smallest permutation polynomial for the Vesta base field.
+///
+/// As q - 1 is divisible by 2 and 3 the smallest choice for a degree D satisfying gcd(q - 1, D) = 1 is 5.
+impl InjectiveMonomial<5> for PastaFq {}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use p3_field::{Field, PrimeField};
+
+    use super::*;
+
+    #[test]
+    fn test_pasta_orders() {
+        // Both primes have the form 2^254 + small.
+        let two_254 = BigUint::from(1u32) << 254;
+        assert_eq!(
+            PastaFp::order() - &two_254,
+            BigUint::from(45560315531419706090280762371685220353u128)
+        );
+        assert_eq!(
+            PastaFq::order() - &two_254,
+            BigUint::from(45560315531506369815346746415080538113u128)
+        );
+        assert_eq!(
+            PastaFp::GENERATOR.as_canonical_biguint(),
+            BigUint::from(5u32)
+        );
+        assert_eq!(
+            PastaFq::GENERATOR.as_canonical_biguint(),
+            BigUint::from(5u32)
+        );
+    }
+
+    mod pasta_fp {
+        use num_bigint::BigUint;
+        use p3_field::PrimeCharacteristicRing;
+        use p3_field_testing::{test_field, test_prime_field, test_two_adic_field};
+
+        use crate::PastaFp;
+
+        const ZERO: PastaFp = PastaFp::ZERO;
+        const ONE: PastaFp = PastaFp::ONE;
+
+        // Get the prime factorization of the order of the multiplicative group.
+        // i.e. the prime factorization of P - 1.
+        fn multiplicative_group_prime_factorization() -> [(BigUint, u32); 5] {
+            [
+                (BigUint::from(2u8), 32),
+                (BigUint::from(3u8), 1),
+                (BigUint::from(463u16), 1),
+                (BigUint::from(539204044132271846773u128), 1),
+                (
+                    "8999194758858563409123804352480028797519453"
+                        .parse()
+                        .unwrap(),
+                    1,
+                ),
+            ]
+        }
+        test_field!(
+            crate::PastaFp,
+            &[super::ZERO],
+            &[super::ONE],
+            &super::multiplicative_group_prime_factorization()
+        );
+
+        test_prime_field!(crate::PastaFp);
+        test_two_adic_field!(crate::PastaFp);
+    }
+
+    mod pasta_fq {
+        use num_bigint::BigUint;
+        use p3_field::PrimeCharacteristicRing;
+        use p3_field_testing::{test_field, test_prime_field, test_two_adic_field};
+
+        use crate::PastaFq;
+
+        const ZERO: PastaFq = PastaFq::ZERO;
+        const ONE: PastaFq = PastaFq::ONE;
+
+        // Get the prime factorization of the order of the multiplicative group.
+        // i.e. the prime factorization of Q - 1.
+        fn multiplicative_group_prime_factorization() -> [(BigUint, u32); 6] {
+            [
+                (BigUint::from(2u8), 32),
+                (BigUint::from(3u8), 2),
+                (BigUint::from(1709u16), 1),
+                (BigUint::from(24859u16), 1),
+                (BigUint::from(1690502597179744445941507u128), 1),
+                (BigUint::from(10427374428728808478656897599072717u128), 1),
+            ]
+        }
+        test_field!(
+            crate::PastaFq,
+            &[super::ZERO],
+            &[super::ONE],
+            &super::multiplicative_group_prime_factorization()
+        );
+
+        test_prime_field!(crate::PastaFq);
+        test_two_adic_field!(crate::PastaFq);
+    }
+}
@@ -0,0 +1,84 @@
+use p3_field::InjectiveMonomial;
+
+use crate::{FieldParameters, MontyFieldN, MontyParameters};
+
+/// The base field of the secp256k1 curve, defined as `F_P` where `P = 2^256 - 2^32 - 977`.
+///
+/// As `P - 1` is only divisible by `2` once, this field does not implement `TwoAdicField`.
+pub type Secp256k1Fp = MontyFieldN<Secp256k1FpParameters, 4>;
+
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq)]
+pub struct Secp256k1FpParameters;
+
+impl MontyParameters<4> for Secp256k1FpParameters {
+    const PRIME: [u64; 4] = [
+        0xfffffffefffffc2f,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ];
+}
+
+impl FieldParameters<4> for Secp256k1FpParameters {
+    const GENERATOR: [u64; 4] = [3, 0, 0, 0];
+}
+
+/// Degree of the smallest permutation polynomial for the secp256k1 base field.
+///
+/// As p - 1 is divisible by 2 and 3 the smallest choice for a degree D satisfying gcd(p - 1, D) = 1 is 5.
+impl InjectiveMonomial<5> for Secp256k1Fp {}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use p3_field::{Field, PrimeCharacteristicRing, PrimeField};
+    use p3_field_testing::{test_field, test_prime_field};
+
+    use super::*;
+
+    type F = Secp256k1Fp;
+
+    #[test]
+    fn test_secp256k1fp() {
+        // P is larger than 2^255 so multiplication needs to track the carry out of the top limb.
+        const { assert!(!Secp256k1FpParameters::NO_CARRY) };
+
+        let p = (BigUint::from(1u32) << 256) - (BigUint::from(1u32) << 32) - 977u32;
+        assert_eq!(F::order(), p);
+        assert_eq!(F::GENERATOR.as_canonical_biguint(), BigUint::from(3u32));
+
+        // The largest element doubles to a value which overflows 256 bits.
+        let max = F::NEG_ONE;
+        assert_eq!((max + max).as_canonical_biguint(), &p - 2u32);
+        assert_eq!((max * max), F::ONE);
+        assert_eq!(max.halve().as_canonical_biguint(), &p >> 1);
+    }
+
+    const ZERO: F = F::ZERO;
+    const ONE: F = F::ONE;
+
+    // Get the prime factorization of the order of the multiplicative group.
+    // i.e. the prime factorization of P - 1.
+    fn multiplicative_group_prime_factorization() -> [(BigUint, u32); 5] {
+        [
+            (BigUint::from(2u8), 1),
+            (BigUint::from(3u8), 1),
+            (BigUint::from(7u8), 1),
+            (BigUint::from(13441u16), 1),
+            (
+                "205115282021455665897114700593932402728804164701536103180137503955397371"
+                    .parse()
+                    .unwrap(),
+                1,
+            ),
+        ]
+    }
+    test_field!(
+        crate::Secp256k1Fp,
+        &[super::ZERO],
+        &[super::ONE],
+        &super::multiplicative_group_prime_factorization()
+    );
+
+    test_prime_field!(crate::Secp256k1Fp);
+}
@@ -0,0 +1,241 @@
+//! Multi-limb integer arithmetic used to implement `MontyFieldN`.
+//!
+//! All integers are stored as little-endian arrays of `N` u64 limbs. Everything here is a `const fn`
+//! so that the Montgomery constants of a field can be derived from its prime at compile time.
+
+use num_bigint::BigUint;
+
+/// Compute `lhs + rhs + carry` returning the low 64 bits and the carry.
+#[inline(always)]
+const fn adc(lhs: u64, rhs: u64, carry: bool) -> (u64, bool) {
+    let (sum, c0) = lhs.overflowing_add(rhs);
+    let (sum, c1) = sum.overflowing_add(carry as u64);
+    (sum, c0 | c1)
+}
+
+/// Compute `lhs - rhs - borrow` returning the low 64 bits and the borrow.
+#[inline(always)]
+const fn sbb(lhs: u64, rhs: u64, borrow: bool) -> (u64, bool) {
+    let (diff, b0) = lhs.overflowing_sub(rhs);
+    let (diff, b1) = diff.overflowing_sub(borrow as u64);
+    (diff, b0 | b1)
+}
+
+/// Compute `acc + lhs * rhs + carry` returning the low and high 64 bits.
+///
+/// The result always fits in 128 bits as `(2^64 - 1) + (2^64 - 1)^2 + (2^64 - 1) = 2^128 - 1`.
+#[inline(always)]
+const fn mac(acc: u64, lhs: u64, rhs: u64, carry: u64) -> (u64, u64) {
+    let res = acc as u128 + (lhs as u128) * (rhs as u128) + carry as u128;
+    (res as u64, (res >> 64) as u64)
+}
+
+/// Compute `lhs + rhs` returning the sum mod `2^{64N}` and the carry.
+#[inline]
+pub(crate) const fn wrapping_add<const N: usize>(lhs: [u64; N], rhs: [u64; N]) -> ([u64; N], bool) {
+    let mut out = [0; N];
+    let mut carry = false;
+    let mut i = 0;
+    while i < N {
+        (out[i], carry) = adc(lhs[i], rhs[i], carry);
+        i += 1;
+    }
+    (out, carry)
+}
+
+/// Compute `lhs - rhs` returning the difference mod `2^{64N}` and the borrow.
+#[inline]
+pub(crate) const fn wrapping_sub<const N: usize>(lhs: [u64; N], rhs: [u64; N]) -> ([u64; N], bool) {
+    let mut out = [0; N];
+    let mut borrow = false;
+    let mut i = 0;
+    while i < N {
+        (out[i], borrow) = sbb(lhs[i], rhs[i], borrow);
+        i += 1;
+    }
+    (out, borrow)
+}
+
+/// Returns `true` if `lhs < rhs`.
+#[inline]
+pub(crate) const fn less_than<const N: usize>(lhs: [u64; N], rhs: [u64; N]) -> bool {
+    wrapping_sub(lhs, rhs).1
+}
+
+/// Returns `true` if every limb is `0`.
+#[inline]
+pub(crate) const fn is_zero<const N: usize>(val: [u64; N]) -> bool {
+    let mut acc = 0;
+    let mut i = 0;
+    while i < N {
+        acc |= val[i];
+        i += 1;
+    }
+    acc == 0
+}
+
+/// Compute `lhs + rhs mod P`.
+///
+/// Assumes that `lhs, rhs < P`. As `P` may be larger than `2^{64N - 1}` we need to
+/// take the carry out of the top limb into account.
+#[inline]
+pub(crate) const fn add_mod<const N: usize>(
+    lhs: [u64; N],
+    rhs: [u64; N],
+    prime: [u64; N],
+) -> [u64; N] {
+    let (sum, carry) = wrapping_add(lhs, rhs);
+    let (sum_corr, borrow) = wrapping_sub(sum, prime);
+    // If the sum overflowed it is certainly larger than P. Otherwise, if subtracting
+    // P underflowed, the sum was already reduced.
+    if carry || !borrow { sum_corr } else { sum }
+}
+
+/// Compute `lhs - rhs mod P`.
+///
+/// Assumes that `lhs, rhs < P`.
+#[inline]
+pub(crate) const fn sub_mod<const N: usize>(
+    lhs: [u64; N],
+    rhs: [u64; N],
+    prime: [u64; N],
+) -> [u64; N] {
+    let (diff, borrow) = wrapping_sub(lhs, rhs);
+    if borrow {
+        wrapping_add(diff, prime).0
+    } else {
+        diff
+    }
+}
+
+/// Compute `val/2 mod P`.
+///
+/// Assumes that `val < P`. If `val` is odd we add `P` (which may carry into an extra bit)
+/// before shifting.
+#[inline]
+pub(crate) const fn halve_mod<const N: usize>(val: [u64; N], prime: [u64; N]) -> [u64; N] {
+    let (val, carry) = if val[0] & 1 == 1 {
+        wrapping_add(val, prime)
+    } else {
+        (val, false)
+    };
+    let mut out = [0; N];
+    let mut i = 0;
+    while i < N - 1 {
+        out[i] = (val[i] >> 1) | (val[i + 1] << 63);
+        i += 1;
+    }
+    out[N - 1] = (val[N - 1] >> 1) | ((carry as u64) << 63);
+    out
+}
+
+/// Compute `-P^{-1} mod 2^64` for an odd `P` given its lowest limb.
+pub(crate) const fn monty_neg_inverse(prime_lo: u64) -> u64 {
+    // prime_lo * prime_lo = 1 mod 8 so prime_lo is its own inverse to 3 bits of precision.
+    // Every Newton iteration doubles the number of correct bits: 3 -> 6 -> 12 -> 24 -> 48 -> 96.
+    let mut inv = prime_lo;
+    let mut i = 0;
+    while i < 5 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(prime_lo.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv.wrapping_neg()
+}
+
+/// Compute `2^k mod P` for the given `k` by repeated modular doubling.
+///
+/// This is only used to derive constants at compile time so speed is not a concern.
+pub(crate) const fn pow_2_mod<const N: usize>(k: usize, prime: [u64; N]) -> [u64; N] {
+    let mut out = [0; N];
+    out[0] = 1;
+    let mut i = 0;
+    while i < k {
+        out = add_mod(out, out, prime);
+        i += 1;
+    }
+    out
+}
+
+/// Returns `true` if the top limb of `P` is small enough to use the "no-carry" variant of CIOS.
+///
+/// This holds when `P[N - 1] < (2^64 - 1)/2 - 1`, see <https://hackmd.io/@gnark/modular_multiplication>.
+pub(crate) const fn can_skip_carry<const N: usize>(prime: [u64; N]) -> bool {
+    prime[N - 1] < (u64::MAX >> 1) - 1
+}
+
+/// Montgomery multiplication using the Coarsely Integrated Operand Scanning (CIOS) method.
+///
+/// Given `lhs, rhs < P` this computes `lhs * rhs * 2^{-64N} mod P`. The multiplication and
+/// reduction steps are interleaved limb by limb so the intermediate value never needs more
+/// than `N + 2` limbs.
+///
+/// `mu` must equal `-P^{-1} mod 2^64` and `no_carry` must only be set if `can_skip_carry(prime)`
+/// holds, in which case the two extra limbs can be dropped as the intermediate value provably
+/// fits in `N` limbs.
+#[inline]
+pub(crate) const fn monty_mul<const N: usize>(
+    lhs: [u64; N],
+    rhs: [u64; N],
+    prime: [u64; N],
+    mu: u64,
+    no_carry: bool,
+) -> [u64; N] {
+    let mut t = [0; N];
+    // Only used if no_carry is false. Holds the limb t[N] of the intermediate value.
+    // The limb t[N + 1] is always 0 or 1 and only needs to be stored briefly.
+    let mut t_hi = 0;
+
+    let mut i = 0;
+    while i < N {
+        if no_carry {
+            // Both the multiplication and reduction carries are propagated in the same pass.
+            let (lo, mut carry_mul) = mac(t[0], lhs[0], rhs[i], 0);
+            let m = lo.wrapping_mul(mu);
+            let (_, mut carry_red) = mac(lo, m, prime[0], 0);
+            let mut j = 1;
+            while j < N {
+                let (t_j, c) = mac(t[j], lhs[j], rhs[i], carry_mul);
+                carry_mul = c;
+                (t[j - 1], carry_red) = mac(t_j, m, prime[j], carry_red);
+                j += 1;
+            }
+            t[N - 1] = carry_mul + carry_red;
+        } else {
+            // t += lhs * rhs[i]
+            let mut carry = 0;
+            let mut j = 0;
+            while j < N {
+                (t[j], carry) = mac(t[j], lhs[j], rhs[i], carry);
+                j += 1;
+            }
+            let (t_n, t_n_plus_1) = adc(t_hi, carry, false);
+
+            // t = (t + m * P) / 2^64 where m is chosen so that the division is exact.
+            let m = t[0].wrapping_mul(mu);
+            let (_, mut carry) = mac(t[0], m, prime[0], 0);
+            let mut j = 1;
+            while j < N {
+                (t[j - 1], carry) = mac(t[j], m, prime[j], carry);
+                j += 1;
+            }
+            let (t_n_minus_1, c) = adc(t_n, carry, false);
+            t[N - 1] = t_n_minus_1;
+            t_hi = t_n_plus_1 as u64 + c as u64;
+        }
+        i += 1;
+    }
+
+    // The result is now less than 2P so a single conditional subtraction suffices.
+    let (t_corr, borrow) = wrapping_sub(t, prime);
+    if t_hi != 0 || !borrow { t_corr } else { t }
+}
+
+/// Convert a little-endian array of limbs into a `BigUint`.
+#[inline]
+pub(crate) fn to_biguint<const N: usize>(value: [u64; N]) -> BigUint {
+    BigUint::from_slice(
+        &value
+            .map(|limb| [limb as u32, (limb >> 32) as u32])
+            .concat(),
+    )
+}
@@ -3,3 +3,4 @@
 extern crate alloc;
 
 pub mod eq_batch;
+pub mod poly;
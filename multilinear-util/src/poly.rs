@@ -0,0 +1,328 @@
+//! Multilinear polynomials given by their evaluations over the Boolean hypercube.
+//!
+//! A multilinear polynomial `P(x_0, ..., x_{n-1})` is stored as its `2^n` evaluations over
+//! `{0,1}^n` in big-endian order, matching the tables produced by [`eval_eq_batch`]: the
+//! evaluation at `x` lives at index `\sum_i x_i ⋅ 2^{n-1-i}`. So `x_0` is the **high** variable,
+//! selecting between the two halves of the table, and `x_{n-1}` is the **low** variable,
+//! selecting between adjacent entries.
+//!
+//! All operations that combine the table with an extension field point go through packed
+//! arithmetic and are parallelised with `p3-maybe-rayon`.
+
+use alloc::vec::Vec;
+
+use p3_field::{ExtensionField, Field, PackedFieldExtension, PackedValue};
+use p3_matrix::Matrix;
+use p3_matrix::dense::{RowMajorMatrix, RowMajorMatrixView};
+use p3_maybe_rayon::prelude::*;
+use p3_util::log2_strict_usize;
+
+use crate::eq_batch::eval_eq_batch;
+
+/// A multilinear polynomial over `F`, given by its evaluations over the Boolean hypercube in
+/// big-endian order.
+///
+/// `F` can be a base field or an extension field. Fixing variables to extension field values
+/// yields a polynomial over the extension field.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MultilinearPoly<F> {
+    evals: Vec<F>,
+}
+
+impl<F: Field> MultilinearPoly<F> {
+    /// Create a polynomial from its evaluations over the hypercube.
+    ///
+    /// # Panics
+    /// Panics if the number of evaluations is not a power of two.
+    pub fn new(evals: Vec<F>) -> Self {
+        assert!(evals.len().is_power_of_two());
+        Self { evals }
+    }
+
+    /// The zero polynomial in `num_vars` variables.
+    pub fn zero(num_vars: usize) -> Self {
+        Self {
+            evals: F::zero_vec(1 << num_vars),
+        }
+    }
+
+    /// The polynomial `eq(x, point)`, with one variable per coordinate of `point`.
+    pub fn eq(point: &[F]) -> Self {
+        let mut evals = F::zero_vec(1 << point.len());
+        eval_eq_batch::<F, F, false>(RowMajorMatrixView::new_col(point), &mut evals, &[F::ONE]);
+        Self { evals }
+    }
+
+    /// The number of variables.
+    pub fn num_vars(&self) -> usize {
+        log2_strict_usize(self.evals.len())
+    }
+
+    /// The evaluations over the hypercube, in big-endian order.
+    pub fn evals(&self) -> &[F] {
+        &self.evals
+    }
+
+    /// Consume the polynomial, returning its evaluations over the hypercube in big-endian order.
+    pub fn into_evals(self) -> Vec<F> {
+        self.evals
+    }
+
+    /// Lift the polynomial to an extension field.
+    pub fn to_extension<EF: ExtensionField<F>>(&self) -> MultilinearPoly<EF> {
+        MultilinearPoly {
+            evals: self.evals.par_iter().map(|&v| EF::from(v)).collect(),
+        }
+    }
+
+    /// Evaluate the polynomial at `point`, which has one coordinate per variable.
+    ///
+    /// This computes `\sum_x eq(x, point) ⋅ P(x)`, with the sum over packed values.
+    ///
+    /// # Panics
+    /// Panics if `point` does not have `num_vars()` coordinates.
+    pub fn evaluate<EF: ExtensionField<F>>(&self, point: &[EF]) -> EF {
+        assert_eq!(point.len(), self.num_vars());
+        let eq = MultilinearPoly::eq(point);
+        packed_dot_product(&self.evals, &eq.evals)
+    }
+
+    /// Fix the `k = point.len()` high variables `x_0, ..., x_{k-1}` to `point`, returning the
+    /// polynomial in the remaining variables `x_k, ..., x_{n-1}`.
+    ///
+    /// # Panics
+    /// Panics if `point` has more coordinates than there are variables.
+    pub fn fix_high_variables<EF: ExtensionField<F>>(&self, point: &[EF]) -> MultilinearPoly<EF> {
+        let num_vars = self.num_vars();
+        assert!(point.len() <= num_vars);
+        // Viewing the table as a matrix with one row per assignment of the high variables, the
+        // result is the combination of the rows weighted by `eq(x_high, point)`.
+        let mat = RowMajorMatrixView::new(&self.evals, 1 << (num_vars - point.len()));
+        let eq = MultilinearPoly::eq(point);
+        MultilinearPoly {
+            evals: mat.columnwise_dot_product(&eq.evals),
+        }
+    }
+
+    /// Fix the `k = point.len()` low variables `x_{n-k}, ..., x_{n-1}` to `point`, returning the
+    /// polynomial in the remaining variables `x_0, ..., x_{n-k-1}`.
+    ///
+    /// # Panics
+    /// Panics if `point` has more coordinates than there are variables.
+    pub fn fix_low_variables<EF: ExtensionField<F>>(&self, point: &[EF]) -> MultilinearPoly<EF> {
+        assert!(point.len() <= self.num_vars());
+        // Viewing the table as a matrix with one column per assignment of the low variables, the
+        // result is the dot product of each row with `eq(x_low, point)`.
+        let mat = RowMajorMatrixView::new(&self.evals, 1 << point.len());
+        let mut eq = MultilinearPoly::<EF>::eq(point).evals;
+        eq.resize(eq.len().next_multiple_of(F::Packing::WIDTH), EF::ZERO);
+        let packed_eq: Vec<EF::ExtensionPacking> = eq
+            .chunks_exact(F::Packing::WIDTH)
+            .map(EF::ExtensionPacking::from_ext_slice)
+            .collect();
+        MultilinearPoly {
+            evals: mat.rowwise_packed_dot_product::<EF>(&packed_eq).collect(),
+        }
+    }
+
+    /// Take the polynomial whose evaluations are the `col`'th column of `mat`.
+    ///
+    /// # Panics
+    /// Panics if the height of `mat` is not a power of two, or `col` is out of bounds.
+    pub fn from_matrix_column<M: Matrix<F>>(mat: &M, col: usize) -> Self {
+        assert!(col < mat.width());
+        Self::new(
+            (0..mat.height())
+                .into_par_iter()
+                .map(|r| unsafe {
+                    // Safety: `r < height` and we checked `col < width` above.
+                    mat.get_unchecked(r, col)
+                })
+                .collect(),
+        )
+    }
+
+    /// Split `mat` into one polynomial per column.
+    ///
+    /// # Panics
+    /// Panics if the height of `mat` is not a power of two.
+    pub fn from_matrix_columns(mat: RowMajorMatrixView<'_, F>) -> Vec<Self> {
+        assert!(mat.height().is_power_of_two());
+        mat.transpose()
+            .values
+            .par_chunks(mat.height())
+            .map(|col| Self::new(col.to_vec()))
+            .collect()
+    }
+
+    /// Turn the polynomial into a matrix with a single column.
+    pub fn into_matrix_column(self) -> RowMajorMatrix<F> {
+        RowMajorMatrix::new_col(self.evals)
+    }
+
+    /// Stack polynomials in the same number of variables as the columns of a matrix.
+    ///
+    /// # Panics
+    /// Panics if `polys` is empty or the polynomials have different numbers of variables.
+    pub fn to_matrix(polys: &[Self]) -> RowMajorMatrix<F> {
+        assert!(!polys.is_empty());
+        let height = polys[0].evals.len();
+        assert!(polys.iter().all(|p| p.evals.len() == height));
+        let mut values = F::zero_vec(height * polys.len());
+        values
+            .par_chunks_exact_mut(polys.len())
+            .enumerate()
+            .for_each(|(r, row)| {
+                for (v, poly) in row.iter_mut().zip(polys) {
+                    *v = poly.evals[r];
+                }
+            });
+        RowMajorMatrix::new(values, polys.len())
+    }
+}
+
+/// Evaluate the multilinear equality polynomial `eq(z, r) = \prod_i (z_i r_i + (1 - z_i)(1 - r_i))`
+/// at a single pair of points.
+///
+/// This is the evaluation of [`MultilinearPoly::eq(z)`](MultilinearPoly::eq) at `r`, computed in
+/// `O(n)` operations without building the table.
+///
+/// # Panics
+/// Panics if `z` and `r` have different lengths.
+pub fn eval_eq<F: Field>(z: &[F], r: &[F]) -> F {
+    assert_eq!(z.len(), r.len());
+    z.iter()
+        .zip(r)
+        .map(|(&z_i, &r_i)| z_i * r_i + (F::ONE - z_i) * (F::ONE - r_i))
+        .product()
+}
+
+/// Compute `\sum_i a_i ⋅ b_i` for base values `a` and extension values `b` of the same length,
+/// packing both sides.
+fn packed_dot_product<F: Field, EF: ExtensionField<F>>(a: &[F], b: &[EF]) -> EF {
+    debug_assert_eq!(a.len(), b.len());
+    let (a_packed, a_suffix) = F::Packing::pack_slice_with_suffix(a);
+    let (b_packed, b_suffix) = b.split_at(a_packed.len() * F::Packing::WIDTH);
+    let packed_sum: EF::ExtensionPacking = a_packed
+        .par_iter()
+        .zip(b_packed.par_chunks_exact(F::Packing::WIDTH))
+        .map(|(&a, b)| EF::ExtensionPacking::from_ext_slice(b) * a)
+        .sum();
+    let suffix_sum: EF = a_suffix.iter().zip(b_suffix).map(|(&a, &b)| b * a).sum();
+    EF::ExtensionPacking::to_ext_iter([packed_sum]).sum::<EF>() + suffix_sum
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use p3_baby_bear::BabyBear;
+    use p3_field::PrimeCharacteristicRing;
+    use p3_field::extension::BinomialExtensionField;
+    use p3_matrix::Matrix;
+    use p3_matrix::dense::RowMajorMatrix;
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+
+    type F = BabyBear;
+    type EF4 = BinomialExtensionField<F, 4>;
+
+    /// Evaluate by fixing the high variable one at a time, straight from the definition.
+    fn naive_evaluate<F: Field, EF: ExtensionField<F>>(evals: &[F], point: &[EF]) -> EF {
+        let mut table: Vec<EF> = evals.iter().map(|&v| EF::from(v)).collect();
+        for &r in point {
+            let (lo, hi) = table.split_at(table.len() / 2);
+            table = lo
+                .iter()
+                .zip(hi)
+                .map(|(&lo, &hi)| lo + r * (hi - lo))
+                .collect();
+        }
+        table[0]
+    }
+
+    #[test]
+    fn evaluate_matches_naive() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        for num_vars in [0, 1, 2, 3, 5, 10] {
+            let poly =
+                MultilinearPoly::<F>::new((0..1 << num_vars).map(|_| rng.random()).collect());
+            let point: Vec<EF4> = (0..num_vars).map(|_| rng.random()).collect();
+            let expected = naive_evaluate(poly.evals(), &point);
+            assert_eq!(poly.evaluate(&point), expected);
+
+            // On the hypercube, the evaluation is a table lookup.
+            let x = rng.random_range(0..1 << num_vars);
+            let bits: Vec<F> = (0..num_vars)
+                .rev()
+                .map(|i| F::from_bool((x >> i) & 1 == 1))
+                .collect();
+            assert_eq!(poly.evaluate(&bits), poly.evals()[x]);
+
+            // Extension field valued polynomials evaluate the same.
+            assert_eq!(poly.to_extension::<EF4>().evaluate(&point), expected);
+        }
+    }
+
+    #[test]
+    fn eval_eq_matches_table() {
+        let mut rng = SmallRng::seed_from_u64(3);
+        for num_vars in [0, 1, 4, 7] {
+            let z: Vec<EF4> = (0..num_vars).map(|_| rng.random()).collect();
+            let r: Vec<EF4> = (0..num_vars).map(|_| rng.random()).collect();
+            assert_eq!(eval_eq(&z, &r), MultilinearPoly::eq(&z).evaluate(&r));
+        }
+    }
+
+    #[test]
+    fn fixing_variables_is_consistent() {
+        let mut rng = SmallRng::seed_from_u64(2);
+        for num_vars in [1, 4, 7, 9] {
+            let poly =
+                MultilinearPoly::<F>::new((0..1 << num_vars).map(|_| rng.random()).collect());
+            let point: Vec<EF4> = (0..num_vars).map(|_| rng.random()).collect();
+            let expected = poly.evaluate(&point);
+            for k in 0..=num_vars {
+                let high = poly.fix_high_variables(&point[..k]);
+                assert_eq!(high.num_vars(), num_vars - k);
+                assert_eq!(high.evaluate(&point[k..]), expected);
+
+                let low = poly.fix_low_variables(&point[num_vars - k..]);
+                assert_eq!(low.num_vars(), num_vars - k);
+                assert_eq!(low.evaluate(&point[..num_vars - k]), expected);
+
+                // Fixing more variables of an extension field valued polynomial.
+                assert_eq!(high.fix_low_variables(&point[k..]).evals(), &[expected]);
+                assert_eq!(
+                    low.fix_high_variables(&point[..num_vars - k]).evals(),
+                    &[expected]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn matrix_roundtrip() {
+        let mut rng = SmallRng::seed_from_u64(3);
+        let mat = RowMajorMatrix::<F>::rand(&mut rng, 16, 5);
+        let polys = MultilinearPoly::from_matrix_columns(mat.as_view());
+        assert_eq!(polys.len(), 5);
+        for (col, poly) in polys.iter().enumerate() {
+            assert_eq!(poly, &MultilinearPoly::from_matrix_column(&mat, col));
+            assert_eq!(poly.num_vars(), 4);
+        }
+        assert_eq!(MultilinearPoly::to_matrix(&polys), mat);
+
+        let col = polys[2].clone().into_matrix_column();
+        assert_eq!(col.width(), 1);
+        assert_eq!(col.values, polys[2].evals());
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_rejects_non_power_of_two() {
+        MultilinearPoly::new(F::zero_vec(6));
+    }
+}
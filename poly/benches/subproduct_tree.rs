@@ -0,0 +1,43 @@
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use p3_baby_bear::BabyBear;
+use p3_dft::Radix2DitParallel;
+use p3_poly::{DensePolynomial, SubproductTree};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+type F = BabyBear;
+
+/// Multipoint evaluation and interpolation at `n` random points. As the subproduct tree uses FFT
+/// multiplication and division, the time per point should only grow like `log^2 n`.
+fn bench_subproduct_tree(c: &mut Criterion) {
+    let log_sizes = [8, 10, 12, 14];
+    let dft = Radix2DitParallel::<F>::default();
+    let mut rng = SmallRng::seed_from_u64(1);
+
+    let mut group = c.benchmark_group("subproduct_tree");
+    group.sample_size(10);
+    for log_n in log_sizes {
+        let n = 1 << log_n;
+        let points: Vec<F> = (0..n).map(|_| rng.random()).collect();
+        let poly = DensePolynomial::new((0..n).map(|_| rng.random()).collect());
+        let values: Vec<F> = (0..n).map(|_| rng.random()).collect();
+
+        group.bench_with_input(BenchmarkId::new("build", n), &points, |b, points| {
+            b.iter(|| SubproductTree::new(black_box(points), &dft));
+        });
+
+        let tree = SubproductTree::new(&points, &dft);
+        group.bench_with_input(BenchmarkId::new("evaluate", n), &poly, |b, poly| {
+            b.iter(|| tree.evaluate(black_box(poly), &dft));
+        });
+        group.bench_with_input(BenchmarkId::new("interpolate", n), &values, |b, values| {
+            b.iter(|| tree.interpolate(black_box(values), &dft));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_subproduct_tree);
+criterion_main!(benches);
@@ -0,0 +1,599 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use itertools::{EitherOrBoth, Itertools};
+use p3_dft::TwoAdicSubgroupDft;
+use p3_field::{ExtensionField, Field, TwoAdicField};
+use p3_matrix::dense::RowMajorMatrix;
+use p3_util::log2_ceil_usize;
+
+/// A dense univariate polynomial, stored as its coefficients in increasing order of degree.
+///
+/// The coefficient vector never has trailing zeros, so the zero polynomial has no coefficients
+/// and two polynomials are equal exactly when their coefficient vectors are.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DensePolynomial<F> {
+    coeffs: Vec<F>,
+}
+
+impl<F: Field> DensePolynomial<F> {
+    /// Create a polynomial from its coefficients, lowest degree first.
+    pub fn new(mut coeffs: Vec<F>) -> Self {
+        trim_zeros(&mut coeffs);
+        Self { coeffs }
+    }
+
+    /// The zero polynomial.
+    pub const fn zero() -> Self {
+        Self { coeffs: Vec::new() }
+    }
+
+    /// The constant polynomial `c`.
+    pub fn constant(c: F) -> Self {
+        Self::new(vec![c])
+    }
+
+    /// The monomial `c * x^degree`.
+    pub fn monomial(c: F, degree: usize) -> Self {
+        let mut coeffs = F::zero_vec(degree + 1);
+        coeffs[degree] = c;
+        Self::new(coeffs)
+    }
+
+    /// The vanishing polynomial `x^n - shift^n` of the coset `shift * H`, where `H` is the
+    /// multiplicative subgroup of order `n`.
+    pub fn vanishing(n: usize, shift: F) -> Self {
+        let mut coeffs = F::zero_vec(n + 1);
+        coeffs[0] = -shift.exp_u64(n as u64);
+        coeffs[n] = F::ONE;
+        Self::new(coeffs)
+    }
+
+    /// The polynomial `prod_i (x - root_i)`.
+    pub fn from_roots(roots: &[F]) -> Self {
+        roots.iter().fold(Self::constant(F::ONE), |acc, &root| {
+            &acc * &Self::new(vec![-root, F::ONE])
+        })
+    }
+
+    /// The coefficients, lowest degree first, without trailing zeros.
+    pub fn coeffs(&self) -> &[F] {
+        &self.coeffs
+    }
+
+    /// Consume the polynomial, returning its coefficients.
+    pub fn into_coeffs(self) -> Vec<F> {
+        self.coeffs
+    }
+
+    /// The degree of the polynomial, or `None` for the zero polynomial.
+    pub const fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    /// Whether this is the zero polynomial.
+    pub const fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// The leading coefficient, or zero for the zero polynomial.
+    pub fn leading_coeff(&self) -> F {
+        self.coeffs.last().copied().unwrap_or(F::ZERO)
+    }
+
+    /// Evaluate the polynomial at `x`, which may lie in an extension field, using Horner's rule.
+    pub fn evaluate<EF: ExtensionField<F>>(&self, x: EF) -> EF {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(EF::ZERO, |acc, &coeff| acc * x + coeff)
+    }
+
+    /// Multiply the polynomial by the scalar `c`.
+    pub fn scale(&self, c: F) -> Self {
+        Self::new(self.coeffs.iter().map(|&x| x * c).collect())
+    }
+
+    /// Return the polynomial `x -> self(g * x)`.
+    ///
+    /// This maps evaluations over a set `D` to evaluations over `g^{-1} * D`, e.g. between
+    /// cosets of a subgroup, or to the next row of a trace when `g` generates the trace domain.
+    pub fn compose_scaled(&self, g: F) -> Self {
+        Self::new(
+            self.coeffs
+                .iter()
+                .zip(g.powers())
+                .map(|(&c, g_i)| c * g_i)
+                .collect(),
+        )
+    }
+
+    /// The formal derivative of the polynomial.
+    pub fn derivative(&self) -> Self {
+        Self::new(
+            self.coeffs
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, &c)| c * F::from_usize(i))
+                .collect(),
+        )
+    }
+
+    /// Divide by `divisor`, returning the quotient and the remainder.
+    ///
+    /// This is schoolbook long division, costing `O(deg(quotient) * deg(divisor))` operations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    pub fn divide_with_remainder(&self, divisor: &Self) -> (Self, Self) {
+        let divisor_degree = divisor.degree().expect("division by the zero polynomial");
+        if self.coeffs.len() <= divisor_degree {
+            return (Self::zero(), self.clone());
+        }
+
+        let lead_inv = divisor.leading_coeff().inverse();
+        let mut remainder = self.coeffs.clone();
+        let mut quotient = F::zero_vec(self.coeffs.len() - divisor_degree);
+        for i in (0..quotient.len()).rev() {
+            let q = remainder[i + divisor_degree] * lead_inv;
+            quotient[i] = q;
+            for (r, &d) in remainder[i..].iter_mut().zip(&divisor.coeffs) {
+                *r -= q * d;
+            }
+        }
+        remainder.truncate(divisor_degree);
+        (Self::new(quotient), Self::new(remainder))
+    }
+
+    /// Divide by the vanishing polynomial `x^n - shift^n` of the coset `shift * H`, where `H`
+    /// is the multiplicative subgroup of order `n`, returning the quotient and the remainder.
+    ///
+    /// This takes linear time, as each coefficient of degree `i >= n` is just folded into
+    /// degree `i - n`. The remainder is zero exactly when the polynomial vanishes on `shift * H`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn divide_by_vanishing(&self, n: usize, shift: F) -> (Self, Self) {
+        assert!(n > 0, "the vanishing polynomial must have positive degree");
+        if self.coeffs.len() <= n {
+            return (Self::zero(), self.clone());
+        }
+
+        let shift_n = shift.exp_u64(n as u64);
+        let mut remainder = self.coeffs.clone();
+        let mut quotient = F::zero_vec(self.coeffs.len() - n);
+        for i in (0..quotient.len()).rev() {
+            let q = remainder[i + n];
+            quotient[i] = q;
+            remainder[i] += q * shift_n;
+        }
+        remainder.truncate(n);
+        (Self::new(quotient), Self::new(remainder))
+    }
+
+    /// Multiply two polynomials using FFTs over a two-adic subgroup of the base field `B`.
+    ///
+    /// Both polynomials are evaluated over a subgroup large enough to hold the product, the
+    /// evaluations are multiplied pointwise and the product is interpolated back. This costs
+    /// `O(n log n)` operations for a product of degree `n`, rather than the `O(n^2)` of the
+    /// schoolbook multiplication used by `Mul`.
+    pub fn mul_fft<B, Dft>(&self, other: &Self, dft: &Dft) -> Self
+    where
+        B: TwoAdicField,
+        F: ExtensionField<B>,
+        Dft: TwoAdicSubgroupDft<B>,
+    {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        let product_len = self.coeffs.len() + other.coeffs.len() - 1;
+        let n = 1 << log2_ceil_usize(product_len);
+
+        // Evaluate both polynomials in one batch, as the two columns of a matrix.
+        let mut values = F::zero_vec(2 * n);
+        for (i, &c) in self.coeffs.iter().enumerate() {
+            values[2 * i] = c;
+        }
+        for (i, &c) in other.coeffs.iter().enumerate() {
+            values[2 * i + 1] = c;
+        }
+        let evals = dft.dft_algebra_batch(RowMajorMatrix::new(values, 2));
+        let products = evals.row_slices().map(|row| row[0] * row[1]).collect();
+        let mut coeffs = dft.idft_algebra(products);
+        coeffs.truncate(product_len);
+        Self::new(coeffs)
+    }
+
+    /// Multiply two polynomials, using [`DensePolynomial::mul_fft`] unless one of them is small
+    /// enough for schoolbook multiplication to be faster.
+    pub fn mul_with_dft<B, Dft>(&self, other: &Self, dft: &Dft) -> Self
+    where
+        B: TwoAdicField,
+        F: ExtensionField<B>,
+        Dft: TwoAdicSubgroupDft<B>,
+    {
+        if self.coeffs.len().min(other.coeffs.len()) <= SCHOOLBOOK_THRESHOLD {
+            self * other
+        } else {
+            self.mul_fft(other, dft)
+        }
+    }
+
+    /// The inverse of this polynomial as a power series, modulo `x^n`.
+    ///
+    /// This uses Newton iteration `g <- g * (2 - self * g)`, which doubles the number of correct
+    /// coefficients of `g` in every step. With FFT multiplication this costs `O(n log n)`
+    /// operations in total.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the constant coefficient is zero, as the power series is not invertible.
+    pub fn inverse_mod_xn<B, Dft>(&self, n: usize, dft: &Dft) -> Self
+    where
+        B: TwoAdicField,
+        F: ExtensionField<B>,
+        Dft: TwoAdicSubgroupDft<B>,
+    {
+        let constant_inv = self
+            .coeffs
+            .first()
+            .and_then(F::try_inverse)
+            .expect("the constant coefficient must be invertible");
+        if n == 0 {
+            return Self::zero();
+        }
+
+        let two = Self::constant(F::TWO);
+        let mut inverse = Self::constant(constant_inv);
+        let mut precision = 1;
+        while precision < n {
+            precision = (2 * precision).min(n);
+            let error = self
+                .truncated(precision)
+                .mul_with_dft(&inverse, dft)
+                .truncated(precision);
+            inverse = inverse
+                .mul_with_dft(&(&two - &error), dft)
+                .truncated(precision);
+        }
+        inverse
+    }
+
+    /// Divide by `divisor`, returning the quotient and the remainder.
+    ///
+    /// This computes the reversed quotient as the reversed dividend times the power series
+    /// inverse of the reversed divisor (see [`DensePolynomial::inverse_mod_xn`]). With FFT
+    /// multiplication this costs `O(n log n)` operations for a dividend of degree `n`, rather
+    /// than the quadratic cost of [`DensePolynomial::divide_with_remainder`], which is still
+    /// used when the quotient or the divisor is small.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    pub fn divide_with_remainder_fft<B, Dft>(&self, divisor: &Self, dft: &Dft) -> (Self, Self)
+    where
+        B: TwoAdicField,
+        F: ExtensionField<B>,
+        Dft: TwoAdicSubgroupDft<B>,
+    {
+        let divisor_degree = divisor.degree().expect("division by the zero polynomial");
+        if self.coeffs.len() <= divisor_degree {
+            return (Self::zero(), self.clone());
+        }
+        let quotient_len = self.coeffs.len() - divisor_degree;
+        if quotient_len.min(divisor_degree) <= SCHOOLBOOK_THRESHOLD {
+            return self.divide_with_remainder(divisor);
+        }
+
+        // Writing `rev_k(p) = x^{k - 1} p(1/x)`, `self = q * divisor + r` becomes
+        // `rev(self) = rev(q) * rev(divisor) + x^{quotient_len} * rev(r)`, so `rev(q)` is
+        // `rev(self) / rev(divisor) mod x^{quotient_len}`. The constant coefficient of
+        // `rev(divisor)` is the leading coefficient of `divisor`, which is non-zero.
+        let divisor_rev_inv = divisor
+            .reversed(divisor_degree + 1)
+            .inverse_mod_xn(quotient_len, dft);
+        let quotient_rev = self
+            .reversed(self.coeffs.len())
+            .truncated(quotient_len)
+            .mul_with_dft(&divisor_rev_inv, dft)
+            .truncated(quotient_len);
+        let quotient = quotient_rev.reversed(quotient_len);
+        let remainder = self - &quotient.mul_with_dft(divisor, dft);
+        debug_assert!(remainder.coeffs.len() <= divisor_degree);
+        (quotient, remainder)
+    }
+
+    /// The polynomial `p mod x^n`.
+    fn truncated(&self, n: usize) -> Self {
+        Self::new(self.coeffs[..n.min(self.coeffs.len())].to_vec())
+    }
+
+    /// The polynomial `x^{len - 1} p(1/x)`, i.e. the first `len` coefficients in reverse order.
+    ///
+    /// `len` must be at least the number of coefficients of `p`.
+    fn reversed(&self, len: usize) -> Self {
+        debug_assert!(len >= self.coeffs.len());
+        let mut coeffs = self.coeffs.clone();
+        coeffs.resize(len, F::ZERO);
+        coeffs.reverse();
+        Self::new(coeffs)
+    }
+}
+
+/// Below this size, schoolbook multiplication and division beat their FFT-based counterparts.
+const SCHOOLBOOK_THRESHOLD: usize = 32;
+
+/// Remove the trailing zeros of a coefficient vector.
+fn trim_zeros<F: Field>(coeffs: &mut Vec<F>) {
+    while coeffs.last().is_some_and(|c| c.is_zero()) {
+        coeffs.pop();
+    }
+}
+
+impl<F: Field> Add for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn add(self, rhs: Self) -> DensePolynomial<F> {
+        DensePolynomial::new(
+            self.coeffs
+                .iter()
+                .zip_longest(&rhs.coeffs)
+                .map(|pair| match pair {
+                    EitherOrBoth::Both(&a, &b) => a + b,
+                    EitherOrBoth::Left(&a) | EitherOrBoth::Right(&a) => a,
+                })
+                .collect(),
+        )
+    }
+}
+
+impl<F: Field> Add for DensePolynomial<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        &self + &rhs
+    }
+}
+
+impl<F: Field> AddAssign<&Self> for DensePolynomial<F> {
+    fn add_assign(&mut self, rhs: &Self) {
+        *self = &*self + rhs;
+    }
+}
+
+impl<F: Field> Sub for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn sub(self, rhs: Self) -> DensePolynomial<F> {
+        DensePolynomial::new(
+            self.coeffs
+                .iter()
+                .zip_longest(&rhs.coeffs)
+                .map(|pair| match pair {
+                    EitherOrBoth::Both(&a, &b) => a - b,
+                    EitherOrBoth::Left(&a) => a,
+                    EitherOrBoth::Right(&b) => -b,
+                })
+                .collect(),
+        )
+    }
+}
+
+impl<F: Field> Sub for DensePolynomial<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        &self - &rhs
+    }
+}
+
+impl<F: Field> SubAssign<&Self> for DensePolynomial<F> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        *self = &*self - rhs;
+    }
+}
+
+impl<F: Field> Neg for DensePolynomial<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            coeffs: self.coeffs.into_iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+impl<F: Field> Mul for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    /// Schoolbook multiplication. For large polynomials over two-adic fields, prefer
+    /// [`DensePolynomial::mul_fft`].
+    fn mul(self, rhs: Self) -> DensePolynomial<F> {
+        if self.is_zero() || rhs.is_zero() {
+            return DensePolynomial::zero();
+        }
+        let mut coeffs = F::zero_vec(self.coeffs.len() + rhs.coeffs.len() - 1);
+        for (i, &a) in self.coeffs.iter().enumerate() {
+            for (c, &b) in coeffs[i..].iter_mut().zip(&rhs.coeffs) {
+                *c += a * b;
+            }
+        }
+        DensePolynomial::new(coeffs)
+    }
+}
+
+impl<F: Field> Mul for DensePolynomial<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        &self * &rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use p3_baby_bear::BabyBear;
+    use p3_dft::Radix2DitParallel;
+    use p3_field::extension::BinomialExtensionField;
+    use p3_field::{Field, PrimeCharacteristicRing, TwoAdicField};
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::DensePolynomial;
+
+    type F = BabyBear;
+    type EF = BinomialExtensionField<F, 4>;
+
+    fn rand_poly<F: Field>(rng: &mut SmallRng, len: usize) -> DensePolynomial<F>
+    where
+        rand::distr::StandardUniform: rand::distr::Distribution<F>,
+    {
+        DensePolynomial::new((0..len).map(|_| rng.random()).collect())
+    }
+
+    #[test]
+    fn trailing_zeros_are_trimmed() {
+        let p = DensePolynomial::new(vec![F::ONE, F::TWO, F::ZERO, F::ZERO]);
+        assert_eq!(p.degree(), Some(1));
+        assert_eq!(p, DensePolynomial::new(vec![F::ONE, F::TWO]));
+        assert!(DensePolynomial::new(vec![F::ZERO]).is_zero());
+        assert_eq!(DensePolynomial::<F>::zero().degree(), None);
+
+        let q = DensePolynomial::new(vec![F::ZERO, -F::TWO]);
+        assert_eq!((&p + &q).degree(), Some(0));
+    }
+
+    #[test]
+    fn arithmetic_matches_evaluations() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let p = rand_poly::<F>(&mut rng, 10);
+        let q = rand_poly::<F>(&mut rng, 7);
+        let x: EF = rng.random();
+        let (px, qx) = (p.evaluate(x), q.evaluate(x));
+        assert_eq!((&p + &q).evaluate(x), px + qx);
+        assert_eq!((&p - &q).evaluate(x), px - qx);
+        assert_eq!((&p * &q).evaluate(x), px * qx);
+        assert_eq!((-p.clone()).evaluate(x), -px);
+        assert_eq!(p.scale(F::TWO).evaluate(x), px.double());
+
+        let g = F::two_adic_generator(5);
+        assert_eq!(p.compose_scaled(g).evaluate(x), p.evaluate(x * g));
+
+        let roots: Vec<F> = (0..5).map(|_| rng.random()).collect();
+        let z = DensePolynomial::from_roots(&roots);
+        assert_eq!(z.degree(), Some(5));
+        assert!(roots.iter().all(|&r| z.evaluate(r).is_zero()));
+    }
+
+    #[test]
+    fn derivative() {
+        // d/dx (1 + 2x + 3x^2) = 2 + 6x
+        let p = DensePolynomial::new(vec![F::ONE, F::TWO, F::from_u8(3)]);
+        assert_eq!(
+            p.derivative(),
+            DensePolynomial::new(vec![F::TWO, F::from_u8(6)])
+        );
+        assert!(DensePolynomial::constant(F::TWO).derivative().is_zero());
+    }
+
+    #[test]
+    fn division_with_remainder() {
+        let mut rng = SmallRng::seed_from_u64(2);
+        for (len_a, len_b) in [(10, 3), (3, 10), (8, 8), (12, 1)] {
+            let a = rand_poly::<EF>(&mut rng, len_a);
+            let b = rand_poly::<EF>(&mut rng, len_b);
+            let (q, r) = a.divide_with_remainder(&b);
+            assert_eq!(&(&q * &b) + &r, a);
+            assert!(r.degree() < b.degree());
+        }
+    }
+
+    #[test]
+    fn division_by_vanishing() {
+        let mut rng = SmallRng::seed_from_u64(3);
+        let (log_n, shift) = (3, F::GENERATOR);
+        let n = 1 << log_n;
+        let z = DensePolynomial::vanishing(n, shift);
+
+        let a = rand_poly::<F>(&mut rng, 30);
+        let (q, r) = a.divide_by_vanishing(n, shift);
+        assert_eq!((q.clone(), r.clone()), a.divide_with_remainder(&z));
+
+        // A multiple of the vanishing polynomial vanishes on the coset, and divides exactly.
+        let multiple = &q * &z;
+        let coset = F::two_adic_generator(log_n).shifted_powers(shift).take(n);
+        assert!(coset.into_iter().all(|x| multiple.evaluate(x).is_zero()));
+        assert_eq!(
+            multiple.divide_by_vanishing(n, shift),
+            (q, DensePolynomial::zero())
+        );
+    }
+
+    #[test]
+    fn inverse_mod_xn() {
+        let mut rng = SmallRng::seed_from_u64(5);
+        let dft = Radix2DitParallel::<F>::default();
+        for n in [0, 1, 7, 64, 200] {
+            let p = rand_poly::<EF>(&mut rng, 150);
+            let inv = p.inverse_mod_xn(n, &dft);
+            assert!(inv.coeffs().len() <= n);
+            let product = &p * &inv;
+            let expected = if n == 0 {
+                DensePolynomial::zero()
+            } else {
+                DensePolynomial::constant(EF::ONE)
+            };
+            assert_eq!(product.truncated(n), expected);
+        }
+    }
+
+    #[test]
+    fn fft_division_matches_schoolbook() {
+        let mut rng = SmallRng::seed_from_u64(6);
+        let dft = Radix2DitParallel::<F>::default();
+        for (len_a, len_b) in [
+            (10, 3),
+            (3, 10),
+            (300, 100),
+            (300, 250),
+            (500, 40),
+            (257, 1),
+        ] {
+            let a = rand_poly::<F>(&mut rng, len_a);
+            let b = rand_poly::<F>(&mut rng, len_b);
+            assert_eq!(
+                a.divide_with_remainder_fft(&b, &dft),
+                a.divide_with_remainder(&b)
+            );
+
+            let a = rand_poly::<EF>(&mut rng, len_a);
+            let b = rand_poly::<EF>(&mut rng, len_b);
+            assert_eq!(
+                a.divide_with_remainder_fft(&b, &dft),
+                a.divide_with_remainder(&b)
+            );
+        }
+    }
+
+    #[test]
+    fn fft_multiplication_matches_schoolbook() {
+        let mut rng = SmallRng::seed_from_u64(4);
+        let dft = Radix2DitParallel::<F>::default();
+        for (len_a, len_b) in [(1, 1), (5, 9), (32, 33), (100, 1), (0, 4)] {
+            let a = rand_poly::<F>(&mut rng, len_a);
+            let b = rand_poly::<F>(&mut rng, len_b);
+            assert_eq!(a.mul_fft(&b, &dft), &a * &b);
+
+            let a = rand_poly::<EF>(&mut rng, len_a);
+            let b = rand_poly::<EF>(&mut rng, len_b);
+            assert_eq!(a.mul_fft(&b, &dft), &a * &b);
+        }
+    }
+}
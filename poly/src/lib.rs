@@ -0,0 +1,11 @@
+//! Dense univariate polynomials over finite fields.
+
+#![no_std]
+
+extern crate alloc;
+
+mod dense;
+mod subproduct_tree;
+
+pub use dense::*;
+pub use subproduct_tree::*;
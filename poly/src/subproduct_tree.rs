@@ -0,0 +1,210 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use p3_dft::TwoAdicSubgroupDft;
+use p3_field::{ExtensionField, Field, TwoAdicField, batch_multiplicative_inverse};
+
+use crate::DensePolynomial;
+
+/// A subproduct tree over a list of distinct points `x_0, ..., x_{n-1}`.
+///
+/// The leaves are the linear polynomials `x - x_i`, and each inner node is the product of its
+/// children, so the root is the vanishing polynomial `prod_i (x - x_i)` of all the points. The
+/// tree supports evaluating a polynomial at all the points by reducing it modulo the nodes on
+/// the way down, and interpolating through the points by combining the leaves on the way up.
+///
+/// Products and remainders are computed with FFT multiplication and Newton-iteration division
+/// over a two-adic subgroup of `B`, via a [`TwoAdicSubgroupDft`] passed to each method. Building
+/// the tree, evaluating and interpolating therefore cost `O(n log^2 n)` operations for `n`
+/// points, rather than the `O(n^2)` of schoolbook arithmetic.
+///
+/// If the number of points is not a power of two, the last node of a layer with an odd number
+/// of nodes is carried up to the next layer unchanged.
+#[derive(Clone, Debug)]
+pub struct SubproductTree<F> {
+    /// The layers of the tree, from the leaves up to the root.
+    layers: Vec<Vec<DensePolynomial<F>>>,
+}
+
+impl<F: Field> SubproductTree<F> {
+    /// Build the subproduct tree over `points`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` is empty.
+    pub fn new<B, Dft>(points: &[F], dft: &Dft) -> Self
+    where
+        B: TwoAdicField,
+        F: ExtensionField<B>,
+        Dft: TwoAdicSubgroupDft<B>,
+    {
+        assert!(
+            !points.is_empty(),
+            "a subproduct tree needs at least one point"
+        );
+        let leaves = points
+            .iter()
+            .map(|&x| DensePolynomial::new(vec![-x, F::ONE]))
+            .collect();
+        let mut layers: Vec<Vec<DensePolynomial<F>>> = vec![leaves];
+        while layers.last().unwrap().len() > 1 {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => left.mul_with_dft(right, dft),
+                    [single] => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next);
+        }
+        Self { layers }
+    }
+
+    /// The points the tree was built over.
+    pub fn points(&self) -> Vec<F> {
+        self.layers[0]
+            .iter()
+            .map(|leaf| -leaf.coeffs()[0])
+            .collect()
+    }
+
+    /// The vanishing polynomial of all the points, i.e. the root of the tree.
+    pub fn vanishing_polynomial(&self) -> &DensePolynomial<F> {
+        &self.layers.last().unwrap()[0]
+    }
+
+    /// Evaluate `poly` at each of the points, in order.
+    pub fn evaluate<B, Dft>(&self, poly: &DensePolynomial<F>, dft: &Dft) -> Vec<F>
+    where
+        B: TwoAdicField,
+        F: ExtensionField<B>,
+        Dft: TwoAdicSubgroupDft<B>,
+    {
+        let (_, root_remainder) = poly.divide_with_remainder_fft(self.vanishing_polynomial(), dft);
+        let mut remainders = vec![root_remainder];
+        for layer in self.layers.iter().rev().skip(1) {
+            remainders = layer
+                .iter()
+                .enumerate()
+                .map(|(i, node)| remainders[i / 2].divide_with_remainder_fft(node, dft).1)
+                .collect();
+        }
+        // Modulo `x - x_i`, what remains is the constant `poly(x_i)`.
+        remainders
+            .iter()
+            .map(|r| r.coeffs().first().copied().unwrap_or(F::ZERO))
+            .collect()
+    }
+
+    /// Return the unique polynomial of degree less than the number of points which takes the
+    /// value `values[i]` at the `i`'th point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of values does not match the number of points, or if the points are
+    /// not distinct.
+    pub fn interpolate<B, Dft>(&self, values: &[F], dft: &Dft) -> DensePolynomial<F>
+    where
+        B: TwoAdicField,
+        F: ExtensionField<B>,
+        Dft: TwoAdicSubgroupDft<B>,
+    {
+        assert_eq!(values.len(), self.layers[0].len());
+
+        // With `m` the vanishing polynomial, the interpolant is
+        // `sum_i values[i] / m'(x_i) * m(x) / (x - x_i)`.
+        let denominators = self.evaluate(&self.vanishing_polynomial().derivative(), dft);
+        assert!(
+            denominators.iter().all(|d| !d.is_zero()),
+            "interpolation points must be distinct"
+        );
+        let weights = batch_multiplicative_inverse(&denominators);
+        let mut combined: Vec<DensePolynomial<F>> = values
+            .iter()
+            .zip(weights)
+            .map(|(&v, w)| DensePolynomial::constant(v * w))
+            .collect();
+
+        // Going up, a node combines its children as `left * m_right + right * m_left`.
+        for layer in &self.layers[..self.layers.len() - 1] {
+            combined = combined
+                .chunks(2)
+                .zip(layer.chunks(2))
+                .map(|(c, m)| match (c, m) {
+                    ([c_left, c_right], [m_left, m_right]) => {
+                        &c_left.mul_with_dft(m_right, dft) + &c_right.mul_with_dft(m_left, dft)
+                    }
+                    ([c_single], [_]) => c_single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+        combined.pop().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use p3_dft::Radix2DitParallel;
+    use p3_field::PrimeCharacteristicRing;
+    use p3_goldilocks::Goldilocks;
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::SubproductTree;
+    use crate::DensePolynomial;
+
+    type F = Goldilocks;
+
+    #[test]
+    fn evaluate_matches_horner() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let dft = Radix2DitParallel::default();
+        for num_points in [1, 2, 5, 8, 13, 100, 300] {
+            let points: Vec<F> = (0..num_points).map(|_| rng.random()).collect();
+            let tree = SubproductTree::new(&points, &dft);
+            assert_eq!(tree.points(), points);
+            assert_eq!(
+                tree.vanishing_polynomial(),
+                &DensePolynomial::from_roots(&points)
+            );
+
+            for len in [0, 1, num_points, 3 * num_points] {
+                let poly = DensePolynomial::new((0..len).map(|_| rng.random()).collect());
+                let expected: Vec<F> = points.iter().map(|&x| poly.evaluate(x)).collect();
+                assert_eq!(tree.evaluate(&poly, &dft), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn interpolate_roundtrip() {
+        let mut rng = SmallRng::seed_from_u64(2);
+        let dft = Radix2DitParallel::default();
+        for num_points in [1, 3, 4, 11, 16, 100, 256] {
+            let points: Vec<F> = (0..num_points).map(|_| rng.random()).collect();
+            let tree = SubproductTree::new(&points, &dft);
+
+            let values: Vec<F> = (0..num_points).map(|_| rng.random()).collect();
+            let poly = tree.interpolate(&values, &dft);
+            assert!(poly.degree().is_none_or(|d| d < num_points));
+            assert_eq!(tree.evaluate(&poly, &dft), values);
+
+            let poly = DensePolynomial::new((0..num_points).map(|_| rng.random()).collect());
+            assert_eq!(tree.interpolate(&tree.evaluate(&poly, &dft), &dft), poly);
+        }
+    }
+
+    #[test]
+    #[should_panic = "distinct"]
+    fn interpolate_repeated_points() {
+        let dft = Radix2DitParallel::default();
+        let tree = SubproductTree::new(&[F::ONE, F::TWO, F::ONE], &dft);
+        tree.interpolate(&[F::ZERO, F::ONE, F::TWO], &dft);
+    }
+}
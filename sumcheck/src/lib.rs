@@ -0,0 +1,11 @@
+//! The sumcheck protocol for products of multilinear polynomials over the Boolean hypercube.
+
+#![no_std]
+
+extern crate alloc;
+
+mod proof;
+pub mod prover;
+pub mod verifier;
+
+pub use proof::*;
@@ -0,0 +1,71 @@
+use alloc::vec::Vec;
+
+use p3_field::Field;
+use p3_multilinear_util::poly::eval_eq;
+use serde::{Deserialize, Serialize};
+
+/// A sumcheck proof, consisting of one univariate polynomial per round.
+///
+/// Each round polynomial is given by its evaluations at `0, 1, ..., degree`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SumcheckProof<EF> {
+    /// The evaluations of the round polynomials, in the order the rounds are run.
+    pub round_polys: Vec<Vec<EF>>,
+}
+
+/// What remains to be checked after the sumcheck rounds.
+///
+/// The verifier has reduced the claim about the sum over the hypercube to a claim about a single
+/// point: the summand evaluated at `point` should equal `expected_eval`. The caller checks this
+/// with evaluations of the multilinear factors at `point`, e.g. obtained from a PCS opening.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SumcheckSubclaim<EF> {
+    /// The random point, with one challenge per variable.
+    pub point: Vec<EF>,
+    /// The claimed value of the summand at `point`.
+    pub expected_eval: EF,
+    /// The degree of the summand in each variable, as passed to
+    /// [`verify`](crate::verifier::verify).
+    pub degree: usize,
+}
+
+impl<EF: Field> SumcheckSubclaim<EF> {
+    /// Check the subclaim, given the evaluations of the multilinear factors at `point` and the
+    /// point `z` of the `eq(x, z)` factor, if the sum had one.
+    ///
+    /// As for the claims proven by [`prove`](crate::prover::prove), the number of factors,
+    /// including the `eq` factor, must be `degree`, and `z` must have one coordinate per variable.
+    /// Otherwise, this returns [`SumcheckError::InvalidProofShape`].
+    pub fn check_evals(&self, evals: &[EF], eq_point: Option<&[EF]>) -> Result<(), SumcheckError> {
+        if evals.len() + usize::from(eq_point.is_some()) != self.degree
+            || eq_point.is_some_and(|z| z.len() != self.point.len())
+        {
+            return Err(SumcheckError::InvalidProofShape);
+        }
+        let product: EF = evals.iter().copied().product();
+        let eq_eval = eq_point.map_or(EF::ONE, |z| eval_eq(z, &self.point));
+        if product * eq_eval == self.expected_eval {
+            Ok(())
+        } else {
+            Err(SumcheckError::FinalEvalMismatch)
+        }
+    }
+}
+
+/// The reasons a sumcheck proof can be rejected.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SumcheckError {
+    /// The proof has the wrong number of rounds, or a round polynomial of the wrong degree, or the
+    /// final evaluations do not match the shape of the claim.
+    InvalidProofShape,
+    /// The field characteristic is at most the degree of the round polynomials, so their
+    /// evaluations at `0, ..., degree` do not determine them.
+    DegreeExceedsCharacteristic,
+    /// A round polynomial does not sum to the claim of the previous round over `{0, 1}`.
+    SumMismatch {
+        /// The index of the first round whose polynomial is inconsistent.
+        round: usize,
+    },
+    /// The final evaluations do not match the claim left by the last round.
+    FinalEvalMismatch,
+}
@@ -0,0 +1,227 @@
+//! The sumcheck prover.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use p3_challenger::FieldChallenger;
+use p3_field::{
+    Algebra, ExtensionField, Field, PackedFieldExtension, PackedValue, PrimeCharacteristicRing,
+};
+use p3_matrix::dense::RowMajorMatrix;
+use p3_maybe_rayon::prelude::*;
+use p3_multilinear_util::eq_batch::eval_eq_batch;
+use p3_util::log2_strict_usize;
+use tracing::{info_span, instrument};
+
+use crate::SumcheckProof;
+
+/// The number of hypercube points each thread handles at a time when computing a round.
+const CHUNK_SIZE: usize = 1 << 10;
+
+/// The output of the sumcheck prover.
+#[derive(Clone, Debug)]
+pub struct SumcheckProverOutput<EF> {
+    pub proof: SumcheckProof<EF>,
+    /// The random point the claim was reduced to.
+    pub point: Vec<EF>,
+    /// The evaluations of each multilinear factor at `point`.
+    pub evals: Vec<EF>,
+}
+
+/// Prove the claim `sum_{x in {0,1}^n} eq(x, z) * prod_k P_k(x) = claimed_sum`.
+///
+/// Each `P_k` is a multilinear polynomial over the base field, given by its evaluations over the
+/// hypercube in big-endian order, i.e. the first variable selects between the two halves of the
+/// table. The `eq(x, z)` factor is only present if `eq_point` is given, in which case it holds
+/// `z`. The round polynomials thus have degree `polys.len()`, plus one if `eq_point` is given.
+///
+/// The first round runs over packed base field values. After the first challenge, the tables are
+/// folded into the extension field for the remaining rounds.
+///
+/// The caller is responsible for observing the claimed sum before calling this, if it is not
+/// already bound to the transcript.
+///
+/// # Panics
+///
+/// Panics if `polys` is empty, the tables are not all of the same power-of-two length, or
+/// `eq_point` does not have one coordinate per variable.
+#[instrument(skip_all, fields(num_polys = polys.len()))]
+pub fn prove<F, EF, Challenger>(
+    polys: &[Vec<F>],
+    eq_point: Option<&[EF]>,
+    challenger: &mut Challenger,
+) -> SumcheckProverOutput<EF>
+where
+    F: Field,
+    EF: ExtensionField<F>,
+    Challenger: FieldChallenger<F>,
+{
+    assert!(!polys.is_empty(), "sumcheck needs at least one polynomial");
+    let len = polys[0].len();
+    assert!(polys.iter().all(|p| p.len() == len));
+    let num_vars = log2_strict_usize(len);
+    let num_evals = polys.len() + usize::from(eq_point.is_some()) + 1;
+
+    let mut eq_table = eq_point.map(|z| {
+        assert_eq!(z.len(), num_vars);
+        let mut table = EF::zero_vec(len);
+        eval_eq_batch::<F, EF, false>(
+            RowMajorMatrix::new_col(z.to_vec()).as_view(),
+            &mut table,
+            &[EF::ONE],
+        );
+        table
+    });
+
+    if num_vars == 0 {
+        return SumcheckProverOutput {
+            proof: SumcheckProof {
+                round_polys: Vec::new(),
+            },
+            point: Vec::new(),
+            evals: polys.iter().map(|p| EF::from(p[0])).collect(),
+        };
+    }
+
+    let mut round_polys = Vec::with_capacity(num_vars);
+    let mut point = Vec::with_capacity(num_vars);
+
+    // The first round is over the base field.
+    let round_poly = info_span!("base round").in_scope(|| {
+        let polys: Vec<&[F]> = polys.iter().map(|p| p.as_slice()).collect();
+        base_round_evals(&polys, eq_table.as_deref(), num_evals)
+    });
+    let r = observe_and_sample(challenger, &round_poly);
+    round_polys.push(round_poly);
+    point.push(r);
+    let mut tables: Vec<Vec<EF>> = polys.iter().map(|p| fold(p, r)).collect();
+    eq_table = eq_table.map(|t| fold(&t, r));
+
+    for _ in 1..num_vars {
+        let polys: Vec<&[EF]> = tables.iter().map(|t| t.as_slice()).collect();
+        let round_poly = round_evals::<EF, EF>(&polys, eq_table.as_deref(), num_evals);
+        let r = observe_and_sample(challenger, &round_poly);
+        round_polys.push(round_poly);
+        point.push(r);
+        tables = tables.iter().map(|t| fold(t, r)).collect();
+        eq_table = eq_table.map(|t| fold(&t, r));
+    }
+
+    SumcheckProverOutput {
+        proof: SumcheckProof { round_polys },
+        point,
+        evals: tables.iter().map(|t| t[0]).collect(),
+    }
+}
+
+/// Observe the round polynomial and sample the challenge for its variable.
+fn observe_and_sample<F, EF, Challenger>(challenger: &mut Challenger, round_poly: &[EF]) -> EF
+where
+    F: Field,
+    EF: ExtensionField<F>,
+    Challenger: FieldChallenger<F>,
+{
+    for &eval in round_poly {
+        challenger.observe_algebra_element(eval);
+    }
+    challenger.sample_algebra_element()
+}
+
+/// Compute the evaluations of the first round polynomial, using packed base field arithmetic
+/// when the tables are large enough.
+fn base_round_evals<F, EF>(polys: &[&[F]], eq_table: Option<&[EF]>, num_evals: usize) -> Vec<EF>
+where
+    F: Field,
+    EF: ExtensionField<F>,
+{
+    let width = F::Packing::WIDTH;
+    let half = polys[0].len() / 2;
+    if !half.is_multiple_of(width) {
+        return match eq_table {
+            Some(eq) => round_evals::<F, EF>(polys, Some(eq), num_evals),
+            None => round_evals::<F, F>(polys, None, num_evals)
+                .into_iter()
+                .map(EF::from)
+                .collect(),
+        };
+    }
+
+    let packed: Vec<&[F::Packing]> = polys.iter().map(|p| F::Packing::pack_slice(p)).collect();
+    match eq_table {
+        Some(eq) => {
+            let eq_packed: Vec<EF::ExtensionPacking> = eq
+                .chunks_exact(width)
+                .map(EF::ExtensionPacking::from_ext_slice)
+                .collect();
+            round_evals::<F::Packing, EF::ExtensionPacking>(&packed, Some(&eq_packed), num_evals)
+                .into_iter()
+                .map(|sum| EF::ExtensionPacking::to_ext_iter([sum]).sum())
+                .collect()
+        }
+        None => round_evals::<F::Packing, F::Packing>(&packed, None, num_evals)
+            .into_iter()
+            .map(|sum| EF::from(sum.as_slice().iter().copied().sum::<F>()))
+            .collect(),
+    }
+}
+
+/// Compute `g(t) = sum_{x'} eq(t, x') * prod_k P_k(t, x')` for `t = 0, ..., num_evals - 1`,
+/// where `P_k(t, x')` interpolates linearly between the two halves of each table.
+///
+/// The products are computed in `P` and the sums in `W`, which is `P` itself or, with an `eq`
+/// factor, an algebra over it.
+fn round_evals<P, W>(polys: &[&[P]], eq_table: Option<&[W]>, num_evals: usize) -> Vec<W>
+where
+    P: PrimeCharacteristicRing + Copy + Send + Sync,
+    W: Algebra<P> + Copy + Send + Sync,
+{
+    let half = polys[0].len() / 2;
+    let num_chunks = half.div_ceil(CHUNK_SIZE);
+    (0..num_chunks).into_par_iter().par_fold_reduce(
+        || W::zero_vec(num_evals),
+        |mut sums, chunk| {
+            let mut values = vec![P::ZERO; polys.len()];
+            let mut diffs = vec![P::ZERO; polys.len()];
+            for i in chunk * CHUNK_SIZE..((chunk + 1) * CHUNK_SIZE).min(half) {
+                for ((value, diff), poly) in values.iter_mut().zip(&mut diffs).zip(polys) {
+                    *value = poly[i];
+                    *diff = poly[half + i] - poly[i];
+                }
+                let mut eq = eq_table.map(|eq| (eq[i], eq[half + i] - eq[i]));
+                for sum in &mut sums {
+                    let product: P = values.iter().copied().product();
+                    match &mut eq {
+                        Some((eq_value, eq_diff)) => {
+                            *sum += *eq_value * product;
+                            *eq_value += *eq_diff;
+                        }
+                        None => *sum += product,
+                    }
+                    for (value, &diff) in values.iter_mut().zip(&diffs) {
+                        *value += diff;
+                    }
+                }
+            }
+            sums
+        },
+        |mut a, b| {
+            for (a, b) in a.iter_mut().zip(b) {
+                *a += b;
+            }
+            a
+        },
+    )
+}
+
+/// Fix the first variable of a table to `r`, halving its length.
+fn fold<T, EF>(table: &[T], r: EF) -> Vec<EF>
+where
+    T: PrimeCharacteristicRing + Copy + Send + Sync,
+    EF: Algebra<T> + Copy + Send + Sync,
+{
+    let (lo, hi) = table.split_at(table.len() / 2);
+    lo.par_iter()
+        .zip(hi)
+        .map(|(&lo, &hi)| r * (hi - lo) + lo)
+        .collect()
+}
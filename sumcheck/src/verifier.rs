@@ -0,0 +1,245 @@
+//! The sumcheck verifier.
+
+use alloc::vec::Vec;
+
+use p3_challenger::FieldChallenger;
+use p3_field::{ExtensionField, Field};
+use p3_interpolation::{barycentric_weights, interpolate_lagrange_with_weights};
+use p3_matrix::dense::RowMajorMatrixView;
+
+use crate::{SumcheckError, SumcheckProof, SumcheckSubclaim};
+
+/// Verify the sumcheck rounds for a claim `sum_{x in {0,1}^num_vars} f(x) = claimed_sum`, where
+/// `f` has degree at most `degree` in each variable.
+///
+/// For the claims proven by [`prove`](crate::prover::prove), `degree` is the number of
+/// multilinear factors, plus one if there is an `eq` factor.
+///
+/// On success, returns the subclaim about `f` at a random point, which the caller must still
+/// check, e.g. with [`SumcheckSubclaim::check_evals`].
+pub fn verify<F, EF, Challenger>(
+    proof: &SumcheckProof<EF>,
+    claimed_sum: EF,
+    num_vars: usize,
+    degree: usize,
+    challenger: &mut Challenger,
+) -> Result<SumcheckSubclaim<EF>, SumcheckError>
+where
+    F: Field,
+    EF: ExtensionField<F>,
+    Challenger: FieldChallenger<F>,
+{
+    // Each round polynomial is checked through its evaluations at `0` and `1`, so it must have
+    // degree at least one.
+    if degree == 0
+        || proof.round_polys.len() != num_vars
+        || proof.round_polys.iter().any(|p| p.len() != degree + 1)
+    {
+        return Err(SumcheckError::InvalidProofShape);
+    }
+
+    // The round polynomials are given by their evaluations at `0, ..., degree`. These nodes are
+    // distinct exactly when the characteristic exceeds `degree`, i.e. when none of `1, ..., degree`
+    // vanishes in the field.
+    let nodes: Vec<EF> = (0..=degree).map(EF::from_usize).collect();
+    if nodes[1..].iter().any(|node| node.is_zero()) {
+        return Err(SumcheckError::DegreeExceedsCharacteristic);
+    }
+    let weights = barycentric_weights(&nodes);
+
+    let mut claim = claimed_sum;
+    let mut point = Vec::with_capacity(num_vars);
+    for (round, round_poly) in proof.round_polys.iter().enumerate() {
+        if round_poly[0] + round_poly[1] != claim {
+            return Err(SumcheckError::SumMismatch { round });
+        }
+        for &eval in round_poly {
+            challenger.observe_algebra_element(eval);
+        }
+        let r: EF = challenger.sample_algebra_element();
+        claim = interpolate_lagrange_with_weights(
+            &nodes,
+            &weights,
+            &RowMajorMatrixView::new_col(round_poly),
+            r,
+        )[0];
+        point.push(r);
+    }
+
+    Ok(SumcheckSubclaim {
+        point,
+        expected_eval: claim,
+        degree,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use p3_baby_bear::{BabyBear, Poseidon2BabyBear, default_babybear_poseidon2_16};
+    use p3_challenger::DuplexChallenger;
+    use p3_field::PrimeCharacteristicRing;
+    use p3_field::extension::BinomialExtensionField;
+    use p3_multilinear_util::poly::eval_eq;
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::verify;
+    use crate::SumcheckError;
+    use crate::prover::prove;
+
+    type F = BabyBear;
+    type EF = BinomialExtensionField<F, 4>;
+    type Challenger = DuplexChallenger<F, Poseidon2BabyBear<16>, 16, 8>;
+
+    fn challenger() -> Challenger {
+        DuplexChallenger::new(default_babybear_poseidon2_16())
+    }
+
+    /// The bits of `x` in big-endian order, as field elements.
+    fn bits(x: usize, num_vars: usize) -> Vec<EF> {
+        (0..num_vars)
+            .rev()
+            .map(|i| EF::from_bool((x >> i) & 1 == 1))
+            .collect()
+    }
+
+    fn claimed_sum(polys: &[Vec<F>], eq_point: Option<&[EF]>) -> EF {
+        let num_vars = polys[0].len().trailing_zeros() as usize;
+        (0..polys[0].len())
+            .map(|x| {
+                let product = EF::from(polys.iter().map(|p| p[x]).product::<F>());
+                eq_point.map_or(product, |z| product * eval_eq(z, &bits(x, num_vars)))
+            })
+            .sum()
+    }
+
+    /// Evaluate a multilinear polynomial, given by its big-endian table, at `point`.
+    fn eval_multilinear(table: &[F], point: &[EF]) -> EF {
+        (0..table.len())
+            .map(|x| eval_eq(&bits(x, point.len()), point) * table[x])
+            .sum()
+    }
+
+    fn prove_and_verify(num_vars: usize, num_polys: usize, with_eq: bool) {
+        let mut rng = SmallRng::seed_from_u64((num_vars * 10 + num_polys) as u64);
+        let polys: Vec<Vec<F>> = (0..num_polys)
+            .map(|_| (0..1 << num_vars).map(|_| rng.random()).collect())
+            .collect();
+        let eq_point: Option<Vec<EF>> =
+            with_eq.then(|| (0..num_vars).map(|_| rng.random()).collect());
+        let sum = claimed_sum(&polys, eq_point.as_deref());
+
+        let output = prove(&polys, eq_point.as_deref(), &mut challenger());
+        for (poly, &eval) in polys.iter().zip(&output.evals) {
+            assert_eq!(eval_multilinear(poly, &output.point), eval);
+        }
+
+        let degree = num_polys + usize::from(with_eq);
+        let subclaim = verify(&output.proof, sum, num_vars, degree, &mut challenger()).unwrap();
+        assert_eq!(subclaim.point, output.point);
+        subclaim
+            .check_evals(&output.evals, eq_point.as_deref())
+            .unwrap();
+
+        // A wrong claim is caught in the first round.
+        if num_vars > 0 {
+            assert_eq!(
+                verify(
+                    &output.proof,
+                    sum + EF::ONE,
+                    num_vars,
+                    degree,
+                    &mut challenger()
+                ),
+                Err(SumcheckError::SumMismatch { round: 0 })
+            );
+        }
+    }
+
+    #[test]
+    fn products_of_multilinears() {
+        for num_vars in [0, 1, 3, 6, 12] {
+            for num_polys in [1, 2, 3, 5] {
+                prove_and_verify(num_vars, num_polys, false);
+            }
+        }
+    }
+
+    #[test]
+    fn with_eq_factor() {
+        for num_vars in [1, 2, 5, 11] {
+            for num_polys in [1, 3] {
+                prove_and_verify(num_vars, num_polys, true);
+            }
+        }
+    }
+
+    #[test]
+    fn tampered_proofs_are_rejected() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let num_vars = 5;
+        let polys: Vec<Vec<F>> = (0..2)
+            .map(|_| (0..1 << num_vars).map(|_| rng.random()).collect())
+            .collect();
+        let sum = claimed_sum(&polys, None);
+        let output = prove::<F, EF, _>(&polys, None, &mut challenger());
+
+        // Changing a later round breaks the consistency with the previous round.
+        let mut proof = output.proof.clone();
+        proof.round_polys[2][1] += EF::ONE;
+        assert_eq!(
+            verify(&proof, sum, num_vars, 2, &mut challenger()),
+            Err(SumcheckError::SumMismatch { round: 2 })
+        );
+
+        // Shifting both evaluations of the last round keeps the sum but changes the final claim.
+        let mut proof = output.proof.clone();
+        proof.round_polys[4][0] += EF::ONE;
+        proof.round_polys[4][1] -= EF::ONE;
+        let subclaim = verify(&proof, sum, num_vars, 2, &mut challenger()).unwrap();
+        assert_eq!(
+            subclaim.check_evals(&output.evals, None),
+            Err(SumcheckError::FinalEvalMismatch)
+        );
+
+        // Missing or extra evaluations, or an `eq` point of the wrong length.
+        let subclaim = verify(&output.proof, sum, num_vars, 2, &mut challenger()).unwrap();
+        subclaim.check_evals(&output.evals, None).unwrap();
+        assert_eq!(
+            subclaim.check_evals(&output.evals[..1], None),
+            Err(SumcheckError::InvalidProofShape)
+        );
+        let mut evals = output.evals.clone();
+        evals.push(EF::ONE);
+        assert_eq!(
+            subclaim.check_evals(&evals, None),
+            Err(SumcheckError::InvalidProofShape)
+        );
+        let eq_point = vec![EF::ONE; num_vars - 1];
+        assert_eq!(
+            subclaim.check_evals(&output.evals[..1], Some(&eq_point)),
+            Err(SumcheckError::InvalidProofShape)
+        );
+
+        // Wrong degree or number of rounds.
+        assert_eq!(
+            verify(&output.proof, sum, num_vars, 3, &mut challenger()),
+            Err(SumcheckError::InvalidProofShape)
+        );
+        assert_eq!(
+            verify(&output.proof, sum, num_vars + 1, 2, &mut challenger()),
+            Err(SumcheckError::InvalidProofShape)
+        );
+
+        // Degree zero round polynomials have a single evaluation and must be rejected.
+        let mut proof = output.proof.clone();
+        proof.round_polys.iter_mut().for_each(|p| p.truncate(1));
+        assert_eq!(
+            verify(&proof, sum, num_vars, 0, &mut challenger()),
+            Err(SumcheckError::InvalidProofShape)
+        );
+    }
+}